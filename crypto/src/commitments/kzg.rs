@@ -5,9 +5,12 @@ use lambdaworks_math::{
     cyclic_group::IsGroup,
     elliptic_curve::traits::IsPairing,
     errors::DeserializationError,
-    field::{element::FieldElement, traits::IsPrimeField},
+    field::{
+        element::FieldElement,
+        traits::{IsFFTField, IsPrimeField},
+    },
     msm::pippenger::msm,
-    polynomial::Polynomial,
+    polynomial::{subproduct_tree::SubproductTree, InterpolateError, Polynomial},
     traits::{Deserializable, Serializable},
     unsigned_integer::element::UnsignedInteger,
 };
@@ -250,6 +253,81 @@ impl<const N: usize, F: IsPrimeField<RepresentativeType = UnsignedInteger<N>>, P
     }
 }
 
+/// Opening of a polynomial at several points at once, with the scheme of Boneh, Drake, Fisch
+/// and Gabizon (https://eprint.iacr.org/2020/081, section 4), which only needs the first two
+/// powers of the secondary group of the SRS. With `I` the polynomial that interpolates the
+/// opened values and `Z` the one that vanishes on the points, the prover commits to the quotient
+/// `q = (p - I) / Z`, and then opens `p - Z(z) q` at a random challenge `z` of the verifier.
+impl<const N: usize, F, P> KateZaveruchaGoldberg<F, P>
+where
+    F: IsFFTField + IsPrimeField<RepresentativeType = UnsignedInteger<N>>,
+    P: IsPairing,
+{
+    /// Returns the quotient `q` of `p` at the points `xs`, where it takes the values `ys`,
+    /// and its commitment, which is the first message of the opening. The interpolation and the
+    /// division switch to their fast versions when there are many points.
+    /// If `ys` aren't the values of `p`, the opening won't verify.
+    pub fn commit_multi_quotient(
+        &self,
+        xs: &[FieldElement<F>],
+        ys: &[FieldElement<F>],
+        p: &Polynomial<FieldElement<F>>,
+    ) -> Result<(Polynomial<FieldElement<F>>, P::G1Point), InterpolateError> {
+        let interpolant = Polynomial::fast_interpolate(xs, ys)?;
+        let vanishing = SubproductTree::new(xs)?.vanishing_polynomial();
+        let (quotient, _remainder) = (p - interpolant).fast_division::<F>(&vanishing)?;
+        let commitment = self.commit(&quotient);
+        Ok((quotient, commitment))
+    }
+
+    /// Returns the proof of the opening of `p` at the points `xs`, with the `quotient` from
+    /// [Self::commit_multi_quotient] and a challenge `z` sampled after its commitment.
+    pub fn open_multi(
+        &self,
+        xs: &[FieldElement<F>],
+        p: &Polynomial<FieldElement<F>>,
+        quotient: &Polynomial<FieldElement<F>>,
+        z: &FieldElement<F>,
+    ) -> P::G1Point {
+        // p - I = Z q, so p - Z(z) q takes the value I(z) at z.
+        let vanishing_at_z = vanishing_polynomial_at(xs, z);
+        let reduced = p - quotient * vanishing_at_z;
+        let y = reduced.evaluate(z);
+        self.open(z, &y, &reduced)
+    }
+
+    /// Checks that the polynomial committed in `p_commitment` takes the values `ys` at the
+    /// points `xs`, given the commitment to the quotient and the proof at the challenge `z`.
+    pub fn verify_multi(
+        &self,
+        xs: &[FieldElement<F>],
+        ys: &[FieldElement<F>],
+        p_commitment: &P::G1Point,
+        quotient_commitment: &P::G1Point,
+        proof: &P::G1Point,
+        z: &FieldElement<F>,
+    ) -> bool {
+        let Ok(interpolant) = Polynomial::fast_interpolate(xs, ys) else {
+            return false;
+        };
+        let vanishing_at_z = vanishing_polynomial_at(xs, z);
+        let reduced_commitment = p_commitment.operate_with(
+            &quotient_commitment
+                .operate_with_self(vanishing_at_z.representative())
+                .neg(),
+        );
+        self.verify(z, &interpolant.evaluate(z), &reduced_commitment, proof)
+    }
+}
+
+/// Returns the value at `z` of the polynomial that vanishes on `xs`.
+fn vanishing_polynomial_at<F: IsPrimeField>(
+    xs: &[FieldElement<F>],
+    z: &FieldElement<F>,
+) -> FieldElement<F> {
+    xs.iter().fold(FieldElement::one(), |acc, x| acc * (z - x))
+}
+
 #[cfg(test)]
 mod tests {
     use lambdaworks_math::{
@@ -400,6 +478,42 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn multi_point_opening_should_verify() {
+        let kzg = KZG::new(create_srs());
+        let p = Polynomial::<FrElement>::new(&(1..40).map(FieldElement::from).collect::<Vec<_>>());
+        let p_commitment = kzg.commit(&p);
+        let xs: Vec<FrElement> = (0..7).map(|i| FieldElement::from(3 * i + 2)).collect();
+        let ys: Vec<FrElement> = xs.iter().map(|x| p.evaluate(x)).collect();
+
+        let (quotient, quotient_commitment) = kzg.commit_multi_quotient(&xs, &ys, &p).unwrap();
+        let z = FieldElement::from(1000);
+        let proof = kzg.open_multi(&xs, &p, &quotient, &z);
+        assert!(kzg.verify_multi(&xs, &ys, &p_commitment, &quotient_commitment, &proof, &z));
+
+        let mut wrong_ys = ys.clone();
+        wrong_ys[3] += FrElement::one();
+        assert!(!kzg.verify_multi(
+            &xs,
+            &wrong_ys,
+            &p_commitment,
+            &quotient_commitment,
+            &proof,
+            &z
+        ));
+        let (quotient, quotient_commitment) =
+            kzg.commit_multi_quotient(&xs, &wrong_ys, &p).unwrap();
+        let proof = kzg.open_multi(&xs, &p, &quotient, &z);
+        assert!(!kzg.verify_multi(
+            &xs,
+            &wrong_ys,
+            &p_commitment,
+            &quotient_commitment,
+            &proof,
+            &z
+        ));
+    }
+
     #[test]
     fn serialize_deserialize_srs() {
         let srs = create_srs();
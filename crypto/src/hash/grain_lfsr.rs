@@ -0,0 +1,145 @@
+use lambdaworks_math::field::{element::FieldElement, traits::IsPrimeField};
+
+const STATE_SIZE: usize = 80;
+
+/// Grain LFSR in self-shrinking mode, the pseudorandom bit generator used by the Poseidon and
+/// Poseidon2 reference implementations to generate round constants.
+///
+/// The 80 bits of the initial state encode the instance: 2 bits for the field (1 for prime
+/// fields), 4 bits for the S-box (0 for `x^alpha`), 12 bits for the size of the field in bits,
+/// 12 bits for the width, 10 bits for the number of full rounds, 10 bits for the number of
+/// partial rounds and 30 bits set to one. The first 160 bits are discarded.
+pub struct GrainLFSR {
+    state: [bool; STATE_SIZE],
+    /// Index of the oldest bit of the state, which is dropped on the next update.
+    head: usize,
+}
+
+impl GrainLFSR {
+    pub fn new(field_bits: usize, width: usize, full_rounds: usize, partial_rounds: usize) -> Self {
+        let fields = [
+            (1, 2),
+            (0, 4),
+            (field_bits, 12),
+            (width, 12),
+            (full_rounds, 10),
+            (partial_rounds, 10),
+            ((1 << 30) - 1, 30),
+        ];
+        let mut state = [false; STATE_SIZE];
+        let bits = fields
+            .iter()
+            .flat_map(|(value, size)| (0..*size).rev().map(move |i| (value >> i) & 1 == 1));
+        for (bit, value) in state.iter_mut().zip(bits) {
+            *bit = value;
+        }
+
+        let mut lfsr = Self { state, head: 0 };
+        for _ in 0..160 {
+            lfsr.update();
+        }
+        lfsr
+    }
+
+    /// Shifts the register, returning the new bit
+    /// `b_{i+80} = b_{i+62} + b_{i+51} + b_{i+38} + b_{i+23} + b_{i+13} + b_i`.
+    fn update(&mut self) -> bool {
+        let bit = |i: usize| self.state[(self.head + i) % STATE_SIZE];
+        let new_bit = bit(62) ^ bit(51) ^ bit(38) ^ bit(23) ^ bit(13) ^ bit(0);
+        self.state[self.head] = new_bit;
+        self.head = (self.head + 1) % STATE_SIZE;
+        new_bit
+    }
+
+    /// Returns the next output bit. The register bits are taken in pairs, and the second bit
+    /// of a pair is output only when the first one is set.
+    pub fn next_bit(&mut self) -> bool {
+        loop {
+            let select = self.update();
+            let bit = self.update();
+            if select {
+                return bit;
+            }
+        }
+    }
+
+    /// Returns the next field element. Numbers of as many bits as the modulus are read, most
+    /// significant bit first, until one of them is less than the modulus.
+    pub fn next_field_element<F: IsPrimeField>(&mut self) -> FieldElement<F> {
+        let n = F::field_bit_size();
+        let modulus_minus_one = F::modulus_minus_one();
+        let one = F::RepresentativeType::from(1);
+        loop {
+            let bits = self.next_bits(n);
+            // Compares the bits with those of `p - 1` from the most significant one.
+            let in_range = bits
+                .iter()
+                .enumerate()
+                .map(|(i, bit)| (*bit, (modulus_minus_one >> (n - 1 - i)) & one == one))
+                .find(|(bit, modulus_bit)| bit != modulus_bit)
+                .is_none_or(|(bit, _)| !bit);
+            if in_range {
+                return field_element_from_bits(&bits);
+            }
+        }
+    }
+
+    /// Returns the number given by the next bits, as many as those of the modulus, reduced
+    /// modulo it. The reference scripts sample the entries of Cauchy matrices this way.
+    pub fn next_reduced_field_element<F: IsPrimeField>(&mut self) -> FieldElement<F> {
+        let bits = self.next_bits(F::field_bit_size());
+        field_element_from_bits(&bits)
+    }
+
+    fn next_bits(&mut self, n: usize) -> Vec<bool> {
+        (0..n).map(|_| self.next_bit()).collect()
+    }
+}
+
+/// Returns the number with the bits `bits`, most significant first, modulo the field modulus.
+fn field_element_from_bits<F: IsPrimeField>(bits: &[bool]) -> FieldElement<F> {
+    bits.iter().fold(FieldElement::zero(), |value, bit| {
+        let value = &value + &value;
+        if *bit {
+            value + FieldElement::one()
+        } else {
+            value
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lambdaworks_math::{
+        field::fields::montgomery_backed_prime_fields::{IsModulus, U256PrimeField},
+        unsigned_integer::element::U256,
+    };
+
+    #[derive(Clone, Debug)]
+    struct BN254FrModulus;
+    impl IsModulus<U256> for BN254FrModulus {
+        const MODULUS: U256 = U256::from_hex_unchecked(
+            "30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001",
+        );
+    }
+    type BN254Fr = U256PrimeField<BN254FrModulus>;
+
+    #[test]
+    fn matches_the_round_constants_of_poseidon_over_bn254() {
+        // The first round constants of the reference Poseidon instance with width 3, 8 full
+        // rounds and 57 partial rounds over the scalar field of BN254.
+        let mut lfsr = GrainLFSR::new(254, 3, 8, 57);
+        let expected = [
+            "0ee9a592ba9a9518d05986d656f40c2114c4993c11bb29938d21d47304cd8e6e",
+            "00f1445235f2148c5986587169fc1bcd887b08d4d00868df5696fff40956e864",
+            "08dff3487e8ac99e1f29a058d0fa80b930c728730b7ab36ce879f3890ecf73f5",
+        ];
+        for constant in expected {
+            assert_eq!(
+                lfsr.next_field_element::<BN254Fr>(),
+                FieldElement::from_hex_unchecked(constant)
+            );
+        }
+    }
+}
@@ -1,4 +1,8 @@
+pub mod grain_lfsr;
 pub mod hash_to_field;
 pub mod pedersen;
 pub mod poseidon;
+pub mod poseidon2;
+pub mod rescue;
 pub mod sha3;
+pub mod sponge;
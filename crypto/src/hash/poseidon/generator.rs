@@ -0,0 +1,657 @@
+use crate::hash::grain_lfsr::GrainLFSR;
+use lambdaworks_math::{
+    field::{element::FieldElement as FE, traits::IsPrimeField},
+    polynomial::Polynomial,
+};
+use std::path::Path;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParameterGenerationError {
+    /// `x^alpha` must be a permutation of the field, so `alpha` must be at least 3 and coprime
+    /// to `p - 1`.
+    InvalidAlpha(u32),
+    /// The rate and the capacity must be positive, and the state must have fewer elements
+    /// than half the modulus.
+    InvalidStateSize(usize),
+    /// No round numbers within the search bounds reach the security level.
+    NoSecureRoundNumbers,
+}
+
+/// Poseidon parameters generated at runtime, holding the same values as an implementation of
+/// [PermutationParameters](super::parameters::PermutationParameters): the MDS matrix is
+/// stored by rows and the round constants by rounds, `STATE_SIZE` for each one.
+///
+/// They are generated like in the reference scripts of Poseidon: the round numbers are the
+/// cheapest ones resisting the known attacks, with two more full rounds and 7.5% more partial
+/// rounds as a security margin, and the round constants come from the Grain LFSR. The MDS
+/// matrix is a Cauchy matrix sampled from the same LFSR, kept only if the minimal polynomials
+/// of its first `2 * STATE_SIZE` powers are irreducible and of maximal degree, as required
+/// for the internal matrix of Poseidon2. This rules out invariant subspace trails through the
+/// partial rounds, and is stricter than the checks of the reference scripts, so the matrix
+/// may differ from theirs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PoseidonParameters<F: IsPrimeField> {
+    pub rate: usize,
+    pub capacity: usize,
+    pub alpha: u32,
+    pub n_full_rounds: usize,
+    pub n_partial_rounds: usize,
+    pub mds_matrix: Vec<FE<F>>,
+    pub round_constants: Vec<FE<F>>,
+}
+
+impl<F: IsPrimeField> PoseidonParameters<F> {
+    /// Generates the parameters for the S-box `x^alpha` and `security_level` bits of security.
+    pub fn generate(
+        rate: usize,
+        capacity: usize,
+        alpha: u32,
+        security_level: usize,
+    ) -> Result<Self, ParameterGenerationError> {
+        let state_size = rate + capacity;
+        if rate == 0 || capacity == 0 {
+            return Err(ParameterGenerationError::InvalidStateSize(state_size));
+        }
+        let (n_full_rounds, n_partial_rounds) =
+            round_numbers::<F>(state_size, alpha, security_level)?;
+
+        let mut lfsr = GrainLFSR::new(
+            F::field_bit_size(),
+            state_size,
+            n_full_rounds,
+            n_partial_rounds,
+        );
+        let round_constants = (0..(n_full_rounds + n_partial_rounds) * state_size)
+            .map(|_| lfsr.next_field_element())
+            .collect();
+        let mds_matrix = secure_cauchy_matrix(&mut lfsr, state_size)
+            .into_iter()
+            .flatten()
+            .collect();
+
+        Ok(Self {
+            rate,
+            capacity,
+            alpha,
+            n_full_rounds,
+            n_partial_rounds,
+            mds_matrix,
+            round_constants,
+        })
+    }
+
+    pub fn state_size(&self) -> usize {
+        self.rate + self.capacity
+    }
+
+    /// Same permutation as [Poseidon::hades_permutation](super::Poseidon::hades_permutation).
+    pub fn hades_permutation(&self, state: &mut [FE<F>]) {
+        let t = self.state_size();
+        assert_eq!(state.len(), t);
+        let half_full_rounds = self.n_full_rounds / 2;
+        for round_number in 0..self.n_full_rounds + self.n_partial_rounds {
+            let constants = &self.round_constants[round_number * t..(round_number + 1) * t];
+            for (value, constant) in state.iter_mut().zip(constants) {
+                *value = &*value + constant;
+            }
+            let is_partial = (half_full_rounds..half_full_rounds + self.n_partial_rounds)
+                .contains(&round_number);
+            if is_partial {
+                state[t - 1] = state[t - 1].pow(self.alpha);
+            } else {
+                for value in state.iter_mut() {
+                    *value = value.pow(self.alpha);
+                }
+            }
+            self.mix(state);
+        }
+    }
+
+    /// Same hash as [Poseidon::hash_many](super::Poseidon::hash_many).
+    pub fn hash_many(&self, inputs: &[FE<F>]) -> FE<F> {
+        let r = self.rate;
+        let mut values = inputs.to_owned();
+        values.push(FE::one());
+        values.resize(values.len().div_ceil(r) * r, FE::zero());
+
+        let mut state = vec![FE::zero(); self.state_size()];
+        for block in values.chunks(r) {
+            for (value, input) in state.iter_mut().zip(block) {
+                *value = &*value + input;
+            }
+            self.hades_permutation(&mut state);
+        }
+        state[0].clone()
+    }
+
+    fn mix(&self, state: &mut [FE<F>]) {
+        let new_state: Vec<FE<F>> = self
+            .mds_matrix
+            .chunks(state.len())
+            .map(|row| {
+                row.iter()
+                    .zip(state.iter())
+                    .fold(FE::zero(), |acc, (entry, value)| acc + entry * value)
+            })
+            .collect();
+        state.clone_from_slice(&new_state);
+    }
+
+    /// Returns Rust source defining `struct_name` and its implementation of
+    /// [PermutationParameters](super::parameters::PermutationParameters) with these parameters,
+    /// like [PoseidonCairoStark252](super::starknet::PoseidonCairoStark252). `field_type` is
+    /// the path of the field. The constants are built with `FieldElement::from_hex_unchecked`,
+    /// which is `const` for the Montgomery backed fields.
+    pub fn rust_source(&self, struct_name: &str, field_type: &str) -> String {
+        let t = self.state_size();
+        let n_rounds = self.n_full_rounds + self.n_partial_rounds;
+        let mut lines = vec![
+            "use lambdaworks_crypto::hash::poseidon::parameters::PermutationParameters;"
+                .to_string(),
+            "use lambdaworks_math::field::element::FieldElement as FE;".to_string(),
+            String::new(),
+            "#[derive(Clone, Default)]".to_string(),
+            format!("pub struct {struct_name};"),
+            String::new(),
+            format!("impl PermutationParameters for {struct_name} {{"),
+            format!("    type F = {field_type};"),
+            format!("    const RATE: usize = {};", self.rate),
+            format!("    const CAPACITY: usize = {};", self.capacity),
+            format!("    const ALPHA: u32 = {};", self.alpha),
+            format!("    const N_FULL_ROUNDS: usize = {};", self.n_full_rounds),
+            format!(
+                "    const N_PARTIAL_ROUNDS: usize = {};",
+                self.n_partial_rounds
+            ),
+            String::new(),
+        ];
+        lines.extend(constants_source("MDS_MATRIX", field_type, &self.mds_matrix));
+        lines.push(format!("    const N_MDS_MATRIX_ROWS: usize = {t};"));
+        lines.push(format!("    const N_MDS_MATRIX_COLS: usize = {t};"));
+        lines.push(String::new());
+        lines.extend(constants_source(
+            "ROUND_CONSTANTS",
+            field_type,
+            &self.round_constants,
+        ));
+        lines.push(format!(
+            "    const N_ROUND_CONSTANTS_ROWS: usize = {n_rounds};"
+        ));
+        lines.push(format!("    const N_ROUND_CONSTANTS_COLS: usize = {t};"));
+        lines.push("}".to_string());
+        lines.push(String::new());
+        lines.join("\n")
+    }
+
+    /// Writes [Self::rust_source] to `path`. It's meant for build scripts, which can write the
+    /// parameters to `OUT_DIR` and `include!` them, so they are generated once at build time.
+    pub fn write_rust_source<P: AsRef<Path>>(
+        &self,
+        path: P,
+        struct_name: &str,
+        field_type: &str,
+    ) -> std::io::Result<()> {
+        std::fs::write(path, self.rust_source(struct_name, field_type))
+    }
+}
+
+fn constants_source<F: IsPrimeField>(
+    name: &str,
+    field_type: &str,
+    values: &[FE<F>],
+) -> Vec<String> {
+    let mut lines = vec![format!(
+        "    const {name}: &'static [FE<{field_type}>] = &["
+    )];
+    lines.extend(
+        values
+            .iter()
+            .map(|value| format!("        FE::from_hex_unchecked(\"{}\"),", to_hex(value))),
+    );
+    lines.push("    ];".to_string());
+    lines
+}
+
+/// Returns the representative of `value` in hexadecimal, without leading zeros.
+fn to_hex<F: IsPrimeField>(value: &FE<F>) -> String {
+    let representative = value.representative();
+    let mask = F::RepresentativeType::from(0xf);
+    let digits = F::field_bit_size().div_ceil(4);
+    let hex: String = (0..digits)
+        .rev()
+        .map(|i| {
+            let nibble = (representative >> (4 * i)) & mask;
+            let digit = (0..16)
+                .find(|digit| F::RepresentativeType::from(*digit) == nibble)
+                .unwrap_or_default();
+            char::from_digit(digit as u32, 16).unwrap_or('0')
+        })
+        .collect();
+    match hex.trim_start_matches('0') {
+        "" => "0".to_string(),
+        trimmed => trimmed.to_string(),
+    }
+}
+
+/// Returns the numbers of full and partial rounds of Poseidon with `state_size` elements and
+/// the S-box `x^alpha` over `F`, for `security_level` bits of security.
+///
+/// Among the round numbers that resist the statistical, interpolation and Gröbner basis
+/// attacks considered by the reference scripts, including the one of eprint 2023/537, it takes
+/// those with the fewest S-boxes, and then adds two full rounds and 7.5% of partial rounds.
+pub fn round_numbers<F: IsPrimeField>(
+    state_size: usize,
+    alpha: u32,
+    security_level: usize,
+) -> Result<(usize, usize), ParameterGenerationError> {
+    validate_alpha::<F>(alpha)?;
+    validate_state_size::<F>(state_size)?;
+
+    let log_p = log2_modulus::<F>();
+    let mut best: Option<(usize, usize, usize)> = None;
+    for partial_rounds in 1..500 {
+        let full_rounds = (4..100).step_by(2).find(|full_rounds| {
+            resists_known_attacks(
+                log_p,
+                state_size,
+                *full_rounds,
+                partial_rounds,
+                alpha,
+                security_level,
+            )
+        });
+        if let Some(full_rounds) = full_rounds {
+            let cost = state_size * full_rounds + partial_rounds;
+            let is_better = best.is_none_or(|(best_cost, best_full_rounds, _)| {
+                cost < best_cost || (cost == best_cost && full_rounds < best_full_rounds)
+            });
+            if is_better {
+                best = Some((cost, full_rounds, partial_rounds));
+            }
+        }
+    }
+    // `ceil(1.075 R_P)`, computed with integers.
+    best.map(|(_, full_rounds, partial_rounds)| {
+        (full_rounds + 2, (partial_rounds * 43).div_ceil(40))
+    })
+    .ok_or(ParameterGenerationError::NoSecureRoundNumbers)
+}
+
+fn resists_known_attacks(
+    log_p: f64,
+    state_size: usize,
+    full_rounds: usize,
+    partial_rounds: usize,
+    alpha: u32,
+    security_level: usize,
+) -> bool {
+    let t = state_size as f64;
+    let r_f = full_rounds as f64;
+    let r_p = partial_rounds as f64;
+    let alpha = alpha as f64;
+    let m = security_level as f64;
+    let n = log_p.ceil();
+    let log_alpha_2 = 1.0 / alpha.log2();
+
+    // Statistical attacks.
+    let r_f_1: f64 = if m <= (log_p - (alpha - 1.0) / 2.0).floor() * (t + 1.0) {
+        6.0
+    } else {
+        10.0
+    };
+    // Interpolation attacks.
+    let r_f_2 = 1.0 + (log_alpha_2 * m.min(n)).ceil() + (t.log2() * log_alpha_2).ceil() - r_p;
+    // Gröbner basis attacks.
+    let r_f_3 = log_alpha_2 * m.min(log_p) - r_p;
+    let r_f_4 = t - 1.0 + log_alpha_2 * (m / (t + 1.0)).min(log_p / 2.0) - r_p;
+    let r_f_5 = (t - 2.0 + m / (2.0 * alpha.log2()) - r_p) / (t - 1.0);
+    let r_f_min = [r_f_1, r_f_2, r_f_3, r_f_4, r_f_5]
+        .into_iter()
+        .map(f64::ceil)
+        .fold(f64::MIN, f64::max);
+
+    // Gröbner basis attack of eprint 2023/537.
+    let r = (state_size / 3) as f64;
+    let over = (r_f - 1.0) * t + r_p + r + r * r_f / 2.0 + r_p + alpha;
+    let under = r * r_f / 2.0 + r_p + alpha;
+    let cost = (2.0 * log2_binomial(over as usize, under as usize)).ceil();
+
+    r_f >= r_f_min && cost >= m
+}
+
+fn log2_binomial(n: usize, k: usize) -> f64 {
+    (1..=k)
+        .map(|i| ((n - k + i) as f64 / i as f64).log2())
+        .sum()
+}
+
+/// Returns the bits of `p - 1`, most significant first.
+fn modulus_minus_one_bits<F: IsPrimeField>() -> impl Iterator<Item = bool> {
+    let bits = F::field_bit_size();
+    let modulus_minus_one = F::modulus_minus_one();
+    let one = F::RepresentativeType::from(1);
+    (0..bits)
+        .rev()
+        .map(move |i| (modulus_minus_one >> i) & one == one)
+}
+
+fn log2_modulus<F: IsPrimeField>() -> f64 {
+    let bits = F::field_bit_size();
+    // The 53 most significant bits fill the mantissa of an `f64`.
+    let top_bits = bits.min(53);
+    let top = modulus_minus_one_bits::<F>()
+        .take(top_bits)
+        .fold(0.0_f64, |acc, bit| 2.0 * acc + if bit { 1.0 } else { 0.0 });
+    top.log2() + (bits - top_bits) as f64
+}
+
+fn validate_alpha<F: IsPrimeField>(alpha: u32) -> Result<(), ParameterGenerationError> {
+    let remainder = modulus_minus_one_bits::<F>().fold(0_u64, |acc, bit| {
+        (2 * acc + u64::from(bit)) % u64::from(alpha.max(1))
+    });
+    if alpha < 3 || gcd(alpha as u64, remainder) != 1 {
+        return Err(ParameterGenerationError::InvalidAlpha(alpha));
+    }
+    Ok(())
+}
+
+fn validate_state_size<F: IsPrimeField>(state_size: usize) -> Result<(), ParameterGenerationError> {
+    // Sampling a Cauchy matrix needs `2 t` distinct elements, and computing characteristic
+    // polynomials needs the inverses of `1..=t`.
+    if state_size < 2 || (1..=2 * state_size as u64).any(|k| FE::<F>::from(k) == FE::zero()) {
+        return Err(ParameterGenerationError::InvalidStateSize(state_size));
+    }
+    Ok(())
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// Samples Cauchy matrices `1 / (x_i + y_j)` like the reference scripts until one is secure.
+fn secure_cauchy_matrix<F: IsPrimeField>(lfsr: &mut GrainLFSR, t: usize) -> Vec<Vec<FE<F>>> {
+    loop {
+        let mut points: Vec<FE<F>>;
+        loop {
+            points = (0..2 * t)
+                .map(|_| lfsr.next_reduced_field_element())
+                .collect();
+            let distinct = points
+                .iter()
+                .enumerate()
+                .all(|(i, point)| !points[..i].contains(point));
+            if distinct {
+                break;
+            }
+        }
+        let (xs, ys) = points.split_at(t);
+        let matrix: Option<Vec<Vec<FE<F>>>> = xs
+            .iter()
+            .map(|x| ys.iter().map(|y| (x + y).inv().ok()).collect())
+            .collect();
+        if let Some(matrix) = matrix {
+            if has_secure_powers(&matrix) {
+                return matrix;
+            }
+        }
+    }
+}
+
+/// Checks that the characteristic polynomials of `M, M^2, ..., M^(2t)` are irreducible, so
+/// that they are also their minimal polynomials and have degree `t`.
+fn has_secure_powers<F: IsPrimeField>(matrix: &[Vec<FE<F>>]) -> bool {
+    let mut power = matrix.to_vec();
+    for _ in 0..2 * matrix.len() {
+        if !characteristic_polynomial(&power).is_irreducible() {
+            return false;
+        }
+        power = matrix_product(matrix, &power);
+    }
+    true
+}
+
+fn matrix_product<F: IsPrimeField>(a: &[Vec<FE<F>>], b: &[Vec<FE<F>>]) -> Vec<Vec<FE<F>>> {
+    a.iter()
+        .map(|row| {
+            (0..b[0].len())
+                .map(|j| {
+                    row.iter()
+                        .zip(b)
+                        .fold(FE::zero(), |acc, (entry, b_row)| acc + entry * &b_row[j])
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Returns `det(x I - M)`, computed with the Faddeev-LeVerrier algorithm: with `M_0 = 0` and
+/// `c_t = 1`, `M_k = M M_(k-1) + c_(t-k+1) I` and `c_(t-k) = -tr(M M_k) / k`.
+fn characteristic_polynomial<F: IsPrimeField>(matrix: &[Vec<FE<F>>]) -> Polynomial<FE<F>> {
+    let t = matrix.len();
+    let mut coefficients = vec![FE::zero(); t + 1];
+    coefficients[t] = FE::one();
+    // `M M_(k-1)`, which is zero for `k = 1`.
+    let mut product = vec![vec![FE::zero(); t]; t];
+    for k in 1..=t {
+        let mut m_k = product;
+        for (i, row) in m_k.iter_mut().enumerate() {
+            row[i] = &row[i] + &coefficients[t - k + 1];
+        }
+        product = matrix_product(matrix, &m_k);
+        let trace = (0..t).fold(FE::zero(), |acc, i| acc + &product[i][i]);
+        let k_inverse = FE::<F>::from(k as u64)
+            .inv()
+            .expect("k is at most the state size, which was checked to be less than the modulus");
+        coefficients[t - k] = -(trace * k_inverse);
+    }
+    Polynomial::new(&coefficients)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hash::poseidon::{
+        parameters::PermutationParameters, starknet::PoseidonCairoStark252, Poseidon,
+    };
+    use lambdaworks_math::{
+        elliptic_curve::short_weierstrass::curves::bls12_381::default_types::FrField,
+        field::fields::{
+            fft_friendly::{
+                babybear::Babybear31PrimeField, stark_252_prime_field::Stark252PrimeField,
+            },
+            montgomery_backed_prime_fields::{IsModulus, U256PrimeField},
+            u64_goldilocks_field::Goldilocks64Field,
+        },
+        unsigned_integer::element::U256,
+    };
+
+    type Goldilocks = FE<Goldilocks64Field>;
+
+    #[derive(Clone, Debug)]
+    struct BN254FrModulus;
+    impl IsModulus<U256> for BN254FrModulus {
+        const MODULUS: U256 = U256::from_hex_unchecked(
+            "30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001",
+        );
+    }
+    type BN254Fr = U256PrimeField<BN254FrModulus>;
+
+    fn poly(coefficients: &[i64]) -> Polynomial<Goldilocks> {
+        let coefficients: Vec<Goldilocks> = coefficients
+            .iter()
+            .map(|c| {
+                let magnitude = Goldilocks::from(c.unsigned_abs());
+                if *c < 0 {
+                    -magnitude
+                } else {
+                    magnitude
+                }
+            })
+            .collect();
+        Polynomial::new(&coefficients)
+    }
+
+    #[test]
+    fn round_numbers_match_known_instances() {
+        assert_eq!(round_numbers::<Stark252PrimeField>(3, 3, 128), Ok((8, 83)));
+        assert_eq!(round_numbers::<FrField>(3, 5, 128), Ok((8, 56)));
+        assert_eq!(round_numbers::<BN254Fr>(3, 5, 128), Ok((8, 56)));
+        assert_eq!(round_numbers::<Goldilocks64Field>(12, 7, 128), Ok((8, 22)));
+        assert_eq!(
+            round_numbers::<Babybear31PrimeField>(16, 7, 128),
+            Ok((8, 13))
+        );
+    }
+
+    #[test]
+    fn constants_match_the_reference_instance_over_bn254() {
+        // Output of the reference script for width 3, x^5, 8 full rounds and 57 partial rounds
+        // over the scalar field of BN254, the instance of circomlib.
+        let mut lfsr = GrainLFSR::new(254, 3, 8, 57);
+        let round_constants: Vec<FE<BN254Fr>> = (0..(8 + 57) * 3)
+            .map(|_| lfsr.next_field_element())
+            .collect();
+        assert_eq!(
+            round_constants[0],
+            FE::from_hex_unchecked(
+                "0ee9a592ba9a9518d05986d656f40c2114c4993c11bb29938d21d47304cd8e6e"
+            )
+        );
+        assert_eq!(
+            round_constants[194],
+            FE::from_hex_unchecked(
+                "1da55cc900f0d21f4a3e694391918a1b3c23b2ac773c6b3ef88e2e4228325161"
+            )
+        );
+
+        let expected_mds = [
+            [
+                "109b7f411ba0e4c9b2b70caf5c36a7b194be7c11ad24378bfedb68592ba8118b",
+                "16ed41e13bb9c0c66ae119424fddbcbc9314dc9fdbdeea55d6c64543dc4903e0",
+                "2b90bba00fca0589f617e7dcbfe82e0df706ab640ceb247b791a93b74e36736d",
+            ],
+            [
+                "2969f27eed31a480b9c36c764379dbca2cc8fdd1415c3dded62940bcde0bd771",
+                "2e2419f9ec02ec394c9871c832963dc1b89d743c8c7b964029b2311687b1fe23",
+                "101071f0032379b697315876690f053d148d4e109f5fb065c8aacc55a0f89bfa",
+            ],
+            [
+                "143021ec686a3f330d5f9e654638065ce6cd79e28c5b3753326244ee65a1b1a7",
+                "176cc029695ad02582a70eff08a6fd99d057e12e58e7d7b6b16cdfabc8ee2911",
+                "19a3fc0a56702bf417ba7fee3802593fa644470307043f7773279cd71d25d5e0",
+            ],
+        ];
+        let mds = secure_cauchy_matrix::<BN254Fr>(&mut lfsr, 3);
+        for (row, expected_row) in mds.iter().zip(expected_mds) {
+            for (entry, expected) in row.iter().zip(expected_row) {
+                assert_eq!(*entry, FE::from_hex_unchecked(expected));
+            }
+        }
+    }
+
+    #[test]
+    fn alpha_must_give_a_permutation() {
+        // 3 divides `p - 1` for Goldilocks.
+        assert_eq!(
+            round_numbers::<Goldilocks64Field>(12, 3, 128),
+            Err(ParameterGenerationError::InvalidAlpha(3))
+        );
+        assert_eq!(
+            round_numbers::<Goldilocks64Field>(12, 2, 128),
+            Err(ParameterGenerationError::InvalidAlpha(2))
+        );
+        assert_eq!(
+            PoseidonParameters::<Goldilocks64Field>::generate(0, 4, 7, 128),
+            Err(ParameterGenerationError::InvalidStateSize(4))
+        );
+    }
+
+    #[test]
+    fn irreducibility_test() {
+        // 7 generates the multiplicative group of Goldilocks, so it isn't a square.
+        assert!(poly(&[-7, 0, 1]).is_irreducible());
+        assert!(poly(&[-1, -1, 0, 1]).is_irreducible());
+        // -1 is a square, since 4 divides `p - 1`.
+        assert!(!poly(&[1, 0, 1]).is_irreducible());
+        assert!(!poly(&[1, -2, 1]).is_irreducible());
+        // (x^2 - 7)^2, whose roots are not in the field either.
+        assert!(!poly(&[49, 0, -14, 0, 1]).is_irreducible());
+    }
+
+    #[test]
+    fn characteristic_polynomial_of_a_small_matrix() {
+        let matrix = vec![
+            vec![Goldilocks::from(1), Goldilocks::from(2)],
+            vec![Goldilocks::from(3), Goldilocks::from(4)],
+        ];
+        assert_eq!(characteristic_polynomial(&matrix), poly(&[-2, -5, 1]));
+    }
+
+    #[test]
+    fn generated_parameters_are_consistent() {
+        let parameters = PoseidonParameters::<Goldilocks64Field>::generate(8, 4, 7, 128).unwrap();
+        assert_eq!(
+            (parameters.n_full_rounds, parameters.n_partial_rounds),
+            (8, 22)
+        );
+        assert_eq!(parameters.round_constants.len(), 30 * 12);
+        assert_eq!(parameters.mds_matrix.len(), 12 * 12);
+
+        let mut lfsr = GrainLFSR::new(64, 12, 8, 22);
+        let first_constant: Goldilocks = lfsr.next_field_element();
+        assert_eq!(parameters.round_constants[0], first_constant);
+
+        let matrix: Vec<Vec<Goldilocks>> = parameters
+            .mds_matrix
+            .chunks(12)
+            .map(|row| row.to_vec())
+            .collect();
+        assert!(has_secure_powers(&matrix));
+        assert!(!has_secure_powers(&[
+            vec![Goldilocks::one(), Goldilocks::zero()],
+            vec![Goldilocks::zero(), Goldilocks::one()],
+        ]));
+    }
+
+    #[test]
+    fn runtime_permutation_matches_the_trait() {
+        let parameters = PoseidonParameters::<Stark252PrimeField> {
+            rate: 2,
+            capacity: 1,
+            alpha: 3,
+            n_full_rounds: 8,
+            n_partial_rounds: 83,
+            mds_matrix: <PoseidonCairoStark252 as PermutationParameters>::MDS_MATRIX.to_vec(),
+            round_constants: <PoseidonCairoStark252 as PermutationParameters>::ROUND_CONSTANTS
+                .to_vec(),
+        };
+        let inputs: Vec<FE<Stark252PrimeField>> = (1..=6_u64).map(FE::from).collect();
+        assert_eq!(
+            parameters.hash_many(&inputs),
+            PoseidonCairoStark252::hash_many(&inputs)
+        );
+    }
+
+    #[test]
+    fn rust_source_defines_the_parameters() {
+        let parameters = PoseidonParameters::<FrField>::generate(2, 1, 5, 128).unwrap();
+        let source = parameters.rust_source("PoseidonBls12381", "FrField");
+
+        assert!(source.contains("impl PermutationParameters for PoseidonBls12381 {"));
+        assert!(source.contains("    const N_PARTIAL_ROUNDS: usize = 56;\n"));
+        assert!(source.contains("    const N_ROUND_CONSTANTS_ROWS: usize = 64;\n"));
+        assert_eq!(
+            source.matches("FE::from_hex_unchecked").count(),
+            3 * 3 + 64 * 3
+        );
+        let first_constant = format!(
+            "FE::from_hex_unchecked(\"{}\")",
+            to_hex(&parameters.round_constants[0])
+        );
+        assert!(source.contains(&first_constant));
+    }
+}
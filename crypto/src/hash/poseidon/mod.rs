@@ -1,5 +1,6 @@
 use lambdaworks_math::field::element::FieldElement as FE;
 
+pub mod generator;
 pub mod parameters;
 pub mod starknet;
 
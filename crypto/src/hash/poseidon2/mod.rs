@@ -0,0 +1,444 @@
+use lambdaworks_math::field::{element::FieldElement as FE, traits::IsField};
+
+pub mod parameters;
+
+pub use parameters::{
+    Poseidon2BabyBear16, Poseidon2BabyBear24, Poseidon2Bls12381, Poseidon2Goldilocks12,
+    Poseidon2Goldilocks8,
+};
+
+use parameters::Poseidon2Parameters;
+
+mod private {
+    use super::*;
+
+    pub trait Sealed {}
+
+    impl<P: Poseidon2Parameters> Sealed for P {}
+}
+
+/// Poseidon2 permutation and hashes.
+///
+/// Poseidon2 applies the external linear layer once to the input, and then `N_FULL_ROUNDS / 2`
+/// full rounds, `N_PARTIAL_ROUNDS` partial rounds and `N_FULL_ROUNDS / 2` full rounds. Full
+/// rounds add a constant to every element, apply the S-box `x^ALPHA` to every element and then
+/// the external linear layer. Partial rounds add a constant only to the first element, apply
+/// the S-box only to it and then the internal linear layer, which takes linear time.
+///
+/// The state is split into `RATE` elements, which absorb the input, followed by `CAPACITY`
+/// elements. Digests have `CAPACITY` elements.
+pub trait Poseidon2: Poseidon2Parameters + self::private::Sealed {
+    fn permutation(state: &mut [FE<Self::F>]);
+    fn full_round(state: &mut [FE<Self::F>], round_number: usize);
+    fn partial_round(state: &mut [FE<Self::F>], round_number: usize);
+    fn external_linear_layer(state: &mut [FE<Self::F>]);
+    fn internal_linear_layer(state: &mut [FE<Self::F>]);
+    fn hash(x: &FE<Self::F>, y: &FE<Self::F>) -> FE<Self::F>;
+    fn hash_single(x: &FE<Self::F>) -> FE<Self::F>;
+    fn hash_many(inputs: &[FE<Self::F>]) -> Vec<FE<Self::F>>;
+    fn compress(left: &[FE<Self::F>], right: &[FE<Self::F>]) -> Vec<FE<Self::F>>;
+}
+
+impl<P: Poseidon2Parameters> Poseidon2 for P {
+    fn permutation(state: &mut [FE<Self::F>]) {
+        assert_eq!(state.len(), P::STATE_SIZE);
+        Self::external_linear_layer(state);
+        for round_number in 0..P::N_FULL_ROUNDS / 2 {
+            Self::full_round(state, round_number);
+        }
+        for round_number in 0..P::N_PARTIAL_ROUNDS {
+            Self::partial_round(state, round_number);
+        }
+        for round_number in P::N_FULL_ROUNDS / 2..P::N_FULL_ROUNDS {
+            Self::full_round(state, round_number);
+        }
+    }
+
+    /// Full round number `round_number` among the full rounds.
+    fn full_round(state: &mut [FE<Self::F>], round_number: usize) {
+        let constants = &P::constants().external[round_number];
+        for (value, constant) in state.iter_mut().zip(constants) {
+            *value = (&*value + constant).pow(P::ALPHA);
+        }
+        Self::external_linear_layer(state);
+    }
+
+    /// Partial round number `round_number` among the partial rounds.
+    fn partial_round(state: &mut [FE<Self::F>], round_number: usize) {
+        state[0] = (&state[0] + &P::constants().internal[round_number]).pow(P::ALPHA);
+        Self::internal_linear_layer(state);
+    }
+
+    /// Multiplies the state by the external matrix. For widths 2 and 3 it's `circ(2, 1)` and
+    /// `circ(2, 1, 1)`. For width 4 it's the matrix `M4` of the reference implementation, and
+    /// for larger multiples of 4 it's `circ(2 M4, M4, ..., M4)`, which applies `M4` to each
+    /// chunk of 4 elements and then adds to each element the sum of the elements in the same
+    /// position of every chunk.
+    fn external_linear_layer(state: &mut [FE<Self::F>]) {
+        match P::STATE_SIZE {
+            2 | 3 => {
+                let sum: FE<Self::F> = state.iter().cloned().sum();
+                for value in state.iter_mut() {
+                    *value = &*value + &sum;
+                }
+            }
+            4 => apply_m4(state),
+            size if size % 4 == 0 => {
+                for chunk in state.chunks_mut(4) {
+                    apply_m4(chunk);
+                }
+                let sums: Vec<FE<Self::F>> = (0..4)
+                    .map(|i| state.iter().skip(i).step_by(4).cloned().sum())
+                    .collect();
+                for (i, value) in state.iter_mut().enumerate() {
+                    *value = &*value + &sums[i % 4];
+                }
+            }
+            size => panic!("Poseidon2 is not defined for width {size}"),
+        }
+    }
+
+    /// Multiplies the state by the internal matrix `J + D`, where `J` is the all-ones matrix
+    /// and `D` is the diagonal matrix of `INTERNAL_DIAGONAL_MINUS_ONE`, adding the sum of the
+    /// state to each element times its diagonal entry.
+    fn internal_linear_layer(state: &mut [FE<Self::F>]) {
+        let sum: FE<Self::F> = state.iter().cloned().sum();
+        let diagonal = &P::constants().internal_diagonal_minus_one;
+        for (value, entry) in state.iter_mut().zip(diagonal) {
+            *value = &*value * entry + &sum;
+        }
+    }
+
+    /// Compresses two field elements into one, with the permutation of `[x, y, 0, ..., 0]`
+    /// truncated to the first element.
+    fn hash(x: &FE<Self::F>, y: &FE<Self::F>) -> FE<Self::F> {
+        Self::compress(core::slice::from_ref(x), core::slice::from_ref(y))[0].clone()
+    }
+
+    fn hash_single(x: &FE<Self::F>) -> FE<Self::F> {
+        let mut state = vec![FE::zero(); P::STATE_SIZE];
+        state[0] = x.clone();
+        // Separates it from `hash` and `hash_many`, which leave the capacity at zero.
+        state[P::STATE_SIZE - 1] = FE::one();
+        Self::permutation(&mut state);
+        state[0].clone()
+    }
+
+    /// Hashes `inputs` with a sponge, padding them with 1 followed by 0's to a multiple of the
+    /// rate, and returns a digest of `CAPACITY` elements.
+    fn hash_many(inputs: &[FE<Self::F>]) -> Vec<FE<Self::F>> {
+        let mut values = inputs.to_owned();
+        values.push(FE::one());
+        values.resize(values.len().div_ceil(P::RATE) * P::RATE, FE::zero());
+
+        let mut sponge = Poseidon2Sponge::<P>::new();
+        sponge.absorb(&values);
+        sponge.squeeze(P::CAPACITY)
+    }
+
+    /// Compresses two digests into one, with the permutation of `left || right` padded with
+    /// zeros, truncated to the length of `left`. Panics if they don't fit in the state.
+    fn compress(left: &[FE<Self::F>], right: &[FE<Self::F>]) -> Vec<FE<Self::F>> {
+        assert!(
+            left.len() + right.len() <= P::STATE_SIZE,
+            "Inputs don't fit in the state"
+        );
+        let mut state = left.to_vec();
+        state.extend_from_slice(right);
+        state.resize(P::STATE_SIZE, FE::zero());
+        Self::permutation(&mut state);
+        state.truncate(left.len());
+        state
+    }
+}
+
+/// Multiplies a chunk of 4 elements by the matrix
+/// `M4 = [[5, 7, 1, 3], [4, 6, 1, 1], [1, 3, 5, 7], [1, 1, 4, 6]]` with 8 additions and
+/// 4 doublings.
+fn apply_m4<F: IsField>(x: &mut [FE<F>]) {
+    let double = |value: &FE<F>| value + value;
+    let t0 = &x[0] + &x[1];
+    let t1 = &x[2] + &x[3];
+    let t2 = double(&x[1]) + &t1;
+    let t3 = double(&x[3]) + &t0;
+    let t4 = double(&double(&t1)) + &t3;
+    let t5 = double(&double(&t0)) + &t2;
+    let t6 = &t3 + &t5;
+    let t7 = &t2 + &t4;
+    x[0] = t6;
+    x[1] = t5;
+    x[2] = t7;
+    x[3] = t4;
+}
+
+/// Sponge over the Poseidon2 permutation. Input elements are added to the rate part of the
+/// state, which is permuted each time it's full, and outputs are read from the rate part.
+pub struct Poseidon2Sponge<P: Poseidon2> {
+    state: Vec<FE<P::F>>,
+    /// Position in the rate part of the next element to absorb or squeeze.
+    position: usize,
+    squeezing: bool,
+}
+
+impl<P: Poseidon2> Poseidon2Sponge<P> {
+    pub fn new() -> Self {
+        Self {
+            state: vec![FE::zero(); P::STATE_SIZE],
+            position: 0,
+            squeezing: false,
+        }
+    }
+
+    pub fn absorb(&mut self, inputs: &[FE<P::F>]) {
+        if self.squeezing {
+            self.squeezing = false;
+            self.position = 0;
+        }
+        for input in inputs {
+            if self.position == P::RATE {
+                P::permutation(&mut self.state);
+                self.position = 0;
+            }
+            self.state[self.position] = &self.state[self.position] + input;
+            self.position += 1;
+        }
+    }
+
+    pub fn squeeze(&mut self, count: usize) -> Vec<FE<P::F>> {
+        if !self.squeezing {
+            P::permutation(&mut self.state);
+            self.squeezing = true;
+            self.position = 0;
+        }
+        let mut outputs = Vec::with_capacity(count);
+        for _ in 0..count {
+            if self.position == P::RATE {
+                P::permutation(&mut self.state);
+                self.position = 0;
+            }
+            outputs.push(self.state[self.position].clone());
+            self.position += 1;
+        }
+        outputs
+    }
+}
+
+impl<P: Poseidon2> Default for Poseidon2Sponge<P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lambdaworks_math::{
+        elliptic_curve::short_weierstrass::curves::bls12_381::default_types::FrElement,
+        field::fields::{
+            fft_friendly::babybear::Babybear31PrimeField, u64_goldilocks_field::Goldilocks64Field,
+        },
+    };
+
+    type FE31 = FE<Babybear31PrimeField>;
+    type FE64 = FE<Goldilocks64Field>;
+
+    /// Returns the matrix of a linear layer of width `width`, by applying it to the unit vectors.
+    fn matrix_of(layer: fn(&mut [FE64]), width: usize) -> Vec<Vec<FE64>> {
+        (0..width)
+            .map(|j| {
+                let mut column = vec![FE64::zero(); width];
+                column[j] = FE64::one();
+                layer(&mut column);
+                column
+            })
+            .collect()
+    }
+
+    #[test]
+    fn external_layer_is_circulant_of_m4() {
+        let m4: [[u64; 4]; 4] = [[5, 7, 1, 3], [4, 6, 1, 1], [1, 3, 5, 7], [1, 1, 4, 6]];
+        let columns = matrix_of(Poseidon2Goldilocks8::external_linear_layer, 8);
+        for (j, column) in columns.iter().enumerate() {
+            for (i, entry) in column.iter().enumerate() {
+                let block = if i / 4 == j / 4 { 2 } else { 1 };
+                assert_eq!(*entry, FE64::from(block * m4[i % 4][j % 4]));
+            }
+        }
+    }
+
+    #[test]
+    fn internal_layer_adds_the_diagonal_to_the_ones_matrix() {
+        let columns = matrix_of(Poseidon2Goldilocks8::internal_linear_layer, 8);
+        let diagonal = &Poseidon2Goldilocks8::constants().internal_diagonal_minus_one;
+        for (j, column) in columns.iter().enumerate() {
+            for (i, entry) in column.iter().enumerate() {
+                let expected = if i == j {
+                    FE64::one() + diagonal[i]
+                } else {
+                    FE64::one()
+                };
+                assert_eq!(*entry, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn constants_have_the_shape_of_the_instance() {
+        let constants = Poseidon2BabyBear24::constants();
+        assert_eq!(constants.external.len(), 8);
+        assert!(constants.external.iter().all(|round| round.len() == 24));
+        assert_eq!(constants.internal.len(), 21);
+        assert_eq!(constants.internal_diagonal_minus_one.len(), 24);
+    }
+
+    #[test]
+    fn permutation_mixes_the_whole_state() {
+        let mut state = vec![FE64::zero(); 12];
+        Poseidon2Goldilocks12::permutation(&mut state);
+        let mut other = vec![FE64::zero(); 12];
+        other[11] = FE64::one();
+        Poseidon2Goldilocks12::permutation(&mut other);
+
+        assert!(state.iter().zip(&other).all(|(a, b)| a != b));
+    }
+
+    #[test]
+    fn sponge_squeezes_the_same_regardless_of_absorb_chunks() {
+        let inputs: Vec<FE64> = (0..11).map(FE64::from).collect();
+        let mut whole = Poseidon2Sponge::<Poseidon2Goldilocks8>::new();
+        whole.absorb(&inputs);
+        let mut split = Poseidon2Sponge::<Poseidon2Goldilocks8>::new();
+        split.absorb(&inputs[..3]);
+        split.absorb(&inputs[3..]);
+
+        let outputs = whole.squeeze(10);
+        assert_eq!(outputs.len(), 10);
+        assert_eq!(split.squeeze(4), outputs[..4]);
+        assert_eq!(split.squeeze(6), outputs[4..]);
+    }
+
+    #[test]
+    fn hash_many_depends_on_the_length() {
+        let inputs: Vec<FE64> = (1..5).map(FE64::from).collect();
+        let mut padded = inputs.clone();
+        padded.push(FE64::zero());
+
+        let digest = Poseidon2Goldilocks8::hash_many(&inputs);
+        assert_eq!(digest.len(), 4);
+        assert_ne!(digest, Poseidon2Goldilocks8::hash_many(&padded));
+    }
+
+    // Test vectors of the reference implementation, the permutation of `[0, 1, ..., t - 1]`.
+
+    #[test]
+    fn permutation_over_goldilocks_8_matches_the_reference() {
+        let mut state: Vec<FE64> = (0..8).map(FE64::from).collect();
+        Poseidon2Goldilocks8::permutation(&mut state);
+        let expected: Vec<FE64> = [
+            0xc5fb1cfe0b4697bb,
+            0x4a4a32ff849af473,
+            0xd2fd266077f8efba,
+            0xf4ad9b74e833916d,
+            0xe6648eb0acc11463,
+            0x8d5529a930d75194,
+            0xe8c993aa10da6c90,
+            0xa73104a95b68031c,
+        ]
+        .into_iter()
+        .map(FE64::from)
+        .collect();
+        assert_eq!(state, expected);
+    }
+
+    #[test]
+    fn permutation_over_babybear_16_matches_the_reference() {
+        let mut state: Vec<FE31> = (0..16).map(FE31::from).collect();
+        Poseidon2BabyBear16::permutation(&mut state);
+        let expected: Vec<FE31> = [
+            896560466, 771677727, 128113032, 1378976435, 160019712, 1452738514, 682850273,
+            223500421, 501450187, 1804685789, 1671399593, 1788755219, 1736880027, 1352180784,
+            1928489698, 1128802977,
+        ]
+        .into_iter()
+        .map(FE31::from)
+        .collect();
+        assert_eq!(state, expected);
+    }
+
+    #[test]
+    fn permutation_over_babybear_24_matches_the_reference() {
+        let mut state: Vec<FE31> = (0..24).map(FE31::from).collect();
+        Poseidon2BabyBear24::permutation(&mut state);
+        let expected: Vec<FE31> = [
+            785637949, 311566256, 241540729, 1641553353, 851108667, 1648913123, 510139232,
+            616108837, 707720633, 1357404478, 1539840236, 275323287, 899761440, 732341189,
+            664618988, 1426148993, 1498654335, 792736017, 1804085503, 402731039, 659103866,
+            1036635937, 1016617890, 1470732388,
+        ]
+        .into_iter()
+        .map(FE31::from)
+        .collect();
+        assert_eq!(state, expected);
+    }
+
+    #[test]
+    fn permutation_over_goldilocks_12_matches_the_reference() {
+        let mut state: Vec<FE64> = (0..12).map(FE64::from).collect();
+        Poseidon2Goldilocks12::permutation(&mut state);
+        let expected: Vec<FE64> = [
+            0x01eaef96bdf1c0c1,
+            0x1f0d2cc525b2540c,
+            0x6282c1dfe1e0358d,
+            0xe780d721f698e1e6,
+            0x280c0b6f753d833b,
+            0x1b942dd5023156ab,
+            0x43f0df3fcccb8398,
+            0xe8e8190585489025,
+            0x56bdbf72f77ada22,
+            0x7911c32bf9dcd705,
+            0xec467926508fbe67,
+            0x6a50450ddf85a6ed,
+        ]
+        .into_iter()
+        .map(FE64::from)
+        .collect();
+        assert_eq!(state, expected);
+    }
+
+    #[test]
+    fn permutation_over_bls12_381_matches_the_reference() {
+        let mut state: Vec<FrElement> = (0..3).map(FrElement::from).collect();
+        Poseidon2Bls12381::permutation(&mut state);
+        let expected: Vec<FrElement> = [
+            "1b152349b1950b6a8ca75ee4407b6e26ca5cca5650534e56ef3fd45761fbf5f0",
+            "4c5793c87d51bdc2c08a32108437dc0000bd0275868f09ebc5f36919af5b3891",
+            "1fc8ed171e67902ca49863159fe5ba6325318843d13976143b8125f08b50dc6b",
+        ]
+        .into_iter()
+        .map(FrElement::from_hex_unchecked)
+        .collect();
+        assert_eq!(state, expected);
+    }
+
+    #[test]
+    fn hash_of_field_elements_over_bls12_381() {
+        let (x, y) = (FrElement::from(1), FrElement::from(2));
+        let mut state = [x.clone(), y.clone(), FrElement::zero()];
+        Poseidon2Bls12381::permutation(&mut state);
+        assert_eq!(Poseidon2Bls12381::hash(&x, &y), state[0]);
+        assert_eq!(
+            Poseidon2Bls12381::hash(&x, &y),
+            FrElement::from_hex_unchecked(
+                "70a58720d46a84d195bc875de66ed3ddef47522a7e806ec7a98c0d656517ce74"
+            )
+        );
+        assert_ne!(
+            Poseidon2Bls12381::hash(&x, &y),
+            Poseidon2Bls12381::hash(&y, &x)
+        );
+        assert_ne!(
+            Poseidon2Bls12381::hash_single(&x),
+            Poseidon2Bls12381::hash(&x, &FrElement::zero())
+        );
+    }
+}
@@ -0,0 +1,214 @@
+use crate::hash::grain_lfsr::GrainLFSR;
+use lambdaworks_math::{
+    elliptic_curve::short_weierstrass::curves::bls12_381::default_types::FrField,
+    field::{
+        element::FieldElement as FE,
+        fields::{
+            fft_friendly::babybear::Babybear31PrimeField, u64_goldilocks_field::Goldilocks64Field,
+        },
+        traits::IsPrimeField,
+    },
+};
+use std::sync::OnceLock;
+
+/// Parameters for Poseidon2.
+///
+/// The round constants are generated with the Grain LFSR, as in the reference implementation:
+/// `STATE_SIZE` constants for each full round and one for each partial round, in round order.
+/// Since this can't be done in a constant context, they are generated on first use and kept in
+/// a static by [Poseidon2Parameters::constants].
+pub trait Poseidon2Parameters {
+    type F: IsPrimeField + 'static;
+
+    const RATE: usize;
+    const CAPACITY: usize;
+    const ALPHA: u64;
+    const N_FULL_ROUNDS: usize;
+    const N_PARTIAL_ROUNDS: usize;
+    const STATE_SIZE: usize = Self::RATE + Self::CAPACITY;
+
+    /// Diagonal of the internal matrix minus the identity, as small integers.
+    const INTERNAL_DIAGONAL_MINUS_ONE: &'static [i64];
+
+    /// Returns the constants of the instance, generating them on the first call.
+    fn constants() -> &'static Poseidon2Constants<Self::F>;
+}
+
+/// Round constants and internal diagonal of a Poseidon2 instance, as field elements.
+#[derive(Debug, Clone)]
+pub struct Poseidon2Constants<F: IsPrimeField> {
+    /// Constants of the full rounds, `STATE_SIZE` for each one.
+    pub external: Vec<Vec<FE<F>>>,
+    /// Constants of the partial rounds, added to the first element.
+    pub internal: Vec<FE<F>>,
+    pub internal_diagonal_minus_one: Vec<FE<F>>,
+}
+
+impl<F: IsPrimeField> Poseidon2Constants<F> {
+    pub fn generate<P: Poseidon2Parameters<F = F>>() -> Self {
+        let mut lfsr = GrainLFSR::new(
+            F::field_bit_size(),
+            P::STATE_SIZE,
+            P::N_FULL_ROUNDS,
+            P::N_PARTIAL_ROUNDS,
+        );
+        let mut external = full_round_constants(&mut lfsr, P::N_FULL_ROUNDS / 2, P::STATE_SIZE);
+        let internal = (0..P::N_PARTIAL_ROUNDS)
+            .map(|_| lfsr.next_field_element())
+            .collect();
+        external.extend(full_round_constants(
+            &mut lfsr,
+            P::N_FULL_ROUNDS - P::N_FULL_ROUNDS / 2,
+            P::STATE_SIZE,
+        ));
+
+        let internal_diagonal_minus_one = P::INTERNAL_DIAGONAL_MINUS_ONE
+            .iter()
+            .map(|entry| {
+                let magnitude = FE::from(entry.unsigned_abs());
+                if *entry < 0 {
+                    -magnitude
+                } else {
+                    magnitude
+                }
+            })
+            .collect();
+
+        Self {
+            external,
+            internal,
+            internal_diagonal_minus_one,
+        }
+    }
+}
+
+fn full_round_constants<F: IsPrimeField>(
+    lfsr: &mut GrainLFSR,
+    rounds: usize,
+    width: usize,
+) -> Vec<Vec<FE<F>>> {
+    (0..rounds)
+        .map(|_| (0..width).map(|_| lfsr.next_field_element()).collect())
+        .collect()
+}
+
+// The internal diagonals are those of the reference implementation, with the entries larger
+// than `p / 2` written as their difference with `p`.
+const BABYBEAR_16_DIAGONAL: [i64; 16] = [
+    0x0a632d94, 0x6db657b7, 0x56fbdc9e, 0x052b3d8a, 0x33745201, 0x5c03108c, 0x0beba37b, 0x258c2e8b,
+    0x12029f39, 0x694909ce, 0x6d231724, 0x21c3b222, 0x3c0904a5, 0x01d6acda, 0x27705c83, 0x5231c802,
+];
+const BABYBEAR_24_DIAGONAL: [i64; 24] = [
+    0x409133f0, 0x1667a8a1, 0x06a6c7b6, 0x6f53160e, 0x273b11d1, 0x03176c5d, 0x72f9bbf9, 0x73ceba91,
+    0x5cdef81d, 0x01393285, 0x46daee06, 0x065d7ba6, 0x52d72d6f, 0x05dd05e0, 0x3bab4b63, 0x6ada3842,
+    0x2fc5fbec, 0x770d61b0, 0x5715aae9, 0x03ef0e90, 0x75b6c770, 0x242adf5f, 0x00d0ca4c, 0x36c0e388,
+];
+const GOLDILOCKS_8_DIAGONAL: [i64; 8] = [
+    -0x5677ee5d012b1c5c,
+    0x1cc48b54f377e2a0,
+    -0x1bf32b083a9f65db,
+    0x11de79ebca97a4a3,
+    -0x6e8838c174816d65,
+    0x2a6fe8085797e791,
+    0x3de6e93329f8d5ad,
+    0x3f7af9125da962fe,
+];
+const GOLDILOCKS_12_DIAGONAL: [i64; 12] = [
+    -0x3c493f70dc456d01,
+    -0x27b4a215b5cdb04b,
+    0x0d0c371c5b35b84f,
+    0x7964f570e7188037,
+    0x5daf18bbd996604b,
+    0x6743bc47b9595257,
+    0x5528b9362c59bb70,
+    -0x53ba1da38ed84976,
+    -0x5df882810449f94c,
+    -0x0c05538f511c8753,
+    0x0c6388b51545e883,
+    -0x2d824495bb6e84a1,
+];
+
+macro_rules! poseidon2_instance {
+    ($(#[$doc:meta])* $name:ident, $field:ty, $rate:expr, $capacity:expr, $alpha:expr,
+     $full_rounds:expr, $partial_rounds:expr, $diagonal:expr) => {
+        $(#[$doc])*
+        #[derive(Clone, Default)]
+        pub struct $name;
+
+        impl Poseidon2Parameters for $name {
+            type F = $field;
+            const RATE: usize = $rate;
+            const CAPACITY: usize = $capacity;
+            const ALPHA: u64 = $alpha;
+            const N_FULL_ROUNDS: usize = $full_rounds;
+            const N_PARTIAL_ROUNDS: usize = $partial_rounds;
+            const INTERNAL_DIAGONAL_MINUS_ONE: &'static [i64] = &$diagonal;
+
+            fn constants() -> &'static Poseidon2Constants<$field> {
+                static CONSTANTS: OnceLock<Poseidon2Constants<$field>> = OnceLock::new();
+                CONSTANTS.get_or_init(Poseidon2Constants::generate::<Self>)
+            }
+        }
+    };
+}
+
+poseidon2_instance!(
+    /// Poseidon2 over BabyBear with width 16.
+    Poseidon2BabyBear16,
+    Babybear31PrimeField,
+    8,
+    8,
+    7,
+    8,
+    13,
+    BABYBEAR_16_DIAGONAL
+);
+
+poseidon2_instance!(
+    /// Poseidon2 over BabyBear with width 24.
+    Poseidon2BabyBear24,
+    Babybear31PrimeField,
+    16,
+    8,
+    7,
+    8,
+    21,
+    BABYBEAR_24_DIAGONAL
+);
+
+poseidon2_instance!(
+    /// Poseidon2 over Goldilocks with width 8.
+    Poseidon2Goldilocks8,
+    Goldilocks64Field,
+    4,
+    4,
+    7,
+    8,
+    22,
+    GOLDILOCKS_8_DIAGONAL
+);
+
+poseidon2_instance!(
+    /// Poseidon2 over Goldilocks with width 12.
+    Poseidon2Goldilocks12,
+    Goldilocks64Field,
+    8,
+    4,
+    7,
+    8,
+    22,
+    GOLDILOCKS_12_DIAGONAL
+);
+
+poseidon2_instance!(
+    /// Poseidon2 over the scalar field of BLS12-381 with width 3, with the internal matrix
+    /// `[[2, 1, 1], [1, 2, 1], [1, 1, 3]]` of the reference implementation.
+    Poseidon2Bls12381,
+    FrField,
+    2,
+    1,
+    5,
+    8,
+    56,
+    [1, 1, 2]
+);
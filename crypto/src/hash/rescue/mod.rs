@@ -0,0 +1,572 @@
+use core::ops::Range;
+use lambdaworks_math::field::{
+    element::FieldElement, fields::u64_goldilocks_field::Goldilocks64Field,
+};
+
+pub mod parameters;
+
+use parameters::{
+    RESCUE_PRIME_ARK1, RESCUE_PRIME_ARK2, RESCUE_PRIME_MDS, RPO_ARK1, RPO_ARK2, RPO_MDS,
+};
+
+pub type Felt = FieldElement<Goldilocks64Field>;
+
+/// Digest of 4 field elements, about 256 bits.
+pub type RescueDigest = [Felt; DIGEST_SIZE];
+
+pub const STATE_WIDTH: usize = 12;
+pub const RATE_WIDTH: usize = 8;
+pub const DIGEST_SIZE: usize = 4;
+
+/// The capacity is at the beginning of the state, followed by the rate.
+pub const CAPACITY_RANGE: Range<usize> = 0..4;
+pub const RATE_RANGE: Range<usize> = 4..12;
+/// The digest is the first half of the rate.
+pub const DIGEST_RANGE: Range<usize> = 4..8;
+
+const ALPHA: u64 = 7;
+/// Inverse of 7 modulo `p - 1`, so that `x -> x^INV_ALPHA` inverts `x -> x^7`.
+const INV_ALPHA: u64 = 10540996611094048183;
+
+/// Permutations of the Rescue family over Goldilocks with a state of 12 elements, and the
+/// hashes built on them with the state layout of Miden's `Rpo256`: a capacity of 4 elements
+/// followed by a rate of 8, with the digest in the first half of the rate.
+pub trait RescuePermutation {
+    fn apply_permutation(state: &mut [Felt; STATE_WIDTH]);
+
+    /// Hashes a sequence of elements. The first capacity element is set to the length of the
+    /// input modulo the rate, the input overwrites the rate 8 elements at a time, and the last
+    /// chunk is padded with zeros.
+    fn hash_elements(elements: &[Felt]) -> RescueDigest {
+        let mut state: [Felt; STATE_WIDTH] = core::array::from_fn(|_| Felt::zero());
+        state[CAPACITY_RANGE.start] = Felt::from((elements.len() % RATE_WIDTH) as u64);
+
+        let mut chunks = elements.chunks_exact(RATE_WIDTH);
+        for chunk in chunks.by_ref() {
+            state[RATE_RANGE].clone_from_slice(chunk);
+            Self::apply_permutation(&mut state);
+        }
+        let remainder = chunks.remainder();
+        if !remainder.is_empty() {
+            for (i, value) in state[RATE_RANGE].iter_mut().enumerate() {
+                *value = remainder.get(i).cloned().unwrap_or_else(Felt::zero);
+            }
+            Self::apply_permutation(&mut state);
+        }
+        digest(&state)
+    }
+
+    /// 2-to-1 compression: the rate takes both digests, the capacity is zero, and the result
+    /// is the digest of the permuted state.
+    fn merge(left: &RescueDigest, right: &RescueDigest) -> RescueDigest {
+        let mut state: [Felt; STATE_WIDTH] = core::array::from_fn(|_| Felt::zero());
+        for (value, input) in state[RATE_RANGE].iter_mut().zip(left.iter().chain(right)) {
+            *value = *input;
+        }
+        Self::apply_permutation(&mut state);
+        digest(&state)
+    }
+}
+
+/// Rescue-Prime with 8 rounds. Each round applies `x^7`, the MDS matrix and the first
+/// constants, and then `x^(1/7)`, the MDS matrix and the second constants.
+#[derive(Clone, Default)]
+pub struct RescuePrime256;
+
+impl RescuePermutation for RescuePrime256 {
+    fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
+        for (ark1, ark2) in RESCUE_PRIME_ARK1.iter().zip(RESCUE_PRIME_ARK2.iter()) {
+            apply_sbox(state);
+            apply_mds(state, &RESCUE_PRIME_MDS);
+            add_constants(state, ark1);
+            apply_inv_sbox(state);
+            apply_mds(state, &RESCUE_PRIME_MDS);
+            add_constants(state, ark2);
+        }
+    }
+}
+
+/// Rescue Prime Optimized, with 7 rounds. Each round applies the MDS matrix, the first
+/// constants and `x^7`, and then the MDS matrix, the second constants and `x^(1/7)`.
+#[derive(Clone, Default)]
+pub struct Rpo256;
+
+impl RescuePermutation for Rpo256 {
+    fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
+        for round in 0..RPO_ARK1.len() {
+            apply_rpo_round(state, round);
+        }
+    }
+}
+
+/// Rescue Prime Extension, with the constants and MDS matrix of RPO. Its 7 rounds alternate
+/// the rounds of RPO with extension rounds, which add the first constants and apply `x^7` to
+/// the state seen as 4 elements of the cubic extension `F_p[x] / (x^3 - x - 1)`, and end with
+/// a round that only applies the MDS matrix and adds the first constants.
+#[derive(Clone, Default)]
+pub struct Rpx256;
+
+impl RescuePermutation for Rpx256 {
+    fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
+        apply_rpo_round(state, 0);
+        apply_extension_round(state, 1);
+        apply_rpo_round(state, 2);
+        apply_extension_round(state, 3);
+        apply_rpo_round(state, 4);
+        apply_extension_round(state, 5);
+        apply_mds(state, &RPO_MDS);
+        add_constants(state, &RPO_ARK1[6]);
+    }
+}
+
+fn digest(state: &[Felt; STATE_WIDTH]) -> RescueDigest {
+    core::array::from_fn(|i| state[DIGEST_RANGE.start + i])
+}
+
+fn apply_rpo_round(state: &mut [Felt; STATE_WIDTH], round: usize) {
+    apply_mds(state, &RPO_MDS);
+    add_constants(state, &RPO_ARK1[round]);
+    apply_sbox(state);
+    apply_mds(state, &RPO_MDS);
+    add_constants(state, &RPO_ARK2[round]);
+    apply_inv_sbox(state);
+}
+
+fn apply_extension_round(state: &mut [Felt; STATE_WIDTH], round: usize) {
+    add_constants(state, &RPO_ARK1[round]);
+    for element in state.chunks_exact_mut(3) {
+        let value = [element[0], element[1], element[2]];
+        element.clone_from_slice(&cubic_exp7(&value));
+    }
+}
+
+fn apply_mds(state: &mut [Felt; STATE_WIDTH], mds: &[[u64; STATE_WIDTH]; STATE_WIDTH]) {
+    let result: [Felt; STATE_WIDTH] = core::array::from_fn(|i| {
+        mds[i]
+            .iter()
+            .zip(state.iter())
+            .fold(Felt::zero(), |acc, (entry, value)| {
+                acc + Felt::from(*entry) * value
+            })
+    });
+    *state = result;
+}
+
+fn add_constants(state: &mut [Felt; STATE_WIDTH], constants: &[u64; STATE_WIDTH]) {
+    for (value, constant) in state.iter_mut().zip(constants) {
+        *value += Felt::from(*constant);
+    }
+}
+
+fn apply_sbox(state: &mut [Felt; STATE_WIDTH]) {
+    for value in state.iter_mut() {
+        *value = value.pow(ALPHA);
+    }
+}
+
+fn apply_inv_sbox(state: &mut [Felt; STATE_WIDTH]) {
+    for value in state.iter_mut() {
+        *value = value.pow(INV_ALPHA);
+    }
+}
+
+/// Product in `F_p[x] / (x^3 - x - 1)`, where `x^3 = x + 1` and `x^4 = x^2 + x`.
+fn cubic_mul(a: &[Felt; 3], b: &[Felt; 3]) -> [Felt; 3] {
+    let c0 = a[0] * b[0];
+    let c1 = a[0] * b[1] + a[1] * b[0];
+    let c2 = a[0] * b[2] + a[1] * b[1] + a[2] * b[0];
+    let c3 = a[1] * b[2] + a[2] * b[1];
+    let c4 = a[2] * b[2];
+    [c0 + c3, c1 + c3 + c4, c2 + c4]
+}
+
+fn cubic_exp7(a: &[Felt; 3]) -> [Felt; 3] {
+    let a2 = cubic_mul(a, a);
+    let a3 = cubic_mul(&a2, a);
+    let a4 = cubic_mul(&a2, &a2);
+    cubic_mul(&a4, &a3)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sha3::{
+        digest::{ExtendableOutput, Update, XofReader},
+        Shake256,
+    };
+
+    const GOLDILOCKS_PRIME: u64 = 0xFFFF_FFFF_0000_0001;
+
+    fn felts(values: &[u64]) -> Vec<Felt> {
+        values.iter().map(|v| Felt::from(*v)).collect()
+    }
+
+    /// Regenerates `2 * 12 * rounds` constants from `seed` and splits them by round halves.
+    fn generate_constants(seed: &str, rounds: usize) -> (Vec<[u64; 12]>, Vec<[u64; 12]>) {
+        let mut shake = Shake256::default();
+        shake.update(seed.as_bytes());
+        let mut reader = shake.finalize_xof();
+        let mut constants = (0..2 * STATE_WIDTH * rounds).map(|_| {
+            let mut bytes = [0_u8; 9];
+            reader.read(&mut bytes);
+            let value = bytes
+                .iter()
+                .rev()
+                .fold(0_u128, |acc, byte| (acc << 8) | *byte as u128);
+            (value % GOLDILOCKS_PRIME as u128) as u64
+        });
+        let mut ark1 = Vec::new();
+        let mut ark2 = Vec::new();
+        for _ in 0..rounds {
+            ark1.push(core::array::from_fn(|_| constants.next().unwrap()));
+            ark2.push(core::array::from_fn(|_| constants.next().unwrap()));
+        }
+        (ark1, ark2)
+    }
+
+    #[test]
+    fn round_constants_match_their_generation() {
+        let (ark1, ark2) = generate_constants("RPO(18446744069414584321,12,4,128)", 7);
+        assert_eq!(ark1, RPO_ARK1);
+        assert_eq!(ark2, RPO_ARK2);
+
+        let (ark1, ark2) = generate_constants("Rescue-XLIX(18446744069414584321,12,4,128)", 8);
+        assert_eq!(ark1, RESCUE_PRIME_ARK1);
+        assert_eq!(ark2, RESCUE_PRIME_ARK2);
+    }
+
+    #[test]
+    fn rpo_mds_is_circulant() {
+        let first_row = [7, 23, 8, 26, 13, 10, 9, 7, 6, 22, 21, 8];
+        for (i, row) in RPO_MDS.iter().enumerate() {
+            for (j, entry) in row.iter().enumerate() {
+                assert_eq!(*entry, first_row[(j + STATE_WIDTH - i) % STATE_WIDTH]);
+            }
+        }
+    }
+
+    #[test]
+    fn inverse_sbox_inverts_sbox() {
+        let mut state: [Felt; STATE_WIDTH] =
+            core::array::from_fn(|i| Felt::from(i as u64 * 1234567 + 89));
+        let expected = state;
+        apply_sbox(&mut state);
+        assert_ne!(state, expected);
+        apply_inv_sbox(&mut state);
+        assert_eq!(state, expected);
+    }
+
+    #[test]
+    fn cubic_exp7_reduces_by_x3_minus_x_minus_1() {
+        // x^7 = 2x^2 + 2x + 1 modulo x^3 - x - 1.
+        let x = [Felt::zero(), Felt::one(), Felt::zero()];
+        assert_eq!(cubic_exp7(&x).to_vec(), felts(&[1, 2, 2]));
+    }
+
+    #[test]
+    fn merge_permutes_the_concatenated_digests() {
+        let left: RescueDigest = core::array::from_fn(|i| Felt::from(i as u64));
+        let right: RescueDigest = core::array::from_fn(|i| Felt::from(i as u64 + 4));
+
+        let mut state: [Felt; STATE_WIDTH] = core::array::from_fn(|_| Felt::zero());
+        state[RATE_RANGE].clone_from_slice(&felts(&[0, 1, 2, 3, 4, 5, 6, 7]));
+        Rpo256::apply_permutation(&mut state);
+
+        assert_eq!(Rpo256::merge(&left, &right).to_vec(), state[DIGEST_RANGE]);
+        assert_ne!(Rpo256::merge(&left, &right), Rpo256::merge(&right, &left));
+    }
+
+    #[test]
+    fn hash_elements_separates_lengths() {
+        let short = felts(&[1, 2, 3]);
+        let padded = felts(&[1, 2, 3, 0]);
+        assert_ne!(
+            Rpo256::hash_elements(&short),
+            Rpo256::hash_elements(&padded)
+        );
+        assert_ne!(
+            Rpx256::hash_elements(&short),
+            Rpx256::hash_elements(&padded)
+        );
+
+        // A full rate doesn't need padding, and its digest is that of a single permutation.
+        let full = felts(&[1, 2, 3, 4, 5, 6, 7, 8]);
+        let mut state: [Felt; STATE_WIDTH] = core::array::from_fn(|_| Felt::zero());
+        state[RATE_RANGE].clone_from_slice(&full);
+        Rpo256::apply_permutation(&mut state);
+        assert_eq!(Rpo256::hash_elements(&full).to_vec(), state[DIGEST_RANGE]);
+    }
+
+    #[test]
+    fn permutations_are_different() {
+        let input: Vec<Felt> = felts(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+        let rpo = Rpo256::hash_elements(&input);
+        let rpx = Rpx256::hash_elements(&input);
+        let rescue_prime = RescuePrime256::hash_elements(&input);
+        assert_ne!(rpo, rpx);
+        assert_ne!(rpo, rescue_prime);
+        assert_ne!(rpx, rescue_prime);
+    }
+
+    // Digests of `hash_elements` on `[0, 1, ..., n - 1]` for `n` from 1 to 19, the inputs of the
+    // test vectors of Miden's `Rpo256`.
+    const RPO_DIGESTS: [[u64; DIGEST_SIZE]; 19] = [
+        [
+            18126731724905382595,
+            7388557040857728717,
+            14290750514634285295,
+            7852282086160480146,
+        ],
+        [
+            10139303045932500183,
+            2293916558361785533,
+            15496361415980502047,
+            17904948502382283940,
+        ],
+        [
+            17457546260239634015,
+            803990662839494686,
+            10386005777401424878,
+            18168807883298448638,
+        ],
+        [
+            13072499238647455740,
+            10174350003422057273,
+            9201651627651151113,
+            6872461887313298746,
+        ],
+        [
+            2903803350580990546,
+            1838870750730563299,
+            4258619137315479708,
+            17334260395129062936,
+        ],
+        [
+            8571221005243425262,
+            3016595589318175865,
+            13933674291329928438,
+            678640375034313072,
+        ],
+        [
+            16314113978986502310,
+            14587622368743051587,
+            2808708361436818462,
+            10660517522478329440,
+        ],
+        [
+            2242391899857912644,
+            12689382052053305418,
+            235236990017815546,
+            5046143039268215739,
+        ],
+        [
+            5218076004221736204,
+            17169400568680971304,
+            8840075572473868990,
+            12382372614369863623,
+        ],
+        [
+            9783834557155203486,
+            12317263104955018849,
+            3933748931816109604,
+            1843043029836917214,
+        ],
+        [
+            14498234468286984551,
+            16837257669834682387,
+            6664141123711355107,
+            4590460158294697186,
+        ],
+        [
+            4661800562479916067,
+            11794407552792839953,
+            9037742258721863712,
+            6287820818064278819,
+        ],
+        [
+            7752693085194633729,
+            7379857372245835536,
+            9270229380648024178,
+            10638301488452560378,
+        ],
+        [
+            11542686762698783357,
+            15570714990728449027,
+            7518801014067819501,
+            12706437751337583515,
+        ],
+        [
+            9553923701032839042,
+            7281190920209838818,
+            2488477917448393955,
+            5088955350303368837,
+        ],
+        [
+            4935426252518736883,
+            12584230452580950419,
+            8762518969632303998,
+            18159875708229758073,
+        ],
+        [
+            12795429638314178838,
+            14360248269767567855,
+            3819563852436765058,
+            10859123583999067291,
+        ],
+        [
+            2695742617679420093,
+            9151515850666059759,
+            15855828029180595485,
+            17190029785471463210,
+        ],
+        [
+            13205273108219124830,
+            2524898486192849221,
+            14618764355375283547,
+            10615614265042186874,
+        ],
+    ];
+
+    const RPX_DIGESTS: [[u64; DIGEST_SIZE]; 19] = [
+        [
+            15293807115397414812,
+            15290017247514670316,
+            10548590320248089637,
+            9459855167724924903,
+        ],
+        [
+            12186327779210739392,
+            12437198001472812457,
+            17431583359007807548,
+            5889070798901825636,
+        ],
+        [
+            109841543348983755,
+            17705465395673162594,
+            5228101643025463311,
+            7748133072458912307,
+        ],
+        [
+            12729520246190904536,
+            6715713369175329478,
+            13802021724186903884,
+            16589532398625893763,
+        ],
+        [
+            3191491209909564984,
+            4336372174992679659,
+            3812090377223784023,
+            16173224027531585338,
+        ],
+        [
+            6461289079179018348,
+            10449674711255412289,
+            5054891760098348434,
+            10721040246835958771,
+        ],
+        [
+            16191592956183275197,
+            746532334447080722,
+            15358793909583453268,
+            9513601171909830185,
+        ],
+        [
+            12373829276206882697,
+            10138650388065685463,
+            15520480835694974951,
+            2510219987660336228,
+        ],
+        [
+            14898769958092295192,
+            14076282783168040015,
+            8476014900264177995,
+            17336863755113979084,
+        ],
+        [
+            17237194195242105781,
+            6087397938124003113,
+            1345882193144969073,
+            14783461183116020251,
+        ],
+        [
+            4575950952442466526,
+            10298089839422454303,
+            14861479923204285799,
+            11880231458488351907,
+        ],
+        [
+            9169920211008402116,
+            12659190867532264163,
+            13563500138844524911,
+            12617975739035351823,
+        ],
+        [
+            17454638445264588716,
+            8802637143045803178,
+            13982112504343449988,
+            17442048147529824646,
+        ],
+        [
+            11373557723159380221,
+            17180935309137919099,
+            3242047510064238430,
+            12672923945735822946,
+        ],
+        [
+            6214573915685641755,
+            17951587517596484461,
+            11692428935571224516,
+            6628032869761165814,
+        ],
+        [
+            586102497461023489,
+            11384107678327501002,
+            10422108750253329853,
+            7699259539482247907,
+        ],
+        [
+            16846145822493683059,
+            6007639340046859794,
+            13049520400071115122,
+            5060263239960030371,
+        ],
+        [
+            6509160877964314093,
+            12642155348170163940,
+            7507001761825557252,
+            4565405860198708542,
+        ],
+        [
+            17905682982576162590,
+            5720278714894771907,
+            9596600499219832172,
+            5974292660959196,
+        ],
+    ];
+
+    fn check_digests<P: RescuePermutation>(expected: &[[u64; DIGEST_SIZE]]) {
+        let elements: Vec<Felt> = (0..expected.len() as u64).map(Felt::from).collect();
+        for (n, digest) in (1..).zip(expected) {
+            assert_eq!(
+                P::hash_elements(&elements[..n]).to_vec(),
+                felts(digest),
+                "length {n}"
+            );
+        }
+
+        // Merging [0, 1, 2, 3] and [4, 5, 6, 7] permutes the same state as hashing 0..8.
+        let left: RescueDigest = core::array::from_fn(|i| Felt::from(i as u64));
+        let right: RescueDigest = core::array::from_fn(|i| Felt::from(i as u64 + 4));
+        assert_eq!(P::merge(&left, &right).to_vec(), felts(&expected[7]));
+    }
+
+    #[test]
+    fn rpo_digests_match_known_answers() {
+        check_digests::<Rpo256>(&RPO_DIGESTS);
+    }
+
+    #[test]
+    fn rpx_digests_match_known_answers() {
+        check_digests::<Rpx256>(&RPX_DIGESTS);
+    }
+}
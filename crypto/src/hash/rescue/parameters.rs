@@ -0,0 +1,635 @@
+use super::STATE_WIDTH;
+
+// Round constants of RPO, also used by RPX. They are the output of SHAKE256 on the seed
+// "RPO(18446744069414584321,12,4,128)", read as integers of 9 bytes in little-endian order and
+// reduced modulo the Goldilocks prime. Round `i` takes the constants `24 i..24 i + 12` for the
+// first half and `24 i + 12..24 i + 24` for the second half.
+
+/// Constants of the first half of each RPO round.
+pub const RPO_ARK1: [[u64; STATE_WIDTH]; 7] = [
+    [
+        5789762306288267392,
+        6522564764413701783,
+        17809893479458208203,
+        107145243989736508,
+        6388978042437517382,
+        15844067734406016715,
+        9975000513555218239,
+        3344984123768313364,
+        9959189626657347191,
+        12960773468763563665,
+        9602914297752488475,
+        16657542370200465908,
+    ],
+    [
+        12987190162843096997,
+        653957632802705281,
+        4441654670647621225,
+        4038207883745915761,
+        5613464648874830118,
+        13222989726778338773,
+        3037761201230264149,
+        16683759727265180203,
+        8337364536491240715,
+        3227397518293416448,
+        8110510111539674682,
+        2872078294163232137,
+    ],
+    [
+        18072785500942327487,
+        6200974112677013481,
+        17682092219085884187,
+        10599526828986756440,
+        975003873302957338,
+        8264241093196931281,
+        10065763900435475170,
+        2181131744534710197,
+        6317303992309418647,
+        1401440938888741532,
+        8884468225181997494,
+        13066900325715521532,
+    ],
+    [
+        5674685213610121970,
+        5759084860419474071,
+        13943282657648897737,
+        1352748651966375394,
+        17110913224029905221,
+        1003883795902368422,
+        4141870621881018291,
+        8121410972417424656,
+        14300518605864919529,
+        13712227150607670181,
+        17021852944633065291,
+        6252096473787587650,
+    ],
+    [
+        4887609836208846458,
+        3027115137917284492,
+        9595098600469470675,
+        10528569829048484079,
+        7864689113198939815,
+        17533723827845969040,
+        5781638039037710951,
+        17024078752430719006,
+        109659393484013511,
+        7158933660534805869,
+        2955076958026921730,
+        7433723648458773977,
+    ],
+    [
+        16308865189192447297,
+        11977192855656444890,
+        12532242556065780287,
+        14594890931430968898,
+        7291784239689209784,
+        5514718540551361949,
+        10025733853830934803,
+        7293794580341021693,
+        6728552937464861756,
+        6332385040983343262,
+        13277683694236792804,
+        2600778905124452676,
+    ],
+    [
+        7123075680859040534,
+        1034205548717903090,
+        7717824418247931797,
+        3019070937878604058,
+        11403792746066867460,
+        10280580802233112374,
+        337153209462421218,
+        13333398568519923717,
+        3596153696935337464,
+        8104208463525993784,
+        14345062289456085693,
+        17036731477169661256,
+    ],
+];
+
+/// Constants of the second half of each RPO round.
+pub const RPO_ARK2: [[u64; STATE_WIDTH]; 7] = [
+    [
+        6077062762357204287,
+        15277620170502011191,
+        5358738125714196705,
+        14233283787297595718,
+        13792579614346651365,
+        11614812331536767105,
+        14871063686742261166,
+        10148237148793043499,
+        4457428952329675767,
+        15590786458219172475,
+        10063319113072092615,
+        14200078843431360086,
+    ],
+    [
+        6202948458916099932,
+        17690140365333231091,
+        3595001575307484651,
+        373995945117666487,
+        1235734395091296013,
+        14172757457833931602,
+        707573103686350224,
+        15453217512188187135,
+        219777875004506018,
+        17876696346199469008,
+        17731621626449383378,
+        2897136237748376248,
+    ],
+    [
+        8023374565629191455,
+        15013690343205953430,
+        4485500052507912973,
+        12489737547229155153,
+        9500452585969030576,
+        2054001340201038870,
+        12420704059284934186,
+        355990932618543755,
+        9071225051243523860,
+        12766199826003448536,
+        9045979173463556963,
+        12934431667190679898,
+    ],
+    [
+        18389244934624494276,
+        16731736864863925227,
+        4440209734760478192,
+        17208448209698888938,
+        8739495587021565984,
+        17000774922218161967,
+        13533282547195532087,
+        525402848358706231,
+        16987541523062161972,
+        5466806524462797102,
+        14512769585918244983,
+        10973956031244051118,
+    ],
+    [
+        6982293561042362913,
+        14065426295947720331,
+        16451845770444974180,
+        7139138592091306727,
+        9012006439959783127,
+        14619614108529063361,
+        1394813199588124371,
+        4635111139507788575,
+        16217473952264203365,
+        10782018226466330683,
+        6844229992533662050,
+        7446486531695178711,
+    ],
+    [
+        3736792340494631448,
+        577852220195055341,
+        6689998335515779805,
+        13886063479078013492,
+        14358505101923202168,
+        7744142531772274164,
+        16135070735728404443,
+        12290902521256031137,
+        12059913662657709804,
+        16456018495793751911,
+        4571485474751953524,
+        17200392109565783176,
+    ],
+    [
+        17130398059294018733,
+        519782857322261988,
+        9625384390925085478,
+        1664893052631119222,
+        7629576092524553570,
+        3485239601103661425,
+        9755891797164033838,
+        15218148195153269027,
+        16460604813734957368,
+        9643968136937729763,
+        3611348709641382851,
+        18256379591337759196,
+    ],
+];
+
+/// MDS matrix of RPO and RPX, the circulant matrix with first row
+/// `[7, 23, 8, 26, 13, 10, 9, 7, 6, 22, 21, 8]`.
+pub const RPO_MDS: [[u64; STATE_WIDTH]; 12] = [
+    [7, 23, 8, 26, 13, 10, 9, 7, 6, 22, 21, 8],
+    [8, 7, 23, 8, 26, 13, 10, 9, 7, 6, 22, 21],
+    [21, 8, 7, 23, 8, 26, 13, 10, 9, 7, 6, 22],
+    [22, 21, 8, 7, 23, 8, 26, 13, 10, 9, 7, 6],
+    [6, 22, 21, 8, 7, 23, 8, 26, 13, 10, 9, 7],
+    [7, 6, 22, 21, 8, 7, 23, 8, 26, 13, 10, 9],
+    [9, 7, 6, 22, 21, 8, 7, 23, 8, 26, 13, 10],
+    [10, 9, 7, 6, 22, 21, 8, 7, 23, 8, 26, 13],
+    [13, 10, 9, 7, 6, 22, 21, 8, 7, 23, 8, 26],
+    [26, 13, 10, 9, 7, 6, 22, 21, 8, 7, 23, 8],
+    [8, 26, 13, 10, 9, 7, 6, 22, 21, 8, 7, 23],
+    [23, 8, 26, 13, 10, 9, 7, 6, 22, 21, 8, 7],
+];
+
+// Round constants of Rescue-Prime, generated like those of RPO from the seed
+// "Rescue-XLIX(18446744069414584321,12,4,128)".
+
+/// Constants of the first half of each Rescue-Prime round.
+pub const RESCUE_PRIME_ARK1: [[u64; STATE_WIDTH]; 8] = [
+    [
+        16089809142501829443,
+        3960375389654894755,
+        2341987601489900096,
+        16513505200733590422,
+        2491992808872511534,
+        2243959319871113313,
+        1072250566756987431,
+        9576211715023554739,
+        13816740116943445245,
+        1013981081016507493,
+        6469202228346393176,
+        651486455260752235,
+    ],
+    [
+        6770068611756627448,
+        9429015895190610092,
+        6345154718738704426,
+        1348264131729825254,
+        11257253180296854021,
+        10209505772531486556,
+        13936278878169192368,
+        465229985152496221,
+        16122840733837976660,
+        15126432412337961371,
+        18195743520412640434,
+        4482481892207055145,
+    ],
+    [
+        4392703580426358869,
+        1665895348145983,
+        4219736658995217386,
+        1227613135081507795,
+        8190773212267744239,
+        8282001820492621236,
+        15836395107332526493,
+        5607076305580595108,
+        8785440730814333716,
+        15628355668353690236,
+        15635676168256493691,
+        8231009457495604357,
+    ],
+    [
+        3242413417035426569,
+        10974415453760425628,
+        18279530845486603448,
+        14045481066120861736,
+        12525452082923300704,
+        1905254592892409109,
+        9346668368089967636,
+        1735104742415647612,
+        3317525224474295113,
+        3946195652028520851,
+        444992070656934445,
+        3102693390775176900,
+    ],
+    [
+        11524270175738513568,
+        16596131169768068084,
+        12046592239696686456,
+        10335258789985873044,
+        3804833210737803414,
+        4871342344579357943,
+        5506150606643613730,
+        1144769156473837296,
+        15770771149643607584,
+        22835664835299105,
+        15624512048862012204,
+        8438597895149015250,
+    ],
+    [
+        2077569020629574154,
+        29247543278389127,
+        7513950682870485886,
+        14493142396838430095,
+        13137935083971782251,
+        17044896521696396448,
+        8358879158995995396,
+        6631372338926182917,
+        16141080336903561376,
+        12097878985033236818,
+        16582826484887094232,
+        11184522740344979309,
+    ],
+    [
+        11545814656420730331,
+        7520668505762229291,
+        5433441394427246897,
+        17588828388580402390,
+        8308794351872961990,
+        14007549481740032380,
+        15898890571959671932,
+        812931430828255689,
+        6818534534911166209,
+        12562621953249472036,
+        3817830678013523962,
+        16954219307307160453,
+    ],
+    [
+        9887604593884716994,
+        1641991848588750522,
+        139136899117674696,
+        642800505514805560,
+        13708493789701921418,
+        7748174686095522614,
+        9519116928453577555,
+        15553022923392481658,
+        15333821711322552149,
+        4568325078327177585,
+        13469947113925392874,
+        10217428523547140877,
+    ],
+];
+
+/// Constants of the second half of each Rescue-Prime round.
+pub const RESCUE_PRIME_ARK2: [[u64; STATE_WIDTH]; 8] = [
+    [
+        10659391161334081468,
+        6658732499907968660,
+        13472970356821082105,
+        11254129182906430457,
+        2200184099877207561,
+        9367536782889046900,
+        5776283441396365529,
+        15880305242785227614,
+        15064577366950298089,
+        17182365414675952436,
+        221227465681839092,
+        10904420836212840752,
+    ],
+    [
+        9371429429698492981,
+        15659859461375396037,
+        3395558493871255061,
+        660144660555450404,
+        5074125520981119417,
+        17453702653133595770,
+        11221110160893954851,
+        6495862879055376432,
+        17061625752140729123,
+        12368428993775985339,
+        8908366829754037876,
+        2078111330029178445,
+    ],
+    [
+        13168535446547922823,
+        18239226123757899503,
+        7641189915286036988,
+        7820691679952216969,
+        1111836394951152974,
+        139835781513562161,
+        7076109422888404220,
+        5005587840202053100,
+        6487413309175970078,
+        5695661949695470409,
+        18151333218502551049,
+        12789465505850716019,
+    ],
+    [
+        17167036726114384788,
+        5848569342998419381,
+        14114543252495674018,
+        15114629034072612072,
+        5270549373288442547,
+        12129247407828856056,
+        18281855207204785420,
+        597402865817114738,
+        6042112508927673927,
+        112810046686999112,
+        2881728079621071110,
+        3443512534203368354,
+    ],
+    [
+        13297012143576436426,
+        7353183188832933627,
+        14475065819552011569,
+        1989958170371263671,
+        2759712450935595252,
+        5888211745553259072,
+        3366223208861836535,
+        10871170457430163614,
+        7436939156294010029,
+        10083282185253045512,
+        1727628517966770716,
+        15876537645083757620,
+    ],
+    [
+        14491184939776942308,
+        16755331289686337123,
+        4204064227783814013,
+        17375825663893345502,
+        16513382692712470059,
+        12671191098792302109,
+        7367953856881804491,
+        4828831248603618923,
+        605213678344474020,
+        10779667723419446880,
+        15588592678889744953,
+        16719715619459928934,
+    ],
+    [
+        7976559292405617294,
+        10624879739965265183,
+        11858994588137577101,
+        6953938202587799945,
+        15487983798101099477,
+        828942630404743552,
+        15918441202173246890,
+        10151280024237311966,
+        10562603357011259664,
+        18397974285238070711,
+        878544804620014725,
+        16579617335735550589,
+    ],
+    [
+        5830949568398165406,
+        15154067596049030386,
+        16528039086724181517,
+        12956340827125720850,
+        15326666748548010663,
+        7814071395104980331,
+        4360149182440697847,
+        8172157773433474587,
+        6361462243907836958,
+        4235781400532735099,
+        15883259392726441221,
+        11205339735648717165,
+    ],
+];
+
+/// MDS matrix of Rescue-Prime: with `V` the `12 x 24` Vandermonde matrix of the powers of the
+/// generator 7, it's the transpose of the right half of the reduced echelon form of `V`.
+pub const RESCUE_PRIME_MDS: [[u64; STATE_WIDTH]; 12] = [
+    [
+        2108866337646019936,
+        11223275256334781131,
+        2318414738826783588,
+        11240468238955543594,
+        8007389560317667115,
+        11080831380224887131,
+        3922954383102346493,
+        17194066286743901609,
+        152620255842323114,
+        7203302445933022224,
+        17781531460838764471,
+        2306881200,
+    ],
+    [
+        3368836954250922620,
+        5531382716338105518,
+        7747104620279034727,
+        14164487169476525880,
+        4653455932372793639,
+        5504123103633670518,
+        3376629427948045767,
+        1687083899297674997,
+        8324288417826065247,
+        17651364087632826504,
+        15568475755679636039,
+        4656488262337620150,
+    ],
+    [
+        2560535215714666606,
+        10793518538122219186,
+        408467828146985886,
+        13894393744319723897,
+        17856013635663093677,
+        14510101432365346218,
+        12175743201430386993,
+        12012700097100374591,
+        976880602086740182,
+        3187015135043748111,
+        4630899319883688283,
+        17674195666610532297,
+    ],
+    [
+        10940635879119829731,
+        9126204055164541072,
+        13441880452578323624,
+        13828699194559433302,
+        6245685172712904082,
+        3117562785727957263,
+        17389107632996288753,
+        3643151412418457029,
+        10484080975961167028,
+        4066673631745731889,
+        8847974898748751041,
+        9548808324754121113,
+    ],
+    [
+        15656099696515372126,
+        309741777966979967,
+        16075523529922094036,
+        5384192144218250710,
+        15171244241641106028,
+        6660319859038124593,
+        6595450094003204814,
+        15330207556174961057,
+        2687301105226976975,
+        15907414358067140389,
+        2767130804164179683,
+        8135839249549115549,
+    ],
+    [
+        14687393836444508153,
+        8122848807512458890,
+        16998154830503301252,
+        2904046703764323264,
+        11170142989407566484,
+        5448553946207765015,
+        9766047029091333225,
+        3852354853341479440,
+        14577128274897891003,
+        11994931371916133447,
+        8299269445020599466,
+        2859592328380146288,
+    ],
+    [
+        4920761474064525703,
+        13379538658122003618,
+        3169184545474588182,
+        15753261541491539618,
+        622292315133191494,
+        14052907820095169428,
+        5159844729950547044,
+        17439978194716087321,
+        9945483003842285313,
+        13647273880020281344,
+        14750994260825376,
+        12575187259316461486,
+    ],
+    [
+        3371852905554824605,
+        8886257005679683950,
+        15677115160380392279,
+        13242906482047961505,
+        12149996307978507817,
+        1427861135554592284,
+        4033726302273030373,
+        14761176804905342155,
+        11465247508084706095,
+        12112647677590318112,
+        17343938135425110721,
+        14654483060427620352,
+    ],
+    [
+        5421794552262605237,
+        14201164512563303484,
+        5290621264363227639,
+        1020180205893205576,
+        14311345105258400438,
+        7828111500457301560,
+        9436759291445548340,
+        5716067521736967068,
+        15357555109169671716,
+        4131452666376493252,
+        16785275933585465720,
+        11180136753375315897,
+    ],
+    [
+        10451661389735482801,
+        12128852772276583847,
+        10630876800354432923,
+        6884824371838330777,
+        16413552665026570512,
+        13637837753341196082,
+        2558124068257217718,
+        4327919242598628564,
+        4236040195908057312,
+        2081029262044280559,
+        2047510589162918469,
+        6835491236529222042,
+    ],
+    [
+        5675273097893923172,
+        8120839782755215647,
+        9856415804450870143,
+        1960632704307471239,
+        15279057263127523057,
+        17999325337309257121,
+        72970456904683065,
+        8899624805082057509,
+        16980481565524365258,
+        6412696708929498357,
+        13917768671775544479,
+        5505378218427096880,
+    ],
+    [
+        10318314766641004576,
+        17320192463105632563,
+        11540812969169097044,
+        7270556942018024148,
+        4755326086930560682,
+        2193604418377108959,
+        11681945506511803967,
+        8000243866012209465,
+        6746478642521594042,
+        12096331252283646217,
+        13208137848575217268,
+        5548519654341606996,
+    ],
+];
@@ -0,0 +1,325 @@
+//! Duplex sponge over any field permutation, following the SAFE API (Sponge API for Field
+//! Elements).
+//!
+//! A sponge is started with the sequence of calls it will receive, its IO pattern, and a
+//! domain separator. Both are hashed into a tag that initializes the capacity, so sponges for
+//! different patterns or domains are independent, and inputs of different lengths need no
+//! padding. The calls are then checked against the pattern.
+//!
+//! When the calls aren't known in advance, the sponge can also be used without a pattern,
+//! padding variable-length inputs with [DuplexSponge::absorb_padded].
+
+pub mod permutation;
+
+pub use permutation::{FieldPermutation, Poseidon2Permutation, PoseidonPermutation};
+
+use lambdaworks_math::field::element::FieldElement as FE;
+use sha3::{Digest, Sha3_256};
+
+/// A call to a sponge, with the number of elements absorbed or squeezed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpongeOp {
+    Absorb(u32),
+    Squeeze(u32),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum SpongeError {
+    /// The pattern has a call of length zero, or consecutive calls of the same kind adding up
+    /// to `2^31` elements or more.
+    InvalidPattern,
+    /// The call doesn't match the next one in the pattern.
+    PatternViolation(SpongeOp),
+    /// The sponge was finished before all the calls of the pattern were made.
+    UnfinishedPattern,
+    /// Padded absorption is only allowed without a pattern.
+    PaddingWithPattern,
+}
+
+/// Duplex sponge over the permutation `P`.
+///
+/// Absorbed elements are added to the rate part of the state, which is permuted when it's full
+/// and more elements come. Squeezed elements are read from the rate part, which is permuted
+/// before the first squeeze after absorbing and each time it's exhausted.
+pub struct DuplexSponge<P: FieldPermutation> {
+    state: Vec<FE<P::F>>,
+    /// Position in the rate part of the next element to absorb.
+    absorb_position: usize,
+    /// Position in the rate part of the next element to squeeze.
+    squeeze_position: usize,
+    /// Calls of the pattern not made yet, in reverse order, if the sponge follows one.
+    remaining_calls: Option<Vec<SpongeOp>>,
+}
+
+impl<P: FieldPermutation> DuplexSponge<P> {
+    /// Starts a sponge that will receive exactly the calls of `pattern`.
+    pub fn new(pattern: &[SpongeOp], domain_separator: &[u8]) -> Result<Self, SpongeError> {
+        let mut sponge = Self::start(&encode_pattern(pattern)?, domain_separator);
+        sponge.remaining_calls = Some(pattern.iter().rev().copied().collect());
+        Ok(sponge)
+    }
+
+    /// Starts a sponge that accepts any sequence of calls. Its tag only depends on the domain
+    /// separator, so inputs of variable length must be absorbed with
+    /// [absorb_padded](Self::absorb_padded) to keep them apart.
+    pub fn new_without_pattern(domain_separator: &[u8]) -> Self {
+        Self::start(&[], domain_separator)
+    }
+
+    fn start(encoded_pattern: &[u8], domain_separator: &[u8]) -> Self {
+        let mut hasher = Sha3_256::new();
+        hasher.update(encoded_pattern);
+        hasher.update(domain_separator);
+        let digest = hasher.finalize();
+        let tag = [0, 8].map(|i| {
+            let mut limb = [0_u8; 8];
+            limb.copy_from_slice(&digest[i..i + 8]);
+            FE::<P::F>::from(u64::from_le_bytes(limb))
+        });
+
+        let mut state = vec![FE::zero(); P::WIDTH];
+        let mut capacity =
+            (0..P::WIDTH).filter(|i| !(P::RATE_OFFSET..P::RATE_OFFSET + P::RATE).contains(i));
+        // The first 128 bits of the hash, as two 64-bit limbs in the first two elements of the
+        // capacity, or together in the only one.
+        let first = capacity.next().expect("the capacity is not empty");
+        match capacity.next() {
+            Some(second) => {
+                let [low, high] = tag;
+                state[first] = low;
+                state[second] = high;
+            }
+            None => {
+                let two_pow_32 = FE::<P::F>::from(1_u64 << 32);
+                let [low, high] = tag;
+                state[first] = low + high * &two_pow_32 * two_pow_32;
+            }
+        }
+
+        Self {
+            state,
+            absorb_position: 0,
+            squeeze_position: P::RATE,
+            remaining_calls: None,
+        }
+    }
+
+    /// Absorbs `inputs`. With a pattern, the call must be the next one in it.
+    pub fn absorb(&mut self, inputs: &[FE<P::F>]) -> Result<(), SpongeError> {
+        self.follow_pattern(SpongeOp::Absorb(inputs.len() as u32))?;
+        for input in inputs {
+            if self.absorb_position == P::RATE {
+                P::permute(&mut self.state);
+                self.absorb_position = 0;
+            }
+            let position = P::RATE_OFFSET + self.absorb_position;
+            self.state[position] = &self.state[position] + input;
+            self.absorb_position += 1;
+        }
+        self.squeeze_position = P::RATE;
+        Ok(())
+    }
+
+    /// Absorbs `inputs` followed by a one and as many zeros as needed to fill the rate, so that
+    /// inputs of different lengths are absorbed differently. Only for sponges without a
+    /// pattern.
+    pub fn absorb_padded(&mut self, inputs: &[FE<P::F>]) -> Result<(), SpongeError> {
+        if self.remaining_calls.is_some() {
+            return Err(SpongeError::PaddingWithPattern);
+        }
+        let mut values = inputs.to_vec();
+        values.push(FE::one());
+        let filled = (self.absorb_position + values.len()) % P::RATE;
+        if filled != 0 {
+            values.resize(values.len() + P::RATE - filled, FE::zero());
+        }
+        self.absorb(&values)
+    }
+
+    /// Squeezes `length` elements, permuting as many times as needed.
+    pub fn squeeze(&mut self, length: usize) -> Result<Vec<FE<P::F>>, SpongeError> {
+        self.follow_pattern(SpongeOp::Squeeze(length as u32))?;
+        let mut outputs = Vec::with_capacity(length);
+        for _ in 0..length {
+            if self.squeeze_position == P::RATE {
+                P::permute(&mut self.state);
+                self.squeeze_position = 0;
+                self.absorb_position = 0;
+            }
+            outputs.push(self.state[P::RATE_OFFSET + self.squeeze_position].clone());
+            self.squeeze_position += 1;
+        }
+        Ok(outputs)
+    }
+
+    /// Ends the sponge, checking that all the calls of its pattern were made.
+    pub fn finish(self) -> Result<(), SpongeError> {
+        match self.remaining_calls {
+            Some(calls) if !calls.is_empty() => Err(SpongeError::UnfinishedPattern),
+            _ => Ok(()),
+        }
+    }
+
+    fn follow_pattern(&mut self, call: SpongeOp) -> Result<(), SpongeError> {
+        if let Some(calls) = self.remaining_calls.as_mut() {
+            if calls.last() != Some(&call) {
+                return Err(SpongeError::PatternViolation(call));
+            }
+            calls.pop();
+        }
+        Ok(())
+    }
+}
+
+/// Encodes a pattern like SAFE: consecutive calls of the same kind are merged, and each group
+/// is a 32-bit big-endian word, `2^31 + n` for `n` absorbed elements and `n` for `n` squeezed
+/// ones.
+fn encode_pattern(pattern: &[SpongeOp]) -> Result<Vec<u8>, SpongeError> {
+    let mut words: Vec<(bool, u32)> = Vec::new();
+    for call in pattern {
+        let (is_absorb, length) = match *call {
+            SpongeOp::Absorb(length) => (true, length),
+            SpongeOp::Squeeze(length) => (false, length),
+        };
+        if length == 0 {
+            return Err(SpongeError::InvalidPattern);
+        }
+        match words.last_mut() {
+            Some((last_is_absorb, total)) if *last_is_absorb == is_absorb => {
+                *total = total
+                    .checked_add(length)
+                    .ok_or(SpongeError::InvalidPattern)?;
+            }
+            _ => words.push((is_absorb, length)),
+        }
+    }
+
+    let mut encoded = Vec::with_capacity(4 * words.len());
+    for (is_absorb, total) in words {
+        if total >= 1 << 31 {
+            return Err(SpongeError::InvalidPattern);
+        }
+        let word = if is_absorb { total | (1 << 31) } else { total };
+        encoded.extend_from_slice(&word.to_be_bytes());
+    }
+    Ok(encoded)
+}
+
+/// Hashes `inputs` to `output_length` elements with a sponge over `P` following the pattern
+/// `[Absorb(inputs.len()), Squeeze(output_length)]`.
+pub fn hash<P: FieldPermutation>(
+    domain_separator: &[u8],
+    inputs: &[FE<P::F>],
+    output_length: usize,
+) -> Result<Vec<FE<P::F>>, SpongeError> {
+    let mut pattern = Vec::new();
+    if !inputs.is_empty() {
+        pattern.push(SpongeOp::Absorb(inputs.len() as u32));
+    }
+    pattern.push(SpongeOp::Squeeze(output_length as u32));
+
+    let mut sponge = DuplexSponge::<P>::new(&pattern, domain_separator)?;
+    if !inputs.is_empty() {
+        sponge.absorb(inputs)?;
+    }
+    let outputs = sponge.squeeze(output_length)?;
+    sponge.finish()?;
+    Ok(outputs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hash::{
+        poseidon::starknet::PoseidonCairoStark252, poseidon2::Poseidon2Goldilocks8, rescue::Rpo256,
+    };
+    use lambdaworks_math::field::fields::u64_goldilocks_field::Goldilocks64Field;
+
+    type Sponge = DuplexSponge<Poseidon2Permutation<Poseidon2Goldilocks8>>;
+    type FpE = FE<Goldilocks64Field>;
+
+    fn elements(range: core::ops::Range<u64>) -> Vec<FpE> {
+        range.map(FpE::from).collect()
+    }
+
+    #[test]
+    fn calls_can_be_split_without_a_pattern() {
+        let mut sponge = Sponge::new_without_pattern(b"test");
+        sponge.absorb(&elements(0..3)).unwrap();
+        sponge.absorb(&elements(3..10)).unwrap();
+        let mut outputs = sponge.squeeze(2).unwrap();
+        outputs.extend(sponge.squeeze(7).unwrap());
+
+        let mut sponge = Sponge::new_without_pattern(b"test");
+        sponge.absorb(&elements(0..10)).unwrap();
+        assert_eq!(sponge.squeeze(9).unwrap(), outputs);
+    }
+
+    #[test]
+    fn domain_separators_and_patterns_change_the_outputs() {
+        type P = Poseidon2Permutation<Poseidon2Goldilocks8>;
+        let inputs = elements(0..5);
+        let output = hash::<P>(b"domain", &inputs, 4).unwrap();
+
+        assert_ne!(hash::<P>(b"other domain", &inputs, 4).unwrap(), output);
+        // The output length is part of the pattern, so outputs aren't prefixes of each other.
+        assert_ne!(hash::<P>(b"domain", &inputs, 8).unwrap()[..4], output);
+        assert_ne!(hash::<P>(b"domain", &elements(0..4), 4).unwrap(), output);
+    }
+
+    #[test]
+    fn calls_must_follow_the_pattern() {
+        let pattern = [
+            SpongeOp::Absorb(2),
+            SpongeOp::Squeeze(1),
+            SpongeOp::Absorb(1),
+        ];
+        let mut sponge = Sponge::new(&pattern, b"").unwrap();
+        assert_eq!(
+            sponge.squeeze(1),
+            Err(SpongeError::PatternViolation(SpongeOp::Squeeze(1)))
+        );
+        assert_eq!(
+            sponge.absorb(&elements(0..3)),
+            Err(SpongeError::PatternViolation(SpongeOp::Absorb(3)))
+        );
+        sponge.absorb(&elements(0..2)).unwrap();
+        sponge.squeeze(1).unwrap();
+        assert_eq!(
+            sponge.absorb_padded(&elements(0..1)),
+            Err(SpongeError::PaddingWithPattern)
+        );
+        assert_eq!(sponge.finish(), Err(SpongeError::UnfinishedPattern));
+
+        assert!(matches!(
+            Sponge::new(&[SpongeOp::Absorb(0)], b""),
+            Err(SpongeError::InvalidPattern)
+        ));
+    }
+
+    #[test]
+    fn padding_separates_lengths() {
+        let squeeze_after = |inputs: &[FpE]| {
+            let mut sponge = Sponge::new_without_pattern(b"");
+            sponge.absorb_padded(inputs).unwrap();
+            sponge.squeeze(4).unwrap()
+        };
+        let mut padded = elements(1..3);
+        assert_ne!(squeeze_after(&padded), squeeze_after(&elements(1..2)));
+        padded.push(FpE::zero());
+        assert_ne!(squeeze_after(&padded), squeeze_after(&elements(1..3)));
+    }
+
+    #[test]
+    fn works_over_other_permutations() {
+        let inputs = elements(0..20);
+        let rpo = hash::<Rpo256>(b"rpo", &inputs, 12).unwrap();
+        assert_eq!(rpo.len(), 12);
+        assert_ne!(rpo[..4], hash::<Rpo256>(b"rpo", &inputs, 4).unwrap());
+
+        type Stark = PoseidonPermutation<PoseidonCairoStark252>;
+        let inputs: Vec<_> = (0..5_u64).map(FE::from).collect();
+        let outputs = hash::<Stark>(b"starknet", &inputs, 3).unwrap();
+        assert_eq!(outputs.len(), 3);
+    }
+}
@@ -0,0 +1,83 @@
+use crate::hash::{
+    poseidon::Poseidon,
+    poseidon2::Poseidon2,
+    rescue::{RescuePermutation, RescuePrime256, Rpo256, Rpx256, STATE_WIDTH},
+};
+use core::marker::PhantomData;
+use lambdaworks_math::field::{
+    element::FieldElement, fields::u64_goldilocks_field::Goldilocks64Field, traits::IsField,
+};
+
+/// Permutation of a state of `RATE + CAPACITY` field elements, the only thing a
+/// [DuplexSponge](super::DuplexSponge) needs.
+///
+/// The rate part of the state is `RATE_OFFSET..RATE_OFFSET + RATE`, and the capacity part is
+/// the rest of the state.
+pub trait FieldPermutation {
+    type F: IsField;
+
+    const RATE: usize;
+    const CAPACITY: usize;
+    const RATE_OFFSET: usize = 0;
+    const WIDTH: usize = Self::RATE + Self::CAPACITY;
+
+    fn permute(state: &mut [FieldElement<Self::F>]);
+}
+
+/// The Hades permutation of a Poseidon instance, with the rate at the beginning of the state.
+#[derive(Clone, Default)]
+pub struct PoseidonPermutation<P: Poseidon> {
+    _poseidon: PhantomData<P>,
+}
+
+impl<P: Poseidon> FieldPermutation for PoseidonPermutation<P> {
+    type F = P::F;
+
+    const RATE: usize = P::RATE;
+    const CAPACITY: usize = P::CAPACITY;
+
+    fn permute(state: &mut [FieldElement<P::F>]) {
+        P::hades_permutation(state);
+    }
+}
+
+/// The permutation of a Poseidon2 instance, with the rate at the beginning of the state.
+#[derive(Clone, Default)]
+pub struct Poseidon2Permutation<P: Poseidon2> {
+    _poseidon: PhantomData<P>,
+}
+
+impl<P: Poseidon2> FieldPermutation for Poseidon2Permutation<P> {
+    type F = P::F;
+
+    const RATE: usize = P::RATE;
+    const CAPACITY: usize = P::CAPACITY;
+
+    fn permute(state: &mut [FieldElement<P::F>]) {
+        P::permutation(state);
+    }
+}
+
+// The Rescue permutations keep the capacity in the first 4 elements of the state.
+macro_rules! rescue_permutation {
+    ($name:ty) => {
+        impl FieldPermutation for $name {
+            type F = Goldilocks64Field;
+
+            const RATE: usize = 8;
+            const CAPACITY: usize = 4;
+            const RATE_OFFSET: usize = 4;
+
+            fn permute(state: &mut [FieldElement<Goldilocks64Field>]) {
+                let state: &mut [_; STATE_WIDTH] = state
+                    .try_into()
+                    .expect("Rescue permutations have 12 elements");
+                <$name as RescuePermutation>::apply_permutation(state);
+            }
+        }
+    };
+}
+
+rescue_permutation!(RescuePrime256);
+rescue_permutation!(Rpo256);
+rescue_permutation!(Rpx256);
@@ -3,3 +3,4 @@ pub mod errors;
 pub mod fiat_shamir;
 pub mod hash;
 pub mod merkle_tree;
+pub mod sumcheck;
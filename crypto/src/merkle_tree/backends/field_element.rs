@@ -1,4 +1,5 @@
 use crate::hash::poseidon::Poseidon;
+use crate::hash::poseidon2::Poseidon2;
 
 use crate::merkle_tree::traits::IsMerkleTreeBackend;
 use lambdaworks_math::{
@@ -74,14 +75,53 @@ where
     }
 }
 
+/// Backend over Poseidon2 with nodes of a single field element, for large fields like the
+/// scalar field of BLS12-381. Over small fields use
+/// [BatchPoseidon2Tree](super::field_element_vector::BatchPoseidon2Tree) instead.
+#[derive(Clone, Default)]
+pub struct TreePoseidon2<P: Poseidon2 + Default> {
+    _poseidon: PhantomData<P>,
+}
+
+impl<P> IsMerkleTreeBackend for TreePoseidon2<P>
+where
+    P: Poseidon2 + Default,
+    FieldElement<P::F>: Sync + Send,
+{
+    type Node = FieldElement<P::F>;
+    type Data = FieldElement<P::F>;
+
+    fn hash_data(input: &FieldElement<P::F>) -> FieldElement<P::F> {
+        P::hash_single(input)
+    }
+
+    fn hash_new_parent(
+        left: &FieldElement<P::F>,
+        right: &FieldElement<P::F>,
+    ) -> FieldElement<P::F> {
+        P::hash(left, right)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use lambdaworks_math::field::{
-        element::FieldElement, fields::fft_friendly::stark_252_prime_field::Stark252PrimeField,
+    use lambdaworks_math::{
+        elliptic_curve::short_weierstrass::curves::bls12_381::default_types::FrElement,
+        field::{
+            element::FieldElement, fields::fft_friendly::stark_252_prime_field::Stark252PrimeField,
+        },
     };
     use sha3::{Keccak256, Keccak512, Sha3_256, Sha3_512};
 
-    use crate::merkle_tree::{backends::field_element::FieldElementBackend, merkle::MerkleTree};
+    use crate::hash::poseidon2::Poseidon2Bls12381;
+    use crate::merkle_tree::{
+        backends::{
+            field_element::{FieldElementBackend, TreePoseidon2},
+            types::{Blake2s256Backend, Blake3Backend},
+        },
+        merkle::MerkleTree,
+        traits::IsMerkleTreeBackend,
+    };
 
     type F = Stark252PrimeField;
     type FE = FieldElement<F>;
@@ -133,4 +173,39 @@ mod tests {
             &values[0]
         ));
     }
+
+    #[test]
+    fn hash_data_field_element_backend_works_with_blake2s_256() {
+        let values: Vec<FE> = (1..6).map(FE::from).collect();
+        let merkle_tree = MerkleTree::<Blake2s256Backend<F>>::build(&values);
+        let proof = merkle_tree.get_proof_by_pos(0).unwrap();
+        assert!(proof.verify::<Blake2s256Backend<F>>(&merkle_tree.root, 0, &values[0]));
+    }
+
+    #[test]
+    fn hash_data_field_element_backend_works_with_blake3() {
+        let values: Vec<FE> = (1..6).map(FE::from).collect();
+        let merkle_tree = MerkleTree::<Blake3Backend<F>>::build(&values);
+        let proof = merkle_tree.get_proof_by_pos(3).unwrap();
+        assert!(proof.verify::<Blake3Backend<F>>(&merkle_tree.root, 3, &values[3]));
+    }
+
+    #[test]
+    fn blake3_backend_matches_the_blake3_hash() {
+        let (left, right) = ([1_u8; 32], [2_u8; 32]);
+        let mut input = left.to_vec();
+        input.extend_from_slice(&right);
+        assert_eq!(
+            Blake3Backend::<F>::hash_new_parent(&left, &right),
+            *blake3::hash(&input).as_bytes()
+        );
+    }
+
+    #[test]
+    fn tree_poseidon2_backend_works_over_bls12_381() {
+        let values: Vec<FrElement> = (1..6).map(FrElement::from).collect();
+        let merkle_tree = MerkleTree::<TreePoseidon2<Poseidon2Bls12381>>::build(&values);
+        let proof = merkle_tree.get_proof_by_pos(4).unwrap();
+        assert!(proof.verify::<TreePoseidon2<Poseidon2Bls12381>>(&merkle_tree.root, 4, &values[4]));
+    }
 }
@@ -1,11 +1,15 @@
 use std::marker::PhantomData;
 
 use crate::hash::poseidon::Poseidon;
+use crate::hash::poseidon2::Poseidon2;
+use crate::hash::rescue::{Felt, RescueDigest, RescuePermutation};
 use crate::merkle_tree::traits::IsMerkleTreeBackend;
 use lambdaworks_math::{
     field::{element::FieldElement, traits::IsField},
     traits::Serializable,
 };
+#[cfg(feature = "parallel")]
+use rayon::prelude::{IntoParallelRefIterator, ParallelIterator, ParallelSlice};
 use sha3::{
     digest::{generic_array::GenericArray, OutputSizeUser},
     Digest,
@@ -83,16 +87,230 @@ where
     }
 }
 
+/// Backend over Poseidon2 with digests of `CAPACITY` field elements, so it is also secure over
+/// small fields like BabyBear or Goldilocks.
+#[derive(Clone, Default)]
+pub struct BatchPoseidon2Tree<P: Poseidon2 + Default> {
+    _poseidon: PhantomData<P>,
+}
+
+impl<P> IsMerkleTreeBackend for BatchPoseidon2Tree<P>
+where
+    P: Poseidon2 + Default,
+    Vec<FieldElement<P::F>>: Sync + Send,
+{
+    type Node = Vec<FieldElement<P::F>>;
+    type Data = Vec<FieldElement<P::F>>;
+
+    fn hash_data(input: &Vec<FieldElement<P::F>>) -> Vec<FieldElement<P::F>> {
+        P::hash_many(input)
+    }
+
+    fn hash_new_parent(
+        left: &Vec<FieldElement<P::F>>,
+        right: &Vec<FieldElement<P::F>>,
+    ) -> Vec<FieldElement<P::F>> {
+        P::compress(left, right)
+    }
+}
+
+/// Backend over the Rescue hashes of Miden, like `Rpo256`: leaves are hashed with
+/// `hash_elements` and nodes with `merge`.
+#[derive(Clone, Default)]
+pub struct BatchRescueTree<P: RescuePermutation + Default> {
+    _rescue: PhantomData<P>,
+}
+
+impl<P> IsMerkleTreeBackend for BatchRescueTree<P>
+where
+    P: RescuePermutation + Default,
+{
+    type Node = RescueDigest;
+    type Data = Vec<Felt>;
+
+    fn hash_data(input: &Vec<Felt>) -> RescueDigest {
+        P::hash_elements(input)
+    }
+
+    fn hash_new_parent(left: &RescueDigest, right: &RescueDigest) -> RescueDigest {
+        P::merge(left, right)
+    }
+}
+
+// Calls `blake3_hash_rows::<N>` for the `N` among the listed lengths equal to `$length`, since
+// the multi-input implementation takes the length of the inputs as a const parameter.
+macro_rules! blake3_hash_rows_of_length {
+    ($rows:expr, $length:expr, $($n:literal),*) => {
+        match $length {
+            $($n => Some(blake3_hash_rows::<$n>($rows)),)*
+            _ => None,
+        }
+    };
+}
+
+/// Same hash as [BatchBlake3Backend](super::types::BatchBlake3Backend), but each row is
+/// serialized into a single buffer and hashed with one call.
+///
+/// When every row takes the same number of bytes, a multiple of 64 up to 1024, which is a
+/// single Blake3 chunk, the leaves are hashed together with the multi-input SIMD
+/// implementation of Blake3, which hashes as many rows at once as the vector registers fit.
+/// Otherwise each row is hashed on its own, which uses SIMD across the chunks of rows longer
+/// than 1024 bytes, and several threads for rows of at least 128 KiB when the `parallel`
+/// feature is enabled. In both cases the leaves are split between threads with `parallel`.
+///
+/// The multi-input implementation is in the undocumented `blake3::platform` module, which is
+/// one reason `blake3` is kept below 1.8.3. The other is that from 1.8.3 on its `traits-preview`
+/// feature implements the traits of `digest` 0.11, while the other backends use `digest` 0.10.
+#[derive(Clone)]
+pub struct BatchBlake3Tree<F> {
+    _field: PhantomData<F>,
+}
+
+impl<F> Default for BatchBlake3Tree<F> {
+    fn default() -> Self {
+        Self {
+            _field: PhantomData,
+        }
+    }
+}
+
+impl<F> IsMerkleTreeBackend for BatchBlake3Tree<F>
+where
+    F: IsField,
+    FieldElement<F>: Serializable,
+    Vec<FieldElement<F>>: Sync + Send,
+{
+    type Node = [u8; 32];
+    type Data = Vec<FieldElement<F>>;
+
+    fn hash_data(input: &Vec<FieldElement<F>>) -> [u8; 32] {
+        blake3_hash(&serialize_row(input))
+    }
+
+    fn hash_leaves(unhashed_leaves: &[Vec<FieldElement<F>>]) -> Vec<[u8; 32]> {
+        #[cfg(feature = "parallel")]
+        let iter = unhashed_leaves.par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let iter = unhashed_leaves.iter();
+        let rows: Vec<Vec<u8>> = iter.map(|row| serialize_row(row)).collect();
+
+        let length = rows.first().map_or(0, Vec::len);
+        if rows.iter().all(|row| row.len() == length) {
+            let hashes = blake3_hash_rows_of_length!(
+                &rows, length, 64, 128, 192, 256, 320, 384, 448, 512, 576, 640, 704, 768, 832, 896,
+                960, 1024
+            );
+            if let Some(hashes) = hashes {
+                return hashes;
+            }
+        }
+
+        #[cfg(feature = "parallel")]
+        let iter = rows.par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let iter = rows.iter();
+        iter.map(|row| blake3_hash(row)).collect()
+    }
+
+    fn hash_new_parent(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(left);
+        hasher.update(right);
+        hasher.finalize().into()
+    }
+}
+
+fn serialize_row<F>(row: &[FieldElement<F>]) -> Vec<u8>
+where
+    F: IsField,
+    FieldElement<F>: Serializable,
+{
+    row.iter().flat_map(|element| element.serialize()).collect()
+}
+
+fn blake3_hash(bytes: &[u8]) -> [u8; 32] {
+    let mut hasher = blake3::Hasher::new();
+    #[cfg(feature = "parallel")]
+    if bytes.len() >= 128 * 1024 {
+        hasher.update_rayon(bytes);
+        return hasher.finalize().into();
+    }
+    hasher.update(bytes);
+    hasher.finalize().into()
+}
+
+// Constants of the Blake3 compression function, which the crate doesn't export.
+const BLAKE3_IV: [u32; 8] = [
+    0x6A09E667, 0xBB67AE85, 0x3C6EF372, 0xA54FF53A, 0x510E527F, 0x9B05688C, 0x1F83D9AB, 0x5BE0CD19,
+];
+const BLAKE3_CHUNK_START: u8 = 1;
+const BLAKE3_CHUNK_END: u8 = 1 << 1;
+const BLAKE3_ROOT: u8 = 1 << 3;
+
+// Rows hashed by each call to the multi-input implementation with the `parallel` feature.
+#[cfg(feature = "parallel")]
+const BLAKE3_BATCH_SIZE: usize = 1024;
+
+/// Hashes rows of `N` bytes, a multiple of the block size that fits in one chunk. Each row is
+/// the only chunk of its message, so its blocks are compressed with the chunk counter at zero,
+/// the first one with the chunk start flag and the last one with the chunk end and root flags,
+/// as `blake3::hash` does for messages of at most 1024 bytes.
+fn blake3_hash_rows<const N: usize>(rows: &[Vec<u8>]) -> Vec<[u8; 32]> {
+    let inputs: Vec<&[u8; N]> = rows
+        .iter()
+        .map(|row| row.as_slice().try_into().expect("rows have N bytes"))
+        .collect();
+    let hash_batch = |batch: &[&[u8; N]]| {
+        let mut hashes = vec![0_u8; 32 * batch.len()];
+        blake3::platform::Platform::detect().hash_many(
+            batch,
+            &BLAKE3_IV,
+            0,
+            blake3::IncrementCounter::No,
+            0,
+            BLAKE3_CHUNK_START,
+            BLAKE3_CHUNK_END | BLAKE3_ROOT,
+            &mut hashes,
+        );
+        hashes
+    };
+
+    #[cfg(feature = "parallel")]
+    let hashes: Vec<u8> = inputs
+        .par_chunks(BLAKE3_BATCH_SIZE)
+        .flat_map_iter(hash_batch)
+        .collect();
+    #[cfg(not(feature = "parallel"))]
+    let hashes = hash_batch(&inputs);
+
+    hashes
+        .chunks_exact(32)
+        .map(|hash| hash.try_into().expect("hashes have 32 bytes"))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
-    use lambdaworks_math::field::{
-        element::FieldElement, fields::fft_friendly::stark_252_prime_field::Stark252PrimeField,
+    use lambdaworks_math::{
+        field::{
+            element::FieldElement, fields::fft_friendly::stark_252_prime_field::Stark252PrimeField,
+        },
+        traits::Serializable,
     };
     use sha2::Sha512;
     use sha3::{Keccak256, Keccak512, Sha3_256, Sha3_512};
 
+    use crate::hash::poseidon2::{Poseidon2BabyBear16, Poseidon2Goldilocks8};
+    use crate::hash::rescue::{RescuePermutation, Rpo256, Rpx256};
     use crate::merkle_tree::{
-        backends::field_element_vector::FieldElementVectorBackend, merkle::MerkleTree,
+        backends::{
+            field_element_vector::{
+                BatchBlake3Tree, BatchPoseidon2Tree, BatchRescueTree, FieldElementVectorBackend,
+            },
+            types::{BatchBlake2s256Backend, BatchBlake3Backend},
+        },
+        merkle::MerkleTree,
+        traits::IsMerkleTreeBackend,
     };
 
     type F = Stark252PrimeField;
@@ -202,4 +420,126 @@ mod tests {
             &values[0]
         ));
     }
+
+    #[test]
+    fn hash_data_field_element_backend_works_with_blake2s_256() {
+        let values: Vec<Vec<FE>> = (0..8_u64)
+            .map(|i| vec![FE::from(i), FE::from(i * i)])
+            .collect();
+        let merkle_tree = MerkleTree::<BatchBlake2s256Backend<F>>::build(&values);
+        let proof = merkle_tree.get_proof_by_pos(2).unwrap();
+        assert!(proof.verify::<BatchBlake2s256Backend<F>>(&merkle_tree.root, 2, &values[2]));
+    }
+
+    #[test]
+    fn hash_data_field_element_backend_works_with_blake3() {
+        let values: Vec<Vec<FE>> = (0..8_u64)
+            .map(|i| vec![FE::from(i), FE::from(i * i)])
+            .collect();
+        let merkle_tree = MerkleTree::<BatchBlake3Backend<F>>::build(&values);
+        let proof = merkle_tree.get_proof_by_pos(7).unwrap();
+        assert!(proof.verify::<BatchBlake3Backend<F>>(&merkle_tree.root, 7, &values[7]));
+        assert!(!proof.verify::<BatchBlake3Backend<F>>(&merkle_tree.root, 7, &values[6]));
+    }
+
+    #[test]
+    fn batch_blake3_tree_matches_the_generic_backend() {
+        // Rows of 64 elements take 2 KiB, more than a Blake3 chunk.
+        let values: Vec<Vec<FE>> = (0..8_u64)
+            .map(|i| (0..64).map(|j| FE::from(i * 64 + j)).collect())
+            .collect();
+        let merkle_tree = MerkleTree::<BatchBlake3Tree<F>>::build(&values);
+        assert_eq!(
+            merkle_tree.root,
+            MerkleTree::<BatchBlake3Backend<F>>::build(&values).root
+        );
+        let proof = merkle_tree.get_proof_by_pos(5).unwrap();
+        assert!(proof.verify::<BatchBlake3Tree<F>>(&merkle_tree.root, 5, &values[5]));
+    }
+
+    #[test]
+    fn batch_blake3_tree_hashes_rows_of_a_chunk_together() {
+        // Rows of 2 to 32 elements take a multiple of 64 bytes up to a chunk, and are hashed
+        // with the multi-input implementation. Rows of 3 elements are hashed one by one.
+        for row_length in [2, 3, 4, 31, 32] {
+            let values: Vec<Vec<FE>> = (0..37_u64)
+                .map(|i| (0..row_length).map(|j| FE::from(i * 100 + j)).collect())
+                .collect();
+            let hashes = BatchBlake3Tree::<F>::hash_leaves(&values);
+            for (row, hash) in values.iter().zip(&hashes) {
+                let bytes: Vec<u8> = row.iter().flat_map(|element| element.serialize()).collect();
+                assert_eq!(hash, blake3::hash(&bytes).as_bytes());
+            }
+        }
+
+        // Rows of different lengths can't be hashed together.
+        let values = vec![vec![FE::from(1), FE::from(2)], vec![FE::from(3)]];
+        assert_eq!(
+            BatchBlake3Tree::<F>::hash_leaves(&values),
+            BatchBlake3Backend::<F>::hash_leaves(&values)
+        );
+    }
+
+    #[test]
+    fn batch_poseidon2_backend_works_over_goldilocks() {
+        use lambdaworks_math::field::fields::u64_goldilocks_field::Goldilocks64Field;
+
+        let values: Vec<Vec<FieldElement<Goldilocks64Field>>> = (0..6_u64)
+            .map(|i| (0..5).map(|j| FieldElement::from(i * 5 + j)).collect())
+            .collect();
+        let merkle_tree = MerkleTree::<BatchPoseidon2Tree<Poseidon2Goldilocks8>>::build(&values);
+        assert_eq!(merkle_tree.root.len(), 4);
+        let proof = merkle_tree.get_proof_by_pos(3).unwrap();
+        assert!(proof.verify::<BatchPoseidon2Tree<Poseidon2Goldilocks8>>(
+            &merkle_tree.root,
+            3,
+            &values[3]
+        ));
+        assert!(!proof.verify::<BatchPoseidon2Tree<Poseidon2Goldilocks8>>(
+            &merkle_tree.root,
+            3,
+            &values[2]
+        ));
+    }
+
+    #[test]
+    fn batch_poseidon2_backend_works_over_babybear() {
+        use lambdaworks_math::field::fields::fft_friendly::babybear::Babybear31PrimeField;
+
+        let values: Vec<Vec<FieldElement<Babybear31PrimeField>>> = (0..4_u64)
+            .map(|i| (0..20).map(|j| FieldElement::from(i * 20 + j)).collect())
+            .collect();
+        let merkle_tree = MerkleTree::<BatchPoseidon2Tree<Poseidon2BabyBear16>>::build(&values);
+        let proof = merkle_tree.get_proof_by_pos(1).unwrap();
+        assert!(proof.verify::<BatchPoseidon2Tree<Poseidon2BabyBear16>>(
+            &merkle_tree.root,
+            1,
+            &values[1]
+        ));
+    }
+
+    #[test]
+    fn batch_rescue_backend_works_with_rpo_and_rpx() {
+        use lambdaworks_math::field::fields::u64_goldilocks_field::Goldilocks64Field;
+
+        let values: Vec<Vec<FieldElement<Goldilocks64Field>>> = (0..8_u64)
+            .map(|i| (0..10).map(|j| FieldElement::from(i * 10 + j)).collect())
+            .collect();
+        let merkle_tree = MerkleTree::<BatchRescueTree<Rpo256>>::build(&values);
+        let proof = merkle_tree.get_proof_by_pos(5).unwrap();
+        assert!(proof.verify::<BatchRescueTree<Rpo256>>(&merkle_tree.root, 5, &values[5]));
+        assert!(!proof.verify::<BatchRescueTree<Rpo256>>(&merkle_tree.root, 5, &values[4]));
+
+        let leaves: Vec<_> = values.iter().map(|v| Rpx256::hash_elements(v)).collect();
+        let level: Vec<_> = leaves
+            .chunks(2)
+            .map(|n| Rpx256::merge(&n[0], &n[1]))
+            .collect();
+        let level: Vec<_> = level
+            .chunks(2)
+            .map(|n| Rpx256::merge(&n[0], &n[1]))
+            .collect();
+        let merkle_tree = MerkleTree::<BatchRescueTree<Rpx256>>::build(&values);
+        assert_eq!(merkle_tree.root, Rpx256::merge(&level[0], &level[1]));
+    }
 }
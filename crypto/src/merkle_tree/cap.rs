@@ -0,0 +1,159 @@
+use lambdaworks_math::{
+    errors::DeserializationError,
+    traits::{Deserializable, Serializable},
+};
+
+use super::utils::{is_power_of_two, read_length};
+
+/// Commitment to the `2^cap_height` nodes at height `cap_height` below the root of a Merkle tree,
+/// from left to right. A cap of height 0 is the root itself.
+///
+/// Paths of a tree built with [build_with_cap](super::merkle::MerkleTree::build_with_cap) stop
+/// at the cap, so they are `cap_height` nodes shorter than paths to the root.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct MerkleCap<T: PartialEq + Eq> {
+    pub nodes: Vec<T>,
+}
+
+impl<T: PartialEq + Eq> MerkleCap<T> {
+    pub fn height(&self) -> usize {
+        self.nodes.len().trailing_zeros() as usize
+    }
+}
+
+/// The number of nodes as 8 bytes in big-endian order, followed by each node prefixed with the
+/// length of its serialization in the same format.
+impl<T> Serializable for MerkleCap<T>
+where
+    T: Serializable + PartialEq + Eq,
+{
+    fn serialize(&self) -> Vec<u8> {
+        let mut bytes = (self.nodes.len() as u64).to_be_bytes().to_vec();
+        for node in &self.nodes {
+            let node_bytes = node.serialize();
+            bytes.extend_from_slice(&(node_bytes.len() as u64).to_be_bytes());
+            bytes.extend_from_slice(&node_bytes);
+        }
+        bytes
+    }
+}
+
+impl<T> Deserializable for MerkleCap<T>
+where
+    T: Deserializable + PartialEq + Eq,
+{
+    fn deserialize(bytes: &[u8]) -> Result<Self, DeserializationError>
+    where
+        Self: Sized,
+    {
+        let (length, mut bytes) = read_length(bytes)?;
+        if !is_power_of_two(length) {
+            return Err(DeserializationError::InvalidValue);
+        }
+        let mut nodes = Vec::new();
+        for _ in 0..length {
+            let (node_length, rest) = read_length(bytes)?;
+            let node_bytes = rest
+                .get(..node_length)
+                .ok_or(DeserializationError::InvalidAmountOfBytes)?;
+            nodes.push(T::deserialize(node_bytes)?);
+            bytes = &rest[node_length..];
+        }
+        if !bytes.is_empty() {
+            return Err(DeserializationError::InvalidAmountOfBytes);
+        }
+        Ok(Self { nodes })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::merkle_tree::{
+        backends::field_element::FieldElementBackend, merkle::MerkleTree, test_merkle::TestBackend,
+    };
+    use lambdaworks_math::field::{
+        element::FieldElement,
+        fields::{
+            fft_friendly::stark_252_prime_field::Stark252PrimeField, u64_prime_field::U64PrimeField,
+        },
+    };
+    use sha3::Keccak256;
+
+    type F = Stark252PrimeField;
+    type FE = FieldElement<F>;
+    type Backend = FieldElementBackend<F, Keccak256, 32>;
+
+    #[test]
+    fn paths_to_the_cap_are_shorter_and_verify() {
+        let values: Vec<FE> = (0..100).map(FE::from).collect();
+        let full_tree = MerkleTree::<Backend>::build(&values);
+        let tree = MerkleTree::<Backend>::build_with_cap(&values, 3).unwrap();
+        let cap = tree.cap();
+
+        assert_eq!(cap.nodes.len(), 8);
+        assert_eq!(cap.height(), 3);
+        assert_eq!(tree.root, full_tree.root);
+        for pos in [0, 37, 99, 127] {
+            let proof = tree.get_proof_by_pos(pos).unwrap();
+            let full_proof = full_tree.get_proof_by_pos(pos).unwrap();
+            assert_eq!(proof.merkle_path.len() + 3, full_proof.merkle_path.len());
+
+            let value = &values[pos.min(99)];
+            assert!(proof.verify_with_cap::<Backend>(&cap, pos, value));
+            assert!(!proof.verify_with_cap::<Backend>(&cap, pos ^ 16, value));
+            assert!(!proof.verify_with_cap::<Backend>(&cap, pos, &FE::from(1000)));
+        }
+    }
+
+    #[test]
+    fn cap_of_height_zero_is_the_root() {
+        let values: Vec<FE> = (0..10).map(FE::from).collect();
+        let tree = MerkleTree::<Backend>::build(&values);
+        assert_eq!(tree.cap().nodes, vec![tree.root]);
+
+        let proof = tree.get_proof_by_pos(6).unwrap();
+        assert!(proof.verify_with_cap::<Backend>(&tree.cap(), 6, &values[6]));
+        assert!(proof.verify::<Backend>(&tree.root, 6, &values[6]));
+    }
+
+    #[test]
+    fn cap_can_hold_every_leaf() {
+        let values: Vec<FE> = (0..16).map(FE::from).collect();
+        let tree = MerkleTree::<Backend>::build_with_cap(&values, 4).unwrap();
+        let proof = tree.get_proof_by_pos(5).unwrap();
+        assert!(proof.merkle_path.is_empty());
+        assert!(proof.verify_with_cap::<Backend>(&tree.cap(), 5, &values[5]));
+        assert!(MerkleTree::<Backend>::build_with_cap(&values, 5).is_none());
+
+        // Padding counts towards the height of the tree.
+        let values: Vec<FE> = (0..5).map(FE::from).collect();
+        let tree = MerkleTree::<Backend>::build_with_cap(&values, 3).unwrap();
+        assert_eq!(tree.cap().nodes.len(), 8);
+        let proof = tree.get_proof_by_pos(4).unwrap();
+        assert!(proof.merkle_path.is_empty());
+        assert!(proof.verify_with_cap::<Backend>(&tree.cap(), 4, &values[4]));
+        assert!(MerkleTree::<Backend>::build_with_cap(&values, 4).is_none());
+    }
+
+    #[test]
+    fn cap_serialization_round_trip() {
+        type Goldilocks = U64PrimeField<0xFFFF_FFFF_0000_0001>;
+        type GoldilocksFE = FieldElement<Goldilocks>;
+
+        let values: Vec<GoldilocksFE> = (1..21).map(GoldilocksFE::from).collect();
+        let tree = MerkleTree::<TestBackend<Goldilocks>>::build_with_cap(&values, 2).unwrap();
+        let bytes = tree.cap().serialize();
+        let cap = MerkleCap::<GoldilocksFE>::deserialize(&bytes).unwrap();
+
+        assert_eq!(cap, tree.cap());
+        let proof = tree.get_proof_by_pos(11).unwrap();
+        assert!(proof.verify_with_cap::<TestBackend<Goldilocks>>(&cap, 11, &values[11]));
+        assert!(MerkleCap::<GoldilocksFE>::deserialize(&bytes[..bytes.len() - 1]).is_err());
+
+        // A huge length is rejected without allocating room for it first.
+        let mut bytes = bytes;
+        bytes[..8].copy_from_slice(&(1_u64 << 60).to_be_bytes());
+        assert!(MerkleCap::<GoldilocksFE>::deserialize(&bytes).is_err());
+    }
+}
@@ -1,9 +1,13 @@
-use super::{proof::Proof, traits::IsMerkleTreeBackend, utils::*};
+use super::{
+    cap::MerkleCap, multiproof::MultiProof, proof::Proof, traits::IsMerkleTreeBackend, utils::*,
+};
 
 #[derive(Clone, serde::Serialize, serde::Deserialize)]
 pub struct MerkleTree<B: IsMerkleTreeBackend> {
     pub root: B::Node,
     nodes: Vec<B::Node>,
+    #[serde(default)]
+    cap_height: usize,
 }
 
 const ROOT: usize = 0;
@@ -30,9 +34,34 @@ where
         MerkleTree {
             root: inner_nodes[ROOT].clone(),
             nodes: inner_nodes,
+            cap_height: 0,
         }
     }
 
+    /// Builds a tree committed to by its [cap](Self::cap) of `2^cap_height` nodes, so that
+    /// the paths returned by [get_proof_by_pos](Self::get_proof_by_pos) end below it.
+    /// Returns `None` if the tree has fewer than `2^cap_height` leaves after padding.
+    pub fn build_with_cap(unhashed_leaves: &[B::Data], cap_height: usize) -> Option<Self> {
+        let tree = Self::build(unhashed_leaves);
+        if cap_height > tree.height() {
+            return None;
+        }
+        Some(Self { cap_height, ..tree })
+    }
+
+    /// The nodes at height `cap_height` below the root, from left to right.
+    pub fn cap(&self) -> MerkleCap<B::Node> {
+        let start = (1 << self.cap_height) - 1;
+        MerkleCap {
+            nodes: self.nodes[start..2 * start + 1].to_vec(),
+        }
+    }
+
+    // Number of levels below the root
+    fn height(&self) -> usize {
+        (self.nodes.len() + 1).trailing_zeros() as usize - 1
+    }
+
     pub fn get_proof_by_pos(&self, pos: usize) -> Option<Proof<B::Node>> {
         let pos = pos + self.nodes.len() / 2;
         let Ok(merkle_path) = self.build_merkle_path(pos) else {
@@ -42,6 +71,13 @@ where
         self.create_proof(merkle_path)
     }
 
+    /// Returns a single proof for all the leaves at `positions`, which may come in any order.
+    /// Like the paths of [get_proof_by_pos](Self::get_proof_by_pos), it ends below the cap.
+    /// Returns `None` if any position is out of range.
+    pub fn get_multi_proof(&self, positions: &[usize]) -> Option<MultiProof<B::Node>> {
+        MultiProof::from_tree_nodes(&self.nodes, positions, self.cap_height)
+    }
+
     fn create_proof(&self, merkle_path: Vec<B::Node>) -> Option<Proof<B::Node>> {
         Some(Proof { merkle_path })
     }
@@ -49,8 +85,10 @@ where
     fn build_merkle_path(&self, pos: usize) -> Result<Vec<B::Node>, std::io::Error> {
         let mut merkle_path = Vec::new();
         let mut pos = pos;
+        // Nodes with smaller indices are in the cap or above it
+        let cap_end = (2 << self.cap_height) - 1;
 
-        while pos != ROOT {
+        while pos >= cap_end {
             let Some(node) = self.nodes.get(sibling_index(pos)) else {
                 // out of bounds, exit returning the current merkle_path
                 return Err(std::io::Error::from(std::io::ErrorKind::InvalidInput));
@@ -0,0 +1,385 @@
+use super::traits::IsMerkleTreeBackend;
+
+/// Append-only accumulator made of perfect Merkle trees, called mountains, one for each bit set
+/// in the number of leaves, from the highest to the lowest.
+///
+/// The root is the hash of the peaks of the mountains, folded from right to left as
+/// `hash(peak_0, hash(peak_1, ... hash(peak_k-1, peak_k)))`. The trees are built like a
+/// [MerkleTree](super::merkle::MerkleTree), so with a power of two leaves both roots are equal.
+/// Every node ever computed is kept, so proofs can be made for any previous size.
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+#[serde(bound(
+    serialize = "B::Node: serde::Serialize",
+    deserialize = "B::Node: serde::Deserialize<'de>"
+))]
+pub struct MerkleMountainRange<B: IsMerkleTreeBackend> {
+    // The nodes at each height from left to right, starting with the leaves
+    levels: Vec<Vec<B::Node>>,
+}
+
+impl<B: IsMerkleTreeBackend> MerkleMountainRange<B> {
+    pub fn new() -> Self {
+        Self { levels: Vec::new() }
+    }
+
+    /// Number of leaves.
+    pub fn size(&self) -> usize {
+        self.levels.first().map_or(0, Vec::len)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.size() == 0
+    }
+
+    /// Adds a leaf, merging the mountains of equal height it completes, and returns its position.
+    pub fn append(&mut self, value: &B::Data) -> usize {
+        let position = self.size();
+        let mut node = B::hash_data(value);
+        for height in 0.. {
+            if self.levels.len() == height {
+                self.levels.push(Vec::new());
+            }
+            let level = &mut self.levels[height];
+            level.push(node);
+            if level.len() % 2 == 1 {
+                break;
+            }
+            node = B::hash_new_parent(&level[level.len() - 2], &level[level.len() - 1]);
+        }
+        position
+    }
+
+    /// Returns the root, or `None` if there are no leaves.
+    pub fn root(&self) -> Option<B::Node> {
+        self.root_at(self.size())
+    }
+
+    /// Returns the root the accumulator had when it had `size` leaves.
+    pub fn root_at(&self, size: usize) -> Option<B::Node> {
+        if size > self.size() {
+            return None;
+        }
+        bag_peaks::<B>(&self.peaks(size))
+    }
+
+    pub fn get_proof_by_pos(&self, pos: usize) -> Option<MmrProof<B::Node>> {
+        self.get_historical_proof(pos, self.size())
+    }
+
+    /// Proves that the leaf at `pos` was in the accumulator when it had `size` leaves, against
+    /// the root given by [root_at](Self::root_at).
+    pub fn get_historical_proof(&self, pos: usize, size: usize) -> Option<MmrProof<B::Node>> {
+        if pos >= size || size > self.size() {
+            return None;
+        }
+        let (peak, height, _) = find_peak(pos, size);
+        let merkle_path = (0..height)
+            .map(|level| self.levels[level][(pos >> level) ^ 1].clone())
+            .collect();
+        let mut peaks = self.peaks(size);
+        peaks.remove(peak);
+        Some(MmrProof { merkle_path, peaks })
+    }
+
+    /// Proves that the accumulator with `old_size` leaves is a prefix of the one with
+    /// `new_size` leaves, against the roots given by [root_at](Self::root_at).
+    pub fn get_consistency_proof(
+        &self,
+        old_size: usize,
+        new_size: usize,
+    ) -> Option<ConsistencyProof<B::Node>> {
+        if old_size == 0 || old_size > new_size || new_size > self.size() {
+            return None;
+        }
+        let mut nodes = Vec::new();
+        for (height, start) in mountains(new_size) {
+            self.collect_new_nodes(height, start, old_size, &mut nodes);
+        }
+        Some(ConsistencyProof {
+            old_peaks: self.peaks(old_size),
+            nodes,
+        })
+    }
+
+    // Pushes the nodes that, together with the old peaks, give the subtree of `2^height` leaves
+    // starting at `start`. Mirrors `rebuild_subtree`.
+    fn collect_new_nodes(
+        &self,
+        height: usize,
+        start: usize,
+        old_size: usize,
+        nodes: &mut Vec<B::Node>,
+    ) {
+        if start >= old_size {
+            nodes.push(self.levels[height][start >> height].clone());
+        } else if start + (1 << height) > old_size {
+            let half = 1 << (height - 1);
+            self.collect_new_nodes(height - 1, start, old_size, nodes);
+            self.collect_new_nodes(height - 1, start + half, old_size, nodes);
+        }
+    }
+
+    fn peaks(&self, size: usize) -> Vec<B::Node> {
+        mountains(size)
+            .into_iter()
+            .map(|(height, start)| self.levels[height][start >> height].clone())
+            .collect()
+    }
+}
+
+impl<B: IsMerkleTreeBackend> Default for MerkleMountainRange<B> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Path from a leaf to the peak of its mountain, and the peaks of the other mountains from left
+/// to right.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct MmrProof<T: PartialEq + Eq> {
+    pub merkle_path: Vec<T>,
+    pub peaks: Vec<T>,
+}
+
+impl<T: PartialEq + Eq + Clone> MmrProof<T> {
+    /// Checks that `value` is the leaf at `pos` of the accumulator with `size` leaves and root
+    /// `root_hash`.
+    pub fn verify<B>(&self, root_hash: &B::Node, size: usize, pos: usize, value: &B::Data) -> bool
+    where
+        B: IsMerkleTreeBackend<Node = T>,
+    {
+        if pos >= size || self.peaks.len() + 1 != size.count_ones() as usize {
+            return false;
+        }
+        let (peak, height, start) = find_peak(pos, size);
+        if self.merkle_path.len() != height {
+            return false;
+        }
+
+        let mut node = B::hash_data(value);
+        let mut index = pos - start;
+        for sibling in &self.merkle_path {
+            node = if index.is_multiple_of(2) {
+                B::hash_new_parent(&node, sibling)
+            } else {
+                B::hash_new_parent(sibling, &node)
+            };
+            index >>= 1;
+        }
+
+        let mut peaks = self.peaks.clone();
+        peaks.insert(peak, node);
+        bag_peaks::<B>(&peaks).as_ref() == Some(root_hash)
+    }
+}
+
+/// Peaks of the old accumulator, and the nodes that together with them give the peaks of the
+/// new one. The nodes are those of a depth-first, left to right walk of the new mountains that
+/// stops at old peaks and at subtrees without old leaves.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ConsistencyProof<T: PartialEq + Eq> {
+    pub old_peaks: Vec<T>,
+    pub nodes: Vec<T>,
+}
+
+impl<T: PartialEq + Eq + Clone> ConsistencyProof<T> {
+    /// Checks that the accumulator with `old_size` leaves and root `old_root` is a prefix of the
+    /// one with `new_size` leaves and root `new_root`.
+    pub fn verify<B>(
+        &self,
+        old_root: &B::Node,
+        old_size: usize,
+        new_root: &B::Node,
+        new_size: usize,
+    ) -> bool
+    where
+        B: IsMerkleTreeBackend<Node = T>,
+    {
+        if old_size == 0
+            || old_size > new_size
+            || self.old_peaks.len() != old_size.count_ones() as usize
+            || bag_peaks::<B>(&self.old_peaks).as_ref() != Some(old_root)
+        {
+            return false;
+        }
+
+        let mut old_peaks = self.old_peaks.iter();
+        let mut nodes = self.nodes.iter();
+        let mut new_peaks = Vec::new();
+        for (height, start) in mountains(new_size) {
+            let rebuilt = rebuild_subtree::<B>(height, start, old_size, &mut old_peaks, &mut nodes);
+            let Some(peak) = rebuilt else {
+                return false;
+            };
+            new_peaks.push(peak);
+        }
+
+        old_peaks.next().is_none()
+            && nodes.next().is_none()
+            && bag_peaks::<B>(&new_peaks).as_ref() == Some(new_root)
+    }
+}
+
+// Computes the subtree of `2^height` leaves starting at `start`, taking the subtrees made of old
+// leaves from the old peaks and those without old leaves from the proof nodes
+fn rebuild_subtree<'a, B>(
+    height: usize,
+    start: usize,
+    old_size: usize,
+    old_peaks: &mut impl Iterator<Item = &'a B::Node>,
+    nodes: &mut impl Iterator<Item = &'a B::Node>,
+) -> Option<B::Node>
+where
+    B: IsMerkleTreeBackend,
+    B::Node: 'a,
+{
+    if start >= old_size {
+        return nodes.next().cloned();
+    }
+    if start + (1 << height) <= old_size {
+        return old_peaks.next().cloned();
+    }
+    let half = 1 << (height - 1);
+    let left = rebuild_subtree::<B>(height - 1, start, old_size, old_peaks, nodes)?;
+    let right = rebuild_subtree::<B>(height - 1, start + half, old_size, old_peaks, nodes)?;
+    Some(B::hash_new_parent(&left, &right))
+}
+
+// Folds the peaks from right to left
+fn bag_peaks<B: IsMerkleTreeBackend>(peaks: &[B::Node]) -> Option<B::Node> {
+    peaks
+        .iter()
+        .rev()
+        .cloned()
+        .reduce(|bagged, peak| B::hash_new_parent(&peak, &bagged))
+}
+
+// Height and first leaf of each mountain of an accumulator with `size` leaves, from left to right
+fn mountains(size: usize) -> Vec<(usize, usize)> {
+    let mut mountains = Vec::new();
+    let mut start = 0;
+    for height in (0..usize::BITS as usize).rev() {
+        if (size >> height) & 1 == 1 {
+            mountains.push((height, start));
+            start += 1 << height;
+        }
+    }
+    mountains
+}
+
+// Index, height and first leaf of the mountain containing the leaf at `pos`
+fn find_peak(pos: usize, size: usize) -> (usize, usize, usize) {
+    mountains(size)
+        .into_iter()
+        .enumerate()
+        .find(|(_, (height, start))| pos < start + (1 << height))
+        .map(|(peak, (height, start))| (peak, height, start))
+        .expect("the position is smaller than the size")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::merkle_tree::{backends::field_element::FieldElementBackend, merkle::MerkleTree};
+    use lambdaworks_math::field::{
+        element::FieldElement, fields::fft_friendly::stark_252_prime_field::Stark252PrimeField,
+    };
+    use sha3::Keccak256;
+
+    type F = Stark252PrimeField;
+    type FE = FieldElement<F>;
+    type Backend = FieldElementBackend<F, Keccak256, 32>;
+    type Mmr = MerkleMountainRange<Backend>;
+
+    fn mmr(size: u64) -> (Vec<FE>, Mmr) {
+        let values: Vec<FE> = (0..size).map(FE::from).collect();
+        let mut mmr = Mmr::new();
+        for (pos, value) in values.iter().enumerate() {
+            assert_eq!(mmr.append(value), pos);
+        }
+        (values, mmr)
+    }
+
+    #[test]
+    fn mountains_follow_the_bits_of_the_size() {
+        assert_eq!(mountains(13), vec![(3, 0), (2, 8), (0, 12)]);
+        assert!(mountains(0).is_empty());
+        assert_eq!(find_peak(9, 13), (1, 2, 8));
+    }
+
+    #[test]
+    fn root_with_a_power_of_two_leaves_is_the_merkle_tree_root() {
+        for size in [1, 2, 8, 32] {
+            let (values, mmr) = mmr(size);
+            assert_eq!(mmr.root(), Some(MerkleTree::<Backend>::build(&values).root));
+        }
+        assert_eq!(Mmr::new().root(), None);
+    }
+
+    #[test]
+    fn historical_roots_do_not_change() {
+        let (_, mmr) = mmr(21);
+        for size in 1..=21 {
+            assert_eq!(mmr.root_at(size), self::mmr(size as u64).1.root());
+        }
+        assert_eq!(mmr.root_at(22), None);
+    }
+
+    #[test]
+    fn inclusion_proofs_verify_for_every_size() {
+        let (values, mmr) = mmr(19);
+        for size in 1..=19 {
+            let root = mmr.root_at(size).unwrap();
+            for (pos, value) in values.iter().enumerate().take(size) {
+                let proof = mmr.get_historical_proof(pos, size).unwrap();
+                assert!(proof.verify::<Backend>(&root, size, pos, value));
+                assert!(!proof.verify::<Backend>(&root, size, pos, &FE::from(100)));
+                if size > 1 {
+                    let other = (pos + 1) % size;
+                    assert!(!proof.verify::<Backend>(&root, size, other, value));
+                }
+            }
+        }
+        assert!(mmr.get_proof_by_pos(19).is_none());
+        assert!(mmr.get_historical_proof(5, 5).is_none());
+    }
+
+    #[test]
+    fn consistency_proofs_verify_between_every_pair_of_sizes() {
+        let (_, mmr) = mmr(17);
+        for old_size in 1..=17 {
+            let old_root = mmr.root_at(old_size).unwrap();
+            for new_size in old_size..=17 {
+                let new_root = mmr.root_at(new_size).unwrap();
+                let proof = mmr.get_consistency_proof(old_size, new_size).unwrap();
+                assert!(proof.verify::<Backend>(&old_root, old_size, &new_root, new_size));
+                if old_size < new_size {
+                    assert!(!proof.verify::<Backend>(&new_root, old_size, &old_root, new_size));
+                }
+            }
+        }
+        assert!(mmr.get_consistency_proof(0, 3).is_none());
+        assert!(mmr.get_consistency_proof(4, 3).is_none());
+    }
+
+    #[test]
+    fn consistency_fails_if_an_old_leaf_changed() {
+        let (values, mmr) = mmr(6);
+        let mut forked = Mmr::new();
+        for value in values.iter().take(4) {
+            forked.append(value);
+        }
+        forked.append(&FE::from(100));
+        for pos in 5..11 {
+            forked.append(&FE::from(pos));
+        }
+
+        let old_root = mmr.root_at(6).unwrap();
+        let proof = forked.get_consistency_proof(6, 11).unwrap();
+        let new_root = forked.root().unwrap();
+        assert!(!proof.verify::<Backend>(&old_root, 6, &new_root, 11));
+
+        let proof = forked.get_consistency_proof(4, 11).unwrap();
+        assert!(proof.verify::<Backend>(&mmr.root_at(4).unwrap(), 4, &new_root, 11));
+    }
+}
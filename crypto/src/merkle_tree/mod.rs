@@ -1,6 +1,11 @@
 pub mod backends;
+pub mod cap;
 pub mod merkle;
+pub mod mmr;
+pub mod multiproof;
 pub mod proof;
+pub mod sparse;
+pub mod streaming;
 #[cfg(test)]
 pub mod test_merkle;
 pub mod traits;
@@ -0,0 +1,318 @@
+use lambdaworks_math::{
+    errors::DeserializationError,
+    traits::{Deserializable, Serializable},
+};
+
+use super::{
+    cap::MerkleCap,
+    traits::IsMerkleTreeBackend,
+    utils::{parent_index, read_length, sibling_index},
+};
+
+/// Opening of several leaves of a Merkle tree with a single set of nodes.
+///
+/// Only the nodes that can't be computed from the opened leaves are stored, so the upper
+/// levels shared by the paths of several leaves appear once. They are stored level by level
+/// from the leaves to the root, and from left to right within each level.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct MultiProof<T: PartialEq + Eq> {
+    pub nodes: Vec<T>,
+}
+
+impl<T: PartialEq + Eq + Clone> MultiProof<T> {
+    /// Builds the proof for the leaves at `positions` from the nodes of a tree, stored like in
+    /// [MerkleTree](super::merkle::MerkleTree), whose cap has height `cap_height`. The proof
+    /// stops at the cap, so it has no nodes in it or above it. Returns `None` if a position is
+    /// out of range.
+    pub(crate) fn from_tree_nodes(
+        tree_nodes: &[T],
+        positions: &[usize],
+        cap_height: usize,
+    ) -> Option<Self> {
+        let leaves_start = tree_nodes.len() / 2;
+        let mut level: Vec<usize> = positions
+            .iter()
+            .map(|position| position + leaves_start)
+            .collect();
+        if level.iter().any(|index| *index >= tree_nodes.len()) {
+            return None;
+        }
+        level.sort_unstable();
+        level.dedup();
+
+        // Nodes with smaller indices are in the cap or above it
+        let cap_end = (2 << cap_height) - 1;
+        let mut nodes = Vec::new();
+        while level.first().is_some_and(|index| *index >= cap_end) {
+            let mut i = 0;
+            while i < level.len() {
+                let sibling = sibling_index(level[i]);
+                if level.get(i + 1) == Some(&sibling) {
+                    i += 1;
+                } else {
+                    nodes.push(tree_nodes[sibling].clone());
+                }
+                i += 1;
+            }
+            level = level.into_iter().map(parent_index).collect();
+            level.dedup();
+        }
+        Some(Self { nodes })
+    }
+
+    /// Checks that `values` are the leaves at `positions` of the tree with root `root_hash`
+    /// and `num_leaves` leaves before padding. Positions may come in any order, and repeated
+    /// positions must have the same value.
+    pub fn verify<B>(
+        &self,
+        root_hash: &B::Node,
+        num_leaves: usize,
+        positions: &[usize],
+        values: &[B::Data],
+    ) -> bool
+    where
+        B: IsMerkleTreeBackend<Node = T>,
+    {
+        let height = num_leaves.next_power_of_two().trailing_zeros() as usize;
+        match self.hash_up_to::<B>(height, num_leaves, positions, values) {
+            Some(level) => level.len() == 1 && &level[0].1 == root_hash,
+            None => false,
+        }
+    }
+
+    /// Same as [verify](Self::verify), for a proof of a tree built with
+    /// [build_with_cap](super::merkle::MerkleTree::build_with_cap), which ends at its `cap`.
+    pub fn verify_with_cap<B>(
+        &self,
+        cap: &MerkleCap<T>,
+        num_leaves: usize,
+        positions: &[usize],
+        values: &[B::Data],
+    ) -> bool
+    where
+        B: IsMerkleTreeBackend<Node = T>,
+    {
+        let height = num_leaves.next_power_of_two().trailing_zeros() as usize;
+        let Some(levels) = height.checked_sub(cap.height()) else {
+            return false;
+        };
+        let cap_start = cap.nodes.len() - 1;
+        match self.hash_up_to::<B>(levels, num_leaves, positions, values) {
+            Some(level) => level.iter().all(|(index, node)| {
+                index.checked_sub(cap_start).and_then(|i| cap.nodes.get(i)) == Some(node)
+            }),
+            None => false,
+        }
+    }
+
+    /// Hashes the leaves `levels` levels up with the nodes of the proof, and returns the
+    /// indices and nodes reached, in order. Returns `None` if the positions or the values are
+    /// invalid, or if the proof doesn't have exactly the nodes needed.
+    fn hash_up_to<B>(
+        &self,
+        levels: usize,
+        num_leaves: usize,
+        positions: &[usize],
+        values: &[B::Data],
+    ) -> Option<Vec<(usize, T)>>
+    where
+        B: IsMerkleTreeBackend<Node = T>,
+    {
+        let padded_leaves = num_leaves.next_power_of_two();
+        if positions.is_empty()
+            || positions.len() != values.len()
+            || positions.iter().any(|position| *position >= padded_leaves)
+        {
+            return None;
+        }
+
+        let mut level: Vec<(usize, T)> = positions
+            .iter()
+            .zip(values)
+            .map(|(position, value)| (position + padded_leaves - 1, B::hash_data(value)))
+            .collect();
+        level.sort_by_key(|(index, _)| *index);
+        for pair in level.windows(2) {
+            if pair[0].0 == pair[1].0 && pair[0].1 != pair[1].1 {
+                return None;
+            }
+        }
+        level.dedup_by_key(|(index, _)| *index);
+
+        let mut proof_nodes = self.nodes.iter();
+        for _ in 0..levels {
+            let mut parents = Vec::with_capacity(level.len());
+            let mut current = level.into_iter().peekable();
+            while let Some((index, node)) = current.next() {
+                let sibling = match current.next_if(|(next, _)| *next == sibling_index(index)) {
+                    Some((_, sibling)) => sibling,
+                    None => proof_nodes.next()?.clone(),
+                };
+                // Left children have odd indices.
+                let parent = if index % 2 == 1 {
+                    B::hash_new_parent(&node, &sibling)
+                } else {
+                    B::hash_new_parent(&sibling, &node)
+                };
+                parents.push((parent_index(index), parent));
+            }
+            level = parents;
+        }
+
+        proof_nodes.next().is_none().then_some(level)
+    }
+}
+
+/// The number of nodes as 8 bytes in big-endian order, followed by each node prefixed with the
+/// length of its serialization in the same format.
+impl<T> Serializable for MultiProof<T>
+where
+    T: Serializable + PartialEq + Eq,
+{
+    fn serialize(&self) -> Vec<u8> {
+        let mut bytes = (self.nodes.len() as u64).to_be_bytes().to_vec();
+        for node in &self.nodes {
+            let node_bytes = node.serialize();
+            bytes.extend_from_slice(&(node_bytes.len() as u64).to_be_bytes());
+            bytes.extend_from_slice(&node_bytes);
+        }
+        bytes
+    }
+}
+
+impl<T> Deserializable for MultiProof<T>
+where
+    T: Deserializable + PartialEq + Eq,
+{
+    fn deserialize(bytes: &[u8]) -> Result<Self, DeserializationError>
+    where
+        Self: Sized,
+    {
+        let (length, mut bytes) = read_length(bytes)?;
+        let mut nodes = Vec::new();
+        for _ in 0..length {
+            let (node_length, rest) = read_length(bytes)?;
+            let node_bytes = rest
+                .get(..node_length)
+                .ok_or(DeserializationError::InvalidAmountOfBytes)?;
+            nodes.push(T::deserialize(node_bytes)?);
+            bytes = &rest[node_length..];
+        }
+        if !bytes.is_empty() {
+            return Err(DeserializationError::InvalidAmountOfBytes);
+        }
+        Ok(Self { nodes })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::merkle_tree::{
+        backends::field_element::FieldElementBackend, merkle::MerkleTree, test_merkle::TestBackend,
+    };
+    use lambdaworks_math::field::{
+        element::FieldElement,
+        fields::{
+            fft_friendly::stark_252_prime_field::Stark252PrimeField, u64_prime_field::U64PrimeField,
+        },
+    };
+    use sha3::Keccak256;
+
+    type F = Stark252PrimeField;
+    type FE = FieldElement<F>;
+    type Backend = FieldElementBackend<F, Keccak256, 32>;
+
+    fn tree(num_leaves: u64) -> (Vec<FE>, MerkleTree<Backend>) {
+        let values: Vec<FE> = (0..num_leaves).map(FE::from).collect();
+        let tree = MerkleTree::<Backend>::build(&values);
+        (values, tree)
+    }
+
+    #[test]
+    fn multi_proof_verifies_the_opened_leaves() {
+        let (values, tree) = tree(13);
+        let positions = [11, 2, 3, 7, 12];
+        let proof = tree.get_multi_proof(&positions).unwrap();
+        let opened: Vec<FE> = positions.iter().map(|i| values[*i]).collect();
+
+        assert!(proof.verify::<Backend>(&tree.root, 13, &positions, &opened));
+        let mut wrong = opened.clone();
+        wrong[1] = FE::from(100);
+        assert!(!proof.verify::<Backend>(&tree.root, 13, &positions, &wrong));
+        assert!(!proof.verify::<Backend>(&tree.root, 13, &[11, 2, 3, 6, 12], &opened));
+        assert!(!proof.verify::<Backend>(&tree.root, 13, &positions[..4], &opened[..4]));
+    }
+
+    #[test]
+    fn shared_nodes_are_sent_once() {
+        let (_, tree) = tree(16);
+        // Leaves 0 to 3 fill a subtree, so only the roots of the two subtrees next to it are sent.
+        let proof = tree.get_multi_proof(&[0, 1, 2, 3]).unwrap();
+        assert_eq!(proof.nodes.len(), 2);
+
+        let separate: usize = [0, 1, 2, 3]
+            .iter()
+            .map(|i| tree.get_proof_by_pos(*i).unwrap().merkle_path.len())
+            .sum();
+        assert_eq!(separate, 16);
+
+        let all: Vec<usize> = (0..16).collect();
+        assert!(tree.get_multi_proof(&all).unwrap().nodes.is_empty());
+        assert!(tree.get_multi_proof(&[16]).is_none());
+    }
+
+    #[test]
+    fn repeated_positions_must_have_the_same_value() {
+        let (values, tree) = tree(8);
+        let proof = tree.get_multi_proof(&[5, 5]).unwrap();
+        let pair = [values[5], values[5]];
+        assert!(proof.verify::<Backend>(&tree.root, 8, &[5, 5], &pair));
+        let pair = [values[5], values[4]];
+        assert!(!proof.verify::<Backend>(&tree.root, 8, &[5, 5], &pair));
+    }
+
+    #[test]
+    fn multi_proof_of_a_capped_tree_verifies_with_the_cap() {
+        let values: Vec<FE> = (0..13).map(FE::from).collect();
+        let tree = MerkleTree::<Backend>::build_with_cap(&values, 2).unwrap();
+        let cap = tree.cap();
+        let positions = [7, 2, 3];
+        let proof = tree.get_multi_proof(&positions).unwrap();
+        let opened: Vec<FE> = positions.iter().map(|i| values[*i]).collect();
+
+        let uncapped = MerkleTree::<Backend>::build(&values)
+            .get_multi_proof(&positions)
+            .unwrap();
+        assert!(proof.nodes.len() < uncapped.nodes.len());
+        assert!(proof.verify_with_cap::<Backend>(&cap, 13, &positions, &opened));
+
+        let mut wrong = opened.clone();
+        wrong[1] = FE::from(100);
+        assert!(!proof.verify_with_cap::<Backend>(&cap, 13, &positions, &wrong));
+        assert!(!uncapped.verify_with_cap::<Backend>(&cap, 13, &positions, &opened));
+        let mut wrong_cap = cap.clone();
+        wrong_cap.nodes[1] = wrong_cap.nodes[0];
+        assert!(!proof.verify_with_cap::<Backend>(&wrong_cap, 13, &positions, &opened));
+        assert!(!proof.verify_with_cap::<Backend>(&cap, 2, &[1], &opened[1..2]));
+    }
+
+    #[test]
+    fn multi_proof_verifies_after_serialization() {
+        type Goldilocks = U64PrimeField<0xFFFF_FFFF_0000_0001>;
+        type GoldilocksFE = FieldElement<Goldilocks>;
+        type Test = TestBackend<Goldilocks>;
+
+        let values: Vec<GoldilocksFE> = (1..30).map(GoldilocksFE::from).collect();
+        let tree = MerkleTree::<Test>::build(&values);
+        let positions = [0, 9, 28];
+        let proof = tree.get_multi_proof(&positions).unwrap();
+        let bytes = proof.serialize();
+        let deserialized = MultiProof::<GoldilocksFE>::deserialize(&bytes).unwrap();
+
+        assert_eq!(deserialized, proof);
+        let opened: Vec<_> = positions.iter().map(|i| values[*i]).collect();
+        assert!(deserialized.verify::<Test>(&tree.root, 29, &positions, &opened));
+        assert!(MultiProof::<GoldilocksFE>::deserialize(&bytes[..bytes.len() - 1]).is_err());
+    }
+}
@@ -3,7 +3,7 @@ use lambdaworks_math::{
     traits::{Deserializable, Serializable},
 };
 
-use super::traits::IsMerkleTreeBackend;
+use super::{cap::MerkleCap, traits::IsMerkleTreeBackend};
 
 /// Stores a merkle path to some leaf.
 /// Internally, the necessary hashes are stored from root to leaf in the
@@ -16,7 +16,26 @@ pub struct Proof<T: PartialEq + Eq> {
 }
 
 impl<T: PartialEq + Eq> Proof<T> {
-    pub fn verify<B>(&self, root_hash: &B::Node, mut index: usize, value: &B::Data) -> bool
+    pub fn verify<B>(&self, root_hash: &B::Node, index: usize, value: &B::Data) -> bool
+    where
+        B: IsMerkleTreeBackend<Node = T>,
+    {
+        let (hashed_value, _) = self.hash_path::<B>(index, value);
+        root_hash == &hashed_value
+    }
+
+    /// Verifies a path returned by a tree built with
+    /// [build_with_cap](super::merkle::MerkleTree::build_with_cap) against its cap.
+    pub fn verify_with_cap<B>(&self, cap: &MerkleCap<T>, index: usize, value: &B::Data) -> bool
+    where
+        B: IsMerkleTreeBackend<Node = T>,
+    {
+        let (hashed_value, cap_index) = self.hash_path::<B>(index, value);
+        cap.nodes.get(cap_index) == Some(&hashed_value)
+    }
+
+    // Returns the node at the end of the path and its position in its level
+    fn hash_path<B>(&self, mut index: usize, value: &B::Data) -> (T, usize)
     where
         B: IsMerkleTreeBackend<Node = T>,
     {
@@ -32,7 +51,7 @@ impl<T: PartialEq + Eq> Proof<T> {
             index >>= 1;
         }
 
-        root_hash == &hashed_value
+        (hashed_value, index)
     }
 }
 
@@ -0,0 +1,456 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use lambdaworks_math::{
+    errors::DeserializationError,
+    traits::{Deserializable, Serializable},
+};
+
+use super::{traits::IsMerkleTreeBackend, utils::read_length};
+
+/// Number of levels below the root, one per bit of the keys.
+pub const SPARSE_TREE_DEPTH: usize = 256;
+
+/// Merkle tree with a leaf for each of the `2^256` keys, storing only the leaves with a value.
+///
+/// The path to a leaf follows the bits of its key from the most significant one, a zero bit
+/// going to the left child. Leaves without a value are the hash of `B::Data::default()`, so
+/// setting a key to the default value removes it. Subtrees without values are never stored:
+/// their hashes are the same for a given height and are computed once in [empty_subtree_hashes].
+pub struct SparseMerkleTree<B: IsMerkleTreeBackend> {
+    root: B::Node,
+    empty_hashes: Vec<B::Node>,
+    // Hashes of the non-empty nodes, indexed by their depth and the bits of the key above it.
+    nodes: HashMap<(usize, [u8; 32]), B::Node>,
+    values: BTreeMap<[u8; 32], B::Data>,
+}
+
+impl<B> SparseMerkleTree<B>
+where
+    B: IsMerkleTreeBackend,
+    B::Data: Default + PartialEq,
+{
+    pub fn new() -> Self {
+        let empty_hashes = empty_subtree_hashes::<B>();
+        Self {
+            root: empty_hashes[SPARSE_TREE_DEPTH].clone(),
+            empty_hashes,
+            nodes: HashMap::new(),
+            values: BTreeMap::new(),
+        }
+    }
+
+    pub fn root(&self) -> &B::Node {
+        &self.root
+    }
+
+    /// Hashes of the subtrees without values, as returned by [empty_subtree_hashes], to verify
+    /// the proofs of this tree without computing them again.
+    pub fn empty_hashes(&self) -> &[B::Node] {
+        &self.empty_hashes
+    }
+
+    pub fn get(&self, key: &[u8; 32]) -> Option<&B::Data> {
+        self.values.get(key)
+    }
+
+    /// Number of keys with a value.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Sets the value of `key`, returning its previous value.
+    pub fn insert(&mut self, key: [u8; 32], value: B::Data) -> Option<B::Data> {
+        let previous = self.set_leaf(key, value);
+        self.update_paths(BTreeSet::from([key]));
+        previous
+    }
+
+    pub fn remove(&mut self, key: &[u8; 32]) -> Option<B::Data> {
+        self.insert(*key, B::Data::default())
+    }
+
+    /// Sets the values of several keys, hashing the nodes shared by their paths only once.
+    /// If a key appears more than once, its last value is kept.
+    pub fn update_batch<I>(&mut self, entries: I)
+    where
+        I: IntoIterator<Item = ([u8; 32], B::Data)>,
+    {
+        let mut keys = BTreeSet::new();
+        for (key, value) in entries {
+            self.set_leaf(key, value);
+            keys.insert(key);
+        }
+        self.update_paths(keys);
+    }
+
+    /// Returns the siblings of the path to `key`, which proves either its value or that it has
+    /// no value.
+    pub fn get_proof(&self, key: &[u8; 32]) -> SparseMerkleProof<B::Node> {
+        let mut bitmap = [0; 32];
+        let mut siblings = Vec::new();
+        for height in 0..SPARSE_TREE_DEPTH {
+            let depth = SPARSE_TREE_DEPTH - height;
+            let sibling = flip_bit(&prefix(key, depth), depth - 1);
+            if let Some(node) = self.nodes.get(&(depth, sibling)) {
+                bitmap[height / 8] |= 1 << (height % 8);
+                siblings.push(node.clone());
+            }
+        }
+        SparseMerkleProof { bitmap, siblings }
+    }
+
+    fn node(&self, depth: usize, prefix: &[u8; 32]) -> &B::Node {
+        self.nodes
+            .get(&(depth, *prefix))
+            .unwrap_or(&self.empty_hashes[SPARSE_TREE_DEPTH - depth])
+    }
+
+    fn set_node(&mut self, depth: usize, prefix: [u8; 32], node: B::Node) {
+        if node == self.empty_hashes[SPARSE_TREE_DEPTH - depth] {
+            self.nodes.remove(&(depth, prefix));
+        } else {
+            self.nodes.insert((depth, prefix), node);
+        }
+    }
+
+    fn set_leaf(&mut self, key: [u8; 32], value: B::Data) -> Option<B::Data> {
+        if value == B::Data::default() {
+            self.set_node(SPARSE_TREE_DEPTH, key, self.empty_hashes[0].clone());
+            self.values.remove(&key)
+        } else {
+            self.set_node(SPARSE_TREE_DEPTH, key, B::hash_data(&value));
+            self.values.insert(key, value)
+        }
+    }
+
+    // Recomputes the ancestors of the given leaves, level by level up to the root
+    fn update_paths(&mut self, keys: BTreeSet<[u8; 32]>) {
+        let mut level = keys;
+        for depth in (0..SPARSE_TREE_DEPTH).rev() {
+            let parents: BTreeSet<[u8; 32]> = level.iter().map(|key| prefix(key, depth)).collect();
+            for parent in &parents {
+                let left = self.node(depth + 1, parent);
+                let right = self.node(depth + 1, &flip_bit(parent, depth));
+                let node = B::hash_new_parent(left, right);
+                self.set_node(depth, *parent, node);
+            }
+            level = parents;
+        }
+        self.root = self.node(0, &[0; 32]).clone();
+    }
+}
+
+impl<B> Default for SparseMerkleTree<B>
+where
+    B: IsMerkleTreeBackend,
+    B::Data: Default + PartialEq,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Hashes of the subtrees without values, indexed by their height. The first one is the hash
+/// of an empty leaf and the last one is the root of an empty [SparseMerkleTree].
+pub fn empty_subtree_hashes<B>() -> Vec<B::Node>
+where
+    B: IsMerkleTreeBackend,
+    B::Data: Default,
+{
+    let mut hashes = Vec::with_capacity(SPARSE_TREE_DEPTH + 1);
+    hashes.push(B::hash_data(&B::Data::default()));
+    for height in 0..SPARSE_TREE_DEPTH {
+        hashes.push(B::hash_new_parent(&hashes[height], &hashes[height]));
+    }
+    hashes
+}
+
+/// Path to a key of a [SparseMerkleTree], without the siblings that are empty subtrees.
+///
+/// Bit `h` of `bitmap`, counting from the least significant bit of its first byte, is set when
+/// the sibling at height `h` is stored in `siblings`. Siblings are stored from the leaf to the
+/// root.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SparseMerkleProof<T: PartialEq + Eq> {
+    pub bitmap: [u8; 32],
+    pub siblings: Vec<T>,
+}
+
+impl<T: PartialEq + Eq + Clone> SparseMerkleProof<T> {
+    /// Checks that `key` has the value `value` in the tree with root `root_hash`. The default
+    /// value is never a member, use [verify_non_membership](Self::verify_non_membership) instead.
+    ///
+    /// `empty_hashes` are the hashes of the empty subtrees, from [empty_subtree_hashes] or
+    /// [SparseMerkleTree::empty_hashes], so that they are computed once for all the proofs.
+    pub fn verify_membership<B>(
+        &self,
+        root_hash: &B::Node,
+        key: &[u8; 32],
+        value: &B::Data,
+        empty_hashes: &[B::Node],
+    ) -> bool
+    where
+        B: IsMerkleTreeBackend<Node = T>,
+        B::Data: Default + PartialEq,
+    {
+        if value == &B::Data::default() {
+            return false;
+        }
+        self.compute_root::<B>(key, B::hash_data(value), empty_hashes)
+            .as_ref()
+            == Some(root_hash)
+    }
+
+    /// Checks that `key` has no value in the tree with root `root_hash`. `empty_hashes` are
+    /// the same as in [verify_membership](Self::verify_membership).
+    pub fn verify_non_membership<B>(
+        &self,
+        root_hash: &B::Node,
+        key: &[u8; 32],
+        empty_hashes: &[B::Node],
+    ) -> bool
+    where
+        B: IsMerkleTreeBackend<Node = T>,
+    {
+        let Some(empty_leaf) = empty_hashes.first() else {
+            return false;
+        };
+        self.compute_root::<B>(key, empty_leaf.clone(), empty_hashes)
+            .as_ref()
+            == Some(root_hash)
+    }
+
+    fn compute_root<B>(&self, key: &[u8; 32], leaf: T, empty_hashes: &[T]) -> Option<T>
+    where
+        B: IsMerkleTreeBackend<Node = T>,
+    {
+        if empty_hashes.len() <= SPARSE_TREE_DEPTH {
+            return None;
+        }
+        let mut siblings = self.siblings.iter();
+        let mut node = leaf;
+        for (height, empty_hash) in empty_hashes.iter().take(SPARSE_TREE_DEPTH).enumerate() {
+            let sibling = if (self.bitmap[height / 8] >> (height % 8)) & 1 == 1 {
+                siblings.next()?
+            } else {
+                empty_hash
+            };
+            node = if bit(key, SPARSE_TREE_DEPTH - 1 - height) {
+                B::hash_new_parent(sibling, &node)
+            } else {
+                B::hash_new_parent(&node, sibling)
+            };
+        }
+        siblings.next().is_none().then_some(node)
+    }
+}
+
+/// The 32 bytes of the bitmap, the number of siblings as 8 bytes in big-endian order, and each
+/// sibling prefixed with the length of its serialization in the same format.
+impl<T> Serializable for SparseMerkleProof<T>
+where
+    T: Serializable + PartialEq + Eq,
+{
+    fn serialize(&self) -> Vec<u8> {
+        let mut bytes = self.bitmap.to_vec();
+        bytes.extend_from_slice(&(self.siblings.len() as u64).to_be_bytes());
+        for node in &self.siblings {
+            let node_bytes = node.serialize();
+            bytes.extend_from_slice(&(node_bytes.len() as u64).to_be_bytes());
+            bytes.extend_from_slice(&node_bytes);
+        }
+        bytes
+    }
+}
+
+impl<T> Deserializable for SparseMerkleProof<T>
+where
+    T: Deserializable + PartialEq + Eq,
+{
+    fn deserialize(bytes: &[u8]) -> Result<Self, DeserializationError>
+    where
+        Self: Sized,
+    {
+        let bitmap: [u8; 32] = bytes
+            .get(..32)
+            .ok_or(DeserializationError::InvalidAmountOfBytes)?
+            .try_into()
+            .map_err(|_| DeserializationError::InvalidAmountOfBytes)?;
+        let (length, mut bytes) = read_length(&bytes[32..])?;
+        let set_bits: u32 = bitmap.iter().map(|byte| byte.count_ones()).sum();
+        if length != set_bits as usize {
+            return Err(DeserializationError::InvalidValue);
+        }
+        let mut siblings = Vec::with_capacity(length);
+        for _ in 0..length {
+            let (node_length, rest) = read_length(bytes)?;
+            let node_bytes = rest
+                .get(..node_length)
+                .ok_or(DeserializationError::InvalidAmountOfBytes)?;
+            siblings.push(T::deserialize(node_bytes)?);
+            bytes = &rest[node_length..];
+        }
+        if !bytes.is_empty() {
+            return Err(DeserializationError::InvalidAmountOfBytes);
+        }
+        Ok(Self { bitmap, siblings })
+    }
+}
+
+// Bit `index` of the key, counting from the most significant bit of its first byte
+fn bit(key: &[u8; 32], index: usize) -> bool {
+    (key[index / 8] >> (7 - index % 8)) & 1 == 1
+}
+
+fn flip_bit(key: &[u8; 32], index: usize) -> [u8; 32] {
+    let mut key = *key;
+    key[index / 8] ^= 1 << (7 - index % 8);
+    key
+}
+
+// The first `depth` bits of the key, followed by zeros
+fn prefix(key: &[u8; 32], depth: usize) -> [u8; 32] {
+    let mut prefix = [0; 32];
+    let full_bytes = depth / 8;
+    prefix[..full_bytes].copy_from_slice(&key[..full_bytes]);
+    if !depth.is_multiple_of(8) {
+        prefix[full_bytes] = key[full_bytes] & (0xFF << (8 - depth % 8));
+    }
+    prefix
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::merkle_tree::{
+        backends::field_element::FieldElementBackend, test_merkle::TestBackend,
+    };
+    use lambdaworks_math::field::{
+        element::FieldElement,
+        fields::{
+            fft_friendly::stark_252_prime_field::Stark252PrimeField, u64_prime_field::U64PrimeField,
+        },
+    };
+    use sha3::Keccak256;
+
+    type F = Stark252PrimeField;
+    type FE = FieldElement<F>;
+    type Backend = FieldElementBackend<F, Keccak256, 32>;
+    type Tree = SparseMerkleTree<Backend>;
+
+    fn key(first: u8, last: u8) -> [u8; 32] {
+        let mut key = [0; 32];
+        key[0] = first;
+        key[31] = last;
+        key
+    }
+
+    #[test]
+    fn removing_every_key_gives_the_empty_root() {
+        let mut tree = Tree::new();
+        let empty_root = *tree.root();
+        assert_eq!(
+            empty_root,
+            empty_subtree_hashes::<Backend>()[SPARSE_TREE_DEPTH]
+        );
+
+        tree.insert(key(1, 2), FE::from(5));
+        tree.insert(key(1, 3), FE::from(6));
+        assert_ne!(*tree.root(), empty_root);
+        assert_eq!(tree.len(), 2);
+
+        assert_eq!(tree.remove(&key(1, 2)), Some(FE::from(5)));
+        assert_eq!(tree.insert(key(1, 3), FE::zero()), Some(FE::from(6)));
+        assert_eq!(*tree.root(), empty_root);
+        assert!(tree.is_empty());
+        assert!(tree.nodes.is_empty());
+    }
+
+    #[test]
+    fn root_depends_on_the_contents_only() {
+        let entries: Vec<_> = (0..20u8)
+            .map(|i| (key(i.wrapping_mul(37), i), FE::from(i as u64 + 1)))
+            .collect();
+
+        let mut sequential = Tree::new();
+        for (key, value) in entries.iter().rev() {
+            sequential.insert(*key, *value);
+        }
+        let mut batched = Tree::new();
+        batched.update_batch(entries.iter().cloned());
+        assert_eq!(sequential.root(), batched.root());
+
+        batched.update_batch([(key(0, 0), FE::from(9)), (key(0, 0), FE::from(1))]);
+        assert_eq!(sequential.root(), batched.root());
+        sequential.insert(key(0, 0), FE::from(2));
+        assert_ne!(sequential.root(), batched.root());
+    }
+
+    #[test]
+    fn membership_and_non_membership_proofs() {
+        let mut tree = Tree::new();
+        tree.update_batch([
+            (key(0, 0), FE::from(1)),
+            (key(0, 1), FE::from(2)),
+            (key(128, 7), FE::from(3)),
+        ]);
+        let root = *tree.root();
+        let empty = empty_subtree_hashes::<Backend>();
+        assert_eq!(tree.empty_hashes(), empty);
+
+        let proof = tree.get_proof(&key(0, 1));
+        assert!(proof.verify_membership::<Backend>(&root, &key(0, 1), &FE::from(2), &empty));
+        assert!(!proof.verify_membership::<Backend>(&root, &key(0, 1), &FE::from(3), &empty));
+        assert!(!proof.verify_membership::<Backend>(&root, &key(0, 0), &FE::from(2), &empty));
+        assert!(!proof.verify_non_membership::<Backend>(&root, &key(0, 1), &empty));
+
+        let proof = tree.get_proof(&key(0, 2));
+        assert!(proof.verify_non_membership::<Backend>(&root, &key(0, 2), &empty));
+        assert!(!proof.verify_membership::<Backend>(&root, &key(0, 2), &FE::zero(), &empty));
+        assert!(!proof.verify_non_membership::<Backend>(&root, &key(0, 2), &empty[..256]));
+
+        tree.insert(key(0, 2), FE::from(4));
+        assert!(!proof.verify_non_membership::<Backend>(tree.root(), &key(0, 2), &empty));
+        let proof = tree.get_proof(&key(0, 2));
+        let value = FE::from(4);
+        assert!(proof.verify_membership::<Backend>(tree.root(), &key(0, 2), &value, &empty));
+    }
+
+    #[test]
+    fn proofs_only_carry_non_empty_siblings() {
+        let mut tree = Tree::new();
+        tree.insert(key(0, 0), FE::from(1));
+        tree.insert(key(0, 1), FE::from(2));
+        tree.insert(key(128, 0), FE::from(3));
+
+        let proof = tree.get_proof(&key(0, 0));
+        assert_eq!(proof.siblings.len(), 2);
+        assert_eq!(proof.bitmap[0], 1);
+        assert_eq!(proof.bitmap[31], 1 << 7);
+        assert_eq!(tree.get_proof(&key(64, 0)).siblings.len(), 2);
+    }
+
+    #[test]
+    fn proof_verifies_after_serialization() {
+        type Goldilocks = U64PrimeField<0xFFFF_FFFF_0000_0001>;
+        type GoldilocksFE = FieldElement<Goldilocks>;
+        type Test = TestBackend<Goldilocks>;
+
+        let mut tree = SparseMerkleTree::<Test>::new();
+        tree.insert(key(3, 4), GoldilocksFE::from(7));
+        tree.insert(key(200, 4), GoldilocksFE::from(8));
+        let proof = tree.get_proof(&key(3, 4));
+        let bytes = proof.serialize();
+        let deserialized = SparseMerkleProof::<GoldilocksFE>::deserialize(&bytes).unwrap();
+
+        assert_eq!(deserialized, proof);
+        let value = GoldilocksFE::from(7);
+        let empty = tree.empty_hashes();
+        assert!(deserialized.verify_membership::<Test>(tree.root(), &key(3, 4), &value, empty));
+        assert!(SparseMerkleProof::<GoldilocksFE>::deserialize(&bytes[..40]).is_err());
+    }
+}
@@ -0,0 +1,330 @@
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{self, Read, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use super::{proof::Proof, traits::IsMerkleTreeBackend, utils::is_power_of_two};
+
+/// Where a [StreamingMerkleTreeBuilder] keeps the nodes of each level, counted from the leaves.
+/// Nodes are pushed from left to right, and read back once the tree is built.
+pub trait NodeStorage<T> {
+    fn push(&mut self, level: usize, node: &T) -> io::Result<()>;
+
+    fn get(&self, level: usize, index: usize) -> io::Result<T>;
+
+    /// Makes every pushed node available to [get](Self::get).
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Keeps the nodes in memory, one `Vec` per level.
+pub struct MemoryStorage<T> {
+    levels: Vec<Vec<T>>,
+}
+
+impl<T> MemoryStorage<T> {
+    pub fn new() -> Self {
+        Self { levels: Vec::new() }
+    }
+}
+
+impl<T> Default for MemoryStorage<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone> NodeStorage<T> for MemoryStorage<T> {
+    fn push(&mut self, level: usize, node: &T) -> io::Result<()> {
+        if self.levels.len() <= level {
+            self.levels.resize_with(level + 1, Vec::new);
+        }
+        self.levels[level].push(node.clone());
+        Ok(())
+    }
+
+    fn get(&self, level: usize, index: usize) -> io::Result<T> {
+        self.levels
+            .get(level)
+            .and_then(|nodes| nodes.get(index))
+            .cloned()
+            .ok_or(io::Error::from(io::ErrorKind::InvalidInput))
+    }
+}
+
+// Bytes of nodes kept for each level before writing them to its file
+const WRITE_BUFFER_SIZE: usize = 1 << 16;
+
+/// Keeps nodes of `N` bytes, as those of the hash backends, in one file per level named
+/// `level_<i>` inside a directory. Existing files with those names are overwritten, and the
+/// files are left in place when the storage is dropped.
+pub struct FileStorage<const N: usize> {
+    directory: PathBuf,
+    files: Vec<Mutex<File>>,
+    buffers: Vec<Vec<u8>>,
+}
+
+impl<const N: usize> FileStorage<N> {
+    pub fn new(directory: impl AsRef<Path>) -> io::Result<Self> {
+        fs::create_dir_all(&directory)?;
+        Ok(Self {
+            directory: directory.as_ref().to_path_buf(),
+            files: Vec::new(),
+            buffers: Vec::new(),
+        })
+    }
+
+    fn add_level(&mut self) -> io::Result<()> {
+        let path = self.directory.join(format!("level_{}", self.files.len()));
+        File::create(&path)?;
+        // In append mode writes go to the end of the file whatever the reads did to the cursor
+        let file = OpenOptions::new().read(true).append(true).open(path)?;
+        self.files.push(Mutex::new(file));
+        self.buffers.push(Vec::with_capacity(WRITE_BUFFER_SIZE));
+        Ok(())
+    }
+
+    fn write_buffer(&mut self, level: usize) -> io::Result<()> {
+        let file = self.files[level]
+            .get_mut()
+            .map_err(|_| io::Error::from(io::ErrorKind::Other))?;
+        file.write_all(&self.buffers[level])?;
+        self.buffers[level].clear();
+        Ok(())
+    }
+}
+
+impl<const N: usize> NodeStorage<[u8; N]> for FileStorage<N> {
+    fn push(&mut self, level: usize, node: &[u8; N]) -> io::Result<()> {
+        while self.files.len() <= level {
+            self.add_level()?;
+        }
+        self.buffers[level].extend_from_slice(node);
+        if self.buffers[level].len() >= WRITE_BUFFER_SIZE {
+            self.write_buffer(level)?;
+        }
+        Ok(())
+    }
+
+    fn get(&self, level: usize, index: usize) -> io::Result<[u8; N]> {
+        let mut file = self
+            .files
+            .get(level)
+            .ok_or(io::Error::from(io::ErrorKind::InvalidInput))?
+            .lock()
+            .map_err(|_| io::Error::from(io::ErrorKind::Other))?;
+        file.seek(SeekFrom::Start((index * N) as u64))?;
+        let mut node = [0; N];
+        file.read_exact(&mut node)?;
+        Ok(node)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        for level in 0..self.files.len() {
+            self.write_buffer(level)?;
+            self.files[level]
+                .get_mut()
+                .map_err(|_| io::Error::from(io::ErrorKind::Other))?
+                .flush()?;
+        }
+        Ok(())
+    }
+}
+
+/// Builds a Merkle tree from leaves given in chunks, keeping in memory only the nodes still
+/// waiting for their right sibling, one per level. Every node is written to the storage as
+/// soon as it is computed.
+pub struct StreamingMerkleTreeBuilder<B: IsMerkleTreeBackend, S: NodeStorage<B::Node>> {
+    storage: S,
+    pending: Vec<Option<B::Node>>,
+    last_leaf: Option<B::Node>,
+    num_leaves: usize,
+}
+
+impl<B, S> StreamingMerkleTreeBuilder<B, S>
+where
+    B: IsMerkleTreeBackend,
+    S: NodeStorage<B::Node>,
+{
+    pub fn new(storage: S) -> Self {
+        Self {
+            storage,
+            pending: Vec::new(),
+            last_leaf: None,
+            num_leaves: 0,
+        }
+    }
+
+    pub fn push_leaves(&mut self, unhashed_leaves: &[B::Data]) -> io::Result<()> {
+        for leaf in B::hash_leaves(unhashed_leaves) {
+            self.last_leaf = Some(leaf.clone());
+            self.push_node(0, leaf)?;
+            self.num_leaves += 1;
+        }
+        Ok(())
+    }
+
+    /// Pads the leaves to a power of two by repeating the last one, like
+    /// [MerkleTree::build](super::merkle::MerkleTree::build), and returns the tree.
+    /// Fails if no leaves were pushed.
+    pub fn finish(mut self) -> io::Result<StoredMerkleTree<B, S>> {
+        let Some(last_leaf) = self.last_leaf.take() else {
+            return Err(io::Error::from(io::ErrorKind::InvalidInput));
+        };
+        while !is_power_of_two(self.num_leaves) {
+            self.push_node(0, last_leaf.clone())?;
+            self.num_leaves += 1;
+        }
+        self.storage.flush()?;
+
+        let height = self.num_leaves.trailing_zeros() as usize;
+        let root = self.pending[height]
+            .take()
+            .expect("with a power of two leaves only the root is pending");
+        Ok(StoredMerkleTree {
+            root,
+            height,
+            storage: self.storage,
+        })
+    }
+
+    // Stores the node and hashes it with the pending left sibling, if any, going up the levels
+    fn push_node(&mut self, mut level: usize, mut node: B::Node) -> io::Result<()> {
+        loop {
+            self.storage.push(level, &node)?;
+            if self.pending.len() == level {
+                self.pending.push(None);
+            }
+            match self.pending[level].take() {
+                Some(left) => {
+                    node = B::hash_new_parent(&left, &node);
+                    level += 1;
+                }
+                None => {
+                    self.pending[level] = Some(node);
+                    return Ok(());
+                }
+            }
+        }
+    }
+}
+
+/// Merkle tree whose nodes are read from a [NodeStorage]. It has the same root and proofs as
+/// the [MerkleTree](super::merkle::MerkleTree) built from the same leaves.
+pub struct StoredMerkleTree<B: IsMerkleTreeBackend, S: NodeStorage<B::Node>> {
+    pub root: B::Node,
+    height: usize,
+    storage: S,
+}
+
+impl<B, S> StoredMerkleTree<B, S>
+where
+    B: IsMerkleTreeBackend,
+    S: NodeStorage<B::Node>,
+{
+    pub fn get_proof_by_pos(&self, pos: usize) -> Option<Proof<B::Node>> {
+        if pos >= 1 << self.height {
+            return None;
+        }
+        let Ok(merkle_path) = self.build_merkle_path(pos) else {
+            return None;
+        };
+        Some(Proof { merkle_path })
+    }
+
+    fn build_merkle_path(&self, pos: usize) -> io::Result<Vec<B::Node>> {
+        (0..self.height)
+            .map(|level| self.storage.get(level, (pos >> level) ^ 1))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::merkle_tree::{
+        backends::field_element::FieldElementBackend, merkle::MerkleTree, test_merkle::TestBackend,
+    };
+    use lambdaworks_math::field::{
+        element::FieldElement,
+        fields::{
+            fft_friendly::stark_252_prime_field::Stark252PrimeField, u64_prime_field::U64PrimeField,
+        },
+    };
+    use sha3::Keccak256;
+
+    type F = Stark252PrimeField;
+    type FE = FieldElement<F>;
+    type Backend = FieldElementBackend<F, Keccak256, 32>;
+
+    fn assert_same_tree<S: NodeStorage<[u8; 32]>>(
+        values: &[FE],
+        chunk_size: usize,
+        storage: S,
+    ) -> StoredMerkleTree<Backend, S> {
+        let mut builder = StreamingMerkleTreeBuilder::<Backend, _>::new(storage);
+        for chunk in values.chunks(chunk_size) {
+            builder.push_leaves(chunk).unwrap();
+        }
+        let stored = builder.finish().unwrap();
+        let tree = MerkleTree::<Backend>::build(values);
+
+        assert_eq!(stored.root, tree.root);
+        for pos in 0..values.len().next_power_of_two() {
+            let stored_proof = stored.get_proof_by_pos(pos).unwrap();
+            let proof = tree.get_proof_by_pos(pos).unwrap();
+            assert_eq!(stored_proof.merkle_path, proof.merkle_path);
+        }
+        stored
+    }
+
+    #[test]
+    fn streamed_tree_in_memory_matches_merkle_tree() {
+        for (num_leaves, chunk_size) in [(1, 1), (2, 1), (5, 2), (13, 4), (64, 64), (100, 7)] {
+            let values: Vec<FE> = (0..num_leaves).map(FE::from).collect();
+            let stored = assert_same_tree(&values, chunk_size, MemoryStorage::new());
+            assert!(stored
+                .get_proof_by_pos(num_leaves.next_power_of_two() as usize)
+                .is_none());
+        }
+    }
+
+    #[test]
+    fn streamed_tree_in_files_matches_merkle_tree() {
+        let directory = std::env::temp_dir().join(format!("merkle_levels_{}", std::process::id()));
+        let values: Vec<FE> = (0..3000).map(FE::from).collect();
+        let storage = FileStorage::<32>::new(&directory).unwrap();
+        let stored = assert_same_tree(&values, 256, storage);
+
+        let leaves = fs::metadata(directory.join("level_0")).unwrap().len();
+        assert_eq!(leaves, 4096 * 32);
+        assert!(stored.storage.get(0, 4096).is_err());
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn field_element_nodes_can_be_streamed() {
+        type Goldilocks = U64PrimeField<0xFFFF_FFFF_0000_0001>;
+        type GoldilocksFE = FieldElement<Goldilocks>;
+        type Test = TestBackend<Goldilocks>;
+
+        let values: Vec<GoldilocksFE> = (1..30).map(GoldilocksFE::from).collect();
+        let mut builder = StreamingMerkleTreeBuilder::<Test, _>::new(MemoryStorage::new());
+        builder.push_leaves(&values[..10]).unwrap();
+        builder.push_leaves(&values[10..]).unwrap();
+        let stored = builder.finish().unwrap();
+
+        let proof = stored.get_proof_by_pos(17).unwrap();
+        assert_eq!(stored.root, MerkleTree::<Test>::build(&values).root);
+        assert!(proof.verify::<Test>(&stored.root, 17, &values[17]));
+    }
+
+    #[test]
+    fn building_without_leaves_fails() {
+        let builder = StreamingMerkleTreeBuilder::<Backend, _>::new(MemoryStorage::new());
+        assert!(builder.finish().is_err());
+    }
+}
@@ -1,3 +1,5 @@
+use lambdaworks_math::errors::DeserializationError;
+
 use super::traits::IsMerkleTreeBackend;
 
 pub fn sibling_index(node_index: usize) -> usize {
@@ -57,6 +59,18 @@ pub fn right_child_index(parent_index: usize) -> usize {
     parent_index * 2 + 2
 }
 
+// Reads a length written as 8 big-endian bytes, returning it with the rest of the bytes
+pub fn read_length(bytes: &[u8]) -> Result<(usize, &[u8]), DeserializationError> {
+    let length_bytes: [u8; 8] = bytes
+        .get(..8)
+        .ok_or(DeserializationError::InvalidAmountOfBytes)?
+        .try_into()
+        .map_err(|_| DeserializationError::InvalidAmountOfBytes)?;
+    let length = usize::try_from(u64::from_be_bytes(length_bytes))
+        .map_err(|_| DeserializationError::PointerSizeError)?;
+    Ok((length, &bytes[8..]))
+}
+
 #[cfg(test)]
 mod tests {
     use lambdaworks_math::field::{element::FieldElement, fields::u64_prime_field::U64PrimeField};
@@ -0,0 +1,244 @@
+//! Sumcheck protocol for combinations of multilinear polynomials.
+//!
+//! The prover convinces the verifier that `sum_{x in {0, 1}^n} g(p_1(x), ..., p_k(x)) = C`,
+//! where the `p_i` are multilinear polynomials and `g` is a polynomial of degree `d`, like the
+//! product of the `p_i`. In round `j`, the prover sends the univariate polynomial
+//! `s_j(X) = sum g(p_1(r_1, ..., r_{j-1}, X, x), ...)` over the remaining `x` in the
+//! hypercube, as its evaluations at `0, 1, ..., d`. The verifier checks
+//! `s_j(0) + s_j(1) = s_{j-1}(r_{j-1})` and samples the next challenge `r_j`, which are drawn
+//! from the transcript here. In the end it's left with the claim
+//! `g(p_1(r), ..., p_k(r)) = s_n(r_n)`, which it checks with oracle access to the `p_i`.
+
+pub mod prover;
+pub mod verifier;
+
+use crate::fiat_shamir::transcript::Transcript;
+use lambdaworks_math::{
+    field::{element::FieldElement, traits::IsField},
+    polynomial::Polynomial,
+    traits::ByteConversion,
+};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum SumcheckError {
+    /// There are no polynomials to combine.
+    NoPolynomials,
+    /// The combination has degree zero, so the round polynomials would be constants.
+    ZeroDegree,
+    /// The polynomials don't all have the same number of variables.
+    DifferentNumberOfVariables,
+    /// The proof has a number of rounds other than the number of variables.
+    WrongNumberOfRounds(usize, usize),
+    /// The round polynomial of the round doesn't have one evaluation more than the degree.
+    WrongRoundPolynomialLength(usize),
+    /// The round polynomial of the round doesn't sum to the claim of the previous round.
+    RoundCheckFailed(usize),
+}
+
+/// Non-interactive sumcheck proof: the claimed sum and the round polynomials, each one as its
+/// evaluations at `0, 1, ..., degree`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SumcheckProof<F: IsField> {
+    pub claimed_sum: FieldElement<F>,
+    pub round_polynomials: Vec<Vec<FieldElement<F>>>,
+}
+
+/// Returns the product of `values`, the combination function for a product of multilinear
+/// polynomials.
+pub fn product<F: IsField>(values: &[FieldElement<F>]) -> FieldElement<F> {
+    values
+        .iter()
+        .fold(FieldElement::one(), |product, value| product * value)
+}
+
+/// Samples a field element from the transcript. The 32 bytes of the challenge are read as a
+/// number in base `2^64`, reduced in the field, so this works for any field.
+pub fn sample_challenge<F: IsField, T: Transcript>(transcript: &mut T) -> FieldElement<F> {
+    let challenge = transcript.challenge();
+    let base = FieldElement::<F>::from(u64::MAX) + FieldElement::<F>::one();
+    challenge
+        .chunks(8)
+        .fold(FieldElement::zero(), |accumulator, chunk| {
+            let mut limb = [0_u8; 8];
+            limb.copy_from_slice(chunk);
+            accumulator * &base + FieldElement::from(u64::from_be_bytes(limb))
+        })
+}
+
+/// Appends the statement to the transcript before anything else, so that the challenges depend
+/// on the number of variables and the degree as well as on the claimed sum.
+fn append_statement<F: IsField, T: Transcript>(
+    transcript: &mut T,
+    n_vars: usize,
+    degree: usize,
+    claimed_sum: &FieldElement<F>,
+) where
+    FieldElement<F>: ByteConversion,
+{
+    transcript.append(&(n_vars as u64).to_be_bytes());
+    transcript.append(&(degree as u64).to_be_bytes());
+    transcript.append(&claimed_sum.to_bytes_be());
+}
+
+/// Appends a round polynomial to the transcript.
+fn append_round_polynomial<F: IsField, T: Transcript>(
+    transcript: &mut T,
+    evaluations: &[FieldElement<F>],
+) where
+    FieldElement<F>: ByteConversion,
+{
+    for evaluation in evaluations {
+        transcript.append(&evaluation.to_bytes_be());
+    }
+}
+
+/// Evaluates at `r` the polynomial of degree `evaluations.len() - 1` that takes the values
+/// `evaluations` at `0, 1, 2, ...`.
+fn evaluate_round_polynomial<F: IsField>(
+    evaluations: &[FieldElement<F>],
+    r: &FieldElement<F>,
+) -> FieldElement<F> {
+    let xs: Vec<_> = (0..evaluations.len() as u64)
+        .map(FieldElement::from)
+        .collect();
+    // The points are distinct as long as the characteristic is larger than the degree.
+    Polynomial::interpolate(&xs, evaluations)
+        .expect("xs and evaluations have equal length and xs are unique")
+        .evaluate(r)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::prover::{prove, prove_product};
+    use super::verifier::verify;
+    use super::*;
+    use crate::fiat_shamir::default_transcript::DefaultTranscript;
+    use lambdaworks_math::{
+        field::fields::u64_prime_field::U64PrimeField,
+        polynomial::dense_multilinear_poly::DenseMultilinearPolynomial,
+    };
+
+    type F = U64PrimeField<18446744069414584321>;
+    type FE = FieldElement<F>;
+
+    fn multilinear(values: &[u64]) -> DenseMultilinearPolynomial<F> {
+        DenseMultilinearPolynomial::new(values.iter().map(|v| FE::from(*v)).collect())
+    }
+
+    #[test]
+    fn product_of_three_polynomials_verifies() {
+        let polys = vec![
+            multilinear(&[1, 2, 3, 4, 5, 6, 7, 8]),
+            multilinear(&[9, 8, 7, 6, 5, 4, 3, 2]),
+            multilinear(&[2, 7, 1, 8, 2, 8, 1, 8]),
+        ];
+        let expected_sum: FE = (0..8)
+            .map(|i| {
+                let values: Vec<_> = polys.iter().map(|poly| poly.evals()[i]).collect();
+                product(&values)
+            })
+            .sum();
+
+        let (proof, prover_point) =
+            prove_product(polys.clone(), &mut DefaultTranscript::new()).unwrap();
+        assert_eq!(proof.claimed_sum, expected_sum);
+        assert!(proof.round_polynomials.iter().all(|round| round.len() == 4));
+
+        let (point, claim) = verify(3, 3, &proof, &mut DefaultTranscript::new()).unwrap();
+        assert_eq!(point, prover_point);
+        let evaluations: Vec<_> = polys
+            .iter()
+            .map(|poly| poly.evaluate(&point).unwrap())
+            .collect();
+        assert_eq!(claim, product(&evaluations));
+    }
+
+    #[test]
+    fn low_degree_combination_verifies() {
+        // g(a, b) = a^2 + 3 b
+        let combine = |values: &[FE]| values[0].square() + FE::from(3) * values[1];
+        let polys = vec![multilinear(&[3, 1, 4, 1]), multilinear(&[5, 9, 2, 6])];
+
+        let (proof, _) = prove(polys.clone(), combine, 2, &mut DefaultTranscript::new()).unwrap();
+        let (point, claim) = verify(2, 2, &proof, &mut DefaultTranscript::new()).unwrap();
+
+        let a = polys[0].evaluate(&point).unwrap();
+        let b = polys[1].evaluate(&point).unwrap();
+        assert_eq!(claim, combine(&[a, b]));
+    }
+
+    #[test]
+    fn wrong_claimed_sum_is_rejected() {
+        let polys = vec![multilinear(&[1, 2, 3, 4]), multilinear(&[5, 6, 7, 8])];
+        let (mut proof, _) = prove_product(polys, &mut DefaultTranscript::new()).unwrap();
+        proof.claimed_sum += FE::one();
+
+        assert_eq!(
+            verify(2, 2, &proof, &mut DefaultTranscript::new()),
+            Err(SumcheckError::RoundCheckFailed(0))
+        );
+    }
+
+    #[test]
+    fn malformed_proofs_are_rejected() {
+        let polys = vec![multilinear(&[1, 2, 3, 4]), multilinear(&[5, 6, 7, 8])];
+        let (proof, _) = prove_product(polys, &mut DefaultTranscript::new()).unwrap();
+
+        assert_eq!(
+            verify(3, 2, &proof, &mut DefaultTranscript::new()),
+            Err(SumcheckError::WrongNumberOfRounds(3, 2))
+        );
+        assert_eq!(
+            verify(2, 3, &proof, &mut DefaultTranscript::new()),
+            Err(SumcheckError::WrongRoundPolynomialLength(0))
+        );
+        assert_eq!(
+            verify(2, 0, &proof, &mut DefaultTranscript::new()),
+            Err(SumcheckError::ZeroDegree)
+        );
+    }
+
+    #[test]
+    fn challenges_depend_on_the_number_of_variables_and_the_degree() {
+        let polys = vec![multilinear(&[1, 2, 3, 4]), multilinear(&[5, 6, 7, 8])];
+        let (proof, prover_point) = prove_product(polys, &mut DefaultTranscript::new()).unwrap();
+
+        // A transcript that absorbed a different statement yields different challenges.
+        let mut transcript = DefaultTranscript::new();
+        append_statement(&mut transcript, 3, 2, &proof.claimed_sum);
+        let mut point = Vec::new();
+        for evaluations in &proof.round_polynomials {
+            append_round_polynomial(&mut transcript, evaluations);
+            point.push(sample_challenge::<F, _>(&mut transcript));
+        }
+        assert_ne!(point, prover_point);
+    }
+
+    #[test]
+    fn zero_degree_combination_is_rejected() {
+        let polys = vec![multilinear(&[1, 2, 3, 4])];
+        assert_eq!(
+            prove(
+                polys,
+                |_: &[FE]| FE::one(),
+                0,
+                &mut DefaultTranscript::new()
+            )
+            .map(|_| ()),
+            Err(SumcheckError::ZeroDegree)
+        );
+    }
+
+    #[test]
+    fn polynomials_with_different_number_of_variables_are_rejected() {
+        let polys = vec![multilinear(&[1, 2, 3, 4]), multilinear(&[5, 6])];
+        assert_eq!(
+            prove_product(polys, &mut DefaultTranscript::new()).map(|_| ()),
+            Err(SumcheckError::DifferentNumberOfVariables)
+        );
+        assert_eq!(
+            prove_product::<F, _>(Vec::new(), &mut DefaultTranscript::new()).map(|_| ()),
+            Err(SumcheckError::NoPolynomials)
+        );
+    }
+}
@@ -0,0 +1,204 @@
+use crate::fiat_shamir::transcript::Transcript;
+use lambdaworks_math::{
+    field::{element::FieldElement, traits::IsField},
+    polynomial::dense_multilinear_poly::DenseMultilinearPolynomial,
+    traits::ByteConversion,
+};
+
+use super::{
+    append_round_polynomial, append_statement, product, sample_challenge, SumcheckError,
+    SumcheckProof,
+};
+
+/// Sumcheck prover for `g(p_1, ..., p_k)`, with `g` given by `combine` and of degree `degree`.
+///
+/// After each round the first remaining variable of the polynomials is fixed to the challenge,
+/// which halves them, so the rounds together take time linear in the size of the hypercube.
+pub struct Prover<F: IsField, C: Fn(&[FieldElement<F>]) -> FieldElement<F>> {
+    polys: Vec<DenseMultilinearPolynomial<F>>,
+    combine: C,
+    degree: usize,
+}
+
+impl<F: IsField, C: Fn(&[FieldElement<F>]) -> FieldElement<F>> Prover<F, C> {
+    /// Creates the prover. Returns an error if there are no polynomials, if they don't all
+    /// have the same number of variables or if `degree` is zero.
+    pub fn new(
+        polys: Vec<DenseMultilinearPolynomial<F>>,
+        combine: C,
+        degree: usize,
+    ) -> Result<Self, SumcheckError> {
+        let n_vars = match polys.first() {
+            Some(poly) => poly.num_vars(),
+            None => return Err(SumcheckError::NoPolynomials),
+        };
+        if polys.iter().any(|poly| poly.num_vars() != n_vars) {
+            return Err(SumcheckError::DifferentNumberOfVariables);
+        }
+        if degree == 0 {
+            return Err(SumcheckError::ZeroDegree);
+        }
+        Ok(Self {
+            polys,
+            combine,
+            degree,
+        })
+    }
+
+    /// Returns the number of variables left to fix.
+    pub fn num_vars(&self) -> usize {
+        self.polys[0].num_vars()
+    }
+
+    /// Returns the sum of the combination over the hypercube of the remaining variables.
+    pub fn sum(&self) -> FieldElement<F> {
+        (0..self.polys[0].len())
+            .map(|i| {
+                let values: Vec<_> = self
+                    .polys
+                    .iter()
+                    .map(|poly| poly.evals()[i].clone())
+                    .collect();
+                (self.combine)(&values)
+            })
+            .sum()
+    }
+
+    /// Returns the round polynomial for the first remaining variable, as its evaluations at
+    /// `0, 1, ..., degree`. Panics if there are no variables left.
+    ///
+    /// For each point of the rest of the hypercube, the values of each polynomial along the
+    /// first variable are a line from its evaluation at `0` to its evaluation at `1`, so they
+    /// are computed at `0, 1, ..., degree` by adding the difference each time.
+    pub fn round_polynomial(&self) -> Vec<FieldElement<F>> {
+        assert!(self.num_vars() > 0, "there are no variables left to fix");
+        let half = self.polys[0].len() / 2;
+        let mut evaluations = vec![FieldElement::zero(); self.degree + 1];
+        for i in 0..half {
+            let mut values: Vec<_> = self
+                .polys
+                .iter()
+                .map(|poly| poly.evals()[i].clone())
+                .collect();
+            let differences: Vec<_> = self
+                .polys
+                .iter()
+                .zip(&values)
+                .map(|(poly, low)| &poly.evals()[half + i] - low)
+                .collect();
+            for evaluation in evaluations.iter_mut() {
+                *evaluation = &*evaluation + (self.combine)(&values);
+                for (value, difference) in values.iter_mut().zip(&differences) {
+                    *value = &*value + difference;
+                }
+            }
+        }
+        evaluations
+    }
+
+    /// Fixes the first remaining variable of the polynomials to the challenge `r`.
+    pub fn fix_variable(&mut self, r: &FieldElement<F>) {
+        for poly in self.polys.iter_mut() {
+            *poly = poly.fix_first_variable(r);
+        }
+    }
+}
+
+/// Proves the sum of `combine(p_1, ..., p_k)` over the hypercube, with `combine` of degree
+/// `degree`, drawing the challenges from `transcript`.
+/// Returns the proof and the point `r` the variables were fixed to.
+pub fn prove<F, C, T>(
+    polys: Vec<DenseMultilinearPolynomial<F>>,
+    combine: C,
+    degree: usize,
+    transcript: &mut T,
+) -> Result<(SumcheckProof<F>, Vec<FieldElement<F>>), SumcheckError>
+where
+    F: IsField,
+    FieldElement<F>: ByteConversion,
+    C: Fn(&[FieldElement<F>]) -> FieldElement<F>,
+    T: Transcript,
+{
+    let mut prover = Prover::new(polys, combine, degree)?;
+    let claimed_sum = prover.sum();
+    let n_vars = prover.num_vars();
+    append_statement(transcript, n_vars, degree, &claimed_sum);
+
+    let mut round_polynomials = Vec::with_capacity(n_vars);
+    let mut point = Vec::with_capacity(n_vars);
+    for _ in 0..n_vars {
+        let round_polynomial = prover.round_polynomial();
+        append_round_polynomial(transcript, &round_polynomial);
+        let r = sample_challenge(transcript);
+        prover.fix_variable(&r);
+        round_polynomials.push(round_polynomial);
+        point.push(r);
+    }
+
+    let proof = SumcheckProof {
+        claimed_sum,
+        round_polynomials,
+    };
+    Ok((proof, point))
+}
+
+/// Proves the sum of the product of `polys` over the hypercube.
+pub fn prove_product<F, T>(
+    polys: Vec<DenseMultilinearPolynomial<F>>,
+    transcript: &mut T,
+) -> Result<(SumcheckProof<F>, Vec<FieldElement<F>>), SumcheckError>
+where
+    F: IsField,
+    FieldElement<F>: ByteConversion,
+    T: Transcript,
+{
+    let degree = polys.len();
+    prove(polys, product::<F>, degree, transcript)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lambdaworks_math::field::fields::u64_prime_field::U64PrimeField;
+
+    type F = U64PrimeField<101>;
+    type FE = FieldElement<F>;
+
+    fn multilinear(values: &[u64]) -> DenseMultilinearPolynomial<F> {
+        DenseMultilinearPolynomial::new(values.iter().map(|v| FE::from(*v)).collect())
+    }
+
+    #[test]
+    fn round_polynomial_sums_out_the_other_variables() {
+        let polys = vec![multilinear(&[1, 2, 3, 4]), multilinear(&[5, 6, 7, 8])];
+        let prover = Prover::new(polys.clone(), product::<F>, 2).unwrap();
+        let round_polynomial = prover.round_polynomial();
+
+        for (t, evaluation) in round_polynomial.iter().enumerate() {
+            let expected: FE = [FE::zero(), FE::one()]
+                .iter()
+                .map(|x| {
+                    let point = [FE::from(t as u64), *x];
+                    polys[0].evaluate(&point).unwrap() * polys[1].evaluate(&point).unwrap()
+                })
+                .sum();
+            assert_eq!(*evaluation, expected);
+        }
+        assert_eq!(round_polynomial[0] + round_polynomial[1], prover.sum());
+    }
+
+    #[test]
+    fn fixing_variables_reduces_the_sum_to_an_evaluation() {
+        let polys = vec![multilinear(&[3, 1, 4, 1]), multilinear(&[5, 9, 2, 6])];
+        let mut prover = Prover::new(polys.clone(), product::<F>, 2).unwrap();
+        let point = [FE::from(17), FE::from(42)];
+
+        prover.fix_variable(&point[0]);
+        prover.fix_variable(&point[1]);
+        assert_eq!(prover.num_vars(), 0);
+        assert_eq!(
+            prover.sum(),
+            polys[0].evaluate(&point).unwrap() * polys[1].evaluate(&point).unwrap()
+        );
+    }
+}
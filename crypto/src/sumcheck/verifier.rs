@@ -0,0 +1,56 @@
+use crate::fiat_shamir::transcript::Transcript;
+use lambdaworks_math::{
+    field::{element::FieldElement, traits::IsField},
+    traits::ByteConversion,
+};
+
+use super::{
+    append_round_polynomial, append_statement, evaluate_round_polynomial, sample_challenge,
+    SumcheckError, SumcheckProof,
+};
+
+/// Verifies a sumcheck proof for a combination of degree `degree` of polynomials in `n_vars`
+/// variables, drawing the same challenges from `transcript` as the prover.
+///
+/// Returns the point `r` and the value that the combination of the polynomials must take at
+/// it, which the caller checks with its own access to the polynomials. A combination of degree
+/// zero is rejected, since its round polynomials can't be checked.
+pub fn verify<F, T>(
+    n_vars: usize,
+    degree: usize,
+    proof: &SumcheckProof<F>,
+    transcript: &mut T,
+) -> Result<(Vec<FieldElement<F>>, FieldElement<F>), SumcheckError>
+where
+    F: IsField,
+    FieldElement<F>: ByteConversion,
+    T: Transcript,
+{
+    if degree == 0 {
+        return Err(SumcheckError::ZeroDegree);
+    }
+    if proof.round_polynomials.len() != n_vars {
+        return Err(SumcheckError::WrongNumberOfRounds(
+            n_vars,
+            proof.round_polynomials.len(),
+        ));
+    }
+    append_statement(transcript, n_vars, degree, &proof.claimed_sum);
+
+    let mut claim = proof.claimed_sum.clone();
+    let mut point = Vec::with_capacity(n_vars);
+    for (round, evaluations) in proof.round_polynomials.iter().enumerate() {
+        if evaluations.len() != degree + 1 {
+            return Err(SumcheckError::WrongRoundPolynomialLength(round));
+        }
+        if &evaluations[0] + &evaluations[1] != claim {
+            return Err(SumcheckError::RoundCheckFailed(round));
+        }
+
+        append_round_polynomial(transcript, evaluations);
+        let r = sample_challenge(transcript);
+        claim = evaluate_round_polynomial(evaluations, &r);
+        point.push(r);
+    }
+    Ok((point, claim))
+}
@@ -4,6 +4,7 @@ use pprof::criterion::{Output, PProfProfiler};
 mod fields;
 use fields::mersenne31::mersenne31_ops_benchmarks;
 use fields::mersenne31_montgomery::mersenne31_mont_ops_benchmarks;
+use fields::prime_field_backends::prime_field_backends_benchmarks;
 use fields::{
     stark252::starkfield_ops_benchmarks, u64_goldilocks::u64_goldilocks_ops_benchmarks,
     u64_goldilocks_montgomery::u64_goldilocks_montgomery_ops_benchmarks,
@@ -12,6 +13,6 @@ use fields::{
 criterion_group!(
     name = field_benches;
     config = Criterion::default().with_profiler(PProfProfiler::new(100, Output::Flamegraph(None)));
-    targets = starkfield_ops_benchmarks, mersenne31_ops_benchmarks, mersenne31_mont_ops_benchmarks, u64_goldilocks_ops_benchmarks, u64_goldilocks_montgomery_ops_benchmarks
+    targets = starkfield_ops_benchmarks, mersenne31_ops_benchmarks, mersenne31_mont_ops_benchmarks, u64_goldilocks_ops_benchmarks, u64_goldilocks_montgomery_ops_benchmarks, prime_field_backends_benchmarks
 );
 criterion_main!(field_benches);
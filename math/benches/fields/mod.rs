@@ -1,5 +1,6 @@
 pub mod mersenne31;
 pub mod mersenne31_montgomery;
+pub mod prime_field_backends;
 pub mod stark252;
 pub mod u64_goldilocks;
 pub mod u64_goldilocks_montgomery;
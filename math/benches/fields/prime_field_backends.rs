@@ -0,0 +1,115 @@
+use std::hint::black_box;
+
+use criterion::Criterion;
+use lambdaworks_math::{
+    field::{
+        element::FieldElement,
+        fields::{
+            barrett_backed_prime_fields::BarrettBackendPrimeField,
+            montgomery_backed_prime_fields::{IsModulus, MontgomeryBackendPrimeField},
+            solinas_backed_prime_fields::SolinasBackendPrimeField,
+        },
+        traits::IsField,
+    },
+    unsigned_integer::element::{UnsignedInteger, U256},
+};
+use rand::random;
+
+#[derive(Clone, Debug, Hash, Copy, PartialEq, Eq)]
+struct Secp256k1Modulus;
+impl IsModulus<U256> for Secp256k1Modulus {
+    const MODULUS: U256 = U256::from_hex_unchecked(
+        "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f",
+    );
+}
+
+#[derive(Clone, Debug, Hash, Copy, PartialEq, Eq)]
+struct P256Modulus;
+impl IsModulus<U256> for P256Modulus {
+    const MODULUS: U256 = U256::from_hex_unchecked(
+        "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff",
+    );
+}
+
+#[derive(Clone, Debug, Hash, Copy, PartialEq, Eq)]
+struct P448Modulus;
+impl IsModulus<UnsignedInteger<7>> for P448Modulus {
+    const MODULUS: UnsignedInteger<7> = UnsignedInteger::from_hex_unchecked(
+        "fffffffffffffffffffffffffffffffffffffffffffffffffffffffeffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+    );
+}
+
+fn rand_field_elements<F, const NUM_LIMBS: usize>(
+    num: usize,
+) -> Vec<(FieldElement<F>, FieldElement<F>)>
+where
+    F: IsField<BaseType = UnsignedInteger<NUM_LIMBS>>,
+{
+    (0..num)
+        .map(|_| {
+            let rand_a = UnsignedInteger::from_limbs(core::array::from_fn(|_| random()));
+            let rand_b = UnsignedInteger::from_limbs(core::array::from_fn(|_| random()));
+            (FieldElement::new(rand_a), FieldElement::new(rand_b))
+        })
+        .collect()
+}
+
+fn backend_ops_benchmarks<F, const NUM_LIMBS: usize>(c: &mut Criterion, name: &str)
+where
+    F: IsField<BaseType = UnsignedInteger<NUM_LIMBS>>,
+{
+    let input = rand_field_elements::<F, NUM_LIMBS>(10000);
+    let mut group = c.benchmark_group(name);
+
+    group.bench_with_input("add", &input, |bench, i| {
+        bench.iter(|| {
+            for (x, y) in i {
+                black_box(black_box(x) + black_box(y));
+            }
+        });
+    });
+
+    group.bench_with_input("mul", &input, |bench, i| {
+        bench.iter(|| {
+            for (x, y) in i {
+                black_box(black_box(x) * black_box(y));
+            }
+        });
+    });
+
+    group.bench_with_input("square", &input, |bench, i| {
+        bench.iter(|| {
+            for (x, _) in i {
+                black_box(black_box(x).square());
+            }
+        });
+    });
+
+    group.finish();
+}
+
+/// Compares the Montgomery, Barrett and Solinas backends on moduli of special form.
+/// Solinas reduction is the fastest when `2^n - q` is small and sparse, as for secp256k1
+/// and p448, while Montgomery's is usually ahead for moduli such as P-256.
+pub fn prime_field_backends_benchmarks(c: &mut Criterion) {
+    backend_ops_benchmarks::<MontgomeryBackendPrimeField<Secp256k1Modulus, 4>, 4>(
+        c,
+        "secp256k1 Montgomery",
+    );
+    backend_ops_benchmarks::<BarrettBackendPrimeField<Secp256k1Modulus, 4>, 4>(
+        c,
+        "secp256k1 Barrett",
+    );
+    backend_ops_benchmarks::<SolinasBackendPrimeField<Secp256k1Modulus, 4>, 4>(
+        c,
+        "secp256k1 Solinas",
+    );
+
+    backend_ops_benchmarks::<MontgomeryBackendPrimeField<P256Modulus, 4>, 4>(c, "P-256 Montgomery");
+    backend_ops_benchmarks::<BarrettBackendPrimeField<P256Modulus, 4>, 4>(c, "P-256 Barrett");
+    backend_ops_benchmarks::<SolinasBackendPrimeField<P256Modulus, 4>, 4>(c, "P-256 Solinas");
+
+    backend_ops_benchmarks::<MontgomeryBackendPrimeField<P448Modulus, 7>, 7>(c, "p448 Montgomery");
+    backend_ops_benchmarks::<BarrettBackendPrimeField<P448Modulus, 7>, 7>(c, "p448 Barrett");
+    backend_ops_benchmarks::<SolinasBackendPrimeField<P448Modulus, 7>, 7>(c, "p448 Solinas");
+}
@@ -0,0 +1,208 @@
+//! Number theory helpers evaluated while expanding the field macros.
+//! Everything here runs on the host at compile time, so clarity is preferred over speed.
+
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+
+/// Bases used for the Miller-Rabin test. They make the test deterministic for every
+/// modulus below 3.3 * 10^24 and give a negligible error probability above it.
+const MILLER_RABIN_BASES: [u64; 24] = [
+    2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89,
+];
+
+/// Bound for the trial division used when factoring `p - 1`.
+const TRIAL_DIVISION_BOUND: u64 = 1 << 20;
+
+/// Returns `true` if `n` passes the Miller-Rabin test for all of `MILLER_RABIN_BASES`.
+pub fn is_probable_prime(n: &BigUint) -> bool {
+    let two = BigUint::from(2u64);
+    if n < &two {
+        return false;
+    }
+    for base in MILLER_RABIN_BASES {
+        let base = BigUint::from(base);
+        if n == &base {
+            return true;
+        }
+        if (n % &base).is_zero() {
+            return false;
+        }
+    }
+
+    let n_minus_one = n - 1u64;
+    let s = two_adicity(&n_minus_one);
+    let d = &n_minus_one >> s;
+
+    'witness: for base in MILLER_RABIN_BASES {
+        let mut x = BigUint::from(base).modpow(&d, n);
+        if x.is_one() || x == n_minus_one {
+            continue;
+        }
+        for _ in 1..s {
+            x = (&x * &x) % n;
+            if x == n_minus_one {
+                continue 'witness;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+/// Returns the largest `s` such that `2^s` divides `n`.
+pub fn two_adicity(n: &BigUint) -> u64 {
+    n.trailing_zeros().unwrap_or(0)
+}
+
+/// Returns `true` if `a` is a quadratic non-residue modulo the odd prime `p`.
+pub fn is_quadratic_non_residue(a: &BigUint, p: &BigUint) -> bool {
+    let exponent = (p - 1u64) >> 1;
+    a.modpow(&exponent, p) == p - 1u64
+}
+
+/// Returns the smallest quadratic non-residue modulo the odd prime `p`.
+pub fn smallest_quadratic_non_residue(p: &BigUint) -> BigUint {
+    let mut candidate = BigUint::from(2u64);
+    while !is_quadratic_non_residue(&candidate, p) {
+        candidate += 1u64;
+    }
+    candidate
+}
+
+/// Returns the distinct prime factors of `n` if they can all be found by trial division
+/// up to `TRIAL_DIVISION_BOUND` followed by a primality test of the remaining cofactor.
+/// Returns `None` if the factorization could not be completed.
+pub fn distinct_prime_factors(n: &BigUint) -> Option<Vec<BigUint>> {
+    let mut factors = Vec::new();
+    let mut rest = n.clone();
+    let mut divisor = 2u64;
+    while divisor < TRIAL_DIVISION_BOUND && !rest.is_one() {
+        let big_divisor = BigUint::from(divisor);
+        if (&rest % &big_divisor).is_zero() {
+            factors.push(big_divisor.clone());
+            while (&rest % &big_divisor).is_zero() {
+                rest /= &big_divisor;
+            }
+        }
+        divisor += if divisor == 2 { 1 } else { 2 };
+    }
+    if rest.is_one() {
+        Some(factors)
+    } else if is_probable_prime(&rest) {
+        factors.push(rest);
+        Some(factors)
+    } else {
+        None
+    }
+}
+
+/// Checks that `g` generates the multiplicative group of the prime field of order `p`.
+/// Returns `None` when `p - 1` can't be factored, in which case only the weaker
+/// `is_quadratic_non_residue` check applies.
+pub fn is_multiplicative_generator(g: &BigUint, p: &BigUint) -> Option<bool> {
+    let order = p - 1u64;
+    let factors = distinct_prime_factors(&order)?;
+    Some(factors.iter().all(|q| !g.modpow(&(&order / q), p).is_one()))
+}
+
+/// Returns a primitive `2^two_adicity`-th root of unity derived from the
+/// quadratic non-residue `non_residue`.
+pub fn two_adic_primitive_root_of_unity(non_residue: &BigUint, p: &BigUint) -> BigUint {
+    let order = p - 1u64;
+    let odd_part = &order >> two_adicity(&order);
+    non_residue.modpow(&odd_part, p)
+}
+
+/// Computes `- p^{-1} mod 2^{64}`.
+pub fn montgomery_mu(p: &BigUint) -> u64 {
+    let p_mod_word = p.iter_u64_digits().next().unwrap_or(0);
+    // Newton iteration doubling the number of correct bits each step.
+    let mut inverse: u64 = 1;
+    for _ in 0..6 {
+        inverse = inverse.wrapping_mul(2u64.wrapping_sub(p_mod_word.wrapping_mul(inverse)));
+    }
+    inverse.wrapping_neg()
+}
+
+/// Computes `2^{2 * 64 * num_limbs} mod p`.
+pub fn montgomery_r2(p: &BigUint, num_limbs: usize) -> BigUint {
+    (BigUint::one() << (2 * 64 * num_limbs)) % p
+}
+
+/// Splits `n` into `num_limbs` 64-bit limbs, most significant limb first, following
+/// the layout of `UnsignedInteger`.
+pub fn to_limbs(n: &BigUint, num_limbs: usize) -> Vec<u64> {
+    let mut limbs = n.to_u64_digits();
+    limbs.resize(num_limbs, 0);
+    limbs.reverse();
+    limbs
+}
+
+/// Number of limbs needed to store values below `n`.
+pub fn limbs_needed(n: &BigUint) -> usize {
+    (n.bits() as usize).div_ceil(64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn big(n: u64) -> BigUint {
+        BigUint::from(n)
+    }
+
+    #[test]
+    fn primality_of_known_fields() {
+        assert!(is_probable_prime(&big(2013265921)));
+        assert!(is_probable_prime(&big(0xFFFF_FFFF_0000_0001)));
+        assert!(is_probable_prime(&big((1 << 31) - 1)));
+        assert!(!is_probable_prime(&big(2013265923)));
+        // Carmichael number
+        assert!(!is_probable_prime(&big(561)));
+    }
+
+    #[test]
+    fn primality_of_stark_252_modulus() {
+        let p = BigUint::parse_bytes(
+            b"800000000000011000000000000000000000000000000000000000000000001",
+            16,
+        )
+        .unwrap();
+        assert!(is_probable_prime(&p));
+        assert!(!is_probable_prime(&(&p + 2u64)));
+    }
+
+    #[test]
+    fn two_adicity_of_babybear_is_27() {
+        assert_eq!(two_adicity(&big(2013265920)), 27);
+    }
+
+    #[test]
+    fn babybear_generator_is_31() {
+        let p = big(2013265921);
+        assert_eq!(is_multiplicative_generator(&big(31), &p), Some(true));
+        assert_eq!(is_multiplicative_generator(&big(2), &p), Some(false));
+    }
+
+    #[test]
+    fn primitive_root_has_exact_two_adic_order() {
+        let p = big(0xFFFF_FFFF_0000_0001);
+        let root = two_adic_primitive_root_of_unity(&smallest_quadratic_non_residue(&p), &p);
+        let s = two_adicity(&(&p - 1u64));
+        assert!(root.modpow(&(BigUint::one() << s), &p).is_one());
+        assert!(!root.modpow(&(BigUint::one() << (s - 1)), &p).is_one());
+    }
+
+    #[test]
+    fn montgomery_parameters_for_mod_23() {
+        assert_eq!(montgomery_mu(&big(23)), 3208129404123400281);
+        assert_eq!(montgomery_r2(&big(23), 6), big(6));
+    }
+
+    #[test]
+    fn limbs_are_most_significant_first() {
+        let n = (big(1) << 64) + 2u64;
+        assert_eq!(to_limbs(&n, 3), vec![0, 1, 2]);
+        assert_eq!(limbs_needed(&n), 2);
+    }
+}
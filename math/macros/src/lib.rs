@@ -0,0 +1,228 @@
+//! Procedural macros for `lambdaworks-math`.
+//! They are re-exported by `lambdaworks-math`, which is the crate that should be used.
+
+mod arithmetic;
+
+use num_bigint::BigUint;
+use num_traits::Num;
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::{meta::ParseNestedMeta, parse_macro_input, Ident, ItemStruct, Lit, LitStr};
+
+/// Declares a prime field backed by `MontgomeryBackendPrimeField` from its modulus.
+///
+/// The annotated unit struct becomes the `IsModulus` configuration of the field. The
+/// following arguments are accepted:
+/// - `modulus` (required): the prime, as an integer literal or a decimal or `0x`-prefixed
+///   hexadecimal string.
+/// - `generator` (optional): a generator of the multiplicative group. If omitted, the
+///   smallest quadratic non-residue is used to derive the two-adic root of unity.
+/// - `limbs` (optional): the number of 64-bit limbs. Defaults to the fewest that fit the modulus.
+/// - `field` (optional): name of a type alias for the resulting field.
+/// - `name` (optional): value returned by `IsFFTField::field_name`.
+///
+/// The primality of the modulus, the generator, the two-adicity, the primitive root of unity
+/// and the Montgomery constants are all computed when the macro is expanded. A modulus that
+/// is not prime is a compile error.
+///
+/// ```ignore
+/// #[montgomery_prime_field(modulus = "0x78000001", generator = 31, field = Babybear)]
+/// pub struct BabybearConfig;
+/// ```
+#[proc_macro_attribute]
+pub fn montgomery_prime_field(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let mut args = FieldArguments::default();
+    let parser = syn::meta::parser(|meta| args.parse(meta));
+    parse_macro_input!(attr with parser);
+    let item = parse_macro_input!(item as ItemStruct);
+
+    match expand(args, item) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+#[derive(Default)]
+struct FieldArguments {
+    modulus: Option<(BigUint, Span)>,
+    generator: Option<(BigUint, Span)>,
+    limbs: Option<(usize, Span)>,
+    field: Option<Ident>,
+    name: Option<LitStr>,
+}
+
+impl FieldArguments {
+    fn parse(&mut self, meta: ParseNestedMeta) -> syn::Result<()> {
+        if meta.path.is_ident("modulus") {
+            self.modulus = Some(parse_integer(&meta.value()?.parse()?)?);
+        } else if meta.path.is_ident("generator") {
+            self.generator = Some(parse_integer(&meta.value()?.parse()?)?);
+        } else if meta.path.is_ident("limbs") {
+            let lit: syn::LitInt = meta.value()?.parse()?;
+            self.limbs = Some((lit.base10_parse()?, lit.span()));
+        } else if meta.path.is_ident("field") {
+            self.field = Some(meta.value()?.parse()?);
+        } else if meta.path.is_ident("name") {
+            self.name = Some(meta.value()?.parse()?);
+        } else {
+            return Err(meta.error("expected `modulus`, `generator`, `limbs`, `field` or `name`"));
+        }
+        Ok(())
+    }
+}
+
+fn parse_integer(lit: &Lit) -> syn::Result<(BigUint, Span)> {
+    let (digits, span) = match lit {
+        Lit::Int(int) => (int.base10_digits().to_string(), int.span()),
+        Lit::Str(string) => (string.value(), string.span()),
+        _ => {
+            return Err(syn::Error::new(
+                lit.span(),
+                "expected an integer or a string",
+            ))
+        }
+    };
+    let digits = digits.replace('_', "");
+    let value = match digits.strip_prefix("0x") {
+        Some(hex) => BigUint::from_str_radix(hex, 16),
+        None => BigUint::from_str_radix(&digits, 10),
+    };
+    value
+        .map(|value| (value, span))
+        .map_err(|_| syn::Error::new(span, "invalid integer"))
+}
+
+fn limbs_tokens(value: &BigUint, num_limbs: usize) -> TokenStream2 {
+    let limbs = arithmetic::to_limbs(value, num_limbs);
+    quote! {
+        ::lambdaworks_math::unsigned_integer::element::UnsignedInteger::from_limbs([#(#limbs),*])
+    }
+}
+
+fn expand(args: FieldArguments, item: ItemStruct) -> syn::Result<TokenStream2> {
+    if !matches!(item.fields, syn::Fields::Unit) || !item.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &item,
+            "the field configuration must be a non-generic unit struct",
+        ));
+    }
+
+    let (modulus, modulus_span) = args
+        .modulus
+        .ok_or_else(|| syn::Error::new(Span::call_site(), "missing `modulus` argument"))?;
+    if modulus.bits() < 2 || !modulus.bit(0) {
+        return Err(syn::Error::new(
+            modulus_span,
+            "the modulus must be an odd prime",
+        ));
+    }
+    if !arithmetic::is_probable_prime(&modulus) {
+        return Err(syn::Error::new(modulus_span, "the modulus is not prime"));
+    }
+
+    let minimum_limbs = arithmetic::limbs_needed(&modulus);
+    let num_limbs = match args.limbs {
+        Some((limbs, span)) if limbs < minimum_limbs => {
+            return Err(syn::Error::new(
+                span,
+                format!("the modulus needs at least {minimum_limbs} limbs"),
+            ))
+        }
+        Some((limbs, _)) => limbs,
+        None => minimum_limbs,
+    };
+
+    let non_residue = match &args.generator {
+        Some((generator, span)) => {
+            let is_generator = match arithmetic::is_multiplicative_generator(generator, &modulus) {
+                Some(is_generator) => is_generator,
+                None => arithmetic::is_quadratic_non_residue(generator, &modulus),
+            };
+            if !is_generator {
+                return Err(syn::Error::new(
+                    *span,
+                    "the generator does not generate the multiplicative group",
+                ));
+            }
+            generator.clone()
+        }
+        None => arithmetic::smallest_quadratic_non_residue(&modulus),
+    };
+
+    let two_adicity = arithmetic::two_adicity(&(&modulus - 1u64));
+    let root_of_unity = arithmetic::two_adic_primitive_root_of_unity(&non_residue, &modulus);
+    let mu = arithmetic::montgomery_mu(&modulus);
+    let r2 = arithmetic::montgomery_r2(&modulus, num_limbs);
+
+    let config = &item.ident;
+    let vis = &item.vis;
+    let attrs = &item.attrs;
+    let modulus_tokens = limbs_tokens(&modulus, num_limbs);
+    let root_tokens = limbs_tokens(&root_of_unity, num_limbs);
+    let r2_tokens = limbs_tokens(&r2, num_limbs);
+    let unsigned_integer =
+        quote! { ::lambdaworks_math::unsigned_integer::element::UnsignedInteger<#num_limbs> };
+    let backend = quote! {
+        ::lambdaworks_math::field::fields::montgomery_backed_prime_fields::MontgomeryBackendPrimeField<#config, #num_limbs>
+    };
+
+    let generator_const = args.generator.as_ref().map(|(generator, _)| {
+        let generator_tokens = limbs_tokens(generator, num_limbs);
+        quote! {
+            /// Generator of the multiplicative group of the field.
+            pub const GENERATOR: #unsigned_integer = #generator_tokens;
+        }
+    });
+    let field_name = args.name.map(|name| {
+        quote! {
+            fn field_name() -> &'static str {
+                #name
+            }
+        }
+    });
+    let field_alias = args.field.map(|field| {
+        quote! {
+            #vis type #field = #backend;
+        }
+    });
+
+    Ok(quote! {
+        #(#attrs)*
+        #[derive(Clone, Debug, Hash, Copy, PartialEq, Eq)]
+        #vis struct #config;
+
+        impl #config {
+            /// Number of 64-bit limbs used to represent the elements of the field.
+            pub const NUM_LIMBS: usize = #num_limbs;
+            /// Montgomery parameter `2^{2 * 64 * NUM_LIMBS} mod p`.
+            pub const R2: #unsigned_integer = #r2_tokens;
+            /// Montgomery parameter `- p^{-1} mod 2^{64}`.
+            pub const MU: u64 = #mu;
+            #generator_const
+        }
+
+        impl ::lambdaworks_math::field::fields::montgomery_backed_prime_fields::IsModulus<#unsigned_integer> for #config {
+            const MODULUS: #unsigned_integer = #modulus_tokens;
+        }
+
+        impl ::lambdaworks_math::field::fields::montgomery_backed_prime_fields::IsFFTModulus<#unsigned_integer> for #config {
+            const TWO_ADICITY: u64 = #two_adicity;
+            const TWO_ADIC_PRIMITVE_ROOT_OF_UNITY: #unsigned_integer = #root_tokens;
+            #field_name
+        }
+
+        const _: () = {
+            assert!(
+                !::lambdaworks_math::unsigned_integer::element::UnsignedInteger::<#num_limbs>::const_ne(&<#backend>::R2, &#config::R2),
+                "R2 differs from the one computed by the backend"
+            );
+            assert!(
+                <#backend>::MU == #config::MU,
+                "MU differs from the one computed by the backend"
+            );
+        };
+
+        #field_alias
+    })
+}
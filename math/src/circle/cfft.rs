@@ -0,0 +1,164 @@
+use crate::{
+    fft::cpu::bit_reversing::in_place_bit_reverse_permute,
+    field::{element::FieldElement, fields::mersenne31::field::Mersenne31Field},
+};
+
+use super::cosets::CircleDomain;
+
+type FE = FieldElement<Mersenne31Field>;
+
+/// Returns the twiddle factors of the circle FFT over `domain`, one `Vec` per layer.
+///
+/// The first layer pairs each point of the half coset with its conjugate, so its twiddle factors
+/// are the y coordinates of the half coset. Each of the other layers pairs `x` with `-x`, over
+/// the x coordinates of the half coset doubled once less than the layer, and its twiddle factors
+/// are the first half of them.
+pub fn get_twiddles(domain: &CircleDomain) -> Vec<Vec<FE>> {
+    let mut twiddles = Vec::with_capacity(domain.log_size() as usize);
+    twiddles.push(
+        domain
+            .half_coset
+            .points()
+            .into_iter()
+            .map(|point| point.y)
+            .collect(),
+    );
+
+    let mut coset = domain.half_coset.clone();
+    while coset.log_size > 0 {
+        let points = coset.points();
+        twiddles.push(
+            points
+                .into_iter()
+                .take(coset.size() / 2)
+                .map(|point| point.x)
+                .collect(),
+        );
+        coset = coset.double();
+    }
+    twiddles
+}
+
+/// Returns the inverses of the twiddle factors of [get_twiddles], used by the inverse circle FFT.
+pub fn get_inverse_twiddles(domain: &CircleDomain) -> Vec<Vec<FE>> {
+    let mut twiddles = get_twiddles(domain);
+    for layer in twiddles.iter_mut() {
+        // A valid domain has no points with a zero coordinate.
+        FieldElement::inplace_batch_inverse(layer).unwrap();
+    }
+    twiddles
+}
+
+/// In-place circle FFT. Takes the coefficients of a polynomial in the basis of the circle FFT and
+/// leaves its evaluations over the domain of the twiddle factors, in the order of the domain.
+/// The input size needs to be the size of that domain.
+///
+/// Each layer runs butterflies `(a, b) -> (a + t b, a - t b)` over blocks half as large as the
+/// previous layer, starting from the blocks of size 2 of the last layer of [get_twiddles].
+pub fn in_place_cfft(input: &mut [FE], twiddles: &[Vec<FE>]) {
+    debug_assert_eq!(input.len(), 1 << twiddles.len());
+
+    // the layer k of the butterflies works on the bit n - 1 - k of the index.
+    in_place_bit_reverse_permute(input);
+    for layer in twiddles.iter().rev() {
+        for block in input.chunks_mut(2 * layer.len()) {
+            let (lo, hi) = block.split_at_mut(layer.len());
+            for ((a, b), t) in lo.iter_mut().zip(hi.iter_mut()).zip(layer) {
+                let tb = *t * *b;
+                *b = *a - tb;
+                *a += tb;
+            }
+        }
+    }
+}
+
+/// In-place inverse circle FFT. Takes the evaluations of a polynomial over the domain of the
+/// twiddle factors, in its order, and leaves the coefficients of the polynomial in the basis of
+/// the circle FFT. `inverse_twiddles` are the ones returned by [get_inverse_twiddles].
+///
+/// A layer splits `f` in `f0 + t f1`, with `f0 = (f(P) + f(P')) / 2` and
+/// `f1 = (f(P) - f(P')) / (2 t)`, where `P'` is the point paired with `P`, and continues with
+/// `f0` and `f1` over the halved domain. The divisions by 2 are done at the end.
+pub fn in_place_icfft(input: &mut [FE], inverse_twiddles: &[Vec<FE>]) {
+    debug_assert_eq!(input.len(), 1 << inverse_twiddles.len());
+
+    for layer in inverse_twiddles {
+        for block in input.chunks_mut(2 * layer.len()) {
+            let (lo, hi) = block.split_at_mut(layer.len());
+            for ((a, b), t_inv) in lo.iter_mut().zip(hi.iter_mut()).zip(layer) {
+                let difference = *a - *b;
+                *a += *b;
+                *b = t_inv * difference;
+            }
+        }
+    }
+    in_place_bit_reverse_permute(input);
+
+    let scale = FE::from(input.len() as u64).inv().unwrap();
+    for value in input.iter_mut() {
+        *value = *value * scale;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::circle::point::CirclePoint;
+
+    /// Evaluates the basis of the circle FFT with `2^log_size` elements at a point.
+    fn basis_at(point: &CirclePoint<Mersenne31Field>, log_size: u32) -> Vec<FE> {
+        let mut mappings = vec![point.y];
+        let mut x = point.x;
+        for _ in 1..log_size {
+            mappings.push(x);
+            x = CirclePoint::double_x(&x);
+        }
+        (0..1_usize << log_size)
+            .map(|j| {
+                mappings
+                    .iter()
+                    .enumerate()
+                    .filter(|(k, _)| (j >> k) & 1 == 1)
+                    .fold(FE::one(), |product, (_, m)| product * m)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn cfft_matches_naive_evaluation() {
+        let domain = CircleDomain::new_standard(4).unwrap();
+        let coefficients: Vec<FE> = (0..16_u64).map(|i| FE::from(i * i + 3)).collect();
+
+        let mut evaluations = coefficients.clone();
+        in_place_cfft(&mut evaluations, &get_twiddles(&domain));
+
+        for (i, evaluation) in evaluations.iter().enumerate() {
+            let expected: FE = basis_at(&domain.at(i), 4)
+                .iter()
+                .zip(&coefficients)
+                .map(|(b, c)| b * c)
+                .sum();
+            assert_eq!(*evaluation, expected);
+        }
+    }
+
+    #[test]
+    fn icfft_is_inverse_of_cfft() {
+        let domain = CircleDomain::new_standard(6).unwrap();
+        let coefficients: Vec<FE> = (0..64_u64).map(|i| FE::from(7 * i + 1)).collect();
+
+        let mut values = coefficients.clone();
+        in_place_cfft(&mut values, &get_twiddles(&domain));
+        in_place_icfft(&mut values, &get_inverse_twiddles(&domain));
+
+        assert_eq!(values, coefficients);
+    }
+
+    #[test]
+    fn twiddles_have_one_layer_per_halving() {
+        let domain = CircleDomain::new_standard(5).unwrap();
+        let twiddles = get_twiddles(&domain);
+        let sizes: Vec<_> = twiddles.iter().map(Vec::len).collect();
+        assert_eq!(sizes, vec![16, 8, 4, 2, 1]);
+    }
+}
@@ -0,0 +1,202 @@
+use crate::{cyclic_group::IsGroup, field::fields::mersenne31::field::Mersenne31Field};
+
+use super::{errors::CircleError, point::CirclePoint};
+
+type Point = CirclePoint<Mersenne31Field>;
+
+/// Coset `initial + <step>` of the circle group over Mersenne31, where `step` generates the
+/// subgroup of order `2^log_size`. Its `i`-th point is `initial + i * step`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CircleCoset {
+    pub initial: Point,
+    pub step: Point,
+    pub log_size: u32,
+}
+
+impl CircleCoset {
+    /// Creates the coset `initial + <g>`, with `g` the generator of the subgroup of order
+    /// `2^log_size`.
+    pub fn new(initial: Point, log_size: u32) -> Result<Self, CircleError> {
+        let step = Point::get_generator_of_subgroup(log_size)?;
+        Ok(Self {
+            initial,
+            step,
+            log_size,
+        })
+    }
+
+    /// Creates the standard coset of size `2^log_size`, `g + <2g>` with `g` the generator of the
+    /// subgroup of order `2^(log_size + 1)`. Its points are the odd multiples of `g`.
+    pub fn new_standard(log_size: u32) -> Result<Self, CircleError> {
+        let initial = Point::get_generator_of_subgroup(log_size + 1)?;
+        Self::new(initial, log_size)
+    }
+
+    pub fn size(&self) -> usize {
+        1 << self.log_size
+    }
+
+    /// Returns the point `initial + index * step`.
+    pub fn at(&self, index: usize) -> Point {
+        self.initial
+            .operate_with(&self.step.operate_with_self(index as u64))
+    }
+
+    /// Returns the points of the coset, in order.
+    pub fn points(&self) -> Vec<Point> {
+        let mut points = Vec::with_capacity(self.size());
+        points.extend((0..self.size()).scan(self.initial.clone(), |point, _| {
+            let current = point.clone();
+            *point = point.operate_with(&self.step);
+            Some(current)
+        }));
+        points
+    }
+
+    /// Returns the coset with the doubles of the points of this one, of half its size.
+    /// The points `i` and `i + size / 2` of this coset double to the point `i` of the result.
+    pub fn double(&self) -> Self {
+        debug_assert!(self.log_size > 0);
+        Self {
+            initial: self.initial.double(),
+            step: self.step.double(),
+            log_size: self.log_size - 1,
+        }
+    }
+
+    /// Returns the coset with the conjugates of the points of this one, `-initial + <step>`.
+    pub fn conjugate(&self) -> Self {
+        Self {
+            initial: self.initial.conjugate(),
+            step: self.step.conjugate(),
+            log_size: self.log_size,
+        }
+    }
+}
+
+/// Domain of the circle FFT over Mersenne31: the union of a coset `Q + <g>` of size `2^(n-1)`
+/// and its conjugate `-Q + <g>`, which must be disjoint. Such a domain is called a twin-coset,
+/// and it has `2^n` points.
+///
+/// The `i`-th point of the domain is the `i`-th point of the coset if `i < 2^(n-1)`, and the
+/// conjugate of the point `i - 2^(n-1)` of the coset otherwise. So the points `i` and
+/// `i + 2^(n-1)` have the same x coordinate and opposite y coordinates.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CircleDomain {
+    pub half_coset: CircleCoset,
+}
+
+impl CircleDomain {
+    /// Creates the twin-coset domain of `half_coset` and its conjugate. Returns an error if they
+    /// aren't disjoint.
+    pub fn new(half_coset: CircleCoset) -> Result<Self, CircleError> {
+        // The cosets are disjoint when 2Q isn't in <g>, that is, when the order of Q is more
+        // than twice the order of g. Then no point of the domain, or of the cosets the FFT
+        // halves it into, has a zero coordinate.
+        if half_coset
+            .initial
+            .repeated_double(half_coset.log_size + 1)
+            .is_neutral_element()
+        {
+            return Err(CircleError::InvalidDomain);
+        }
+        Ok(Self { half_coset })
+    }
+
+    /// Creates the standard domain of size `2^log_size`, whose points are the odd multiples of
+    /// the generator `g` of the subgroup of order `2^(log_size + 1)`. It's the standard coset of
+    /// that size, split in `g + <4g>` and its conjugate.
+    pub fn new_standard(log_size: u32) -> Result<Self, CircleError> {
+        if log_size == 0 {
+            return Err(CircleError::SubgroupOrderError(log_size));
+        }
+        let initial = Point::get_generator_of_subgroup(log_size + 1)?;
+        Self::new(CircleCoset::new(initial, log_size - 1)?)
+    }
+
+    pub fn log_size(&self) -> u32 {
+        self.half_coset.log_size + 1
+    }
+
+    pub fn size(&self) -> usize {
+        1 << self.log_size()
+    }
+
+    /// Returns the `index`-th point of the domain.
+    pub fn at(&self, index: usize) -> Point {
+        let half_size = self.half_coset.size();
+        if index < half_size {
+            self.half_coset.at(index)
+        } else {
+            self.half_coset.at(index - half_size).conjugate()
+        }
+    }
+
+    /// Returns the points of the domain, in order.
+    pub fn points(&self) -> Vec<Point> {
+        let mut points = self.half_coset.points();
+        let conjugates: Vec<_> = points.iter().map(|point| point.conjugate()).collect();
+        points.extend(conjugates);
+        points
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn standard_coset_has_odd_multiples_of_generator() {
+        let coset = CircleCoset::new_standard(3).unwrap();
+        let generator = Point::get_generator_of_subgroup(4).unwrap();
+        let expected: Vec<_> = (0..8_u64)
+            .map(|i| generator.operate_with_self(2 * i + 1))
+            .collect();
+        assert_eq!(coset.points(), expected);
+        assert_eq!(coset.at(5), expected[5]);
+    }
+
+    #[test]
+    fn standard_domain_has_the_points_of_standard_coset() {
+        let domain = CircleDomain::new_standard(4).unwrap();
+        let mut points = domain.points();
+        let mut expected = CircleCoset::new_standard(4).unwrap().points();
+        assert_eq!(points.len(), 16);
+        assert_eq!(domain.at(11), points[11]);
+
+        let key = |point: &Point| (point.x.representative(), point.y.representative());
+        points.sort_by_key(key);
+        expected.sort_by_key(key);
+        assert_eq!(points, expected);
+    }
+
+    #[test]
+    fn domain_points_are_paired_with_their_conjugates() {
+        let domain = CircleDomain::new_standard(3).unwrap();
+        for i in 0..4 {
+            assert_eq!(domain.at(i + 4), domain.at(i).conjugate());
+        }
+    }
+
+    #[test]
+    fn coset_that_overlaps_its_conjugate_is_rejected() {
+        // The points of the subgroup are conjugates of each other.
+        let subgroup = CircleCoset::new(Point::neutral_element(), 3).unwrap();
+        assert_eq!(CircleDomain::new(subgroup), Err(CircleError::InvalidDomain));
+        assert_eq!(
+            CircleDomain::new_standard(0),
+            Err(CircleError::SubgroupOrderError(0))
+        );
+    }
+
+    #[test]
+    fn double_of_coset_halves_it() {
+        let coset = CircleCoset::new_standard(4).unwrap();
+        let doubled = coset.double();
+        assert_eq!(doubled.size(), 8);
+        for i in 0..8 {
+            assert_eq!(doubled.at(i), coset.at(i).double());
+            assert_eq!(doubled.at(i), coset.at(i + 8).double());
+        }
+    }
+}
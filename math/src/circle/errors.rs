@@ -0,0 +1,9 @@
+#[derive(Debug, PartialEq, Eq)]
+pub enum CircleError {
+    /// The coordinates don't satisfy `x^2 + y^2 = 1`.
+    InvalidPoint,
+    /// The circle group has no subgroup of order `2^n`.
+    SubgroupOrderError(u32),
+    /// The coset and its conjugate aren't disjoint, so they can't be joined into a domain.
+    InvalidDomain,
+}
@@ -0,0 +1,12 @@
+//! Circle group over Mersenne31 and the circle FFT, following Haböck, Levit and Papini,
+//! "Circle STARKs" (https://eprint.iacr.org/2024/278).
+//!
+//! `p = 2^31 - 1` has a single two-adic factor in `p - 1`, but the circle `x^2 + y^2 = 1` over
+//! Mersenne31 is a group of order `p + 1 = 2^31`. Its cosets are the domains of the circle FFT,
+//! which evaluates and interpolates polynomials over them as the usual FFT does over subgroups.
+
+pub mod cfft;
+pub mod cosets;
+pub mod errors;
+pub mod point;
+pub mod polynomial;
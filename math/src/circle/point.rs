@@ -0,0 +1,199 @@
+use crate::{
+    cyclic_group::IsGroup,
+    field::{
+        element::FieldElement,
+        fields::mersenne31::{extension::Mersenne31Complex, field::Mersenne31Field},
+        traits::IsField,
+    },
+};
+
+use super::errors::CircleError;
+
+/// A point `(x, y)` of the circle `x^2 + y^2 = 1` over the field `F`.
+///
+/// The points form a group under `(x0, y0) + (x1, y1) = (x0 x1 - y0 y1, x0 y1 + x1 y0)`, which
+/// is the product of `x0 + i y0` and `x1 + i y1` with `i^2 = -1`. As with elliptic curves, the
+/// group is written additively, so the neutral element is `(1, 0)` and the inverse of a point
+/// is its conjugate `(x, -y)`.
+#[derive(Debug, Clone)]
+pub struct CirclePoint<F: IsField> {
+    pub x: FieldElement<F>,
+    pub y: FieldElement<F>,
+}
+
+impl<F: IsField> CirclePoint<F> {
+    /// Creates the point `(x, y)`. Returns an error if it's not on the circle.
+    pub fn new(x: FieldElement<F>, y: FieldElement<F>) -> Result<Self, CircleError> {
+        if x.square() + y.square() != FieldElement::one() {
+            return Err(CircleError::InvalidPoint);
+        }
+        Ok(Self { x, y })
+    }
+
+    /// Returns `(x, -y)`, the inverse of this point.
+    pub fn conjugate(&self) -> Self {
+        Self {
+            x: self.x.clone(),
+            y: -&self.y,
+        }
+    }
+
+    /// Returns `(-x, -y)`, which is the sum of this point and `(-1, 0)`, the point of order 2.
+    pub fn antipode(&self) -> Self {
+        Self {
+            x: -&self.x,
+            y: -&self.y,
+        }
+    }
+
+    /// Returns the x coordinate of the double of a point with x coordinate `x`, which is
+    /// `2 x^2 - 1`. The circle FFT halves its domain with this map at each layer after the first.
+    pub fn double_x(x: &FieldElement<F>) -> FieldElement<F> {
+        let square = x.square();
+        &square + &square - FieldElement::one()
+    }
+
+    /// Returns the sum of this point with itself.
+    pub fn double(&self) -> Self {
+        let xy = &self.x * &self.y;
+        Self {
+            x: Self::double_x(&self.x),
+            y: &xy + &xy,
+        }
+    }
+
+    /// Returns `2^times` times this point.
+    pub fn repeated_double(&self, times: u32) -> Self {
+        (0..times).fold(self.clone(), |point, _| point.double())
+    }
+}
+
+impl<F: IsField> PartialEq for CirclePoint<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.x == other.x && self.y == other.y
+    }
+}
+
+impl<F: IsField> Eq for CirclePoint<F> {}
+
+impl<F: IsField> IsGroup for CirclePoint<F> {
+    fn neutral_element() -> Self {
+        Self {
+            x: FieldElement::one(),
+            y: FieldElement::zero(),
+        }
+    }
+
+    fn operate_with(&self, other: &Self) -> Self {
+        Self {
+            x: &self.x * &other.x - &self.y * &other.y,
+            y: &self.x * &other.y + &other.x * &self.y,
+        }
+    }
+
+    fn neg(&self) -> Self {
+        self.conjugate()
+    }
+}
+
+/// Mersenne31 has no square root of -1, so its circle group has order `p + 1 = 2^31`.
+impl CirclePoint<Mersenne31Field> {
+    pub const LOG_GROUP_ORDER: u32 = 31;
+
+    /// Returns a generator of the circle group, of order `2^31`.
+    pub fn generator() -> Self {
+        Self {
+            x: FieldElement::from(2),
+            y: FieldElement::from(1268011823),
+        }
+    }
+
+    /// Returns a generator of the subgroup of order `2^log_order`.
+    pub fn get_generator_of_subgroup(log_order: u32) -> Result<Self, CircleError> {
+        if log_order > Self::LOG_GROUP_ORDER {
+            return Err(CircleError::SubgroupOrderError(log_order));
+        }
+        Ok(Self::generator().repeated_double(Self::LOG_GROUP_ORDER - log_order))
+    }
+
+    /// Returns the point as the element `x + i y` of `Mersenne31Complex`, which has norm one.
+    pub fn to_complex(&self) -> FieldElement<Mersenne31Complex> {
+        FieldElement::new([self.x, self.y])
+    }
+
+    /// Returns the point `(x, y)` for the element `x + i y` of `Mersenne31Complex`.
+    /// Returns an error if the element doesn't have norm one.
+    pub fn from_complex(z: &FieldElement<Mersenne31Complex>) -> Result<Self, CircleError> {
+        let [x, y] = z.value();
+        Self::new(*x, *y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type P = CirclePoint<Mersenne31Field>;
+    type FE = FieldElement<Mersenne31Field>;
+
+    #[test]
+    fn generator_has_order_two_to_the_31() {
+        let generator = P::generator();
+        assert!(generator.repeated_double(31).is_neutral_element());
+        assert_eq!(
+            generator.repeated_double(30),
+            P::neutral_element().antipode()
+        );
+    }
+
+    #[test]
+    fn generator_of_subgroup_has_the_right_order() {
+        let generator = P::get_generator_of_subgroup(5).unwrap();
+        assert!(generator.operate_with_self(32_u64).is_neutral_element());
+        assert!(!generator.operate_with_self(16_u64).is_neutral_element());
+        assert_eq!(
+            P::get_generator_of_subgroup(32),
+            Err(CircleError::SubgroupOrderError(32))
+        );
+    }
+
+    #[test]
+    fn group_operation_stays_on_the_circle() {
+        let a = P::generator().operate_with_self(12345_u64);
+        let b = P::generator().operate_with_self(6789_u64);
+        let sum = a.operate_with(&b);
+        assert!(P::new(sum.x, sum.y).is_ok());
+        assert_eq!(sum, P::generator().operate_with_self(12345_u64 + 6789));
+    }
+
+    #[test]
+    fn conjugate_is_inverse_and_antipode_adds_point_of_order_two() {
+        let point = P::generator().operate_with_self(777_u64);
+        let order_two = P::get_generator_of_subgroup(1).unwrap();
+        assert!(point.operate_with(&point.conjugate()).is_neutral_element());
+        assert_eq!(point.antipode(), point.operate_with(&order_two));
+    }
+
+    #[test]
+    fn double_matches_group_operation() {
+        let point = P::generator().operate_with_self(1000_u64);
+        assert_eq!(point.double(), point.operate_with(&point));
+        assert_eq!(P::double_x(&point.x), point.double().x);
+    }
+
+    #[test]
+    fn points_outside_the_circle_are_rejected() {
+        assert_eq!(
+            P::new(FE::from(2), FE::from(2)),
+            Err(CircleError::InvalidPoint)
+        );
+    }
+
+    #[test]
+    fn complex_representation_has_the_same_group_operation() {
+        let a = P::generator().operate_with_self(31_u64);
+        let b = P::generator().operate_with_self(1_u64 << 20);
+        let product = a.to_complex() * b.to_complex();
+        assert_eq!(P::from_complex(&product).unwrap(), a.operate_with(&b));
+    }
+}
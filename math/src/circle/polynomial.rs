@@ -0,0 +1,181 @@
+use crate::{
+    fft::errors::FFTError,
+    field::{
+        element::FieldElement,
+        fields::mersenne31::field::Mersenne31Field,
+        traits::{IsField, IsSubFieldOf},
+    },
+};
+
+use super::{
+    cfft::{get_inverse_twiddles, get_twiddles, in_place_cfft, in_place_icfft},
+    cosets::CircleDomain,
+    point::CirclePoint,
+};
+
+type FE = FieldElement<Mersenne31Field>;
+
+/// Polynomial over the circle of Mersenne31, with its coefficients in the basis of the circle FFT.
+///
+/// With `2^n` coefficients, the `j`-th one multiplies
+/// `y^{j_0} x^{j_1} π(x)^{j_2} ... π^{n-2}(x)^{j_{n-1}}`, where `j_k` is the bit `k` of `j` and
+/// `π(x) = 2 x^2 - 1`. Appending zeros leaves the
+/// polynomial unchanged, so the coefficients from a small domain can be evaluated over a larger
+/// one, as a low degree extension does.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CirclePolynomial {
+    coefficients: Vec<FE>,
+}
+
+impl CirclePolynomial {
+    /// Creates a polynomial with the given coefficients. They are padded with zeros up to the next
+    /// power of two.
+    pub fn new(coefficients: &[FE]) -> Self {
+        let mut coefficients = coefficients.to_vec();
+        coefficients.resize(coefficients.len().next_power_of_two(), FE::zero());
+        Self { coefficients }
+    }
+
+    pub fn coefficients(&self) -> &[FE] {
+        &self.coefficients
+    }
+
+    /// Returns the base 2 logarithm of the number of coefficients.
+    pub fn log_size(&self) -> u32 {
+        self.coefficients.len().trailing_zeros()
+    }
+
+    /// Evaluates the polynomial at a point of the circle over Mersenne31 or an extension of it.
+    pub fn evaluate<E: IsField>(&self, point: &CirclePoint<E>) -> FieldElement<E>
+    where
+        Mersenne31Field: IsSubFieldOf<E>,
+    {
+        let log_size = self.log_size();
+        let mut mappings = Vec::with_capacity(log_size as usize);
+        mappings.push(point.y.clone());
+        let mut x = point.x.clone();
+        for _ in 1..log_size {
+            let next = CirclePoint::double_x(&x);
+            mappings.push(x);
+            x = next;
+        }
+
+        // fold the coefficients on the highest bit of their index at a time.
+        let mut values: Vec<FieldElement<E>> = self
+            .coefficients
+            .iter()
+            .map(|coefficient| coefficient.to_extension())
+            .collect();
+        for mapping in mappings.iter().rev() {
+            let half = values.len() / 2;
+            let (lo, hi) = values.split_at_mut(half);
+            for (a, b) in lo.iter_mut().zip(hi.iter()) {
+                *a = &*a + mapping * b;
+            }
+            values.truncate(half);
+        }
+        values.swap_remove(0)
+    }
+
+    /// Returns the evaluations of the polynomial over `domain`, in its order, with the circle FFT.
+    /// Returns an error if the polynomial has more coefficients than points in the domain.
+    pub fn evaluate_cfft(&self, domain: &CircleDomain) -> Result<Vec<FE>, FFTError> {
+        if self.coefficients.len() > domain.size() {
+            return Err(FFTError::DomainSizeError(
+                self.coefficients.len(),
+                domain.size(),
+            ));
+        }
+
+        let mut evaluations = self.coefficients.clone();
+        evaluations.resize(domain.size(), FE::zero());
+        in_place_cfft(&mut evaluations, &get_twiddles(domain));
+        Ok(evaluations)
+    }
+
+    /// Returns the polynomial whose evaluations over `domain`, in its order, are `evaluations`.
+    /// This is considered to be the inverse operation of [Self::evaluate_cfft()].
+    /// Returns an error if there isn't exactly one evaluation per point of the domain.
+    pub fn interpolate_cfft(evaluations: &[FE], domain: &CircleDomain) -> Result<Self, FFTError> {
+        if evaluations.len() != domain.size() {
+            return Err(FFTError::DomainSizeError(evaluations.len(), domain.size()));
+        }
+
+        let mut coefficients = evaluations.to_vec();
+        in_place_icfft(&mut coefficients, &get_inverse_twiddles(domain));
+        Ok(Self { coefficients })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::circle::cosets::CircleCoset;
+    use crate::cyclic_group::IsGroup;
+    use proptest::{collection, prelude::*};
+
+    proptest! {
+        #[test]
+        fn interpolate_is_inverse_of_evaluate(
+            coefficients in (1..7_u32).prop_flat_map(|n| collection::vec(any::<u32>().prop_map(|x| FE::from(x as u64)), 1 << n))
+        ) {
+            let poly = CirclePolynomial::new(&coefficients);
+            let domain = CircleDomain::new_standard(poly.log_size()).unwrap();
+
+            let evaluations = poly.evaluate_cfft(&domain).unwrap();
+            prop_assert_eq!(CirclePolynomial::interpolate_cfft(&evaluations, &domain).unwrap(), poly);
+        }
+    }
+
+    #[test]
+    fn cfft_evaluations_match_evaluation_at_each_point() {
+        let poly = CirclePolynomial::new(&(0..8_u64).map(FE::from).collect::<Vec<_>>());
+        let domain = CircleDomain::new_standard(3).unwrap();
+
+        let evaluations = poly.evaluate_cfft(&domain).unwrap();
+        for (point, evaluation) in domain.points().iter().zip(&evaluations) {
+            assert_eq!(&poly.evaluate(point), evaluation);
+        }
+    }
+
+    #[test]
+    fn low_degree_extension_over_larger_twin_coset() {
+        let trace_domain = CircleDomain::new_standard(3).unwrap();
+        let values: Vec<FE> = (0..8_u64).map(|i| FE::from(i * i * i + 2)).collect();
+        let poly = CirclePolynomial::interpolate_cfft(&values, &trace_domain).unwrap();
+
+        // a twin-coset of size 32 shifted away from the trace domain.
+        let initial = CirclePoint::<Mersenne31Field>::get_generator_of_subgroup(10)
+            .unwrap()
+            .operate_with_self(3_u64);
+        let lde_domain = CircleDomain::new(CircleCoset::new(initial, 4).unwrap()).unwrap();
+        let lde_evaluations = poly.evaluate_cfft(&lde_domain).unwrap();
+
+        for (i, evaluation) in lde_evaluations.iter().enumerate() {
+            assert_eq!(evaluation, &poly.evaluate(&lde_domain.at(i)));
+        }
+        for (i, value) in values.iter().enumerate() {
+            assert_eq!(value, &poly.evaluate(&trace_domain.at(i)));
+        }
+        let lde_poly = CirclePolynomial::interpolate_cfft(&lde_evaluations, &lde_domain).unwrap();
+        assert_eq!(lde_poly.coefficients()[..8], poly.coefficients()[..]);
+        assert!(lde_poly.coefficients()[8..]
+            .iter()
+            .all(|c| *c == FE::zero()));
+    }
+
+    #[test]
+    fn polynomial_too_large_for_domain_fails() {
+        let poly = CirclePolynomial::new(&[FE::one(); 16]);
+        let domain = CircleDomain::new_standard(3).unwrap();
+
+        assert!(matches!(
+            poly.evaluate_cfft(&domain),
+            Err(FFTError::DomainSizeError(16, 8))
+        ));
+        assert!(matches!(
+            CirclePolynomial::interpolate_cfft(&[FE::one(); 4], &domain),
+            Err(FFTError::DomainSizeError(4, 8))
+        ));
+    }
+}
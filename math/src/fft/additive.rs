@@ -0,0 +1,341 @@
+//! Additive FFT over fields of characteristic two, following Lin, Chung and Han,
+//! "Novel Polynomial Basis and Its Application to Reed-Solomon Erasure Codes" (FOCS 2014).
+//!
+//! Binary fields have no large multiplicative subgroups of order `2^k`, so instead of roots of
+//! unity the evaluation domain is an affine subspace `shift + span(β_0, ..., β_{k-1})` over
+//! `GF(2)`. The transform works on the coefficients of the polynomial in the *novel basis*
+//! `X_i(x) = Π_{j: bit j of i is set} Ŵ_j(x)`, where `Ŵ_j` is the normalized vanishing
+//! polynomial of `span(β_0, ..., β_{j-1})`. Conversions to and from the monomial basis are
+//! provided so that `Polynomial` can be evaluated and interpolated.
+
+use crate::{
+    fft::errors::FFTError,
+    field::{element::FieldElement, traits::IsBinaryField},
+    polynomial::Polynomial,
+};
+
+/// Affine subspace `shift + span(basis)` of a binary field, together with the data needed to
+/// run additive FFTs over it.
+///
+/// The `i`-th element of the domain is `shift + Σ_j b_j β_j`, where `b_j` is the `j`-th bit of `i`.
+#[derive(Debug, Clone)]
+pub struct AdditiveFFTDomain<F: IsBinaryField> {
+    basis: Vec<FieldElement<F>>,
+    shift: FieldElement<F>,
+    /// `subspace_polynomials[j][i]` is the coefficient of `x^{2^i}` in `Ŵ_j`.
+    subspace_polynomials: Vec<Vec<FieldElement<F>>>,
+    /// `twiddles[r][c]` is `Ŵ_r` evaluated at the element `c * 2^{r+1}` of the domain.
+    twiddles: Vec<Vec<FieldElement<F>>>,
+}
+
+/// Evaluates the linearized polynomial `Σ_i coefficients[i] x^{2^i}` at `x`.
+fn evaluate_linearized<F: IsBinaryField>(
+    coefficients: &[FieldElement<F>],
+    x: &FieldElement<F>,
+) -> FieldElement<F> {
+    let mut power = x.clone();
+    let mut result = FieldElement::zero();
+    for coefficient in coefficients {
+        result += coefficient * &power;
+        power = power.square();
+    }
+    result
+}
+
+impl<F: IsBinaryField> AdditiveFFTDomain<F> {
+    /// Creates the domain `shift + span(basis)`, of size `2^basis.len()`.
+    /// Returns an error if the elements of `basis` are not linearly independent over `GF(2)`.
+    pub fn new(basis: Vec<FieldElement<F>>, shift: FieldElement<F>) -> Result<Self, FFTError> {
+        // W_0(x) = x and W_{j+1}(x) = W_j(x)^2 + W_j(β_j) W_j(x) vanishes on span(β_0, ..., β_j).
+        let mut subspace_polynomials: Vec<Vec<FieldElement<F>>> = Vec::with_capacity(basis.len());
+        let mut vanishing = vec![FieldElement::<F>::one()];
+        for beta in &basis {
+            let value = evaluate_linearized(&vanishing, beta);
+            // β_j is in the span of the previous elements exactly when W_j vanishes on it.
+            let value_inv = value.inv().map_err(|_| FFTError::DomainBasisError)?;
+            subspace_polynomials.push(vanishing.iter().map(|c| c * &value_inv).collect());
+
+            let mut next = vec![FieldElement::zero(); vanishing.len() + 1];
+            for (i, coefficient) in vanishing.iter().enumerate() {
+                next[i] += &value * coefficient;
+                next[i + 1] += coefficient.square();
+            }
+            vanishing = next;
+        }
+
+        let log_size = basis.len();
+        let twiddles = subspace_polynomials
+            .iter()
+            .enumerate()
+            .map(|(r, polynomial)| {
+                // Ŵ_r is linear, so its value at shift + Σ_{j > r} b_j β_j is computed from the
+                // value at an index with one bit less.
+                let images: Vec<_> = basis[r + 1..]
+                    .iter()
+                    .map(|beta| evaluate_linearized(polynomial, beta))
+                    .collect();
+                let mut layer = Vec::with_capacity(1 << (log_size - 1 - r));
+                layer.push(evaluate_linearized(polynomial, &shift));
+                for c in 1..1usize << (log_size - 1 - r) {
+                    let lowest_bit = c.trailing_zeros() as usize;
+                    let twiddle = &layer[c & (c - 1)] + &images[lowest_bit];
+                    layer.push(twiddle);
+                }
+                layer
+            })
+            .collect();
+
+        Ok(Self {
+            basis,
+            shift,
+            subspace_polynomials,
+            twiddles,
+        })
+    }
+
+    /// Returns `log2` of the number of elements of the domain.
+    pub fn log_size(&self) -> usize {
+        self.basis.len()
+    }
+
+    /// Returns the number of elements of the domain.
+    pub fn size(&self) -> usize {
+        1 << self.basis.len()
+    }
+
+    /// Returns the `index`-th element of the domain.
+    pub fn element(&self, index: usize) -> FieldElement<F> {
+        self.basis
+            .iter()
+            .enumerate()
+            .filter(|(j, _)| (index >> j) & 1 == 1)
+            .fold(self.shift.clone(), |acc, (_, beta)| acc + beta)
+    }
+
+    /// Returns all the elements of the domain, in order.
+    pub fn elements(&self) -> Vec<FieldElement<F>> {
+        (0..self.size()).map(|i| self.element(i)).collect()
+    }
+}
+
+/// Evaluates in place the polynomial with coefficients `input` in the novel basis of `domain`
+/// at every element of `domain`, in order. `input.len()` must be the size of the domain.
+pub fn in_place_additive_fft<F: IsBinaryField>(
+    input: &mut [FieldElement<F>],
+    domain: &AdditiveFFTDomain<F>,
+) {
+    debug_assert_eq!(input.len(), domain.size());
+    for r in (0..domain.log_size()).rev() {
+        let half = 1 << r;
+        for (block, twiddle) in input.chunks_mut(2 * half).zip(&domain.twiddles[r]) {
+            let (lo, hi) = block.split_at_mut(half);
+            for (a, b) in lo.iter_mut().zip(hi) {
+                // f = g + Ŵ_r h, and Ŵ_r takes the values t and t + 1 on the two halves.
+                *a = &*a + twiddle * &*b;
+                *b = &*b + &*a;
+            }
+        }
+    }
+}
+
+/// Inverse of `in_place_additive_fft`: turns the evaluations of a polynomial over `domain`
+/// into its coefficients in the novel basis.
+pub fn in_place_inverse_additive_fft<F: IsBinaryField>(
+    input: &mut [FieldElement<F>],
+    domain: &AdditiveFFTDomain<F>,
+) {
+    debug_assert_eq!(input.len(), domain.size());
+    for r in 0..domain.log_size() {
+        let half = 1 << r;
+        for (block, twiddle) in input.chunks_mut(2 * half).zip(&domain.twiddles[r]) {
+            let (lo, hi) = block.split_at_mut(half);
+            for (a, b) in lo.iter_mut().zip(hi) {
+                *b = &*b - &*a;
+                *a = &*a - twiddle * &*b;
+            }
+        }
+    }
+}
+
+/// Converts in place the coefficients of a polynomial in the monomial basis to the novel basis
+/// of `domain`. `coefficients.len()` must be the size of the domain.
+///
+/// The polynomial is split recursively as `f = r + q Ŵ_{m-1}` with `deg r, deg q < 2^{m-1}`,
+/// storing `r` in the lower half and `q` in the upper half.
+pub fn monomial_to_novel_basis<F: IsBinaryField>(
+    coefficients: &mut [FieldElement<F>],
+    domain: &AdditiveFFTDomain<F>,
+) {
+    debug_assert_eq!(coefficients.len(), domain.size());
+    for m in (1..=domain.log_size()).rev() {
+        let polynomial = &domain.subspace_polynomials[m - 1];
+        // Ŵ_{m-1} has degree 2^{m-1} and is monic up to the scalar `leading`.
+        let (leading, lower) = polynomial.split_last().unwrap();
+        let leading_inv = leading.inv().unwrap();
+        let half = 1 << (m - 1);
+        for chunk in coefficients.chunks_mut(2 * half) {
+            for d in (half..2 * half).rev() {
+                let quotient = &chunk[d] * &leading_inv;
+                for (i, coefficient) in lower.iter().enumerate() {
+                    let index = d - half + (1 << i);
+                    chunk[index] = &chunk[index] - &quotient * coefficient;
+                }
+                chunk[d] = quotient;
+            }
+        }
+    }
+}
+
+/// Inverse of `monomial_to_novel_basis`.
+pub fn novel_to_monomial_basis<F: IsBinaryField>(
+    coefficients: &mut [FieldElement<F>],
+    domain: &AdditiveFFTDomain<F>,
+) {
+    debug_assert_eq!(coefficients.len(), domain.size());
+    for m in 1..=domain.log_size() {
+        let polynomial = &domain.subspace_polynomials[m - 1];
+        let (leading, lower) = polynomial.split_last().unwrap();
+        let half = 1 << (m - 1);
+        for chunk in coefficients.chunks_mut(2 * half) {
+            for d in half..2 * half {
+                let quotient = chunk[d].clone();
+                for (i, coefficient) in lower.iter().enumerate() {
+                    chunk[d - half + (1 << i)] += &quotient * coefficient;
+                }
+                chunk[d] = quotient * leading;
+            }
+        }
+    }
+}
+
+impl<F: IsBinaryField> Polynomial<FieldElement<F>> {
+    /// Evaluates the polynomial at every element of `domain` using the additive FFT.
+    /// The polynomial must have at most `domain.size()` coefficients.
+    pub fn evaluate_additive_fft(
+        poly: &Polynomial<FieldElement<F>>,
+        domain: &AdditiveFFTDomain<F>,
+    ) -> Result<Vec<FieldElement<F>>, FFTError> {
+        if poly.coeff_len() > domain.size() {
+            return Err(FFTError::DomainSizeError(poly.coeff_len(), domain.size()));
+        }
+        let mut coefficients = poly.coefficients().to_vec();
+        coefficients.resize(domain.size(), FieldElement::zero());
+        monomial_to_novel_basis(&mut coefficients, domain);
+        in_place_additive_fft(&mut coefficients, domain);
+        Ok(coefficients)
+    }
+
+    /// Returns the polynomial of degree less than `domain.size()` that takes the values
+    /// `evaluations` over the elements of `domain`, using the additive FFT.
+    pub fn interpolate_additive_fft(
+        evaluations: &[FieldElement<F>],
+        domain: &AdditiveFFTDomain<F>,
+    ) -> Result<Self, FFTError> {
+        if evaluations.len() != domain.size() {
+            return Err(FFTError::DomainSizeError(evaluations.len(), domain.size()));
+        }
+        let mut coefficients = evaluations.to_vec();
+        in_place_inverse_additive_fft(&mut coefficients, domain);
+        novel_to_monomial_basis(&mut coefficients, domain);
+        Ok(Polynomial::new(&coefficients))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::fields::binary::tower::{BinaryField128b, BinaryField16b};
+    use proptest::{collection, prelude::*};
+
+    type FE = FieldElement<BinaryField128b>;
+
+    fn domain(log_size: usize, shift: u128) -> AdditiveFFTDomain<BinaryField128b> {
+        // Any set of distinct powers of two is linearly independent.
+        let basis = (0..log_size).map(|i| FE::new(1 << (3 * i + 1))).collect();
+        AdditiveFFTDomain::new(basis, FE::new(shift)).unwrap()
+    }
+
+    prop_compose! {
+        fn field_element()(value: u128) -> FE {
+            FE::new(value)
+        }
+    }
+    prop_compose! {
+        fn poly(max_len: usize)(coeffs in collection::vec(field_element(), 0..max_len)) -> Polynomial<FE> {
+            Polynomial::new(&coeffs)
+        }
+    }
+
+    #[test]
+    fn domain_elements_are_distinct() {
+        let elements = domain(5, 0xabc).elements();
+        for (i, a) in elements.iter().enumerate() {
+            assert!(elements[i + 1..].iter().all(|b| a != b));
+        }
+    }
+
+    #[test]
+    fn linearly_dependent_basis_is_rejected() {
+        let basis = vec![FE::new(3), FE::new(5), FE::new(6)];
+        assert!(matches!(
+            AdditiveFFTDomain::new(basis, FE::zero()),
+            Err(FFTError::DomainBasisError)
+        ));
+    }
+
+    #[test]
+    fn subspace_polynomials_vanish_on_their_subspace_and_are_normalized() {
+        let domain = domain(4, 0);
+        for (j, polynomial) in domain.subspace_polynomials.iter().enumerate() {
+            for i in 0..1 << j {
+                assert_eq!(
+                    evaluate_linearized(polynomial, &domain.element(i)),
+                    FE::zero()
+                );
+            }
+            assert_eq!(evaluate_linearized(polynomial, &domain.basis[j]), FE::one());
+        }
+    }
+
+    #[test]
+    fn polynomial_too_large_for_the_domain_is_rejected() {
+        let poly = Polynomial::new(&[FE::one(); 5]);
+        assert!(Polynomial::evaluate_additive_fft(&poly, &domain(2, 0)).is_err());
+    }
+
+    #[test]
+    fn additive_fft_over_a_small_tower_field() {
+        type FE16 = FieldElement<BinaryField16b>;
+        let basis = (0..3).map(|i| FE16::from(1 << i)).collect();
+        let domain = AdditiveFFTDomain::new(basis, FE16::from(0x100)).unwrap();
+        let poly = Polynomial::new(&[FE16::from(7), FE16::from(0x1234), FE16::from(0xbeef)]);
+        let evaluations = Polynomial::evaluate_additive_fft(&poly, &domain).unwrap();
+        assert_eq!(evaluations, poly.evaluate_slice(&domain.elements()));
+    }
+
+    proptest! {
+        #[test]
+        fn additive_fft_matches_naive_evaluation(poly in poly(32), shift: u128) {
+            let domain = domain(5, shift);
+            let evaluations = Polynomial::evaluate_additive_fft(&poly, &domain).unwrap();
+            prop_assert_eq!(evaluations, poly.evaluate_slice(&domain.elements()));
+        }
+
+        #[test]
+        fn novel_basis_conversion_roundtrip(coeffs in collection::vec(field_element(), 16)) {
+            let domain = domain(4, 0);
+            let mut converted = coeffs.clone();
+            monomial_to_novel_basis(&mut converted, &domain);
+            novel_to_monomial_basis(&mut converted, &domain);
+            prop_assert_eq!(converted, coeffs);
+        }
+
+        #[test]
+        fn interpolation_is_inverse_of_evaluation(poly in poly(16), shift: u128) {
+            let domain = domain(4, shift);
+            let evaluations = Polynomial::evaluate_additive_fft(&poly, &domain).unwrap();
+            let interpolated = Polynomial::interpolate_additive_fft(&evaluations, &domain).unwrap();
+            prop_assert_eq!(interpolated, poly);
+        }
+    }
+}
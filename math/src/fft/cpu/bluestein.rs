@@ -0,0 +1,135 @@
+use crate::{
+    fft::errors::FFTError,
+    field::{
+        element::FieldElement,
+        traits::{IsFFTField, IsField, IsSubFieldOf, RootsConfig},
+    },
+};
+
+use super::{ops, roots_of_unity::get_twiddles};
+
+/// Returns `[root^(t (t - 1) / 2) for t in 0..count]`.
+fn chirp<F: IsField>(root: &FieldElement<F>, count: usize) -> Vec<FieldElement<F>> {
+    let mut power = FieldElement::<F>::one();
+    let mut value = FieldElement::<F>::one();
+    (0..count)
+        .map(|_| {
+            let result = value.clone();
+            value = &value * &power;
+            power = &power * root;
+            result
+        })
+        .collect()
+}
+
+/// Bluestein's algorithm (chirp-z transform). Returns `[Σ_j input[j] root^(j k) for k in 0..n]`,
+/// with `n = input.len()`, for any length and any non-zero `root` in a subfield F of E.
+///
+/// Writing `j k = C(j + k, 2) - C(j, 2) - C(k, 2)`, with `C(x, 2) = x (x - 1) / 2`, the transform
+/// is a correlation of the input with the powers `root^C(t, 2)`, which is computed with
+/// power-of-two FFTs over F of size at least `2n - 1`. So that size can't exceed `2^TWO_ADICITY`.
+/// It takes `O(n log n)` operations, although a few times more than the FFTs of [ops].
+pub fn bluestein_fft<F, E>(
+    input: &[FieldElement<E>],
+    root: &FieldElement<F>,
+) -> Result<Vec<FieldElement<E>>, FFTError>
+where
+    F: IsFFTField + IsSubFieldOf<E>,
+    E: IsField,
+{
+    let n = input.len();
+    if n == 0 {
+        return Ok(Vec::new());
+    }
+
+    let inverse_chirp = chirp(&root.inv()?, n);
+    let size = (2 * n - 1).next_power_of_two();
+    let order = size.trailing_zeros() as u64;
+
+    // the input is reversed, so that the correlation becomes a convolution.
+    let mut a: Vec<FieldElement<E>> = input
+        .iter()
+        .zip(&inverse_chirp)
+        .rev()
+        .map(|(x, c)| c * x)
+        .collect();
+    a.resize(size, FieldElement::zero());
+    let mut b = chirp(root, 2 * n - 1);
+    b.resize(size, FieldElement::zero());
+
+    let twiddles = get_twiddles::<F>(order, RootsConfig::BitReverse)?;
+    let a_evaluations = ops::fft(&a, &twiddles)?;
+    let b_evaluations = ops::fft(&b, &twiddles)?;
+    let products: Vec<FieldElement<E>> = a_evaluations
+        .iter()
+        .zip(&b_evaluations)
+        .map(|(a, b)| b * a)
+        .collect();
+
+    let inverse_twiddles = get_twiddles::<F>(order, RootsConfig::BitReverseInversed)?;
+    let convolution = ops::fft(&products, &inverse_twiddles)?;
+
+    // the inverse FFT above isn't normalized, so it's done here along with the last chirp.
+    let size_inv = FieldElement::<F>::from(size as u64).inv()?;
+    Ok(convolution[n - 1..2 * n - 1]
+        .iter()
+        .zip(&inverse_chirp)
+        .map(|(value, c)| &(c * &size_inv) * value)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::test_fields::u64_test_field::{U64TestField, U64TestFieldExtension};
+    use crate::polynomial::Polynomial;
+    use proptest::{collection, prelude::*};
+
+    type F = U64TestField;
+    type FE = FieldElement<F>;
+
+    fn naive_transform(input: &[FE], root: &FE) -> Vec<FE> {
+        let points: Vec<_> = (0..input.len()).map(|k| root.pow(k)).collect();
+        Polynomial::new(input).evaluate_slice(&points)
+    }
+
+    proptest! {
+        #[test]
+        fn bluestein_matches_naive_transform(
+            input in collection::vec(any::<u64>().prop_map(FE::from), 0..40),
+            root in 1..F::neg(&1),
+        ) {
+            let root = FE::from(root);
+            prop_assert_eq!(bluestein_fft(&input, &root).unwrap(), naive_transform(&input, &root));
+        }
+    }
+
+    #[test]
+    fn bluestein_with_root_of_unity_of_order_17() {
+        // 17 divides the order of the multiplicative group, which is generated by 7.
+        let root = FE::from(7).pow(F::neg(&1) / 17);
+        let input: Vec<FE> = (0..17_u64).map(|i| FE::from(i * i + 1)).collect();
+
+        let output = bluestein_fft(&input, &root).unwrap();
+        assert_eq!(output, naive_transform(&input, &root));
+        assert_eq!(output[0], input.into_iter().sum::<FE>());
+    }
+
+    #[test]
+    fn bluestein_over_extension_with_root_in_base_field() {
+        type L = U64TestFieldExtension;
+
+        let input: Vec<FieldElement<L>> = (0..5_u64)
+            .map(|i| FieldElement::<L>::from(&[FE::from(i), FE::from(3 * i + 1)]))
+            .collect();
+        let root = FE::from(3);
+
+        let expected: Vec<_> = (0..5_u64)
+            .map(|k| {
+                let point = FieldElement::<L>::from(&[root.pow(k), FE::zero()]);
+                Polynomial::new(&input).evaluate(&point)
+            })
+            .collect();
+        assert_eq!(bluestein_fft(&input, &root).unwrap(), expected);
+    }
+}
@@ -1,7 +1,9 @@
 use crate::field::{
     element::FieldElement,
-    traits::{IsFFTField, IsField, IsSubFieldOf},
+    traits::{IsField, IsSubFieldOf},
 };
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
 
 /// In-Place Radix-2 NR DIT FFT algorithm over a slice of two-adic field elements.
 /// It's required that the twiddle factors are in bit-reverse order. Else this function will not
@@ -19,7 +21,20 @@ use crate::field::{
 /// It supports values in a field E and domain in a subfield F.
 pub fn in_place_nr_2radix_fft<F, E>(input: &mut [FieldElement<E>], twiddles: &[FieldElement<F>])
 where
-    F: IsFFTField + IsSubFieldOf<E>,
+    F: IsSubFieldOf<E>,
+    E: IsField,
+{
+    in_place_nr_2radix_fft_of_group(input, twiddles, 0);
+}
+
+/// Runs the remaining layers of the radix-2 NR FFT over `input`, which is the group with index
+/// `first_group` among the groups of its size. The twiddle factors are the ones of the whole FFT.
+fn in_place_nr_2radix_fft_of_group<F, E>(
+    input: &mut [FieldElement<E>],
+    twiddles: &[FieldElement<F>],
+    first_group: usize,
+) where
+    F: IsSubFieldOf<E>,
     E: IsField,
 {
     // divide input in groups, starting with 1, duplicating the number of groups in each stage.
@@ -35,19 +50,14 @@ where
         #[allow(clippy::needless_range_loop)] // the suggestion would obfuscate a bit the algorithm
         for group in 0..group_count {
             let first_in_group = group * group_size;
-            let first_in_next_group = first_in_group + group_size / 2;
-
-            let w = &twiddles[group]; // a twiddle factor is used per group
 
-            for i in first_in_group..first_in_next_group {
-                let wi = w * &input[i + group_size / 2];
+            let w = &twiddles[first_group * group_count + group]; // a twiddle factor is used per group
 
-                let y0 = &input[i] + &wi;
-                let y1 = &input[i] - &wi;
-
-                input[i] = y0;
-                input[i + group_size / 2] = y1;
-            }
+            // the butterflies of a group are independent, so they are done as a batch, which
+            // fields with packed arithmetic compute several at a time.
+            let (lo, hi) =
+                input[first_in_group..first_in_group + group_size].split_at_mut(group_size / 2);
+            <F as IsSubFieldOf<E>>::subfield_butterfly_batch(lo, hi, w);
         }
         group_count *= 2;
         group_size /= 2;
@@ -71,7 +81,7 @@ where
 #[allow(dead_code)]
 pub fn in_place_rn_2radix_fft<F>(input: &mut [FieldElement<F>], twiddles: &[FieldElement<F>])
 where
-    F: IsFFTField,
+    F: IsField,
 {
     // divide input in groups, starting with 1, duplicating the number of groups in each stage.
     let mut group_count = 1;
@@ -118,58 +128,45 @@ where
 /// - DIT: decimation in time
 pub fn in_place_nr_4radix_fft<F, E>(input: &mut [FieldElement<E>], twiddles: &[FieldElement<F>])
 where
-    F: IsFFTField + IsSubFieldOf<E>,
+    F: IsSubFieldOf<E>,
     E: IsField,
 {
     debug_assert!(input.len().is_power_of_two());
     debug_assert!(input.len().ilog2() % 2 == 0); // Even power of 2 => x is power of 4
 
+    in_place_nr_4radix_fft_of_group(input, twiddles, 0);
+}
+
+/// Runs the remaining layers of the radix-4 NR FFT over `input`, which is the group with index
+/// `first_group` among the groups of its size. The twiddle factors are the ones of the whole FFT.
+fn in_place_nr_4radix_fft_of_group<F, E>(
+    input: &mut [FieldElement<E>],
+    twiddles: &[FieldElement<F>],
+    first_group: usize,
+) where
+    F: IsSubFieldOf<E>,
+    E: IsField,
+{
     // divide input in groups, starting with 1, duplicating the number of groups in each stage.
     let mut group_count = 1;
     let mut group_size = input.len();
 
     // for each group, there'll be group_size / 4 butterflies.
-    // a butterfly is the atomic operation of a FFT, e.g:
-    // x' = x + yw2 + zw1 + tw1w2
-    // y' = x - yw2 + zw1 - tw1w2
-    // z' = x + yw3 - zw1 - tw1w3
-    // t' = x - yw3 - zw1 + tw1w3
     // The 0.25 factor is what gives FFT its performance, it recursively divides the problem size
     // by 4 (group size).
 
     while group_count < input.len() {
-        #[allow(clippy::needless_range_loop)] // the suggestion would obfuscate a bit the algorithm
-        for group in 0..group_count {
-            let first_in_group = group * group_size;
-            let first_in_next_group = first_in_group + group_size / 4;
-
+        for (group, chunk) in input.chunks_mut(group_size).enumerate() {
+            let group = first_group * group_count + group;
             let (w1, w2, w3) = (
                 &twiddles[group],
                 &twiddles[2 * group],
                 &twiddles[2 * group + 1],
             );
 
-            for i in first_in_group..first_in_next_group {
-                let (j, k, l) = (
-                    i + group_size / 4,
-                    i + group_size / 2,
-                    i + 3 * group_size / 4,
-                );
-
-                let zw1 = w1 * &input[k];
-                let tw1 = w1 * &input[l];
-                let a = w2 * (&input[j] + &tw1);
-                let b = w3 * (&input[j] - &tw1);
-
-                let x = &input[i] + &zw1 + &a;
-                let y = &input[i] + &zw1 - &a;
-                let z = &input[i] - &zw1 + &b;
-                let t = &input[i] - &zw1 - &b;
-
-                input[i] = x;
-                input[j] = y;
-                input[k] = z;
-                input[l] = t;
+            let (x, y, z, t) = split_in_quarters(chunk);
+            for (((x, y), z), t) in x.iter_mut().zip(y).zip(z).zip(t) {
+                radix4_butterfly(x, y, z, t, w1, w2, w3);
             }
         }
         group_count *= 4;
@@ -177,6 +174,151 @@ where
     }
 }
 
+/// Splits a slice into its four quarters.
+#[allow(clippy::type_complexity)]
+fn split_in_quarters<T>(input: &mut [T]) -> (&mut [T], &mut [T], &mut [T], &mut [T]) {
+    let quarter = input.len() / 4;
+    let (x, rest) = input.split_at_mut(quarter);
+    let (y, rest) = rest.split_at_mut(quarter);
+    let (z, t) = rest.split_at_mut(quarter);
+    (x, y, z, t)
+}
+
+/// A radix-4 butterfly, the atomic operation of the radix-4 FFT:
+/// x' = x + yw2 + zw1 + tw1w2
+/// y' = x - yw2 + zw1 - tw1w2
+/// z' = x + yw3 - zw1 - tw1w3
+/// t' = x - yw3 - zw1 + tw1w3
+#[inline(always)]
+fn radix4_butterfly<F, E>(
+    x: &mut FieldElement<E>,
+    y: &mut FieldElement<E>,
+    z: &mut FieldElement<E>,
+    t: &mut FieldElement<E>,
+    w1: &FieldElement<F>,
+    w2: &FieldElement<F>,
+    w3: &FieldElement<F>,
+) where
+    F: IsSubFieldOf<E>,
+    E: IsField,
+{
+    let zw1 = w1 * &*z;
+    let tw1 = w1 * &*t;
+    let a = w2 * (&*y + &tw1);
+    let b = w3 * (&*y - &tw1);
+
+    let x_plus_zw1 = &*x + &zw1;
+    let x_minus_zw1 = &*x - &zw1;
+    *x = &x_plus_zw1 + &a;
+    *y = &x_plus_zw1 - &a;
+    *z = &x_minus_zw1 + &b;
+    *t = &x_minus_zw1 - &b;
+}
+
+/// Below this size the parallel FFTs run on a single thread, since splitting the work costs more
+/// than it saves.
+#[cfg(feature = "rayon")]
+const PARALLEL_THRESHOLD: usize = 1 << 12;
+
+/// Largest size of the groups that each thread transforms on its own. Once the groups are this
+/// small, the remaining layers of the FFT work within each group, which fits in the L2 cache.
+#[cfg(feature = "rayon")]
+const PARALLEL_GROUP_SIZE: usize = 1 << 12;
+
+/// Number of butterflies handed to a thread at once in the layers with large groups.
+#[cfg(feature = "rayon")]
+const PARALLEL_BLOCK_SIZE: usize = 1 << 9;
+
+/// Size of the groups processed by a single thread for an input of size `len`, which leaves
+/// at least one group per thread.
+#[cfg(feature = "rayon")]
+fn parallel_group_size(len: usize) -> usize {
+    let threads = rayon::current_num_threads().next_power_of_two();
+    (len / threads).clamp(1, PARALLEL_GROUP_SIZE)
+}
+
+/// Multithreaded version of [in_place_nr_2radix_fft], with the same requirements and output.
+///
+/// The first layers of the FFT have few large groups, so the butterflies of each group are split
+/// among the threads. Once the groups are small enough, each thread runs all the remaining layers
+/// over some of them, which keeps the data in its cache.
+#[cfg(feature = "rayon")]
+pub fn in_place_nr_2radix_fft_parallel<F, E>(
+    input: &mut [FieldElement<E>],
+    twiddles: &[FieldElement<F>],
+) where
+    F: IsSubFieldOf<E>,
+    E: IsField,
+{
+    if input.len() < PARALLEL_THRESHOLD {
+        return in_place_nr_2radix_fft(input, twiddles);
+    }
+
+    let last_group_size = parallel_group_size(input.len());
+    let mut group_size = input.len();
+    while group_size > last_group_size {
+        for (group, chunk) in input.chunks_mut(group_size).enumerate() {
+            let w = &twiddles[group];
+            let (lo, hi) = chunk.split_at_mut(group_size / 2);
+            lo.par_chunks_mut(PARALLEL_BLOCK_SIZE)
+                .zip(hi.par_chunks_mut(PARALLEL_BLOCK_SIZE))
+                .for_each(|(lo, hi)| <F as IsSubFieldOf<E>>::subfield_butterfly_batch(lo, hi, w));
+        }
+        group_size /= 2;
+    }
+
+    input
+        .par_chunks_mut(group_size)
+        .enumerate()
+        .for_each(|(group, chunk)| in_place_nr_2radix_fft_of_group(chunk, twiddles, group));
+}
+
+/// Multithreaded version of [in_place_nr_4radix_fft], with the same requirements and output.
+/// The work is split among the threads as in [in_place_nr_2radix_fft_parallel].
+#[cfg(feature = "rayon")]
+pub fn in_place_nr_4radix_fft_parallel<F, E>(
+    input: &mut [FieldElement<E>],
+    twiddles: &[FieldElement<F>],
+) where
+    F: IsSubFieldOf<E>,
+    E: IsField,
+{
+    debug_assert!(input.len().is_power_of_two());
+    debug_assert!(input.len().ilog2().is_multiple_of(2)); // Even power of 2 => x is power of 4
+
+    if input.len() < PARALLEL_THRESHOLD {
+        return in_place_nr_4radix_fft(input, twiddles);
+    }
+
+    let last_group_size = parallel_group_size(input.len());
+    let mut group_size = input.len();
+    while group_size > last_group_size {
+        for (group, chunk) in input.chunks_mut(group_size).enumerate() {
+            let (w1, w2, w3) = (
+                &twiddles[group],
+                &twiddles[2 * group],
+                &twiddles[2 * group + 1],
+            );
+            let (x, y, z, t) = split_in_quarters(chunk);
+            x.par_chunks_mut(PARALLEL_BLOCK_SIZE)
+                .zip(y.par_chunks_mut(PARALLEL_BLOCK_SIZE))
+                .zip(z.par_chunks_mut(PARALLEL_BLOCK_SIZE))
+                .zip(t.par_chunks_mut(PARALLEL_BLOCK_SIZE))
+                .for_each(|(((x, y), z), t)| {
+                    for (((x, y), z), t) in x.iter_mut().zip(y).zip(z).zip(t) {
+                        radix4_butterfly(x, y, z, t, w1, w2, w3);
+                    }
+                });
+        }
+        group_size /= 4;
+    }
+
+    input
+        .par_chunks_mut(group_size)
+        .enumerate()
+        .for_each(|(group, chunk)| in_place_nr_4radix_fft_of_group(chunk, twiddles, group));
+}
+
 #[cfg(test)]
 mod tests {
     use crate::fft::cpu::bit_reversing::in_place_bit_reverse_permute;
@@ -257,4 +399,42 @@ mod tests {
             prop_assert_eq!(expected, result);
         }
     }
+
+    #[cfg(feature = "rayon")]
+    fn random_coeffs(len: usize) -> Vec<FE> {
+        (0..len as u64).map(|i| FE::from(i * i + 7)).collect()
+    }
+
+    // The sizes are above the single thread threshold, so both phases of the parallel FFT run.
+    #[test]
+    #[cfg(feature = "rayon")]
+    fn parallel_nr_2radix_fft_matches_sequential() {
+        for order in [12, 13, 15] {
+            let coeffs = random_coeffs(1 << order);
+            let twiddles = get_twiddles::<F>(order, RootsConfig::BitReverse).unwrap();
+
+            let mut expected = coeffs.clone();
+            in_place_nr_2radix_fft::<F, F>(&mut expected, &twiddles);
+            let mut result = coeffs;
+            in_place_nr_2radix_fft_parallel::<F, F>(&mut result, &twiddles);
+
+            assert_eq!(result, expected);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "rayon")]
+    fn parallel_nr_4radix_fft_matches_sequential() {
+        for order in [12, 14] {
+            let coeffs = random_coeffs(1 << order);
+            let twiddles = get_twiddles::<F>(order, RootsConfig::BitReverse).unwrap();
+
+            let mut expected = coeffs.clone();
+            in_place_nr_4radix_fft::<F, F>(&mut expected, &twiddles);
+            let mut result = coeffs;
+            in_place_nr_4radix_fft_parallel::<F, F>(&mut result, &twiddles);
+
+            assert_eq!(result, expected);
+        }
+    }
 }
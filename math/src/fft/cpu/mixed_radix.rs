@@ -0,0 +1,160 @@
+use crate::{
+    fft::errors::FFTError,
+    field::{
+        element::FieldElement,
+        traits::{IsFFTField, IsField, IsSubFieldOf},
+    },
+};
+
+use super::{bit_reversing::in_place_bit_reverse_permute, bluestein::bluestein_fft, ops};
+
+/// Radices split off the size of the input, before the remaining power of two is transformed
+/// with the radix-2 FFT.
+const RADICES: [usize; 3] = [3, 5, 2];
+
+/// Mixed-radix FFT. Returns `[Σ_j input[j] root^(j k) for k in 0..n]`, with `n = input.len()`
+/// and `root` a primitive `n`-th root of unity in a subfield F of E, for any length `n`.
+///
+/// Factors 3 and 5 of `n` are split off with Cooley-Tukey steps, and the remaining power of two
+/// is transformed with the radix-2 FFT. Any factor of `n` other than 2, 3 and 5 is transformed
+/// with [bluestein_fft], so smooth sizes are the fast ones.
+pub fn mixed_radix_fft<F, E>(
+    input: &[FieldElement<E>],
+    root: &FieldElement<F>,
+) -> Result<Vec<FieldElement<E>>, FFTError>
+where
+    F: IsFFTField + IsSubFieldOf<E>,
+    E: IsField,
+{
+    let n = input.len();
+    if n <= 1 {
+        return Ok(input.to_vec());
+    }
+    if n.is_power_of_two() {
+        return ops::fft(input, &bit_reversed_powers(root, n / 2));
+    }
+    let radix = match RADICES.into_iter().find(|radix| n.is_multiple_of(*radix)) {
+        Some(radix) => radix,
+        None => return bluestein_fft(input, root),
+    };
+
+    // decimation in time: the s-th subsequence holds the inputs with index s modulo the radix.
+    let m = n / radix;
+    let sub_root = root.pow(radix);
+    let sub_transforms = (0..radix)
+        .map(|s| {
+            let subsequence: Vec<_> = input.iter().skip(s).step_by(radix).cloned().collect();
+            mixed_radix_fft(&subsequence, &sub_root)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // X[k + m q] = Σ_s (root^(s k) Y_s[k]) ζ^(s q), with ζ = root^m a primitive radix-th root
+    // of unity, so each k takes a DFT of size radix.
+    let radix_roots = powers(&root.pow(m), radix);
+    let mut output = vec![FieldElement::zero(); n];
+    let mut root_k = FieldElement::<F>::one();
+    for k in 0..m {
+        let twisted: Vec<FieldElement<E>> = powers(&root_k, radix)
+            .iter()
+            .zip(&sub_transforms)
+            .map(|(twiddle, sub_transform)| twiddle * &sub_transform[k])
+            .collect();
+        for q in 0..radix {
+            output[k + m * q] = twisted
+                .iter()
+                .enumerate()
+                .map(|(s, value)| &radix_roots[(s * q) % radix] * value)
+                .sum();
+        }
+        root_k = &root_k * root;
+    }
+
+    Ok(output)
+}
+
+/// Returns `[base^i for i in 0..count]`.
+fn powers<F: IsField>(base: &FieldElement<F>, count: usize) -> Vec<FieldElement<F>> {
+    let mut powers = Vec::with_capacity(count);
+    powers.extend((0..count).scan(FieldElement::<F>::one(), |state, _| {
+        let power = state.clone();
+        *state = &*state * base;
+        Some(power)
+    }));
+    powers
+}
+
+/// Returns `[base^i for i in 0..count]` in bit-reverse order, which are the twiddle factors of
+/// the radix-2 FFT of size `2 * count` when `base` is a root of unity of that order.
+fn bit_reversed_powers<F: IsField>(base: &FieldElement<F>, count: usize) -> Vec<FieldElement<F>> {
+    let mut powers = powers(base, count);
+    in_place_bit_reverse_permute(&mut powers);
+    powers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::{
+        test_fields::u64_test_field::U64TestField,
+        traits::{IsSmoothFFTField, RootsConfig},
+    };
+    use crate::{fft::cpu::roots_of_unity::get_twiddles, polynomial::Polynomial};
+    use proptest::{collection, prelude::*};
+
+    type F = U64TestField;
+    type FE = FieldElement<F>;
+
+    fn naive_transform(input: &[FE], root: &FE) -> Vec<FE> {
+        let points: Vec<_> = (0..input.len()).map(|k| root.pow(k)).collect();
+        Polynomial::new(input).evaluate_slice(&points)
+    }
+
+    proptest! {
+        #[test]
+        fn mixed_radix_matches_naive_transform(
+            (input, n) in prop::sample::select(vec![1_usize, 3, 5, 6, 10, 12, 15, 30, 48, 60, 120])
+                .prop_flat_map(|n| (collection::vec(any::<u64>().prop_map(FE::from), n), Just(n)))
+        ) {
+            let root = F::get_primitive_root_of_unity_of_order(n as u64).unwrap();
+            prop_assert_eq!(mixed_radix_fft(&input, &root).unwrap(), naive_transform(&input, &root));
+        }
+    }
+
+    #[test]
+    fn mixed_radix_with_factor_not_in_radices_matches_naive_transform() {
+        // 17 divides the order of the multiplicative group, which is generated by 7.
+        let n = 2 * 3 * 17;
+        let root = FE::from(7).pow(F::neg(&1) / n as u64);
+        let input: Vec<FE> = (0..n as u64).map(|i| FE::from(i * i + 5)).collect();
+
+        assert_eq!(
+            mixed_radix_fft(&input, &root).unwrap(),
+            naive_transform(&input, &root)
+        );
+    }
+
+    #[test]
+    fn mixed_radix_of_power_of_two_size_matches_radix_2_fft() {
+        let input: Vec<FE> = (0..64_u64).map(|i| FE::from(3 * i + 1)).collect();
+        let root = F::get_primitive_root_of_unity(6).unwrap();
+        let twiddles = get_twiddles::<F>(6, RootsConfig::BitReverse).unwrap();
+
+        assert_eq!(
+            mixed_radix_fft(&input, &root).unwrap(),
+            ops::fft(&input, &twiddles).unwrap()
+        );
+    }
+
+    #[test]
+    fn root_of_unity_of_order_not_dividing_the_smooth_subgroup_fails() {
+        assert!(F::get_primitive_root_of_unity_of_order(9).is_err());
+        assert!(F::get_primitive_root_of_unity_of_order(17).is_err());
+        assert!(F::get_primitive_root_of_unity_of_order(0).is_err());
+        assert_eq!(
+            F::get_primitive_root_of_unity_of_order(15)
+                .unwrap()
+                .pow(15_u64),
+            FE::one()
+        );
+    }
+}
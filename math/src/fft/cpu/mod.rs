@@ -1,4 +1,8 @@
 pub mod bit_reversing;
+pub mod bluestein;
 pub mod fft;
+pub mod mixed_radix;
 pub mod ops;
 pub mod roots_of_unity;
+#[cfg(feature = "rayon")]
+pub mod six_step;
@@ -2,15 +2,26 @@ use crate::{
     fft::errors::FFTError,
     field::{
         element::FieldElement,
-        traits::{IsFFTField, IsField, IsSubFieldOf},
+        traits::{IsField, IsSubFieldOf},
     },
 };
 
-use super::{bit_reversing::in_place_bit_reverse_permute, fft::in_place_nr_2radix_fft};
+use super::bit_reversing::in_place_bit_reverse_permute;
+#[cfg(not(feature = "rayon"))]
+use super::fft::in_place_nr_2radix_fft;
+#[cfg(feature = "rayon")]
+use super::{fft::in_place_nr_2radix_fft_parallel, six_step::six_step_fft};
+
+/// From this size on, the input of the FFT no longer fits in the cache and the six-step FFT is
+/// used when the `rayon` feature is on.
+#[cfg(feature = "rayon")]
+const SIX_STEP_THRESHOLD: usize = 1 << 16;
 
 /// Executes Fast Fourier Transform over elements of a two-adic finite field `E` and domain in a
 /// subfield `F`. Usually used for fast polynomial evaluation.
-pub fn fft<F: IsFFTField + IsSubFieldOf<E>, E: IsField>(
+/// With the `rayon` feature, the work is split among threads, and the six-step FFT is used for
+/// large inputs.
+pub fn fft<F: IsSubFieldOf<E>, E: IsField>(
     input: &[FieldElement<E>],
     twiddles: &[FieldElement<F>],
 ) -> Result<Vec<FieldElement<E>>, FFTError> {
@@ -18,7 +29,15 @@ pub fn fft<F: IsFFTField + IsSubFieldOf<E>, E: IsField>(
         return Err(FFTError::InputError(input.len()));
     }
 
+    #[cfg(feature = "rayon")]
+    if input.len() >= SIX_STEP_THRESHOLD {
+        return Ok(six_step_fft(input, twiddles));
+    }
+
     let mut results = input.to_vec();
+    #[cfg(feature = "rayon")]
+    in_place_nr_2radix_fft_parallel(&mut results, twiddles);
+    #[cfg(not(feature = "rayon"))]
     in_place_nr_2radix_fft(&mut results, twiddles);
     in_place_bit_reverse_permute(&mut results);
 
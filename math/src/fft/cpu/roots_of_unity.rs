@@ -1,6 +1,6 @@
 use crate::field::{
     element::FieldElement,
-    traits::{IsFFTField, RootsConfig},
+    traits::{IsFFTField, IsField, IsRuntimeFFTField, RootsConfig},
 };
 
 use crate::fft::errors::FFTError;
@@ -18,9 +18,24 @@ pub fn get_powers_of_primitive_root<F: IsFFTField>(
         return Ok(Vec::new());
     }
 
+    let root = F::get_primitive_root_of_unity(n)?;
+    Ok(get_powers_of_root(&root, count, config))
+}
+
+/// Returns a `Vec` of the powers of `root` in some configuration `config`, as in
+/// [get_powers_of_primitive_root]. Inversed configurations use the powers of its inverse.
+fn get_powers_of_root<F: IsField>(
+    root: &FieldElement<F>,
+    count: usize,
+    config: RootsConfig,
+) -> Vec<FieldElement<F>> {
+    if count == 0 {
+        return Vec::new();
+    }
+
     let root = match config {
-        RootsConfig::Natural | RootsConfig::BitReverse => F::get_primitive_root_of_unity(n)?,
-        _ => F::get_primitive_root_of_unity(n)?.inv().unwrap(),
+        RootsConfig::Natural | RootsConfig::BitReverse => root.clone(),
+        _ => root.inv().unwrap(),
     };
     let up_to = match config {
         RootsConfig::Natural | RootsConfig::NaturalInversed => count,
@@ -43,7 +58,7 @@ pub fn get_powers_of_primitive_root<F: IsFFTField>(
         in_place_bit_reverse_permute(&mut results);
     }
 
-    Ok(results)
+    results
 }
 
 /// Returns a `Vec` of the powers of a `2^n`th primitive root of unity, scaled `offset` times,
@@ -73,6 +88,20 @@ pub fn get_twiddles<F: IsFFTField>(
     get_powers_of_primitive_root(order, (1 << order) / 2, config)
 }
 
+/// Returns 2^`order` / 2 twiddle factors for FFT in some configuration `config`, as
+/// [get_twiddles] does, for a field whose roots of unity are only known at runtime.
+pub fn get_runtime_twiddles<F: IsRuntimeFFTField>(
+    order: u64,
+    config: RootsConfig,
+) -> Result<Vec<FieldElement<F>>, FFTError> {
+    if order > 63 {
+        return Err(FFTError::OrderError(order));
+    }
+
+    let root = F::get_primitive_root_of_unity(order)?;
+    Ok(get_powers_of_root(&root, (1 << order) / 2, config))
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
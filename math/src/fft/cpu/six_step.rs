@@ -0,0 +1,133 @@
+use crate::field::{
+    element::FieldElement,
+    traits::{IsField, IsSubFieldOf},
+};
+use rayon::prelude::*;
+
+use super::{bit_reversing::in_place_bit_reverse_permute, fft::in_place_nr_2radix_fft};
+
+/// Side of the square tiles in which matrices are transposed.
+const TRANSPOSE_TILE_SIZE: usize = 16;
+
+/// Six-step FFT over a slice of two-adic field elements, with naturally ordered input and output.
+/// The twiddle factors are the ones of [in_place_nr_2radix_fft] for the input size, in bit-reverse
+/// order. Also the input size needs to be a power of two, at least 4.
+///
+/// The input of size `n = n1 * n2` is seen as a matrix with `n1` rows and `n2` columns, and its
+/// FFT is computed with FFTs of size `n1` and `n2` over the rows of the matrix and its transpose:
+/// 1. Transpose the matrix.
+/// 2. Compute the FFT of size `n1` of every row.
+/// 3. Multiply the element at row `j` and column `k` by `w^{j * k}`, with `w` the root of unity of
+///    order `n`.
+/// 4. Transpose the matrix.
+/// 5. Compute the FFT of size `n2` of every row.
+/// 6. Transpose the matrix.
+///
+/// The rows are about `sqrt(n)` long, so their FFTs fit in the cache, and they're computed in
+/// parallel. This beats the radix-2 FFT for inputs that don't fit in the cache.
+/// See Bailey, "FFTs in external or hierarchical memory" (https://www.davidhbailey.com/dhbpapers/fftq.pdf)
+pub fn six_step_fft<F, E>(
+    input: &[FieldElement<E>],
+    twiddles: &[FieldElement<F>],
+) -> Vec<FieldElement<E>>
+where
+    F: IsSubFieldOf<E>,
+    E: IsField,
+{
+    debug_assert!(input.len().is_power_of_two() && input.len() >= 4);
+
+    let log_n = input.len().trailing_zeros();
+    let n1 = 1 << log_n.div_ceil(2);
+    let n2 = 1 << (log_n / 2);
+
+    // The first `m / 2` bit-reversed twiddle factors of size `n` are the ones of size `m`.
+    let row_fft = |row: &mut [FieldElement<E>]| {
+        in_place_nr_2radix_fft(row, &twiddles[..row.len() / 2]);
+        in_place_bit_reverse_permute(row);
+    };
+
+    let mut matrix = transpose(input, n1, n2);
+    matrix.par_chunks_mut(n1).for_each(row_fft);
+
+    // `w` is in position 1 in natural order, which is `n / 4` in bit-reverse order.
+    let root = &twiddles[input.len() / 4];
+    matrix.par_chunks_mut(n1).enumerate().for_each(|(j, row)| {
+        let root_j = root.pow(j);
+        let mut factor = FieldElement::<F>::one();
+        for element in row.iter_mut().skip(1) {
+            factor = &factor * &root_j;
+            *element = &factor * &*element;
+        }
+    });
+
+    let mut matrix = transpose(&matrix, n2, n1);
+    matrix.par_chunks_mut(n2).for_each(row_fft);
+
+    transpose(&matrix, n1, n2)
+}
+
+/// Transposes a matrix with `rows` rows and `cols` columns, stored row by row. The output rows are
+/// split among the threads, and each one is filled in tiles to make better use of the cache.
+fn transpose<T: Clone + Send + Sync>(input: &[T], rows: usize, cols: usize) -> Vec<T> {
+    let mut output = input.to_vec();
+    output
+        .par_chunks_mut(TRANSPOSE_TILE_SIZE * rows)
+        .enumerate()
+        .for_each(|(tile, output_rows)| {
+            let first_col = tile * TRANSPOSE_TILE_SIZE;
+            for first_row in (0..rows).step_by(TRANSPOSE_TILE_SIZE) {
+                let last_row = (first_row + TRANSPOSE_TILE_SIZE).min(rows);
+                for (i, output_row) in output_rows.chunks_mut(rows).enumerate() {
+                    let col = first_col + i;
+                    for row in first_row..last_row {
+                        output_row[row] = input[row * cols + col].clone();
+                    }
+                }
+            }
+        });
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fft::cpu::roots_of_unity::get_twiddles;
+    use crate::fft::test_helpers::naive_matrix_dft_test;
+    use crate::field::{test_fields::u64_test_field::U64TestField, traits::RootsConfig};
+    use proptest::{collection, prelude::*};
+
+    type FE = FieldElement<U64TestField>;
+
+    #[test]
+    fn transpose_of_non_square_matrix() {
+        let matrix: Vec<usize> = (0..6).collect();
+        assert_eq!(transpose(&matrix, 2, 3), vec![0, 3, 1, 4, 2, 5]);
+        assert_eq!(transpose(&transpose(&matrix, 2, 3), 3, 2), matrix);
+    }
+
+    #[test]
+    fn six_step_fft_matches_radix_2_fft_for_large_input() {
+        let coeffs: Vec<FE> = (0..1 << 16).map(|i: u64| FE::from(i * i + 3)).collect();
+        let twiddles = get_twiddles::<U64TestField>(16, RootsConfig::BitReverse).unwrap();
+
+        let mut expected = coeffs.clone();
+        in_place_nr_2radix_fft(&mut expected, &twiddles);
+        in_place_bit_reverse_permute(&mut expected);
+
+        assert_eq!(six_step_fft(&coeffs, &twiddles), expected);
+    }
+
+    proptest! {
+        #[test]
+        fn six_step_fft_matches_naive_eval(
+            coeffs in (2..9_u32).prop_flat_map(|i| collection::vec(any::<u64>().prop_map(FE::from), 1 << i))
+        ) {
+            let expected = naive_matrix_dft_test(&coeffs);
+
+            let order = coeffs.len().trailing_zeros();
+            let twiddles = get_twiddles::<U64TestField>(order.into(), RootsConfig::BitReverse).unwrap();
+
+            prop_assert_eq!(six_step_fft(&coeffs, &twiddles), expected);
+        }
+    }
+}
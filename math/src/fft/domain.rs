@@ -0,0 +1,312 @@
+#[cfg(feature = "metal")]
+use crate::fft::gpu::metal::ops as metal_ops;
+use crate::{
+    fft::{
+        cpu::{ops, roots_of_unity::get_twiddles},
+        errors::FFTError,
+    },
+    field::{
+        element::FieldElement,
+        traits::{IsFFTField, IsField, IsSubFieldOf, RootsConfig},
+    },
+    polynomial::Polynomial,
+};
+#[cfg(feature = "metal")]
+use lambdaworks_gpu::metal::abstractions::state::MetalState;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+/// Coset `offset * <w>` of the subgroup of order `2^order` of a two-adic field `F`, together with
+/// the twiddle factors and coset shifts needed to run FFTs over it.
+///
+/// Everything is computed once when the domain is created, so evaluating or interpolating many
+/// polynomials over the same domain doesn't recompute the roots of unity each time, as
+/// [Polynomial::evaluate_offset_fft] and [Polynomial::interpolate_offset_fft] do. A domain is
+/// never modified after it's created, so it can be shared among threads.
+///
+/// With the `metal` feature, the FFTs run on the GPU with the precomputed twiddle factors when
+/// the field has a Metal implementation, as in [Polynomial::evaluate_fft].
+#[derive(Debug, Clone)]
+pub struct FFTDomain<F: IsFFTField> {
+    order: u64,
+    offset: FieldElement<F>,
+    /// Twiddle factors of the FFT, in bit-reverse order.
+    twiddles: Vec<FieldElement<F>>,
+    /// Twiddle factors of the inverse FFT, in bit-reverse order.
+    inverse_twiddles: Vec<FieldElement<F>>,
+    /// `offset^i` for `i` in `0..size`.
+    offset_powers: Vec<FieldElement<F>>,
+    /// `offset^(-i) / size` for `i` in `0..size`, which undo the coset shift and normalize the
+    /// inverse FFT at once.
+    inverse_offset_powers: Vec<FieldElement<F>>,
+}
+
+/// Returns `[factor * base^i for i in 0..count]`.
+fn scaled_powers<F: IsField>(
+    base: &FieldElement<F>,
+    factor: FieldElement<F>,
+    count: usize,
+) -> Vec<FieldElement<F>> {
+    let mut powers = Vec::with_capacity(count);
+    powers.extend((0..count).scan(factor, |state, _| {
+        let power = state.clone();
+        *state = &*state * base;
+        Some(power)
+    }));
+    powers
+}
+
+impl<F: IsFFTField> FFTDomain<F> {
+    /// Creates the subgroup of order `2^order`.
+    pub fn new(order: u64) -> Result<Self, FFTError> {
+        Self::new_with_offset(order, FieldElement::one())
+    }
+
+    /// Creates the coset `offset * <w>`, with `w` a primitive root of unity of order `2^order`.
+    /// `offset` must be non-zero.
+    pub fn new_with_offset(order: u64, offset: FieldElement<F>) -> Result<Self, FFTError> {
+        let twiddles = get_twiddles(order, RootsConfig::BitReverse)?;
+        let inverse_twiddles = get_twiddles(order, RootsConfig::BitReverseInversed)?;
+
+        let size = 1 << order;
+        let size_inv = FieldElement::<F>::from(size as u64).inv()?;
+        let offset_powers = scaled_powers(&offset, FieldElement::one(), size);
+        let inverse_offset_powers = scaled_powers(&offset.inv()?, size_inv, size);
+
+        Ok(Self {
+            order,
+            offset,
+            twiddles,
+            inverse_twiddles,
+            offset_powers,
+            inverse_offset_powers,
+        })
+    }
+
+    /// Returns the number of elements of the domain.
+    pub fn size(&self) -> usize {
+        1 << self.order
+    }
+
+    /// Returns the base 2 logarithm of the size of the domain.
+    pub fn order(&self) -> u64 {
+        self.order
+    }
+
+    pub fn offset(&self) -> &FieldElement<F> {
+        &self.offset
+    }
+
+    /// Returns the evaluations of `poly` at `offset * w^i`, for `i` in `0..size`.
+    /// Returns an error if `poly` has more coefficients than the size of the domain.
+    pub fn evaluate<E>(
+        &self,
+        poly: &Polynomial<FieldElement<E>>,
+    ) -> Result<Vec<FieldElement<E>>, FFTError>
+    where
+        F: IsSubFieldOf<E>,
+        E: IsField,
+    {
+        let coefficients = poly.coefficients();
+        if coefficients.len() > self.size() {
+            return Err(FFTError::DomainSizeError(coefficients.len(), self.size()));
+        }
+
+        // evaluating P(x) over the coset is evaluating P(offset * x) over the subgroup.
+        let mut scaled: Vec<_> = coefficients
+            .iter()
+            .zip(&self.offset_powers)
+            .map(|(coefficient, power)| power * coefficient)
+            .collect();
+        scaled.resize(self.size(), FieldElement::zero());
+
+        Self::fft(&scaled, &self.twiddles)
+    }
+
+    /// Returns the polynomial of degree less than `size` such that `P(offset * w^i) = evaluations[i]`.
+    /// This is the inverse operation of [Self::evaluate()].
+    /// Returns an error if there isn't exactly one evaluation per element of the domain.
+    pub fn interpolate<E>(
+        &self,
+        evaluations: &[FieldElement<E>],
+    ) -> Result<Polynomial<FieldElement<E>>, FFTError>
+    where
+        F: IsSubFieldOf<E>,
+        E: IsField,
+    {
+        if evaluations.len() != self.size() {
+            return Err(FFTError::DomainSizeError(evaluations.len(), self.size()));
+        }
+
+        let mut coefficients = Self::fft(evaluations, &self.inverse_twiddles)?;
+        for (coefficient, power) in coefficients.iter_mut().zip(&self.inverse_offset_powers) {
+            *coefficient = power * &*coefficient;
+        }
+
+        Ok(Polynomial::new(&coefficients))
+    }
+
+    /// Runs the FFT of `input` over the subgroup with the given twiddle factors, on the GPU when
+    /// the `metal` feature is on and the field is supported, and on the CPU otherwise.
+    fn fft<E>(
+        input: &[FieldElement<E>],
+        twiddles: &[FieldElement<F>],
+    ) -> Result<Vec<FieldElement<E>>, FFTError>
+    where
+        F: IsSubFieldOf<E>,
+        E: IsField,
+    {
+        #[cfg(feature = "metal")]
+        {
+            if !F::field_name().is_empty() {
+                let state = MetalState::new(None)?;
+                return Ok(metal_ops::fft(input, twiddles, &state)?);
+            }
+            println!(
+                "GPU evaluation failed for field {}. Program will fallback to CPU.",
+                std::any::type_name::<F>()
+            );
+        }
+
+        ops::fft(input, twiddles)
+    }
+
+    /// Evaluates each polynomial of `polys` over the domain, as in [Self::evaluate()].
+    /// With the `rayon` feature, the polynomials are evaluated in parallel.
+    pub fn evaluate_batch<E>(
+        &self,
+        polys: &[Polynomial<FieldElement<E>>],
+    ) -> Result<Vec<Vec<FieldElement<E>>>, FFTError>
+    where
+        F: IsSubFieldOf<E>,
+        E: IsField,
+    {
+        #[cfg(feature = "rayon")]
+        let iter = polys.par_iter();
+        #[cfg(not(feature = "rayon"))]
+        let iter = polys.iter();
+
+        iter.map(|poly| self.evaluate(poly)).collect()
+    }
+
+    /// Interpolates each column of `columns` over the domain, as in [Self::interpolate()].
+    /// With the `rayon` feature, the columns are interpolated in parallel.
+    pub fn interpolate_batch<E>(
+        &self,
+        columns: &[Vec<FieldElement<E>>],
+    ) -> Result<Vec<Polynomial<FieldElement<E>>>, FFTError>
+    where
+        F: IsSubFieldOf<E>,
+        E: IsField,
+    {
+        #[cfg(feature = "rayon")]
+        let iter = columns.par_iter();
+        #[cfg(not(feature = "rayon"))]
+        let iter = columns.iter();
+
+        iter.map(|column| self.interpolate(column)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fft::cpu::roots_of_unity::get_powers_of_primitive_root_coset;
+    use crate::field::test_fields::u64_test_field::{U64TestField, U64TestFieldExtension};
+    use proptest::{collection, prelude::*};
+
+    type F = U64TestField;
+    type FE = FieldElement<F>;
+
+    prop_compose! {
+        fn offset()(num in 1..F::neg(&1)) -> FE { FE::from(num) }
+    }
+
+    proptest! {
+        #[test]
+        fn evaluate_matches_naive_evaluation(
+            coeffs in collection::vec(any::<u64>().prop_map(FE::from), 0..32),
+            order in 5..7_u64,
+            offset in offset(),
+        ) {
+            let poly = Polynomial::new(&coeffs);
+            let domain = FFTDomain::new_with_offset(order, offset.clone()).unwrap();
+            let points = get_powers_of_primitive_root_coset(order, 1 << order, &offset).unwrap();
+
+            prop_assert_eq!(domain.evaluate(&poly).unwrap(), poly.evaluate_slice(&points));
+        }
+
+        #[test]
+        fn evaluate_matches_evaluate_offset_fft(
+            coeffs in collection::vec(any::<u64>().prop_map(FE::from), 1..16),
+            offset in offset(),
+        ) {
+            let poly = Polynomial::new(&coeffs);
+            let expected = Polynomial::evaluate_offset_fft(&poly, 4, None, &offset).unwrap();
+            let order = expected.len().trailing_zeros().into();
+            let domain = FFTDomain::new_with_offset(order, offset).unwrap();
+
+            prop_assert_eq!(domain.evaluate(&poly).unwrap(), expected);
+        }
+
+        #[test]
+        fn interpolate_is_inverse_of_evaluate(
+            coeffs in collection::vec(any::<u64>().prop_map(FE::from), 0..16),
+            offset in offset(),
+        ) {
+            let poly = Polynomial::new(&coeffs);
+            let domain = FFTDomain::new_with_offset(4, offset).unwrap();
+
+            let evaluations = domain.evaluate(&poly).unwrap();
+            prop_assert_eq!(domain.interpolate(&evaluations).unwrap(), poly);
+        }
+    }
+
+    #[test]
+    fn batch_operations_match_single_ones() {
+        let domain = FFTDomain::<F>::new_with_offset(3, FE::from(3)).unwrap();
+        let polys: Vec<_> = (1..5_u64)
+            .map(|i| Polynomial::new(&[FE::from(i), FE::from(2 * i), FE::from(i * i)]))
+            .collect();
+
+        let evaluations = domain.evaluate_batch(&polys).unwrap();
+        for (poly, poly_evaluations) in polys.iter().zip(&evaluations) {
+            assert_eq!(&domain.evaluate(poly).unwrap(), poly_evaluations);
+        }
+        assert_eq!(domain.interpolate_batch(&evaluations).unwrap(), polys);
+    }
+
+    #[test]
+    fn evaluations_in_extension_over_domain_in_prime_field() {
+        type L = U64TestFieldExtension;
+
+        let poly = Polynomial::new(&[
+            FieldElement::<L>::from(&[FE::one(), FE::one()]),
+            FieldElement::<L>::from(&[-FE::from(2), FE::from(17)]),
+            FieldElement::<L>::one(),
+        ]);
+        let domain = FFTDomain::<F>::new_with_offset(5, FE::from(2)).unwrap();
+
+        let evaluations = domain.evaluate(&poly).unwrap();
+        assert_eq!(
+            evaluations,
+            Polynomial::evaluate_offset_fft::<F>(&poly, 8, Some(4), &FE::from(2)).unwrap()
+        );
+        assert_eq!(domain.interpolate(&evaluations).unwrap(), poly);
+    }
+
+    #[test]
+    fn polynomial_too_large_for_domain_fails() {
+        let domain = FFTDomain::<F>::new(2).unwrap();
+        let poly = Polynomial::new(&vec![FE::one(); 5]);
+
+        assert!(matches!(
+            domain.evaluate(&poly),
+            Err(FFTError::DomainSizeError(5, 4))
+        ));
+        assert!(matches!(
+            domain.interpolate(&vec![FE::one(); 8]),
+            Err(FFTError::DomainSizeError(8, 4))
+        ));
+    }
+}
@@ -15,6 +15,14 @@ pub enum FFTError {
     InputError(usize),
     #[error("Order should be less than or equal to 63, but is {0}")]
     OrderError(u64),
+    #[error("Input length is {0}, which doesn't fit in a domain of size {1}")]
+    DomainSizeError(usize, usize),
+    #[error("The basis of the domain is not linearly independent")]
+    DomainBasisError,
+    #[error("The polynomial can't be inverted, since its constant coefficient is zero")]
+    PolynomialInverseError,
+    #[error("The offset of a coset must be non-zero")]
+    CosetOffsetError,
     #[cfg(feature = "metal")]
     #[error("A Metal related error has ocurred")]
     MetalError(#[from] MetalError),
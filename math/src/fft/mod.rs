@@ -1,4 +1,6 @@
+pub mod additive;
 pub mod cpu;
+pub mod domain;
 pub mod errors;
 pub mod gpu;
 pub mod polynomial;
@@ -4,9 +4,9 @@ use crate::field::traits::{IsField, IsSubFieldOf};
 use crate::{
     field::{
         element::FieldElement,
-        traits::{IsFFTField, RootsConfig},
+        traits::{IsFFTField, IsRuntimeFFTField, IsSmoothFFTField, RootsConfig},
     },
-    polynomial::Polynomial,
+    polynomial::{factorization::prime_divisors, Polynomial},
 };
 
 #[cfg(feature = "cuda")]
@@ -14,7 +14,8 @@ use crate::fft::gpu::cuda::polynomial::{evaluate_fft_cuda, interpolate_fft_cuda}
 #[cfg(feature = "metal")]
 use crate::fft::gpu::metal::polynomial::{evaluate_fft_metal, interpolate_fft_metal};
 
-use super::cpu::{ops, roots_of_unity};
+use super::cpu::{mixed_radix::mixed_radix_fft, ops, roots_of_unity};
+use super::domain::FFTDomain;
 
 impl<E: IsField> Polynomial<FieldElement<E>> {
     /// Returns `N` evaluations of this polynomial using FFT over a domain in a subfield F of E (so the results
@@ -124,6 +125,166 @@ impl<E: IsField> Polynomial<FieldElement<E>> {
         let scaled = Polynomial::interpolate_fft::<F>(fft_evals)?;
         Ok(scaled.scale(&offset.inv().unwrap()))
     }
+
+    /// Returns the evaluations of this polynomial over `domain`, reusing the twiddle factors and
+    /// coset shifts it precomputed. See [FFTDomain::evaluate()].
+    pub fn evaluate_fft_with_domain<F: IsFFTField + IsSubFieldOf<E>>(
+        poly: &Polynomial<FieldElement<E>>,
+        domain: &FFTDomain<F>,
+    ) -> Result<Vec<FieldElement<E>>, FFTError> {
+        domain.evaluate(poly)
+    }
+
+    /// Returns the polynomial that interpolates `fft_evals` over `domain`, reusing the twiddle
+    /// factors and coset shifts it precomputed. See [FFTDomain::interpolate()].
+    pub fn interpolate_fft_with_domain<F: IsFFTField + IsSubFieldOf<E>>(
+        fft_evals: &[FieldElement<E>],
+        domain: &FFTDomain<F>,
+    ) -> Result<Self, FFTError> {
+        domain.interpolate(fft_evals)
+    }
+
+    /// Returns the `domain_size` evaluations of this polynomial over the subgroup of that order
+    /// of a subfield F of E (so the results are P(w^i), with w being a primitive root of unity of
+    /// order `domain_size`). Unlike [Self::evaluate_fft()], `domain_size` only needs to divide the
+    /// order of the smooth subgroup of F, so it can be e.g. `3 * 2^k`. Other sizes are rejected,
+    /// use [Self::evaluate_fft_with_root()] with a root of unity of that order instead.
+    /// The polynomial can't have more than `domain_size` coefficients.
+    pub fn evaluate_smooth_fft<F: IsSmoothFFTField + IsSubFieldOf<E>>(
+        poly: &Polynomial<FieldElement<E>>,
+        domain_size: usize,
+    ) -> Result<Vec<FieldElement<E>>, FFTError> {
+        if poly.coeff_len() > domain_size {
+            return Err(FFTError::DomainSizeError(poly.coeff_len(), domain_size));
+        }
+        let root = F::get_primitive_root_of_unity_of_order(domain_size as u64)?;
+
+        let mut coeffs = poly.coefficients().to_vec();
+        coeffs.resize(domain_size, FieldElement::zero());
+        mixed_radix_fft(&coeffs, &root)
+    }
+
+    /// Returns the `domain_size` evaluations of this polynomial over the coset `offset * <w>`,
+    /// as in [Self::evaluate_smooth_fft()].
+    pub fn evaluate_offset_smooth_fft<F: IsSmoothFFTField + IsSubFieldOf<E>>(
+        poly: &Polynomial<FieldElement<E>>,
+        domain_size: usize,
+        offset: &FieldElement<F>,
+    ) -> Result<Vec<FieldElement<E>>, FFTError> {
+        let scaled = poly.scale(offset);
+        Polynomial::evaluate_smooth_fft::<F>(&scaled, domain_size)
+    }
+
+    /// Returns a new polynomial that interpolates `(w^i, fft_evals[i])`, with `w` being a
+    /// primitive root of unity of order `N = fft_evals.len()` in a subfield F of E, which only
+    /// needs to divide the order of the smooth subgroup of F.
+    /// This is considered to be the inverse operation of [Self::evaluate_smooth_fft()].
+    pub fn interpolate_smooth_fft<F: IsSmoothFFTField + IsSubFieldOf<E>>(
+        fft_evals: &[FieldElement<E>],
+    ) -> Result<Self, FFTError> {
+        let root = F::get_primitive_root_of_unity_of_order(fft_evals.len() as u64)?;
+        let coeffs = mixed_radix_fft(fft_evals, &root.inv()?)?;
+
+        let scale_factor = FieldElement::from(fft_evals.len() as u64).inv()?;
+        Ok(Polynomial::new(&coeffs).scale_coeffs(&scale_factor))
+    }
+
+    /// Returns a new polynomial that interpolates offset `(w^i, fft_evals[i])`, as in
+    /// [Self::interpolate_smooth_fft()].
+    /// This is considered to be the inverse operation of [Self::evaluate_offset_smooth_fft()].
+    pub fn interpolate_offset_smooth_fft<F: IsSmoothFFTField + IsSubFieldOf<E>>(
+        fft_evals: &[FieldElement<E>],
+        offset: &FieldElement<F>,
+    ) -> Result<Self, FFTError> {
+        let scaled = Polynomial::interpolate_smooth_fft::<F>(fft_evals)?;
+        Ok(scaled.scale(&offset.inv()?))
+    }
+
+    /// Returns the `domain_size` evaluations `P(root^i)` of this polynomial, where `root` is a
+    /// root of unity of order `domain_size` in a subfield F of E. Any size whose roots of unity
+    /// exist in F is accepted: factors 2, 3 and 5 of `domain_size` are handled by the
+    /// mixed-radix FFT, and any other factor by Bluestein's algorithm.
+    /// The polynomial can't have more than `domain_size` coefficients.
+    pub fn evaluate_fft_with_root<F: IsFFTField + IsSubFieldOf<E>>(
+        poly: &Polynomial<FieldElement<E>>,
+        domain_size: usize,
+        root: &FieldElement<F>,
+    ) -> Result<Vec<FieldElement<E>>, FFTError> {
+        if poly.coeff_len() > domain_size {
+            return Err(FFTError::DomainSizeError(poly.coeff_len(), domain_size));
+        }
+        if root.pow(domain_size) != FieldElement::one() {
+            return Err(FFTError::RootOfUnityError(domain_size as u64));
+        }
+
+        let mut coeffs = poly.coefficients().to_vec();
+        coeffs.resize(domain_size, FieldElement::zero());
+        mixed_radix_fft(&coeffs, root)
+    }
+
+    /// Returns a new polynomial that interpolates `(root^i, fft_evals[i])`, where `root` is a
+    /// primitive root of unity of order `N = fft_evals.len()` in a subfield F of E.
+    /// This is considered to be the inverse operation of [Self::evaluate_fft_with_root()].
+    /// Returns an error if there are no evaluations or if `root` isn't a primitive `N`-th root.
+    pub fn interpolate_fft_with_root<F: IsFFTField + IsSubFieldOf<E>>(
+        fft_evals: &[FieldElement<E>],
+        root: &FieldElement<F>,
+    ) -> Result<Self, FFTError> {
+        let n = fft_evals.len();
+        // `root` has order exactly `n` iff `root^n = 1` and `root^(n / q) != 1` for every prime
+        // `q` dividing `n`.
+        if n == 0
+            || root.pow(n) != FieldElement::one()
+            || prime_divisors(n)
+                .into_iter()
+                .any(|q| root.pow(n / q) == FieldElement::one())
+        {
+            return Err(FFTError::RootOfUnityError(n as u64));
+        }
+        let coeffs = mixed_radix_fft(fft_evals, &root.inv()?)?;
+
+        let scale_factor = FieldElement::from(fft_evals.len() as u64).inv()?;
+        Ok(Polynomial::new(&coeffs).scale_coeffs(&scale_factor))
+    }
+}
+
+impl<F: IsRuntimeFFTField> Polynomial<FieldElement<F>> {
+    /// Same as [Self::evaluate_fft()], for a field whose roots of unity are only known at
+    /// runtime. It always runs on the CPU.
+    pub fn evaluate_runtime_fft(
+        poly: &Polynomial<FieldElement<F>>,
+        blowup_factor: usize,
+        domain_size: Option<usize>,
+    ) -> Result<Vec<FieldElement<F>>, FFTError> {
+        let domain_size = domain_size.unwrap_or(0);
+        let len = std::cmp::max(poly.coeff_len(), domain_size).next_power_of_two() * blowup_factor;
+
+        if poly.coefficients().is_empty() {
+            return Ok(vec![FieldElement::zero(); len]);
+        }
+
+        let mut coeffs = poly.coefficients().to_vec();
+        coeffs.resize(len, FieldElement::zero());
+        let order = len.trailing_zeros();
+        let twiddles =
+            roots_of_unity::get_runtime_twiddles::<F>(order.into(), RootsConfig::BitReverse)?;
+        ops::fft(&coeffs, &twiddles)
+    }
+
+    /// Same as [Self::interpolate_fft()], for a field whose roots of unity are only known at
+    /// runtime. This is considered to be the inverse operation of [Self::evaluate_runtime_fft()].
+    pub fn interpolate_runtime_fft(fft_evals: &[FieldElement<F>]) -> Result<Self, FFTError> {
+        let order = fft_evals.len().trailing_zeros();
+        let twiddles = roots_of_unity::get_runtime_twiddles::<F>(
+            order.into(),
+            RootsConfig::BitReverseInversed,
+        )?;
+
+        let coeffs = ops::fft(fft_evals, &twiddles)?;
+
+        let scale_factor = FieldElement::from(fft_evals.len() as u64).inv()?;
+        Ok(Polynomial::new(&coeffs).scale_coeffs(&scale_factor))
+    }
 }
 
 pub fn compose_fft<F, E>(
@@ -338,6 +499,72 @@ mod tests {
 
                 prop_assert_eq!(poly, new_poly);
             }
+
+            // Property-based test that ensures FFT eval. over smooth domains, whose size isn't a power of two, gives same result as a naive polynomial evaluation.
+            #[test]
+            fn test_smooth_fft_coset_matches_naive_evaluation(poly in poly(4), offset in offset(), domain_size in prop::sample::select(vec![16_usize, 24, 30, 40, 48, 60])) {
+                let root = F::get_primitive_root_of_unity_of_order(domain_size as u64).unwrap();
+                let points: Vec<_> = (0..domain_size).map(|i| &offset * root.pow(i)).collect();
+
+                let fft_eval = Polynomial::evaluate_offset_smooth_fft::<F>(&poly, domain_size, &offset).unwrap();
+                prop_assert_eq!(fft_eval, poly.evaluate_slice(&points));
+            }
+
+            // Property-based test that ensures interpolation over smooth domains is the inverse operation of evaluation.
+            #[test]
+            fn test_smooth_fft_interpolate_is_inverse_of_evaluate(poly in poly(4), offset in offset(), domain_size in prop::sample::select(vec![16_usize, 20, 24, 30, 120])) {
+                let eval = Polynomial::evaluate_offset_smooth_fft::<F>(&poly, domain_size, &offset).unwrap();
+                let new_poly = Polynomial::interpolate_offset_smooth_fft::<F>(&eval, &offset).unwrap();
+                prop_assert_eq!(poly, new_poly);
+            }
+
+            // Property-based test that ensures FFT eval. over domains whose size has factors other than 2, 3 and 5 matches naive evaluation, and interpolation inverts it.
+            #[test]
+            fn test_fft_with_root_of_any_order_matches_naive_evaluation(poly in poly(4), domain_size in prop::sample::select(vec![17_usize, 34, 51, 85, 257])) {
+                // The multiplicative group is generated by 7, and its order is divisible by 17 and 257.
+                let root = FE::from(7).pow(F::neg(&1) / domain_size as u64);
+                let points: Vec<_> = (0..domain_size).map(|i| root.pow(i)).collect();
+
+                let fft_eval = Polynomial::evaluate_fft_with_root::<F>(&poly, domain_size, &root).unwrap();
+                prop_assert_eq!(&fft_eval, &poly.evaluate_slice(&points));
+                prop_assert_eq!(Polynomial::interpolate_fft_with_root::<F>(&fft_eval, &root).unwrap(), poly);
+            }
+        }
+
+        #[test]
+        fn smooth_fft_of_size_not_dividing_the_smooth_subgroup_fails() {
+            let poly = Polynomial::new(&[FE::new(1), FE::new(2)]);
+            assert!(matches!(
+                Polynomial::evaluate_smooth_fft::<F>(&poly, 9),
+                Err(FFTError::RootOfUnityError(9))
+            ));
+            assert!(matches!(
+                Polynomial::evaluate_smooth_fft::<F>(&poly, 1),
+                Err(FFTError::DomainSizeError(2, 1))
+            ));
+            assert!(matches!(
+                Polynomial::evaluate_fft_with_root::<F>(&poly, 9, &FE::new(2)),
+                Err(FFTError::RootOfUnityError(9))
+            ));
+        }
+
+        #[test]
+        fn interpolation_with_a_root_that_is_not_primitive_fails() {
+            // 7 generates the multiplicative group, so `root` has order 17 and `root^17` is 1.
+            let root = FE::from(7).pow(F::neg(&1) / 17);
+            let evals = vec![FE::one(); 34];
+            assert!(matches!(
+                Polynomial::interpolate_fft_with_root::<F>(&evals, &root),
+                Err(FFTError::RootOfUnityError(34))
+            ));
+            assert!(matches!(
+                Polynomial::interpolate_fft_with_root::<F>(&evals[..17], &FE::one()),
+                Err(FFTError::RootOfUnityError(17))
+            ));
+            assert!(matches!(
+                Polynomial::interpolate_fft_with_root::<F>(&evals[..0], &root),
+                Err(FFTError::RootOfUnityError(0))
+            ));
         }
 
         #[test]
@@ -37,6 +37,7 @@ use super::traits::{IsPrimeField, IsSubFieldOf, LegendreSymbol};
 /// A field element with operations algorithms defined in `F`
 #[allow(clippy::derived_hash_with_manual_eq)]
 #[derive(Debug, Clone, Hash, Copy)]
+#[repr(transparent)]
 pub struct FieldElement<F: IsField> {
     value: F::BaseType,
 }
@@ -113,6 +114,45 @@ where
     pub const fn const_from_raw(value: F::BaseType) -> Self {
         Self { value }
     }
+
+    /// Returns the internal representations of `elements`.
+    pub fn slice_as_raw(elements: &[Self]) -> &[F::BaseType] {
+        // SAFETY: `FieldElement` is `repr(transparent)` over `F::BaseType`.
+        unsafe { core::slice::from_raw_parts(elements.as_ptr().cast(), elements.len()) }
+    }
+
+    /// Returns the internal representations of `elements` as a mutable slice.
+    /// Every value written to it must be a valid internal representation of an element of `F`.
+    pub fn slice_as_raw_mut(elements: &mut [Self]) -> &mut [F::BaseType] {
+        // SAFETY: `FieldElement` is `repr(transparent)` over `F::BaseType`.
+        unsafe { core::slice::from_raw_parts_mut(elements.as_mut_ptr().cast(), elements.len()) }
+    }
+
+    /// Sets `a[i] = a[i] + b[i]` for every `i`, using packed arithmetic if `F` has it.
+    pub fn add_assign_batch(a: &mut [Self], b: &[Self]) {
+        F::add_assign_batch(a, b)
+    }
+
+    /// Sets `a[i] = a[i] - b[i]` for every `i`, using packed arithmetic if `F` has it.
+    pub fn sub_assign_batch(a: &mut [Self], b: &[Self]) {
+        F::sub_assign_batch(a, b)
+    }
+
+    /// Sets `a[i] = a[i] * b[i]` for every `i`, using packed arithmetic if `F` has it.
+    pub fn mul_assign_batch(a: &mut [Self], b: &[Self]) {
+        F::mul_assign_batch(a, b)
+    }
+
+    /// Sets `a[i] = a[i] * b[i] + c` for every `i`, using packed arithmetic if `F` has it.
+    pub fn mul_add_scalar_batch(a: &mut [Self], b: &[Self], c: &Self) {
+        F::mul_add_scalar_batch(a, b, c)
+    }
+
+    /// Sets `(lo[i], hi[i]) = (lo[i] + w * hi[i], lo[i] - w * hi[i])` for every `i`, using
+    /// packed arithmetic if `F` has it.
+    pub fn butterfly_batch(lo: &mut [Self], hi: &mut [Self], w: &Self) {
+        F::butterfly_batch(lo, hi, w)
+    }
 }
 
 /// Equality operator overloading for field elements
@@ -6,3 +6,11 @@ pub enum FieldError {
     /// Can't calculate inverse of zero
     InvZeroError,
 }
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum RuntimeModulusError {
+    /// The modulus is not an odd prime
+    NotAnOddPrime,
+    /// The element belongs to a field with a different modulus
+    ModulusMismatch,
+}
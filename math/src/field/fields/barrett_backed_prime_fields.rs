@@ -0,0 +1,340 @@
+use crate::field::element::FieldElement;
+use crate::field::errors::FieldError;
+use crate::field::fields::montgomery_backed_prime_fields::{IsFFTModulus, IsModulus};
+use crate::field::traits::{IsFFTField, IsField, IsPrimeField};
+use crate::traits::{ByteConversion, Serializable};
+use crate::unsigned_integer::{
+    barrett::BarrettAlgorithms, element::UnsignedInteger, modular::ModularAlgorithms,
+};
+
+use core::fmt::{self, Debug};
+use core::marker::PhantomData;
+
+pub type U384BarrettPrimeField<M> = BarrettBackendPrimeField<M, 6>;
+pub type U256BarrettPrimeField<M> = BarrettBackendPrimeField<M, 4>;
+pub type U64BarrettPrimeField<M> = BarrettBackendPrimeField<M, 1>;
+
+/// Prime field whose elements are kept in canonical form and reduced after each multiplication
+/// with Barrett's algorithm. It works for any odd prime modulus and avoids the conversions to and
+/// from Montgomery form, so it's a good fit when elements are serialized or compared often.
+/// Its API mirrors `MontgomeryBackendPrimeField`, and moduli are declared the same way.
+#[cfg_attr(
+    any(
+        feature = "lambdaworks-serde-binary",
+        feature = "lambdaworks-serde-string"
+    ),
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(Clone, Debug, Hash, Copy)]
+pub struct BarrettBackendPrimeField<M, const NUM_LIMBS: usize> {
+    phantom: PhantomData<M>,
+}
+
+impl<M, const NUM_LIMBS: usize> BarrettBackendPrimeField<M, NUM_LIMBS>
+where
+    M: IsModulus<UnsignedInteger<NUM_LIMBS>>,
+{
+    /// `floor(2^{2n} / modulus) - 2^n`, where `n` is the bit size of the modulus.
+    pub const MU: UnsignedInteger<NUM_LIMBS> = BarrettAlgorithms::compute_mu(&M::MODULUS);
+    /// Bit size of the modulus.
+    pub const BITS: usize = M::MODULUS.bits_le();
+    pub const ZERO: UnsignedInteger<NUM_LIMBS> = UnsignedInteger::from_u64(0);
+    pub const ONE: UnsignedInteger<NUM_LIMBS> = UnsignedInteger::from_u64(1);
+
+    #[inline(always)]
+    fn reduce(
+        hi: &UnsignedInteger<NUM_LIMBS>,
+        lo: &UnsignedInteger<NUM_LIMBS>,
+    ) -> UnsignedInteger<NUM_LIMBS> {
+        BarrettAlgorithms::reduce(hi, lo, &M::MODULUS, &Self::MU, Self::BITS)
+    }
+
+    /// Reduces an integer that fits in `NUM_LIMBS` limbs. Barrett reduction needs it to be
+    /// below `2^{2n}`, which only fails for moduli using less than half of the limbs.
+    #[inline(always)]
+    fn reduce_single(x: &UnsignedInteger<NUM_LIMBS>) -> UnsignedInteger<NUM_LIMBS> {
+        if *x < M::MODULUS {
+            *x
+        } else if 2 * Self::BITS >= 64 * NUM_LIMBS {
+            Self::reduce(&Self::ZERO, x)
+        } else {
+            x.div_rem(&M::MODULUS).1
+        }
+    }
+}
+
+impl<M, const NUM_LIMBS: usize> IsField for BarrettBackendPrimeField<M, NUM_LIMBS>
+where
+    M: IsModulus<UnsignedInteger<NUM_LIMBS>> + Clone + Debug,
+{
+    type BaseType = UnsignedInteger<NUM_LIMBS>;
+
+    #[inline(always)]
+    fn add(a: &Self::BaseType, b: &Self::BaseType) -> Self::BaseType {
+        ModularAlgorithms::add(a, b, &M::MODULUS)
+    }
+
+    #[inline(always)]
+    fn mul(a: &Self::BaseType, b: &Self::BaseType) -> Self::BaseType {
+        let (hi, lo) = UnsignedInteger::mul(a, b);
+        Self::reduce(&hi, &lo)
+    }
+
+    #[inline(always)]
+    fn square(a: &Self::BaseType) -> Self::BaseType {
+        let (hi, lo) = UnsignedInteger::square(a);
+        Self::reduce(&hi, &lo)
+    }
+
+    #[inline(always)]
+    fn sub(a: &Self::BaseType, b: &Self::BaseType) -> Self::BaseType {
+        ModularAlgorithms::sub(a, b, &M::MODULUS)
+    }
+
+    #[inline(always)]
+    fn neg(a: &Self::BaseType) -> Self::BaseType {
+        ModularAlgorithms::neg(a, &M::MODULUS)
+    }
+
+    #[inline(always)]
+    fn inv(a: &Self::BaseType) -> Result<Self::BaseType, FieldError> {
+        if a == &Self::ZERO {
+            Err(FieldError::InvZeroError)
+        } else {
+            Ok(ModularAlgorithms::inv(a, &M::MODULUS))
+        }
+    }
+
+    #[inline(always)]
+    fn div(a: &Self::BaseType, b: &Self::BaseType) -> Self::BaseType {
+        Self::mul(a, &Self::inv(b).unwrap())
+    }
+
+    #[inline(always)]
+    fn eq(a: &Self::BaseType, b: &Self::BaseType) -> bool {
+        a == b
+    }
+
+    #[inline(always)]
+    fn zero() -> Self::BaseType {
+        Self::ZERO
+    }
+
+    #[inline(always)]
+    fn one() -> Self::BaseType {
+        Self::ONE
+    }
+
+    #[inline(always)]
+    fn from_u64(x: u64) -> Self::BaseType {
+        Self::reduce_single(&UnsignedInteger::from_u64(x))
+    }
+
+    #[inline(always)]
+    fn from_base_type(x: Self::BaseType) -> Self::BaseType {
+        Self::reduce_single(&x)
+    }
+}
+
+impl<M, const NUM_LIMBS: usize> IsPrimeField for BarrettBackendPrimeField<M, NUM_LIMBS>
+where
+    M: IsModulus<UnsignedInteger<NUM_LIMBS>> + Clone + Debug,
+{
+    type RepresentativeType = Self::BaseType;
+
+    fn representative(x: &Self::BaseType) -> Self::RepresentativeType {
+        *x
+    }
+
+    fn field_bit_size() -> usize {
+        Self::BITS
+    }
+
+    fn from_hex(hex_string: &str) -> Result<Self::BaseType, crate::errors::CreationError> {
+        let integer = Self::BaseType::from_hex(hex_string)?;
+        Ok(Self::reduce_single(&integer))
+    }
+}
+
+impl<M, const NUM_LIMBS: usize> IsFFTField for BarrettBackendPrimeField<M, NUM_LIMBS>
+where
+    M: IsFFTModulus<UnsignedInteger<NUM_LIMBS>> + Clone + Debug,
+{
+    const TWO_ADICITY: u64 = M::TWO_ADICITY;
+    const TWO_ADIC_PRIMITVE_ROOT_OF_UNITY: Self::BaseType = M::TWO_ADIC_PRIMITVE_ROOT_OF_UNITY;
+
+    fn field_name() -> &'static str {
+        M::field_name()
+    }
+}
+
+impl<M, const NUM_LIMBS: usize> fmt::Display
+    for FieldElement<BarrettBackendPrimeField<M, NUM_LIMBS>>
+where
+    M: IsModulus<UnsignedInteger<NUM_LIMBS>> + Clone + Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value())
+    }
+}
+
+impl<M, const NUM_LIMBS: usize> ByteConversion
+    for FieldElement<BarrettBackendPrimeField<M, NUM_LIMBS>>
+where
+    M: IsModulus<UnsignedInteger<NUM_LIMBS>> + Clone + Debug,
+{
+    #[cfg(feature = "std")]
+    fn to_bytes_be(&self) -> Vec<u8> {
+        self.value().to_bytes_be()
+    }
+
+    #[cfg(feature = "std")]
+    fn to_bytes_le(&self) -> Vec<u8> {
+        self.value().to_bytes_le()
+    }
+
+    fn from_bytes_be(bytes: &[u8]) -> Result<Self, crate::errors::ByteConversionError> {
+        let value = UnsignedInteger::from_bytes_be(bytes)?;
+        Ok(Self::new(value))
+    }
+
+    fn from_bytes_le(bytes: &[u8]) -> Result<Self, crate::errors::ByteConversionError> {
+        let value = UnsignedInteger::from_bytes_le(bytes)?;
+        Ok(Self::new(value))
+    }
+}
+
+impl<M, const NUM_LIMBS: usize> Serializable
+    for FieldElement<BarrettBackendPrimeField<M, NUM_LIMBS>>
+where
+    M: IsModulus<UnsignedInteger<NUM_LIMBS>> + Clone + Debug,
+{
+    #[cfg(feature = "std")]
+    fn serialize(&self) -> Vec<u8> {
+        self.value().to_bytes_be()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::fields::fft_friendly::stark_252_prime_field::Stark252PrimeField;
+    use crate::field::fields::montgomery_backed_prime_fields::{
+        montgomery_prime_field, U256PrimeField,
+    };
+    use crate::unsigned_integer::element::{U256, U64};
+    use proptest::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Copy)]
+    struct U64Modulus23;
+    impl IsModulus<U64> for U64Modulus23 {
+        const MODULUS: U64 = UnsignedInteger::from_u64(23);
+    }
+
+    type F23 = U64BarrettPrimeField<U64Modulus23>;
+    type F23Element = FieldElement<F23>;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Copy)]
+    struct Secp256k1Modulus;
+    impl IsModulus<U256> for Secp256k1Modulus {
+        const MODULUS: U256 = U256::from_hex_unchecked(
+            "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f",
+        );
+    }
+
+    type Secp256k1Barrett = FieldElement<U256BarrettPrimeField<Secp256k1Modulus>>;
+    type Secp256k1Montgomery = FieldElement<U256PrimeField<Secp256k1Modulus>>;
+
+    #[montgomery_prime_field(
+        modulus = "0x800000000000011000000000000000000000000000000000000000000000001",
+        field = Stark252Montgomery
+    )]
+    struct Stark252Modulus;
+
+    type StarkBarrett = FieldElement<U256BarrettPrimeField<Stark252Modulus>>;
+
+    #[test]
+    fn from_u64_reduces_modulo_23() {
+        assert_eq!(F23Element::from(25).representative(), U64::from_u64(2));
+    }
+
+    #[test]
+    fn mul_order_minus_1() {
+        let a = F23Element::from(22);
+        assert_eq!(a * a, F23Element::one());
+    }
+
+    #[test]
+    fn inv_0_error() {
+        assert!(matches!(
+            F23Element::zero().inv(),
+            Err(FieldError::InvZeroError)
+        ));
+    }
+
+    #[test]
+    fn three_inverse() {
+        let a = F23Element::from(3);
+        assert_eq!(a.inv().unwrap(), F23Element::from(8));
+    }
+
+    #[test]
+    fn field_bit_size_is_the_bit_size_of_the_modulus() {
+        assert_eq!(F23::field_bit_size(), 5);
+        assert_eq!(
+            U256BarrettPrimeField::<Secp256k1Modulus>::field_bit_size(),
+            256
+        );
+    }
+
+    #[test]
+    fn to_bytes_from_bytes_be_is_the_identity() {
+        let x = Secp256k1Barrett::new(U256::from_hex_unchecked("0123456789abcdef0123456789abcdef"));
+        assert_eq!(
+            Secp256k1Barrett::from_bytes_be(&x.to_bytes_be()).unwrap(),
+            x
+        );
+    }
+
+    #[test]
+    fn fft_field_root_of_unity_matches_montgomery_backend() {
+        let root =
+            U256BarrettPrimeField::<Stark252Modulus>::get_primitive_root_of_unity(10).unwrap();
+        let expected = Stark252PrimeField::get_primitive_root_of_unity(10).unwrap();
+        assert_eq!(root.representative(), expected.representative());
+    }
+
+    prop_compose! {
+        fn secp256k1_pair()(a in any::<[u64; 4]>(), b in any::<[u64; 4]>()) -> (U256, U256) {
+            (UnsignedInteger::from_limbs(a), UnsignedInteger::from_limbs(b))
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn operations_match_montgomery_backend((a, b) in secp256k1_pair()) {
+            let (x, y) = (Secp256k1Barrett::new(a), Secp256k1Barrett::new(b));
+            let (z, w) = (Secp256k1Montgomery::new(a), Secp256k1Montgomery::new(b));
+            prop_assert_eq!((x + y).representative(), (z + w).representative());
+            prop_assert_eq!((x - y).representative(), (z - w).representative());
+            prop_assert_eq!((x * y).representative(), (z * w).representative());
+            prop_assert_eq!(x.square().representative(), z.square().representative());
+            prop_assert_eq!((-x).representative(), (-z).representative());
+            if y != Secp256k1Barrett::zero() {
+                // Montgomery's inverse doesn't support moduli using all the limbs.
+                prop_assert_eq!((x / y) * y, x);
+            }
+        }
+
+        #[test]
+        fn operations_match_montgomery_backend_for_smaller_modulus((a, b) in secp256k1_pair()) {
+            let (x, y) = (StarkBarrett::new(a), StarkBarrett::new(b));
+            let (z, w) = (FieldElement::<Stark252PrimeField>::new(a), FieldElement::<Stark252PrimeField>::new(b));
+            prop_assert_eq!((x * y).representative(), (z * w).representative());
+            prop_assert_eq!(x.square().representative(), z.square().representative());
+            if y != StarkBarrett::zero() {
+                prop_assert_eq!(y.inv().unwrap().representative(), w.inv().unwrap().representative());
+            }
+        }
+    }
+}
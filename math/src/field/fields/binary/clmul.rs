@@ -0,0 +1,138 @@
+//! Multiplication in `GF(2^128)` represented as polynomials modulo `x^128 + x^7 + x^2 + x + 1`.
+//! Uses the `pclmulqdq` instruction when it is available and a portable carry-less
+//! multiplication otherwise.
+
+/// Returns the carry-less product of `a` and `b`, computed one bit at a time.
+#[inline(always)]
+pub(super) fn clmul64_portable(a: u64, b: u64) -> u128 {
+    let a = a as u128;
+    let mut result = 0u128;
+    for i in 0..64 {
+        // All ones if the i-th bit of b is set, all zeros otherwise.
+        let mask = (((b >> i) & 1) as u128).wrapping_neg();
+        result ^= (a << i) & mask;
+    }
+    result
+}
+
+/// Returns the carry-less product of `a` and `b` using the `pclmulqdq` instruction.
+///
+/// # Safety
+///
+/// The CPU must support `pclmulqdq`.
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "pclmulqdq")]
+pub(super) unsafe fn clmul64_pclmul(a: u64, b: u64) -> u128 {
+    use core::arch::x86_64::{__m128i, _mm_clmulepi64_si128, _mm_cvtsi64_si128};
+
+    let product = _mm_clmulepi64_si128(_mm_cvtsi64_si128(a as i64), _mm_cvtsi64_si128(b as i64), 0);
+    // x86_64 is little-endian, so the low lane holds the low 64 bits.
+    core::mem::transmute::<__m128i, u128>(product)
+}
+
+/// Returns `true` if `clmul64_pclmul` can be called on this CPU.
+#[cfg(target_arch = "x86_64")]
+#[inline(always)]
+fn has_pclmul() -> bool {
+    #[cfg(target_feature = "pclmulqdq")]
+    {
+        true
+    }
+    #[cfg(all(not(target_feature = "pclmulqdq"), feature = "std"))]
+    {
+        std::is_x86_feature_detected!("pclmulqdq")
+    }
+    #[cfg(all(not(target_feature = "pclmulqdq"), not(feature = "std")))]
+    {
+        false
+    }
+}
+
+/// Returns the carry-less product of `a` and `b`.
+#[inline(always)]
+pub(super) fn clmul64(a: u64, b: u64) -> u128 {
+    #[cfg(target_arch = "x86_64")]
+    if has_pclmul() {
+        // SAFETY: `has_pclmul` checked that the instruction is supported.
+        return unsafe { clmul64_pclmul(a, b) };
+    }
+    clmul64_portable(a, b)
+}
+
+/// Returns the 256-bit carry-less product of `a` and `b` as `(hi, lo)`,
+/// using Karatsuba to spend three 64-bit multiplications.
+#[inline(always)]
+fn clmul128(a: u128, b: u128, clmul: impl Fn(u64, u64) -> u128) -> (u128, u128) {
+    let (a0, a1) = (a as u64, (a >> 64) as u64);
+    let (b0, b1) = (b as u64, (b >> 64) as u64);
+    let z0 = clmul(a0, b0);
+    let z2 = clmul(a1, b1);
+    let z1 = clmul(a0 ^ a1, b0 ^ b1) ^ z0 ^ z2;
+    (z2 ^ (z1 >> 64), z0 ^ (z1 << 64))
+}
+
+/// Reduces `hi * x^128 + lo` modulo `x^128 + x^7 + x^2 + x + 1`.
+#[inline(always)]
+fn reduce(hi: u128, lo: u128) -> u128 {
+    // x^128 = x^7 + x^2 + x + 1, so `hi * x^128` equals the following 135-bit value.
+    let folded = hi ^ (hi << 1) ^ (hi << 2) ^ (hi << 7);
+    let overflow = (hi >> 127) ^ (hi >> 126) ^ (hi >> 121);
+    // The overflow has at most 7 bits, so folding it again fits in 128 bits.
+    lo ^ folded ^ overflow ^ (overflow << 1) ^ (overflow << 2) ^ (overflow << 7)
+}
+
+/// Multiplies `a` and `b` in the polynomial basis of `GF(2^128)`.
+#[inline(always)]
+pub(super) fn mul_polynomial_basis(a: u128, b: u128) -> u128 {
+    let (hi, lo) = clmul128(a, b, clmul64);
+    reduce(hi, lo)
+}
+
+/// Multiplies `a` and `b` in the polynomial basis of `GF(2^128)` without using
+/// any architecture specific instruction.
+#[cfg(test)]
+pub(super) fn mul_polynomial_basis_portable(a: u128, b: u128) -> u128 {
+    let (hi, lo) = clmul128(a, b, clmul64_portable);
+    reduce(hi, lo)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn portable_clmul_of_small_polynomials() {
+        // (x + 1) * (x + 1) = x^2 + 1
+        assert_eq!(clmul64_portable(0b11, 0b11), 0b101);
+        // x^63 * x^63 = x^126
+        assert_eq!(clmul64_portable(1 << 63, 1 << 63), 1 << 126);
+    }
+
+    #[test]
+    fn clmul_matches_portable_clmul() {
+        let mut a = 0x0123_4567_89ab_cdefu64;
+        let mut b = 0xfedc_ba98_7654_3210u64;
+        for _ in 0..100 {
+            assert_eq!(clmul64(a, b), clmul64_portable(a, b));
+            a = a.rotate_left(7).wrapping_mul(0x9e37_79b9_7f4a_7c15);
+            b = b.rotate_right(11).wrapping_mul(0xbf58_476d_1ce4_e5b9) ^ a;
+        }
+    }
+
+    #[test]
+    fn x_to_the_128_is_reduced() {
+        let x_127 = 1u128 << 127;
+        assert_eq!(mul_polynomial_basis(x_127, 2), 0x87);
+        assert_eq!(mul_polynomial_basis_portable(x_127, 2), 0x87);
+    }
+
+    #[test]
+    fn polynomial_basis_multiplication_matches_portable_one() {
+        let a = 0x0123_4567_89ab_cdef_fedc_ba98_7654_3210u128;
+        let b = 0xdead_beef_cafe_babe_0011_2233_4455_6677u128;
+        assert_eq!(
+            mul_polynomial_basis(a, b),
+            mul_polynomial_basis_portable(a, b)
+        );
+    }
+}
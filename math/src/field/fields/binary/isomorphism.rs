@@ -0,0 +1,336 @@
+//! Change of basis between the tower representation of `GF(2^128)` and its representation
+//! as polynomials modulo `x^128 + x^7 + x^2 + x + 1`, where multiplication can be done with
+//! carry-less multiplications.
+//!
+//! Column `i` of `TOWER_TO_POLYNOMIAL` is the image of the tower basis element with bit `i` set,
+//! that is, the product of the `β_k` for the bits `k` set in `i`, where `β_0, ..., β_6` are
+//! roots in the polynomial field of the quadratics defining the tower. `POLYNOMIAL_TO_TOWER` is
+//! its inverse.
+
+/// Images of the tower basis elements in the polynomial basis.
+pub(super) const TOWER_TO_POLYNOMIAL: [u128; 128] = [
+    0x00000000000000000000000000000001,
+    0x295ac0b1f4731af9676aac9fa4b20b08,
+    0x500317bd159d73bb34d2f7fba603e341,
+    0xf210539fd8dd2772cbc26e38bdbd6c63,
+    0x872430dcdf135bcc433f53640b5ab39a,
+    0x8219b5897684c1d0e200bbc85e3a7d97,
+    0xdaec11278a2c0a891c6e94d79177c892,
+    0x65ae77f268cca17d8b1df555903aa5e9,
+    0x08ee6d05a2afa6e5f848729a9637483a,
+    0x3cca8320b45aefd9dba503f87315b1a8,
+    0x9b47458777c15dea1b5375ef70e6dc9f,
+    0x593bbedc17b5f4da227cd3c6669d38f0,
+    0x74ab97e6503349909f2bf21dc607a1b2,
+    0xbe2d80bb66dc1a0abe3c8b64a0b6f9b2,
+    0x619e6d79f681747ceb7330904219f5ac,
+    0xe3adaf9fe529f056a02b78bef4f6133e,
+    0x6167c15ae3f2515951c65cfffdd09b94,
+    0x795c476973f47d5a0ca96f6a4a44beed,
+    0xa21ddb601a326857be8058f408d22e26,
+    0x14242a8779dae83b7c8684ae94a2f185,
+    0xaba0d0c88da93f15481bbbc48565fee5,
+    0x64fb761163d93ebdb71ccc36839f9647,
+    0x15235865a1cd74289d6c9244d04bba72,
+    0x6cfb3c4d3bac86c0a1ecacbebea777cc,
+    0x7d8c36d6a1df81a2fbe17814b6ca9260,
+    0x9ec96c9e3b18c252657fe7e195131cd2,
+    0x003ed92204c4a40746589462f9ab9eb0,
+    0x14d48b577c139fc094bdde3e42eb1694,
+    0x8e28d30985a5894b678923535f7cf658,
+    0xae102916e86017c74e970f9894d50784,
+    0x4858fa9d599f4ada4e8a0597c2c2907c,
+    0x1c4b0fb064f73abe0eba006fb57f1fa8,
+    0x11bf2ae00eefb7458f1990f8ffd4b9bc,
+    0xe27320022b72914b1f7c7f431823965b,
+    0x78a4be3f2c0aed97f54aab2180a90996,
+    0x9309d793180fe6215280ee029b62750a,
+    0x15efacff420803f31bff864c76a70bb7,
+    0x68f677be5ea4b78621b53c72a0ae65c5,
+    0x21cf690854228f0b8834da2f070d8555,
+    0x9e3c72aa35c7dc0a570036d9afa9fd24,
+    0xc20bd9b336b720205fc47d2972773261,
+    0x6066022b6885c19d723470241b7518b1,
+    0x4917e78ecb765a5964d0490c5aec068a,
+    0xbfc31b1de3110bc8d105446109bf168f,
+    0xebb4f73ef95c2f7fbb703b7f9c609dca,
+    0xcffddc5020a30d9b2b81cf51c8e43261,
+    0xa3d8634c33134f8e72116beae0b08ed5,
+    0x8326f8c3dfbf323fb91ebbac8f51bcef,
+    0xc7f171687ef7f9407ed2d61814a23da6,
+    0x41e84d1083d1f1a227a997bfb68b61e0,
+    0xabdf8a1a7556243fed4625c713e7b8aa,
+    0xaa13e6e092c16a1ac0a0df962c48580d,
+    0xe768403d91de59ff6aeb212ee593d9bc,
+    0xeec4d42478d9ceedd7930ce02540de37,
+    0x9384b2124154e3ba05b4271a872099bb,
+    0xca6f4c794e793ff534593ba7c52d3cc4,
+    0x04dcc115f382c6fbd7a5e045cfc43a8e,
+    0xefd42701f792d8ea6317581b69404289,
+    0x61fecb5c1940132e3a789aee58508f24,
+    0xd6fc5a3e10dfef65ec51eb6a01862512,
+    0x101395ec2df1ed0865b260f9cba3804b,
+    0x0cd81d04055597c8361029771a6fa104,
+    0xc24f2128351fa2a4d099fdb1647d40bb,
+    0x5db0b5421a8b0b33ad1738d8a8c5a935,
+    0x8845a6b78c9fffc36a2a74600cfa98de,
+    0x0e4a84a1a05b93ee76b306ba7b2cc027,
+    0x84a3b0628c5d601b1c3a2b07326ece2e,
+    0x49063a06f4806668c82375749b87b9ed,
+    0xbd7319eed3387d42579243cc622ec3b1,
+    0x15f67ae5e16ae3ef8fd81e8b94050dc6,
+    0x0f1feb70a0869280a80f4dccee6aae78,
+    0xce69b0b3ca656544db1346c4d0f1faa8,
+    0x6b2094e306c74b4dc5b801c549ceb609,
+    0xa7a148ae12a0b730a23395304f18c285,
+    0xa19d5b5b30c3bc6994021fa6a1964215,
+    0x3c782bb4d3789cecfc676bd3998bc456,
+    0xeb5c82619156a47c099b0953f6f42fb8,
+    0x624deda6b451daf39fd75558a8f5cbb8,
+    0xf7cd9ee571319c4d28fc76c975c1076e,
+    0x2a8f209180482cafc2f1ca5432dc3809,
+    0x46562beee593bc832ee71ad7ad205800,
+    0x3c06f3db284566e92fcccfbf00c0ef82,
+    0x2e0507589d6341b9b9b837384799a89e,
+    0x0d4fd7063331ccb20c9ff220e24062cf,
+    0x87597a4c993b43074f2f5015fdc2a914,
+    0x6f8aa318d8219e5edf28413e947051fd,
+    0x54166f2d05b5efc3e7d00d1e72114949,
+    0x6fd87e3822534a43a8cc3604543bee0c,
+    0xc9d54d2d51bddf02c08a0ec29c679ffa,
+    0xcee647836e86cb230710994598afe5af,
+    0x469c3e383972c204d0b14acc089cfd36,
+    0x12c3b31d3781c6a2a8292447ab036dfa,
+    0xc625a07316d387218924847a2a12868a,
+    0x6f62931d0ee3632b728b5998e69c1144,
+    0x2e02c893894afee731eb3074c1ae65c5,
+    0xce97267bd7f2fb2ae827c2157352d011,
+    0xf58c7c90c35f45cae9c139444142f3a9,
+    0xe266aad9ff6bc6d347e645f1f0407b19,
+    0x6fb40f2e7017f50c9f8ba7ce90947d51,
+    0xce9ff4f344cd0c90cebf9d15c0ab2fa3,
+    0xda64a6d94cdfdf262ba1655dcabe5380,
+    0x43ec70f1c219fbed6a45b7be0b2e99e5,
+    0x4c48c528ff1a9896d432130d420f1d4e,
+    0x0233c5231c1e4e1b6791ed43f81324b3,
+    0x077642848573838b63768b8f9612c4cd,
+    0xd6ad6325a3e442ba38c0e087a0ca7f1c,
+    0xc90ce2fa05b3b2b9e80489183b1c2af5,
+    0xa1297e4066244e58616dc59acec5b18a,
+    0x9e6fb217dc36f2d06cee21ee91020e5a,
+    0xf8956a38326cca0611de55dee3ee1f9b,
+    0x2b0f72b7a8c4023ba19037624be8346e,
+    0x5ddc3fa9e365b573607a9288fd195181,
+    0xc32bb979fe782db843ced416ca065df5,
+    0x5f26497086bf7eb5b837acc5ad01b311,
+    0x0413a1697711b5cc7e5bf6db947a354a,
+    0xc8e774034ccb90b149877532dba02782,
+    0x47a4597e0944b8a9ff24194d482fe941,
+    0xd0225a3f7ad416e5dd4e835b7c96110a,
+    0x8aa88b140134c5f98305ff34ea167f1b,
+    0x49e4160b426c493a74c73c916884cddc,
+    0xf630e33d5cb412faf2a700e89459a76b,
+    0x9d20f59a77d399e698bc67ca9022662d,
+    0xa00381f170467dd21d54670470b9c5a4,
+    0x2486c8f7d1c1b1110f6f437968c8a9ea,
+    0x5dc362a25cdc370f1e1dc7fd4184b5c9,
+    0xb251bb7444eeb69b1ca3f65578dae2b0,
+    0x7a7a55f1cb4956d755452ba244b1989f,
+    0x7fea7baa40b8a97b5bbf90cdc7ea034b,
+];
+
+/// Images of the polynomial basis elements in the tower basis.
+pub(super) const POLYNOMIAL_TO_TOWER: [u128; 128] = [
+    0x00000000000000000000000000000001,
+    0xbf72eab170e2403236a22fb2474b2624,
+    0x8645417677574bad83d8c2c50ebd742a,
+    0xc44eab9c393c484b84d5bf58e28ea4b7,
+    0x46c2875840d9ac05aa6af98018d25f55,
+    0x043d6fde6578fb2f87664f6baf87e469,
+    0xf0dc20d57405dfcf2ea0a5fa91719628,
+    0x578a64b458185a65f92edec3d1fbf5a8,
+    0x3bdd9ba927c62b5a90112ff325fda842,
+    0x447511f9cd52e28e56ec948dbae696cd,
+    0x521233d5247d2d904ca600853b9a92c2,
+    0x0420c3919e0e2b89a6be10291dc10d21,
+    0x89ef2f7f4dad1497d720270ba9982789,
+    0x025b2fcf1a4cfb9ab0c844efc2e19581,
+    0x3c6abc0b64232cce88a2573915fcf8f2,
+    0x03817ed2be3ef4067e2cae73303e0da0,
+    0xd62cd908d0a81e98608bdab99d89f1c6,
+    0x38a28969a8c7cdc9ffd01c24927d5791,
+    0x7ca8754b078c356a761639b40d36b15a,
+    0x981c951ea552c109980e1a9ad8e3a156,
+    0x00d9c3e90543dd4eb8fd0554aa1055b1,
+    0xd0d532f5ee26ec4bbeb4341a5e87eb70,
+    0xbea85be25942639069c221d93e5c4b1e,
+    0x23b7ceacadb43c51d2015c0161250fa3,
+    0xe4d28f24d8c44cc509af9daefb2db540,
+    0x02e18a802c373bdb13bc8c90831934ed,
+    0xfd1a61a4defe2d30eaa9c75d3ef6766e,
+    0x96bc3cd5d254c66c7c715bf3999f6961,
+    0xfb555c7c73dce02877d64afd71dfd73f,
+    0x5a9ffad42dd04edeb2f5699465c3fa8c,
+    0x6f02c7febf18d6201576e3c2beb79b2b,
+    0x29e8f972bb8ade100250f03575f63fd5,
+    0x2639adeee2599f7b1e7aeccd28c73426,
+    0x96f9236c7f93388e32777ad49d06a5b6,
+    0x7ee735d67e96b9b8acb6d3efec67a7cf,
+    0xd203959dd28dc5a56c2be55c0131094d,
+    0x7dd8f01d6892abd20e43484009002f02,
+    0x76c27535204f7fe8930c4e6c992dfda6,
+    0xcdd16e15b8979b51d1054200eec21139,
+    0xec4be9a86ee119c35da68f353b668121,
+    0xb044100ec4c7b90087bf891de9341e64,
+    0x3efc932c3b092fd2ca279c41736d4872,
+    0x598353e3407885db574d7a66fc0b1bfb,
+    0xbdf21b24f927d94a5f88acd9a6a26151,
+    0x9d1b1e8a0528b0bde50e7c6b316d23c1,
+    0x05747d6f3e3b4fb465f4d047d844163d,
+    0x0f7ab1eb63bfc96c72163a870638c134,
+    0xc9e9c20cc04e2c41d125eec451612c7c,
+    0xf71be74eb049508309dfd8e3f48fccd8,
+    0x0e090f562fbf8b2a12c981d3b51cdcfb,
+    0x20367b859ac7fc30d4a24e940abddd3e,
+    0xdde3e272828b5034e18df0efd282b781,
+    0x847805dd73905067e8671e345527404b,
+    0x8ce21e74cb31a543b3c4a512fa2cd0e8,
+    0x21f0a6f21bc33f81544d2d53b0f9d6a0,
+    0xf4a82729a9b78575899da3f171624f95,
+    0x50753ff467cf95c7d8d9c8450a7529d3,
+    0xa2bb0def058350355cc1e4cf5eb3ae21,
+    0xfe06c5b0baedd53e769b7263d7e91533,
+    0x88a95a20910490a9a11f23e5fe516fb8,
+    0x77b755bb1ab352762b317a72a262b471,
+    0x3183a35447f94ff6e162395d683996a4,
+    0x4031e1cd0c10bd2c9925951c5b93f0ac,
+    0x6f090f1817574051398d08d694abf4e7,
+    0x405d49977e9573ec52c285b8d5a7f4d0,
+    0xb084f18fc3d54e51b459bbb1331e5ddf,
+    0x23d78272255d9e811efb0079925a9547,
+    0x441311679c785639c1b7713f7ad50628,
+    0x783691f5d4e1cee242fd1beb22fa1de2,
+    0xfe1776b4038f27961a3d7b57b848b294,
+    0x42f5607e1e49f0ebbc2fe7bd8e46cb64,
+    0xdf63680d6a4f6b427f38c516efbf246a,
+    0x442dc195eb6ec0c278545b122fb49c90,
+    0x6a4641b5f498aeb92bc18f73913adf49,
+    0xa17ba92e37205592bdf05f93359e93e5,
+    0x436115e7ea0e23903d9f22c9649c3af2,
+    0x5a16aee00bb4d5af36d2af6470baf341,
+    0x4038ec264d5e9008b12d48d6bb2a8313,
+    0xe95430f0322710f310fe617d8283c40c,
+    0xe739388ffada26d68b5fad444e20dbc2,
+    0x5d50b4629f8ab36db38427787ec4dced,
+    0xb8da0f97c95256fdcdfe4c1b60f56a9e,
+    0x909a0fce02183c181b19bf073a8d4b9e,
+    0x64c7dcbf62d3b242a6f92d42bb586321,
+    0x3545207abf741a1e60a7c3d0095d804e,
+    0x676f8bca0da3a3fd93143d80c88da7cb,
+    0x98de2c7a29f7dc9d871fa323d39d5d91,
+    0xb557a8e47545110dc065457c402f22a9,
+    0x26836bb3033270d1abe1d142ae1a9d2b,
+    0x6cf11a49377887aa69bf97a3eaeac23c,
+    0x2a86feb39eaac2802e1476b67d0502cd,
+    0x8997107a28d87f11c31bfbc498f70a7a,
+    0xbdce4c7258905fc0746a1cc281f471a0,
+    0x07fbf3bd51d106448bf56c50c7fc6613,
+    0x828f73cfd0c0f03fa8cb49f442c569d7,
+    0xf44b2e5714ce3d62711279386b35e8da,
+    0x50a25806cf60c1273ef16247802de432,
+    0xed82e940baa8fd2dbb3fd7342d379acb,
+    0xcbef0ab563d336d0fb53e7646505be6d,
+    0xe8b359c0986fd0202ac2fb54a74b82d1,
+    0xc9b2eb08d3251f4ca9ba1a07b1263256,
+    0x0b7c0d9aa302d7608ee77d4195211626,
+    0x664d3bd8d824bd2bda59e928b05641b5,
+    0x810738713fad36691d40539ce2d600f4,
+    0x837cb7576e2be135bdca9c56b5baeacd,
+    0x545aa8179399fc7f315291a18d68fa58,
+    0xc20179ec0eaaa3453130c708b7d05f88,
+    0x86d48283c609b10e35f3f0806b5bcd68,
+    0xa8475acb93f4ab5c6accf2b25e0b8dd0,
+    0x22148a3fac7ece43325de3992d0adb81,
+    0xdabc62a6357d9607aabaf2c227694380,
+    0x4ca485b87531ba480e285afc1089ab4c,
+    0xe6994e049f4c7c7e3dcea7d5e793394e,
+    0xeefbd2cccb7b2ff48d1cd615a85c332b,
+    0xac2e79d5ae93ead9f81cba47b7695df0,
+    0x1768cb4ef41ff771c1cad73020bbe10f,
+    0xfeab684486c79f471f0e24d5bc9311b7,
+    0xed60a7f1a1e0068725cfd1e04dd87db6,
+    0xa5955f35a442afd50799582ce95a5f77,
+    0x60f7d192cf67760dbe434b7dc91218eb,
+    0x5399ab6e5df83682137ef29e0f83bcdc,
+    0xc58ee61b69f5e3303f674775ff17bc38,
+    0xc0250e606fca6bd881990edb17578df4,
+    0xe6b91edb1227264a6fd680f3b4b9281a,
+    0xca70146d90d235fa14076abb4075dd2a,
+    0x6b5481d82d3010e9aa57fa0ab54756c8,
+    0x11511dbe657d5776b62561defe5b1660,
+    0xd211232d595bde75eda3993b6813dcb4,
+];
+
+/// Lookup tables applying a linear map given by its columns one byte at a time.
+type ByteTables = [[u128; 256]; 16];
+
+const fn byte_tables(columns: &[u128; 128]) -> ByteTables {
+    let mut tables = [[0u128; 256]; 16];
+    let mut byte = 0;
+    while byte < 16 {
+        let mut value: usize = 1;
+        while value < 256 {
+            // Every entry is the entry without its lowest bit plus the column of that bit.
+            let lowest_bit = value.trailing_zeros() as usize;
+            tables[byte][value] =
+                tables[byte][value & (value - 1)] ^ columns[8 * byte + lowest_bit];
+            value += 1;
+        }
+        byte += 1;
+    }
+    tables
+}
+
+static TOWER_TO_POLYNOMIAL_TABLES: ByteTables = byte_tables(&TOWER_TO_POLYNOMIAL);
+static POLYNOMIAL_TO_TOWER_TABLES: ByteTables = byte_tables(&POLYNOMIAL_TO_TOWER);
+
+#[inline(always)]
+fn apply(tables: &ByteTables, x: u128) -> u128 {
+    let bytes = x.to_le_bytes();
+    let mut result = 0;
+    for (table, byte) in tables.iter().zip(bytes) {
+        result ^= table[byte as usize];
+    }
+    result
+}
+
+/// Maps an element in the tower basis to the polynomial basis.
+#[inline(always)]
+pub(super) fn to_polynomial_basis(x: u128) -> u128 {
+    apply(&TOWER_TO_POLYNOMIAL_TABLES, x)
+}
+
+/// Maps an element in the polynomial basis to the tower basis.
+#[inline(always)]
+pub(super) fn to_tower_basis(x: u128) -> u128 {
+    apply(&POLYNOMIAL_TO_TOWER_TABLES, x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn change_of_basis_maps_one_to_one() {
+        assert_eq!(to_polynomial_basis(1), 1);
+        assert_eq!(to_tower_basis(1), 1);
+    }
+
+    #[test]
+    fn change_of_basis_is_invertible() {
+        for i in 0..128 {
+            assert_eq!(to_tower_basis(to_polynomial_basis(1 << i)), 1 << i);
+            assert_eq!(to_polynomial_basis(to_tower_basis(1 << i)), 1 << i);
+        }
+    }
+}
@@ -0,0 +1,6 @@
+/// Carry-less multiplication in the polynomial basis of GF(2^128)
+mod clmul;
+/// Change of basis between the tower and polynomial representations of GF(2^128)
+mod isomorphism;
+/// Implementation of the binary tower fields GF(2), GF(2^2), ..., GF(2^128)
+pub mod tower;
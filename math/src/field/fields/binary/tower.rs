@@ -0,0 +1,515 @@
+use core::fmt::{self, Display};
+
+use super::{clmul, isomorphism};
+use crate::{
+    errors::ByteConversionError,
+    field::{
+        element::FieldElement,
+        errors::FieldError,
+        traits::{IsBinaryField, IsField, IsSubFieldOf},
+    },
+    traits::ByteConversion,
+};
+
+/// Binary tower field `T_LEVEL` of `2^(2^LEVEL)` elements, for `LEVEL` between 0 and 7.
+///
+/// The tower is built as `T_0 = GF(2)` and `T_{k+1} = T_k[X_k] / (X_k^2 + X_{k-1} X_k + 1)`,
+/// with `X_{-1} = 1`. An element `a0 + a1 X_k` of `T_{k+1}` is stored with `a0` in the low
+/// half of the bits and `a1` in the high half, so the elements of `T_k` are exactly the
+/// elements of `T_{k+1}` whose high half is zero and every field of the tower is a subfield
+/// of the next ones without any conversion.
+///
+/// Elements are stored in a `u128` whose bits above `2^LEVEL` are always zero.
+/// `from_u64` and `from_base_type` take the bits of their argument as the coordinates in the
+/// tower basis, so `FieldElement::from(3)` is `1 + X_0` and not `3 * 1 = 1`.
+#[derive(Debug, Clone, Copy, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct BinaryTowerField<const LEVEL: usize>;
+
+/// `GF(2)`
+pub type BinaryField1b = BinaryTowerField<0>;
+/// `GF(2^2)`
+pub type BinaryField2b = BinaryTowerField<1>;
+/// `GF(2^4)`
+pub type BinaryField4b = BinaryTowerField<2>;
+/// `GF(2^8)`
+pub type BinaryField8b = BinaryTowerField<3>;
+/// `GF(2^16)`
+pub type BinaryField16b = BinaryTowerField<4>;
+/// `GF(2^32)`
+pub type BinaryField32b = BinaryTowerField<5>;
+/// `GF(2^64)`
+pub type BinaryField64b = BinaryTowerField<6>;
+/// `GF(2^128)`
+pub type BinaryField128b = BinaryTowerField<7>;
+
+/// Levels from which multiplication maps the operands to the polynomial basis of `GF(2^128)`
+/// and uses carry-less multiplication instead of the recursive tower formulas.
+const CLMUL_LEVEL: usize = 6;
+
+impl<const LEVEL: usize> BinaryTowerField<LEVEL> {
+    /// Number of bits of the elements of the field.
+    pub const BITS: usize = {
+        assert!(
+            LEVEL <= 7,
+            "binary tower fields are only defined up to level 7"
+        );
+        1 << LEVEL
+    };
+
+    /// Mask of the bits that an element of the field can have set.
+    pub const MASK: u128 = if Self::BITS == 128 {
+        u128::MAX
+    } else {
+        (1 << Self::BITS) - 1
+    };
+}
+
+/// Multiplies `a` in `T_level` by the generator `X_{level-1}`, or by one if `level` is zero.
+#[inline(always)]
+fn mul_by_generator(a: u128, level: usize) -> u128 {
+    if level == 0 {
+        return a;
+    }
+    // (a0 + a1 X) X = a1 + (a0 + a1 X_{level-2}) X, since X^2 = X_{level-2} X + 1.
+    let half = 1 << (level - 1);
+    let (a0, a1) = (a & ((1 << half) - 1), a >> half);
+    a1 | ((a0 ^ mul_by_generator(a1, level - 1)) << half)
+}
+
+/// Multiplies `a` and `b` in `T_level` with the recursive Karatsuba formulas of the tower.
+fn tower_mul(a: u128, b: u128, level: usize) -> u128 {
+    if level == 0 {
+        return a & b;
+    }
+    let half = 1 << (level - 1);
+    let mask = (1 << half) - 1;
+    let (a0, a1) = (a & mask, a >> half);
+    let (b0, b1) = (b & mask, b >> half);
+
+    let z0 = mul(a0, b0, level - 1);
+    let z2 = mul(a1, b1, level - 1);
+    let z1 = mul(a0 ^ a1, b0 ^ b1, level - 1) ^ z0 ^ z2;
+    // (a0 + a1 X)(b0 + b1 X) = z0 + z1 X + z2 X^2 = (z0 + z2) + (z1 + z2 X_{level-2}) X
+    (z0 ^ z2) | ((z1 ^ mul_by_generator(z2, level - 1)) << half)
+}
+
+/// Multiplies `a` and `b` in `T_level`.
+#[inline(always)]
+fn mul(a: u128, b: u128, level: usize) -> u128 {
+    if level >= CLMUL_LEVEL {
+        let product = clmul::mul_polynomial_basis(
+            isomorphism::to_polynomial_basis(a),
+            isomorphism::to_polynomial_basis(b),
+        );
+        isomorphism::to_tower_basis(product)
+    } else {
+        tower_mul(a, b, level)
+    }
+}
+
+/// Squares `a` in `T_level`. Squaring is linear in characteristic two, so it's much cheaper
+/// than a multiplication.
+fn square(a: u128, level: usize) -> u128 {
+    if level == 0 {
+        return a;
+    }
+    let half = 1 << (level - 1);
+    let (a0, a1) = (a & ((1 << half) - 1), a >> half);
+    let a1_squared = square(a1, level - 1);
+    // (a0 + a1 X)^2 = a0^2 + a1^2 (X_{level-2} X + 1)
+    (square(a0, level - 1) ^ a1_squared) | (mul_by_generator(a1_squared, level - 1) << half)
+}
+
+/// Inverts the non-zero element `a` of `T_level`.
+fn inv(a: u128, level: usize) -> u128 {
+    if level == 0 {
+        return a;
+    }
+    let half = 1 << (level - 1);
+    let (a0, a1) = (a & ((1 << half) - 1), a >> half);
+    // (a0 + a1 X)^{-1} = (a0 + a1 t + a1 X) / (a0 (a0 + a1 t) + a1^2), with t = X_{level-2}.
+    let a0_plus_a1_t = a0 ^ mul_by_generator(a1, level - 1);
+    let determinant = mul(a0, a0_plus_a1_t, level - 1) ^ square(a1, level - 1);
+    let determinant_inv = inv(determinant, level - 1);
+    mul(a0_plus_a1_t, determinant_inv, level - 1) | (mul(a1, determinant_inv, level - 1) << half)
+}
+
+impl<const LEVEL: usize> IsField for BinaryTowerField<LEVEL> {
+    type BaseType = u128;
+
+    /// Returns the sum of `a` and `b`, which is their bitwise xor.
+    fn add(a: &u128, b: &u128) -> u128 {
+        a ^ b
+    }
+
+    /// Returns the multiplication of `a` and `b`.
+    fn mul(a: &u128, b: &u128) -> u128 {
+        mul(*a, *b, LEVEL)
+    }
+
+    fn square(a: &u128) -> u128 {
+        square(*a, LEVEL)
+    }
+
+    /// Returns the subtraction of `a` and `b`, which equals their sum.
+    fn sub(a: &u128, b: &u128) -> u128 {
+        a ^ b
+    }
+
+    /// Returns the additive inverse of `a`, which is `a` itself.
+    fn neg(a: &u128) -> u128 {
+        *a
+    }
+
+    /// Returns the multiplicative inverse of `a`.
+    fn inv(a: &u128) -> Result<u128, FieldError> {
+        if *a == 0 {
+            return Err(FieldError::InvZeroError);
+        }
+        Ok(inv(*a, LEVEL))
+    }
+
+    /// Returns the division of `a` and `b`.
+    fn div(a: &u128, b: &u128) -> u128 {
+        let b_inv = Self::inv(b).expect("InvZeroError");
+        mul(*a, b_inv, LEVEL)
+    }
+
+    /// Returns a boolean indicating whether `a` and `b` are equal or not.
+    fn eq(a: &u128, b: &u128) -> bool {
+        a == b
+    }
+
+    /// Returns the additive neutral element.
+    fn zero() -> u128 {
+        0
+    }
+
+    /// Returns the multiplicative neutral element.
+    fn one() -> u128 {
+        1
+    }
+
+    /// Returns the element whose coordinates in the tower basis are the bits of `x`.
+    /// Bits beyond the size of the field are discarded.
+    fn from_u64(x: u64) -> u128 {
+        x as u128 & Self::MASK
+    }
+
+    /// Returns the element whose coordinates in the tower basis are the bits of `x`.
+    /// Bits beyond the size of the field are discarded.
+    fn from_base_type(x: u128) -> u128 {
+        x & Self::MASK
+    }
+}
+
+impl<const LEVEL: usize> IsBinaryField for BinaryTowerField<LEVEL> {}
+
+/// Multiplies `a` in `T_sub_level` by `b` in `T_level`. The coordinates of `b` over `T_sub_level`
+/// are its consecutive chunks of `2^sub_level` bits, so each of them is multiplied by `a`.
+fn mul_by_subfield_element(a: u128, b: u128, sub_level: usize, level: usize) -> u128 {
+    // `sub_level` is below `level`, so chunks have at most 64 bits.
+    let chunk_bits = 1 << sub_level;
+    let chunk_mask = (1 << chunk_bits) - 1;
+    (0..(1 << (level - sub_level)))
+        .map(|i| i * chunk_bits)
+        .fold(0, |result, shift| {
+            result | (mul(a, (b >> shift) & chunk_mask, sub_level) << shift)
+        })
+}
+
+macro_rules! impl_binary_subfields {
+    ($($sub:literal => [$($level:literal),*]),* $(,)?) => {
+        $($(
+            impl IsSubFieldOf<BinaryTowerField<$level>> for BinaryTowerField<$sub> {
+                fn mul(a: &u128, b: &u128) -> u128 {
+                    mul_by_subfield_element(*a, *b, $sub, $level)
+                }
+
+                fn add(a: &u128, b: &u128) -> u128 {
+                    a ^ b
+                }
+
+                fn div(a: &u128, b: &u128) -> u128 {
+                    let b_inv = BinaryTowerField::<$level>::inv(b).expect("InvZeroError");
+                    mul_by_subfield_element(*a, b_inv, $sub, $level)
+                }
+
+                fn sub(a: &u128, b: &u128) -> u128 {
+                    a ^ b
+                }
+
+                fn embed(a: u128) -> u128 {
+                    a
+                }
+
+                #[cfg(feature = "std")]
+                fn to_subfield_vec(b: u128) -> Vec<u128> {
+                    let chunk_bits = BinaryTowerField::<$sub>::BITS;
+                    (0..BinaryTowerField::<$level>::BITS / chunk_bits)
+                        .map(|i| (b >> (i * chunk_bits)) & BinaryTowerField::<$sub>::MASK)
+                        .collect()
+                }
+            }
+        )*)*
+    };
+}
+
+impl_binary_subfields!(
+    0 => [1, 2, 3, 4, 5, 6, 7],
+    1 => [2, 3, 4, 5, 6, 7],
+    2 => [3, 4, 5, 6, 7],
+    3 => [4, 5, 6, 7],
+    4 => [5, 6, 7],
+    5 => [6, 7],
+    6 => [7],
+);
+
+#[cfg(feature = "lambdaworks-serde-binary")]
+impl ByteConversion for u128 {
+    #[cfg(feature = "std")]
+    fn to_bytes_be(&self) -> Vec<u8> {
+        self.to_be_bytes().to_vec()
+    }
+
+    #[cfg(feature = "std")]
+    fn to_bytes_le(&self) -> Vec<u8> {
+        self.to_le_bytes().to_vec()
+    }
+
+    fn from_bytes_be(bytes: &[u8]) -> Result<Self, ByteConversionError> {
+        let bytes: [u8; 16] = bytes
+            .try_into()
+            .map_err(|_| ByteConversionError::FromBEBytesError)?;
+        Ok(u128::from_be_bytes(bytes))
+    }
+
+    fn from_bytes_le(bytes: &[u8]) -> Result<Self, ByteConversionError> {
+        let bytes: [u8; 16] = bytes
+            .try_into()
+            .map_err(|_| ByteConversionError::FromLEBytesError)?;
+        Ok(u128::from_le_bytes(bytes))
+    }
+}
+
+impl<const LEVEL: usize> BinaryTowerField<LEVEL> {
+    /// Number of bytes used to serialize an element. Fields smaller than a byte use one byte.
+    pub const NUM_BYTES: usize = Self::BITS.div_ceil(8);
+}
+
+impl<const LEVEL: usize> ByteConversion for FieldElement<BinaryTowerField<LEVEL>> {
+    #[cfg(feature = "std")]
+    fn to_bytes_be(&self) -> Vec<u8> {
+        self.value().to_be_bytes()[16 - BinaryTowerField::<LEVEL>::NUM_BYTES..].to_vec()
+    }
+
+    #[cfg(feature = "std")]
+    fn to_bytes_le(&self) -> Vec<u8> {
+        self.value().to_le_bytes()[..BinaryTowerField::<LEVEL>::NUM_BYTES].to_vec()
+    }
+
+    fn from_bytes_be(bytes: &[u8]) -> Result<Self, ByteConversionError> {
+        let num_bytes = BinaryTowerField::<LEVEL>::NUM_BYTES;
+        if bytes.len() != num_bytes {
+            return Err(ByteConversionError::FromBEBytesError);
+        }
+        let mut buffer = [0u8; 16];
+        buffer[16 - num_bytes..].copy_from_slice(bytes);
+        let value = u128::from_be_bytes(buffer);
+        if value & !BinaryTowerField::<LEVEL>::MASK != 0 {
+            return Err(ByteConversionError::InvalidValue);
+        }
+        Ok(Self::from_raw(value))
+    }
+
+    fn from_bytes_le(bytes: &[u8]) -> Result<Self, ByteConversionError> {
+        let num_bytes = BinaryTowerField::<LEVEL>::NUM_BYTES;
+        if bytes.len() != num_bytes {
+            return Err(ByteConversionError::FromLEBytesError);
+        }
+        let mut buffer = [0u8; 16];
+        buffer[..num_bytes].copy_from_slice(bytes);
+        let value = u128::from_le_bytes(buffer);
+        if value & !BinaryTowerField::<LEVEL>::MASK != 0 {
+            return Err(ByteConversionError::InvalidValue);
+        }
+        Ok(Self::from_raw(value))
+    }
+}
+
+impl<const LEVEL: usize> Display for FieldElement<BinaryTowerField<LEVEL>> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:x}", self.value())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    type FE128 = FieldElement<BinaryField128b>;
+    type FE64 = FieldElement<BinaryField64b>;
+    type FE16 = FieldElement<BinaryField16b>;
+    type FE8 = FieldElement<BinaryField8b>;
+    type FE2 = FieldElement<BinaryField2b>;
+
+    #[test]
+    fn generator_of_gf4_satisfies_its_quadratic() {
+        // X_0^2 + X_0 + 1 = 0
+        let x = FE2::from(2);
+        assert_eq!(x.square() + x + FE2::one(), FE2::zero());
+    }
+
+    #[test]
+    fn gf4_multiplication_table() {
+        // Elements are 0, 1, x = X_0, x + 1.
+        let x = FE2::from(2);
+        let x_plus_one = FE2::from(3);
+        assert_eq!(x * x, x_plus_one);
+        assert_eq!(x * x_plus_one, FE2::one());
+        assert_eq!(x_plus_one * x_plus_one, x);
+    }
+
+    #[test]
+    fn addition_is_xor_and_every_element_is_its_own_negation() {
+        let a = FE16::from(0x1234);
+        let b = FE16::from(0xabcd);
+        assert_eq!(a + b, FE16::from(0x1234 ^ 0xabcd));
+        assert_eq!(a - b, a + b);
+        assert_eq!(-a, a);
+        assert_eq!(a + a, FE16::zero());
+    }
+
+    #[test]
+    fn from_u64_discards_bits_beyond_the_field() {
+        assert_eq!(FE8::from(0x1ff).value(), &0xff);
+        assert_eq!(FieldElement::<BinaryField1b>::from(3).value(), &1);
+    }
+
+    #[test]
+    fn multiplication_in_gf2_16_matches_reference_vector() {
+        assert_eq!(FE16::from(0x1234) * FE16::from(0xabcd), FE16::from(0xcf0c));
+    }
+
+    #[test]
+    fn multiplication_in_gf2_128_matches_reference_vector() {
+        let a = FE128::new(0x0123456789abcdeffedcba9876543210);
+        let b = FE128::new(0xdeadbeefcafebabe0011223344556677);
+        assert_eq!(a * b, FE128::new(0x580ff342efaa9721183b8d0f6deecdc5));
+    }
+
+    #[test]
+    fn clmul_multiplication_matches_tower_formulas() {
+        let a = 0x0123456789abcdeffedcba9876543210;
+        let b = 0xdeadbeefcafebabe0011223344556677;
+        assert_eq!(mul(a, b, 7), tower_mul(a, b, 7));
+        let (a, b) = (a & BinaryField64b::MASK, b & BinaryField64b::MASK);
+        assert_eq!(mul(a, b, 6), tower_mul(a, b, 6));
+    }
+
+    #[test]
+    fn inverse_of_zero_is_an_error() {
+        assert!(FE128::zero().inv().is_err());
+    }
+
+    #[test]
+    fn every_element_of_gf2_8_times_its_inverse_is_one() {
+        for x in 1..256 {
+            let x = FE8::from(x);
+            assert_eq!(x * x.inv().unwrap(), FE8::one());
+        }
+    }
+
+    #[test]
+    fn multiplicative_group_of_gf2_16_has_order_65535() {
+        let x = FE16::from(0xbeef);
+        assert_eq!(x.pow(65535u64), FE16::one());
+    }
+
+    #[test]
+    fn subfields_are_closed_under_multiplication() {
+        let a = FE128::from(0xab);
+        let b = FE128::from(0x7f);
+        assert_eq!((a * b).value(), (FE8::from(0xab) * FE8::from(0x7f)).value());
+    }
+
+    #[test]
+    fn multiplication_by_subfield_element_matches_multiplication_in_the_extension() {
+        let a = FE16::from(0x1234);
+        let b = FE128::new(0xdeadbeefcafebabe0011223344556677);
+        assert_eq!(a * b, FE128::new(*a.value()) * b);
+        assert_eq!(a + b, FE128::new(*a.value()) + b);
+        assert_eq!(a - b, FE128::new(*a.value()) - b);
+        assert_eq!(a / b, FE128::new(*a.value()) / b);
+    }
+
+    #[test]
+    fn to_subfield_vec_splits_into_coordinates() {
+        let coordinates = <BinaryField32b as IsSubFieldOf<BinaryField128b>>::to_subfield_vec(
+            0x0123456789abcdeffedcba9876543210,
+        );
+        assert_eq!(
+            coordinates,
+            vec![0x76543210, 0xfedcba98, 0x89abcdef, 0x01234567]
+        );
+    }
+
+    #[test]
+    fn byte_conversion_roundtrip() {
+        let x = FE64::from(0x0123456789abcdef);
+        assert_eq!(
+            x.to_bytes_be(),
+            0x0123456789abcdefu64.to_be_bytes().to_vec()
+        );
+        assert_eq!(FE64::from_bytes_be(&x.to_bytes_be()).unwrap(), x);
+        assert_eq!(FE64::from_bytes_le(&x.to_bytes_le()).unwrap(), x);
+        let bit = FieldElement::<BinaryField1b>::one();
+        assert_eq!(bit.to_bytes_le(), vec![1]);
+        assert!(FieldElement::<BinaryField1b>::from_bytes_le(&[2]).is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn multiplication_is_commutative_and_associative(a: u128, b: u128, c: u128) {
+            let (a, b, c) = (FE128::new(a), FE128::new(b), FE128::new(c));
+            prop_assert_eq!(a * b, b * a);
+            prop_assert_eq!((a * b) * c, a * (b * c));
+        }
+
+        #[test]
+        fn multiplication_distributes_over_addition(a: u128, b: u128, c: u128) {
+            let (a, b, c) = (FE128::new(a), FE128::new(b), FE128::new(c));
+            prop_assert_eq!(a * (b + c), a * b + a * c);
+        }
+
+        #[test]
+        fn square_matches_multiplication(a: u128) {
+            let a = FE128::new(a);
+            prop_assert_eq!(a.square(), a * a);
+            let a = FE16::new(*a.value());
+            prop_assert_eq!(a.square(), a * a);
+        }
+
+        #[test]
+        fn inverse_in_every_level(a in 1u128..) {
+            let a128 = FE128::new(a);
+            prop_assert_eq!(a128 * a128.inv().unwrap(), FE128::one());
+            let a64 = FE64::new(a);
+            if a64 != FE64::zero() {
+                prop_assert_eq!(a64 * a64.inv().unwrap(), FE64::one());
+            }
+            let a16 = FE16::new(a);
+            if a16 != FE16::zero() {
+                prop_assert_eq!(a16 * a16.inv().unwrap(), FE16::one());
+            }
+        }
+
+        #[test]
+        fn frobenius_has_order_128(a: u128) {
+            let a = FE128::new(a);
+            let frobenius = (0..128).fold(a, |x, _| x.square());
+            prop_assert_eq!(frobenius, a);
+        }
+    }
+}
@@ -2,7 +2,7 @@ use crate::{
     field::{
         element::FieldElement,
         fields::montgomery_backed_prime_fields::{IsModulus, MontgomeryBackendPrimeField},
-        traits::IsFFTField,
+        traits::{IsFFTField, IsSmoothFFTField},
     },
     unsigned_integer::element::{UnsignedInteger, U64},
 };
@@ -35,6 +35,14 @@ impl IsFFTField for Babybear31PrimeField {
     }
 }
 
+// The whole multiplicative group, of order 2^27 * 3 * 5, is smooth and generated by 31.
+#[cfg(any(not(feature = "metal"), not(feature = "cuda")))]
+impl IsSmoothFFTField for Babybear31PrimeField {
+    const SMOOTH_SUBGROUP_EXPONENTS: [u64; 3] = [27, 1, 1];
+
+    const SMOOTH_SUBGROUP_GENERATOR: Self::BaseType = UnsignedInteger { limbs: [31] };
+}
+
 impl FieldElement<Babybear31PrimeField> {
     pub fn to_bytes_le(&self) -> [u8; 8] {
         let limbs = self.representative().limbs;
@@ -3,6 +3,7 @@ use crate::{
     field::{
         element::FieldElement,
         errors::FieldError,
+        packed::{impl_packed_batch_operations, mersenne31::PackedMersenne31},
         traits::{IsField, IsPrimeField},
     },
 };
@@ -145,6 +146,8 @@ impl IsField for Mersenne31Field {
     fn from_base_type(x: u32) -> u32 {
         Self::weak_reduce(x)
     }
+
+    impl_packed_batch_operations!(PackedMersenne31);
 }
 
 impl IsPrimeField for Mersenne31Field {
@@ -1,3 +1,7 @@
+/// Implementation of prime fields with Barrett reduction.
+pub mod barrett_backed_prime_fields;
+/// Implementation of binary tower fields, of characteristic two.
+pub mod binary;
 /// Implementation of two-adic prime fields to use with the Fast Fourier Transform (FFT).
 pub mod fft_friendly;
 /// Implementation of the 32-bit Mersenne Prime field (p = 2^31 - 1)
@@ -7,6 +11,11 @@ pub mod montgomery_backed_prime_fields;
 pub mod p448_goldilocks_prime_field;
 /// Implemenation of Pallas field
 pub mod pallas_field;
+/// Implementation of prime fields whose modulus is chosen at runtime.
+#[cfg(feature = "std")]
+pub mod runtime_prime_field;
+/// Implementation of prime fields of the form 2^n - c with Solinas reduction.
+pub mod solinas_backed_prime_fields;
 /// Implementation of the u64 Goldilocks Prime field (p = 2^64 - 2^32 + 1)
 pub mod u64_goldilocks_field;
 /// Implementation of prime fields over 64 bit unsigned integers.
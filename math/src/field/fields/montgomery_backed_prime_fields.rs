@@ -1,6 +1,7 @@
 use crate::field::element::FieldElement;
 use crate::field::errors::FieldError;
-use crate::field::traits::IsPrimeField;
+use crate::field::packed::{self, montgomery31::PackedMontgomery31, scalar_batch};
+use crate::field::traits::{IsFFTField, IsPrimeField};
 use crate::traits::{ByteConversion, Serializable};
 use crate::{
     field::traits::IsField, unsigned_integer::element::UnsignedInteger,
@@ -10,6 +11,8 @@ use crate::{
 use core::fmt::Debug;
 use core::marker::PhantomData;
 
+pub use lambdaworks_math_macros::montgomery_prime_field;
+
 pub type U384PrimeField<M> = MontgomeryBackendPrimeField<M, 6>;
 pub type U256PrimeField<M> = MontgomeryBackendPrimeField<M, 4>;
 pub type U64PrimeField<M> = MontgomeryBackendPrimeField<M, 1>;
@@ -21,6 +24,18 @@ pub trait IsModulus<U>: Debug {
     const MODULUS: U;
 }
 
+/// Two-adicity data of a modulus. Implementing it for a modulus `M` makes the corresponding
+/// `MontgomeryBackendPrimeField` an `IsFFTField`. This is what `montgomery_prime_field` generates,
+/// and it allows fields declared outside of this crate to use the FFT.
+pub trait IsFFTModulus<U>: IsModulus<U> {
+    const TWO_ADICITY: u64;
+    const TWO_ADIC_PRIMITVE_ROOT_OF_UNITY: U;
+
+    fn field_name() -> &'static str {
+        ""
+    }
+}
+
 #[cfg_attr(
     any(
         feature = "lambdaworks-serde-binary",
@@ -37,8 +52,9 @@ impl<M, const NUM_LIMBS: usize> MontgomeryBackendPrimeField<M, NUM_LIMBS>
 where
     M: IsModulus<UnsignedInteger<NUM_LIMBS>>,
 {
-    pub const R2: UnsignedInteger<NUM_LIMBS> = Self::compute_r2_parameter(&M::MODULUS);
-    pub const MU: u64 = Self::compute_mu_parameter(&M::MODULUS);
+    pub const R2: UnsignedInteger<NUM_LIMBS> =
+        MontgomeryAlgorithms::compute_r2_parameter(&M::MODULUS);
+    pub const MU: u64 = MontgomeryAlgorithms::compute_mu_parameter(&M::MODULUS);
     pub const ZERO: UnsignedInteger<NUM_LIMBS> = UnsignedInteger::from_u64(0);
     pub const ONE: UnsignedInteger<NUM_LIMBS> = MontgomeryAlgorithms::cios(
         &UnsignedInteger::from_u64(1),
@@ -48,58 +64,6 @@ where
     );
     const MODULUS_HAS_ONE_SPARE_BIT: bool = Self::modulus_has_one_spare_bit();
 
-    /// Computes `- modulus^{-1} mod 2^{64}`
-    /// This algorithm is given  by Dussé and Kaliski Jr. in
-    /// "S. R. Dussé and B. S. Kaliski Jr. A cryptographic library for the Motorola
-    /// DSP56000. In I. Damgård, editor, Advances in Cryptology – EUROCRYPT’90,
-    /// volume 473 of Lecture Notes in Computer Science, pages 230–244. Springer,
-    /// Heidelberg, May 1991."
-    const fn compute_mu_parameter(modulus: &UnsignedInteger<NUM_LIMBS>) -> u64 {
-        let mut y = 1;
-        let word_size = 64;
-        let mut i: usize = 2;
-        while i <= word_size {
-            let (_, lo) = UnsignedInteger::mul(modulus, &UnsignedInteger::from_u64(y));
-            let least_significant_limb = lo.limbs[NUM_LIMBS - 1];
-            if (least_significant_limb << (word_size - i)) >> (word_size - i) != 1 {
-                y += 1 << (i - 1);
-            }
-            i += 1;
-        }
-        y.wrapping_neg()
-    }
-
-    /// Computes 2^{384 * 2} modulo `modulus`
-    const fn compute_r2_parameter(
-        modulus: &UnsignedInteger<NUM_LIMBS>,
-    ) -> UnsignedInteger<NUM_LIMBS> {
-        let word_size = 64;
-        let mut l: usize = 0;
-        let zero = UnsignedInteger::from_u64(0);
-        // Define `c` as the largest power of 2 smaller than `modulus`
-        while l < NUM_LIMBS * word_size {
-            if UnsignedInteger::const_ne(&modulus.const_shr(l), &zero) {
-                break;
-            }
-            l += 1;
-        }
-        let mut c = UnsignedInteger::from_u64(1).const_shl(l);
-
-        // Double `c` and reduce modulo `modulus` until getting
-        // `2^{2 * number_limbs * word_size}` mod `modulus`
-        let mut i: usize = 1;
-        while i <= 2 * NUM_LIMBS * word_size - l {
-            let (double_c, overflow) = UnsignedInteger::add(&c, &c);
-            c = if UnsignedInteger::const_le(modulus, &double_c) || overflow {
-                UnsignedInteger::sub(&double_c, modulus).0
-            } else {
-                double_c
-            };
-            i += 1;
-        }
-        c
-    }
-
     /// Checks whether the most significant limb of the modulus is at
     /// most `0x7FFFFFFFFFFFFFFE`. This check is useful since special
     /// optimizations exist for this kind of moduli.
@@ -107,6 +71,11 @@ where
     const fn modulus_has_one_spare_bit() -> bool {
         M::MODULUS.limbs[0] < (1u64 << 63) - 1
     }
+
+    /// Batch operations use `PackedMontgomery31` when the modulus is a single limb below 2^31,
+    /// as for BabyBear.
+    const HAS_PACKED_ARITHMETIC: bool =
+        NUM_LIMBS == 1 && M::MODULUS.limbs[NUM_LIMBS - 1] < (1u64 << 31);
 }
 
 impl<M, const NUM_LIMBS: usize> IsField for MontgomeryBackendPrimeField<M, NUM_LIMBS>
@@ -276,6 +245,71 @@ where
     fn from_base_type(x: Self::BaseType) -> Self::BaseType {
         MontgomeryAlgorithms::cios(&x, &Self::R2, &M::MODULUS, &Self::MU)
     }
+
+    fn add_assign_batch(a: &mut [FieldElement<Self>], b: &[FieldElement<Self>]) {
+        if Self::HAS_PACKED_ARITHMETIC {
+            packed::add_assign_batch::<PackedMontgomery31<M, NUM_LIMBS>>(
+                UnsignedInteger::slice_as_limbs_mut(FieldElement::slice_as_raw_mut(a)),
+                UnsignedInteger::slice_as_limbs(FieldElement::slice_as_raw(b)),
+            )
+        } else {
+            scalar_batch::add_assign(a, b)
+        }
+    }
+
+    fn sub_assign_batch(a: &mut [FieldElement<Self>], b: &[FieldElement<Self>]) {
+        if Self::HAS_PACKED_ARITHMETIC {
+            packed::sub_assign_batch::<PackedMontgomery31<M, NUM_LIMBS>>(
+                UnsignedInteger::slice_as_limbs_mut(FieldElement::slice_as_raw_mut(a)),
+                UnsignedInteger::slice_as_limbs(FieldElement::slice_as_raw(b)),
+            )
+        } else {
+            scalar_batch::sub_assign(a, b)
+        }
+    }
+
+    fn mul_assign_batch(a: &mut [FieldElement<Self>], b: &[FieldElement<Self>]) {
+        if Self::HAS_PACKED_ARITHMETIC {
+            packed::mul_assign_batch::<PackedMontgomery31<M, NUM_LIMBS>>(
+                UnsignedInteger::slice_as_limbs_mut(FieldElement::slice_as_raw_mut(a)),
+                UnsignedInteger::slice_as_limbs(FieldElement::slice_as_raw(b)),
+            )
+        } else {
+            scalar_batch::mul_assign(a, b)
+        }
+    }
+
+    fn mul_add_scalar_batch(
+        a: &mut [FieldElement<Self>],
+        b: &[FieldElement<Self>],
+        c: &FieldElement<Self>,
+    ) {
+        if Self::HAS_PACKED_ARITHMETIC {
+            packed::mul_add_scalar_batch::<PackedMontgomery31<M, NUM_LIMBS>>(
+                UnsignedInteger::slice_as_limbs_mut(FieldElement::slice_as_raw_mut(a)),
+                UnsignedInteger::slice_as_limbs(FieldElement::slice_as_raw(b)),
+                c.value().limbs[NUM_LIMBS - 1],
+            )
+        } else {
+            scalar_batch::mul_add_scalar(a, b, c)
+        }
+    }
+
+    fn butterfly_batch(
+        lo: &mut [FieldElement<Self>],
+        hi: &mut [FieldElement<Self>],
+        w: &FieldElement<Self>,
+    ) {
+        if Self::HAS_PACKED_ARITHMETIC {
+            packed::butterfly_batch::<PackedMontgomery31<M, NUM_LIMBS>>(
+                UnsignedInteger::slice_as_limbs_mut(FieldElement::slice_as_raw_mut(lo)),
+                UnsignedInteger::slice_as_limbs_mut(FieldElement::slice_as_raw_mut(hi)),
+                w.value().limbs[NUM_LIMBS - 1],
+            )
+        } else {
+            scalar_batch::butterfly(lo, hi, w)
+        }
+    }
 }
 
 impl<M, const NUM_LIMBS: usize> IsPrimeField for MontgomeryBackendPrimeField<M, NUM_LIMBS>
@@ -290,8 +324,8 @@ where
 
     fn field_bit_size() -> usize {
         let mut evaluated_bit = NUM_LIMBS * 64 - 1;
-        let max_element = M::MODULUS - UnsignedInteger::<NUM_LIMBS>::from_u128(1);
-        let one = UnsignedInteger::from_u128(1);
+        let max_element = M::MODULUS - UnsignedInteger::<NUM_LIMBS>::from_u64(1);
+        let one = UnsignedInteger::from_u64(1);
 
         while ((max_element >> evaluated_bit) & one) != one {
             evaluated_bit -= 1;
@@ -311,6 +345,18 @@ where
     }
 }
 
+impl<M, const NUM_LIMBS: usize> IsFFTField for MontgomeryBackendPrimeField<M, NUM_LIMBS>
+where
+    M: IsFFTModulus<UnsignedInteger<NUM_LIMBS>> + Clone + Debug,
+{
+    const TWO_ADICITY: u64 = M::TWO_ADICITY;
+    const TWO_ADIC_PRIMITVE_ROOT_OF_UNITY: Self::BaseType = M::TWO_ADIC_PRIMITVE_ROOT_OF_UNITY;
+
+    fn field_name() -> &'static str {
+        M::field_name()
+    }
+}
+
 impl<M, const NUM_LIMBS: usize> FieldElement<MontgomeryBackendPrimeField<M, NUM_LIMBS>> where
     M: IsModulus<UnsignedInteger<NUM_LIMBS>> + Clone + Debug
 {
@@ -1148,4 +1194,89 @@ mod tests_u256_prime_fields {
         let expected_sum = GoldilocksElement::from(4638951561171426781);
         assert_eq!(a + b, expected_sum);
     }
+
+    #[test]
+    fn goldilocks_uses_64_bits() {
+        assert_eq!(GoldilocksField::field_bit_size(), 64);
+    }
+}
+
+#[cfg(test)]
+mod tests_montgomery_prime_field_macro {
+    use super::montgomery_prime_field;
+    use crate::field::element::FieldElement;
+    use crate::field::fields::fft_friendly::{
+        babybear::Babybear31PrimeField, stark_252_prime_field::Stark252PrimeField,
+    };
+    use crate::field::traits::IsFFTField;
+    use crate::unsigned_integer::element::UnsignedInteger;
+
+    #[montgomery_prime_field(modulus = 2013265921, generator = 31, field = MacroBabybear)]
+    pub struct MacroBabybearConfig;
+
+    #[montgomery_prime_field(
+        modulus = "0x800000000000011000000000000000000000000000000000000000000000001",
+        field = MacroStark252,
+        name = "stark256"
+    )]
+    pub struct MacroStark252Config;
+
+    #[test]
+    fn macro_computes_the_number_of_limbs() {
+        assert_eq!(MacroBabybearConfig::NUM_LIMBS, 1);
+        assert_eq!(MacroStark252Config::NUM_LIMBS, 4);
+    }
+
+    #[test]
+    fn macro_constants_match_the_backend() {
+        assert_eq!(MacroStark252Config::R2, Stark252PrimeField::R2);
+        assert_eq!(MacroStark252Config::MU, Stark252PrimeField::MU);
+        assert_eq!(MacroBabybearConfig::R2, Babybear31PrimeField::R2);
+        assert_eq!(
+            MacroBabybearConfig::GENERATOR,
+            UnsignedInteger::from_u64(31)
+        );
+    }
+
+    #[test]
+    fn macro_computes_the_two_adicity() {
+        assert_eq!(MacroBabybear::TWO_ADICITY, 27);
+        assert_eq!(MacroStark252::TWO_ADICITY, 192);
+        assert_eq!(MacroStark252::field_name(), "stark256");
+    }
+
+    #[test]
+    fn macro_two_adic_root_of_unity_is_primitive() {
+        let root = MacroStark252::get_primitive_root_of_unity(MacroStark252::TWO_ADICITY).unwrap();
+        let half_order = (0..MacroStark252::TWO_ADICITY - 1).fold(root, |acc, _| acc.square());
+        assert_eq!(half_order, -FieldElement::<MacroStark252>::one());
+        assert_eq!(half_order.square(), FieldElement::one());
+    }
+
+    #[test]
+    fn macro_field_arithmetic_matches_existing_field() {
+        let a = FieldElement::<MacroBabybear>::from(1234567890);
+        let b = FieldElement::<MacroBabybear>::from(987654321);
+        let c = FieldElement::<Babybear31PrimeField>::from(1234567890);
+        let d = FieldElement::<Babybear31PrimeField>::from(987654321);
+        assert_eq!((a * b).representative(), (&c * &d).representative());
+        assert_eq!((a / b).representative(), (&c / &d).representative());
+        assert_eq!((-a).representative(), (-c).representative());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn macro_field_can_be_used_with_fft() {
+        use crate::polynomial::Polynomial;
+
+        let poly = Polynomial::new(&[
+            FieldElement::<MacroBabybear>::from(1),
+            FieldElement::from(2),
+            FieldElement::from(3),
+            FieldElement::from(4),
+        ]);
+        let evaluations = Polynomial::evaluate_fft::<MacroBabybear>(&poly, 4, None).unwrap();
+        let interpolated = Polynomial::interpolate_fft::<MacroBabybear>(&evaluations).unwrap();
+        assert_eq!(interpolated, poly);
+    }
 }
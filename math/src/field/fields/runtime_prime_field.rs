@@ -0,0 +1,764 @@
+use crate::field::element::FieldElement;
+use crate::field::errors::{FieldError, RuntimeModulusError};
+use crate::field::traits::{IsField, IsPrimeField, IsRuntimeFFTField};
+use crate::traits::{ByteConversion, Serializable};
+use crate::unsigned_integer::{
+    element::UnsignedInteger, modular::ModularAlgorithms, montgomery::MontgomeryAlgorithms,
+};
+
+use core::fmt::{self, Debug};
+use core::hash::{BuildHasher, Hasher};
+use core::iter;
+use core::marker::PhantomData;
+use core::ptr;
+use core::sync::atomic::{AtomicPtr, Ordering};
+use std::collections::hash_map::RandomState;
+
+pub type U384RuntimePrimeField<M> = RuntimePrimeField<M, 6>;
+pub type U256RuntimePrimeField<M> = RuntimePrimeField<M, 4>;
+pub type U64RuntimePrimeField<M> = RuntimePrimeField<M, 1>;
+
+/// Bases of the Miller-Rabin test run on every new modulus. No composite below
+/// 318665857834031151167461, which is above 2^78, passes the test with all of them.
+const MILLER_RABIN_BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+/// Moduli of at most this many bits are proven prime by the fixed bases alone.
+const MILLER_RABIN_DETERMINISTIC_BITS: usize = 78;
+
+/// Number of random bases tried on larger moduli. A composite passes each one with probability
+/// at most 1/4, so it's accepted with probability at most 2^-64.
+const MILLER_RABIN_RANDOM_ROUNDS: usize = 32;
+
+/// Montgomery parameters of a prime modulus known only at runtime. They're computed once by
+/// `new`, which also finds the two-adicity of the field and a primitive root of unity of that
+/// order, so that the FFT can be used whenever the modulus is two-adic.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RuntimeModulus<const NUM_LIMBS: usize> {
+    modulus: UnsignedInteger<NUM_LIMBS>,
+    r2: UnsignedInteger<NUM_LIMBS>,
+    mu: u64,
+    one: UnsignedInteger<NUM_LIMBS>,
+    bits: usize,
+    has_one_spare_bit: bool,
+    two_adicity: u64,
+    two_adic_primitive_root_of_unity: UnsignedInteger<NUM_LIMBS>,
+}
+
+impl<const NUM_LIMBS: usize> RuntimeModulus<NUM_LIMBS> {
+    /// Computes the parameters of the field of integers modulo `modulus`, which must be an odd
+    /// prime.
+    ///
+    /// Primality is checked with the Miller-Rabin test. Moduli of up to 78 bits are checked
+    /// deterministically. Larger ones are also tested with random bases, so a composite,
+    /// including one built to pass fixed bases, is accepted with probability at most 2^-64.
+    /// This is not a proof of primality.
+    pub fn new(modulus: UnsignedInteger<NUM_LIMBS>) -> Result<Self, RuntimeModulusError> {
+        let zero = UnsignedInteger::from_u64(0);
+        let one = UnsignedInteger::from_u64(1);
+        if modulus.limbs[NUM_LIMBS - 1] & 1 == 0 || modulus == one {
+            return Err(RuntimeModulusError::NotAnOddPrime);
+        }
+
+        let mut params = Self {
+            modulus,
+            r2: MontgomeryAlgorithms::compute_r2_parameter(&modulus),
+            mu: MontgomeryAlgorithms::compute_mu_parameter(&modulus),
+            one: zero,
+            bits: modulus.bits_le(),
+            has_one_spare_bit: modulus.limbs[0] < (1u64 << 63) - 1,
+            two_adicity: 0,
+            two_adic_primitive_root_of_unity: zero,
+        };
+        params.one = params.to_montgomery(&one);
+
+        // modulus - 1 = odd_part * 2^two_adicity
+        let modulus_minus_one = modulus - one;
+        let mut two_adicity = 0;
+        while two_adicity < 64 * NUM_LIMBS && !bit(&modulus_minus_one, two_adicity) {
+            two_adicity += 1;
+        }
+        let odd_part = modulus_minus_one >> two_adicity;
+        params.two_adicity = two_adicity as u64;
+
+        if !params.passes_miller_rabin(&odd_part) {
+            return Err(RuntimeModulusError::NotAnOddPrime);
+        }
+
+        // Any quadratic non-residue `g` gives a primitive root of unity of order
+        // 2^two_adicity as g^odd_part.
+        let minus_one = params.neg(&params.one);
+        let half = modulus_minus_one >> 1;
+        let mut candidate = 2;
+        let non_residue = loop {
+            let g = params.to_montgomery(&UnsignedInteger::from_u64(candidate));
+            if params.pow(&g, &half) == minus_one {
+                break g;
+            }
+            candidate += 1;
+        };
+        params.two_adic_primitive_root_of_unity = params.pow(&non_residue, &odd_part);
+        Ok(params)
+    }
+
+    /// Computes the parameters of the runtime field with the same modulus as the static field `F`.
+    pub fn from_static_field<F>() -> Result<Self, RuntimeModulusError>
+    where
+        F: IsPrimeField<RepresentativeType = UnsignedInteger<NUM_LIMBS>>,
+    {
+        Self::new(static_modulus::<F, NUM_LIMBS>())
+    }
+
+    pub fn modulus(&self) -> &UnsignedInteger<NUM_LIMBS> {
+        &self.modulus
+    }
+
+    /// Bit size of the modulus.
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Largest `k` such that `2^k` divides `modulus - 1`.
+    pub fn two_adicity(&self) -> u64 {
+        self.two_adicity
+    }
+
+    #[inline(always)]
+    fn mul(
+        &self,
+        a: &UnsignedInteger<NUM_LIMBS>,
+        b: &UnsignedInteger<NUM_LIMBS>,
+    ) -> UnsignedInteger<NUM_LIMBS> {
+        if self.has_one_spare_bit {
+            MontgomeryAlgorithms::cios_optimized_for_moduli_with_one_spare_bit(
+                a,
+                b,
+                &self.modulus,
+                &self.mu,
+            )
+        } else {
+            MontgomeryAlgorithms::cios(a, b, &self.modulus, &self.mu)
+        }
+    }
+
+    #[inline(always)]
+    fn neg(&self, a: &UnsignedInteger<NUM_LIMBS>) -> UnsignedInteger<NUM_LIMBS> {
+        ModularAlgorithms::neg(a, &self.modulus)
+    }
+
+    #[inline(always)]
+    fn to_montgomery(&self, x: &UnsignedInteger<NUM_LIMBS>) -> UnsignedInteger<NUM_LIMBS> {
+        MontgomeryAlgorithms::cios(x, &self.r2, &self.modulus, &self.mu)
+    }
+
+    #[inline(always)]
+    fn to_canonical(&self, x: &UnsignedInteger<NUM_LIMBS>) -> UnsignedInteger<NUM_LIMBS> {
+        MontgomeryAlgorithms::cios(x, &UnsignedInteger::from_u64(1), &self.modulus, &self.mu)
+    }
+
+    /// Square and multiply exponentiation of an element in Montgomery form.
+    fn pow(
+        &self,
+        base: &UnsignedInteger<NUM_LIMBS>,
+        exponent: &UnsignedInteger<NUM_LIMBS>,
+    ) -> UnsignedInteger<NUM_LIMBS> {
+        let mut result = self.one;
+        for i in (0..exponent.bits_le()).rev() {
+            result = MontgomeryAlgorithms::sos_square(&result, &self.modulus, &self.mu);
+            if bit(exponent, i) {
+                result = self.mul(&result, base);
+            }
+        }
+        result
+    }
+
+    /// Miller-Rabin test with the fixed bases and, for large moduli, random ones, where
+    /// `odd_part` is `(modulus - 1) / 2^two_adicity`.
+    fn passes_miller_rabin(&self, odd_part: &UnsignedInteger<NUM_LIMBS>) -> bool {
+        let passes_fixed_bases = MILLER_RABIN_BASES.iter().all(|&base| {
+            let base = UnsignedInteger::from_u64(base);
+            base >= self.modulus || self.is_strong_probable_prime(&base, odd_part)
+        });
+        if !passes_fixed_bases {
+            return false;
+        }
+        if self.bits <= MILLER_RABIN_DETERMINISTIC_BITS {
+            return true;
+        }
+
+        let mut random_bases = self.random_bases();
+        (0..MILLER_RABIN_RANDOM_ROUNDS).all(|_| {
+            let base = random_bases.next().unwrap();
+            self.is_strong_probable_prime(&base, odd_part)
+        })
+    }
+
+    /// Checks that the modulus is a strong probable prime to base `base`, which is in canonical
+    /// form and below the modulus.
+    fn is_strong_probable_prime(
+        &self,
+        base: &UnsignedInteger<NUM_LIMBS>,
+        odd_part: &UnsignedInteger<NUM_LIMBS>,
+    ) -> bool {
+        let minus_one = self.neg(&self.one);
+        let mut x = self.pow(&self.to_montgomery(base), odd_part);
+        if x == self.one || x == minus_one {
+            return true;
+        }
+        for _ in 1..self.two_adicity {
+            x = MontgomeryAlgorithms::sos_square(&x, &self.modulus, &self.mu);
+            if x == minus_one {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Returns uniformly random bases in `[2, modulus - 2]`, so that the moduli accepted can't
+    /// be chosen in advance to pass the test. The randomness comes from the keys that std
+    /// seeds from the operating system for `HashMap`, used through SipHash as a PRF.
+    fn random_bases(&self) -> impl Iterator<Item = UnsignedInteger<NUM_LIMBS>> + '_ {
+        let state = RandomState::new();
+        let mut counter = 0u64;
+        let mut random_limb = move || {
+            counter += 1;
+            let mut hasher = state.build_hasher();
+            hasher.write_u64(counter);
+            hasher.finish()
+        };
+        let two = UnsignedInteger::from_u64(2);
+        let max = self.modulus - two;
+        let shift = 64 * NUM_LIMBS - self.bits;
+        iter::repeat_with(move || {
+            let mut limbs = [0; NUM_LIMBS];
+            limbs.iter_mut().for_each(|limb| *limb = random_limb());
+            UnsignedInteger::from_limbs(limbs) >> shift
+        })
+        .filter(move |base| *base >= two && *base <= max)
+    }
+}
+
+/// Returns the `i`-th least significant bit of `x`.
+#[inline(always)]
+fn bit<const NUM_LIMBS: usize>(x: &UnsignedInteger<NUM_LIMBS>, i: usize) -> bool {
+    (x.limbs[NUM_LIMBS - 1 - i / 64] >> (i % 64)) & 1 == 1
+}
+
+/// Returns the modulus of a static prime field as `(-1) + 1`.
+fn static_modulus<F, const NUM_LIMBS: usize>() -> UnsignedInteger<NUM_LIMBS>
+where
+    F: IsPrimeField<RepresentativeType = UnsignedInteger<NUM_LIMBS>>,
+{
+    (-FieldElement::<F>::one()).representative() + UnsignedInteger::from_u64(1)
+}
+
+/// Holds the modulus of a `RuntimePrimeField`. It can be set again to load a different field,
+/// after which the elements created with the previous modulus are no longer meaningful.
+///
+/// The parameters of the moduli loaded are never freed, since other threads may still be using
+/// them. They're interned by modulus instead, so loading a modulus again reuses its parameters
+/// and the memory used only grows with the number of different moduli.
+pub struct RuntimeModulusSlot<const NUM_LIMBS: usize> {
+    params: AtomicPtr<RuntimeModulus<NUM_LIMBS>>,
+    // Head of the list of the parameters of every modulus loaded, newest first
+    interned: AtomicPtr<InternedModulus<NUM_LIMBS>>,
+}
+
+struct InternedModulus<const NUM_LIMBS: usize> {
+    params: RuntimeModulus<NUM_LIMBS>,
+    next: *const InternedModulus<NUM_LIMBS>,
+}
+
+impl<const NUM_LIMBS: usize> RuntimeModulusSlot<NUM_LIMBS> {
+    pub const fn new() -> Self {
+        Self {
+            params: AtomicPtr::new(ptr::null_mut()),
+            interned: AtomicPtr::new(ptr::null_mut()),
+        }
+    }
+
+    /// Loads `params`, reusing the parameters of its modulus if it was loaded before.
+    pub fn set(&self, params: RuntimeModulus<NUM_LIMBS>) -> &'static RuntimeModulus<NUM_LIMBS> {
+        let params = self.intern(params);
+        self.params
+            .store(ptr::from_ref(params).cast_mut(), Ordering::Release);
+        params
+    }
+
+    pub fn get(&self) -> Option<&'static RuntimeModulus<NUM_LIMBS>> {
+        // SAFETY: the pointer is either null or points to interned parameters, which live
+        // forever and are never mutated.
+        unsafe { self.params.load(Ordering::Acquire).as_ref() }
+    }
+
+    /// Returns the interned parameters with the modulus of `params`, adding them if there are
+    /// none. The parameters only depend on the modulus, so the ones found are the same.
+    fn intern(&self, params: RuntimeModulus<NUM_LIMBS>) -> &'static RuntimeModulus<NUM_LIMBS> {
+        let node = Box::into_raw(Box::new(InternedModulus {
+            params,
+            next: ptr::null(),
+        }));
+        let mut head = self.interned.load(Ordering::Acquire);
+        loop {
+            // SAFETY: `node` isn't shared until the exchange below succeeds, and the nodes in
+            // the list are never freed nor mutated once they're in it.
+            unsafe {
+                let modulus = (*node).params.modulus;
+                if let Some(found) = interned_params(head).find(|p| p.modulus == modulus) {
+                    drop(Box::from_raw(node));
+                    return found;
+                }
+                (*node).next = head;
+            }
+            match self.interned.compare_exchange_weak(
+                head,
+                node,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            ) {
+                // SAFETY: the node is in the list now, so it lives forever.
+                Ok(_) => return unsafe { &(*node).params },
+                Err(current) => head = current,
+            }
+        }
+    }
+}
+
+/// Iterates over the parameters in the list of interned moduli starting at `head`.
+///
+/// # Safety
+/// `head` must be null or a node of the list of a `RuntimeModulusSlot`.
+unsafe fn interned_params<const NUM_LIMBS: usize>(
+    head: *const InternedModulus<NUM_LIMBS>,
+) -> impl Iterator<Item = &'static RuntimeModulus<NUM_LIMBS>> {
+    iter::successors(head.as_ref(), |node| node.next.as_ref()).map(|node| &node.params)
+}
+
+impl<const NUM_LIMBS: usize> Default for RuntimeModulusSlot<NUM_LIMBS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Modulus of a `RuntimePrimeField`, chosen at runtime. Each type implementing it has its own
+/// slot, so several runtime fields can be used at the same time. Use `declare_runtime_modulus`
+/// to declare one.
+pub trait IsRuntimeModulus<const NUM_LIMBS: usize>: Clone + Debug {
+    fn slot() -> &'static RuntimeModulusSlot<NUM_LIMBS>;
+
+    /// Sets the modulus of the field.
+    fn set(params: RuntimeModulus<NUM_LIMBS>) -> &'static RuntimeModulus<NUM_LIMBS> {
+        Self::slot().set(params)
+    }
+
+    /// Returns the parameters of the field.
+    /// # Panics
+    /// Panics if the modulus hasn't been set.
+    #[inline(always)]
+    fn params() -> &'static RuntimeModulus<NUM_LIMBS> {
+        Self::slot()
+            .get()
+            .expect("The modulus of the runtime field has not been set")
+    }
+}
+
+/// Declares a type implementing `IsRuntimeModulus` with its own slot.
+/// ```
+/// use lambdaworks_math::declare_runtime_modulus;
+/// use lambdaworks_math::field::element::FieldElement;
+/// use lambdaworks_math::field::fields::runtime_prime_field::{
+///     IsRuntimeModulus, RuntimeModulus, U64RuntimePrimeField,
+/// };
+/// use lambdaworks_math::unsigned_integer::element::U64;
+///
+/// declare_runtime_modulus!(pub LoadedModulus, 1);
+///
+/// LoadedModulus::set(RuntimeModulus::new(U64::from_u64(2013265921)).unwrap());
+/// type FE = FieldElement<U64RuntimePrimeField<LoadedModulus>>;
+/// assert_eq!(FE::from(2013265920) + FE::one(), FE::zero());
+/// ```
+#[macro_export]
+macro_rules! declare_runtime_modulus {
+    ($vis:vis $name:ident, $num_limbs:expr) => {
+        #[derive(Clone, Debug, Hash, Copy, PartialEq, Eq)]
+        $vis struct $name;
+
+        impl $crate::field::fields::runtime_prime_field::IsRuntimeModulus<$num_limbs> for $name {
+            fn slot(
+            ) -> &'static $crate::field::fields::runtime_prime_field::RuntimeModulusSlot<$num_limbs>
+            {
+                static SLOT: $crate::field::fields::runtime_prime_field::RuntimeModulusSlot<
+                    $num_limbs,
+                > = $crate::field::fields::runtime_prime_field::RuntimeModulusSlot::new();
+                &SLOT
+            }
+        }
+    };
+}
+
+/// Prime field whose modulus is chosen at runtime, through the slot of `M`. Elements are kept
+/// in Montgomery form, as in `MontgomeryBackendPrimeField`, with the constants computed when the
+/// modulus is set. Each operation reads the current parameters from the slot.
+#[cfg_attr(
+    any(
+        feature = "lambdaworks-serde-binary",
+        feature = "lambdaworks-serde-string"
+    ),
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(Clone, Debug, Hash, Copy)]
+pub struct RuntimePrimeField<M, const NUM_LIMBS: usize> {
+    phantom: PhantomData<M>,
+}
+
+impl<M, const NUM_LIMBS: usize> IsField for RuntimePrimeField<M, NUM_LIMBS>
+where
+    M: IsRuntimeModulus<NUM_LIMBS>,
+{
+    type BaseType = UnsignedInteger<NUM_LIMBS>;
+
+    #[inline(always)]
+    fn add(a: &Self::BaseType, b: &Self::BaseType) -> Self::BaseType {
+        ModularAlgorithms::add(a, b, &M::params().modulus)
+    }
+
+    #[inline(always)]
+    fn mul(a: &Self::BaseType, b: &Self::BaseType) -> Self::BaseType {
+        M::params().mul(a, b)
+    }
+
+    #[inline(always)]
+    fn square(a: &Self::BaseType) -> Self::BaseType {
+        let params = M::params();
+        MontgomeryAlgorithms::sos_square(a, &params.modulus, &params.mu)
+    }
+
+    #[inline(always)]
+    fn sub(a: &Self::BaseType, b: &Self::BaseType) -> Self::BaseType {
+        ModularAlgorithms::sub(a, b, &M::params().modulus)
+    }
+
+    #[inline(always)]
+    fn neg(a: &Self::BaseType) -> Self::BaseType {
+        M::params().neg(a)
+    }
+
+    fn inv(a: &Self::BaseType) -> Result<Self::BaseType, FieldError> {
+        if *a == Self::zero() {
+            return Err(FieldError::InvZeroError);
+        }
+        // For `a = x * R`, the canonical inverse is `x^{-1} * R^{-1}`, and two
+        // multiplications by `R^2` bring it to `x^{-1} * R`.
+        let params = M::params();
+        let inverse = ModularAlgorithms::inv(a, &params.modulus);
+        Ok(params.to_montgomery(&params.to_montgomery(&inverse)))
+    }
+
+    #[inline(always)]
+    fn div(a: &Self::BaseType, b: &Self::BaseType) -> Self::BaseType {
+        Self::mul(a, &Self::inv(b).unwrap())
+    }
+
+    #[inline(always)]
+    fn eq(a: &Self::BaseType, b: &Self::BaseType) -> bool {
+        a == b
+    }
+
+    #[inline(always)]
+    fn zero() -> Self::BaseType {
+        UnsignedInteger::from_u64(0)
+    }
+
+    #[inline(always)]
+    fn one() -> Self::BaseType {
+        M::params().one
+    }
+
+    #[inline(always)]
+    fn from_u64(x: u64) -> Self::BaseType {
+        M::params().to_montgomery(&UnsignedInteger::from_u64(x))
+    }
+
+    #[inline(always)]
+    fn from_base_type(x: Self::BaseType) -> Self::BaseType {
+        M::params().to_montgomery(&x)
+    }
+}
+
+impl<M, const NUM_LIMBS: usize> IsPrimeField for RuntimePrimeField<M, NUM_LIMBS>
+where
+    M: IsRuntimeModulus<NUM_LIMBS>,
+{
+    type RepresentativeType = Self::BaseType;
+
+    fn representative(x: &Self::BaseType) -> Self::RepresentativeType {
+        M::params().to_canonical(x)
+    }
+
+    fn field_bit_size() -> usize {
+        M::params().bits
+    }
+
+    fn from_hex(hex_string: &str) -> Result<Self::BaseType, crate::errors::CreationError> {
+        let integer = Self::BaseType::from_hex(hex_string)?;
+        Ok(M::params().to_montgomery(&integer))
+    }
+}
+
+impl<M, const NUM_LIMBS: usize> IsRuntimeFFTField for RuntimePrimeField<M, NUM_LIMBS>
+where
+    M: IsRuntimeModulus<NUM_LIMBS>,
+{
+    fn two_adicity() -> u64 {
+        M::params().two_adicity
+    }
+
+    fn get_primitive_root_of_unity(order: u64) -> Result<FieldElement<Self>, FieldError> {
+        let params = M::params();
+        if order == 0 {
+            return Ok(FieldElement::one());
+        }
+        if order > params.two_adicity {
+            return Err(FieldError::RootOfUnityError(order));
+        }
+        let log_power = params.two_adicity - order;
+        let root = FieldElement::from_raw(params.two_adic_primitive_root_of_unity);
+        Ok((0..log_power).fold(root, |acc, _| acc.square()))
+    }
+}
+
+impl<M, const NUM_LIMBS: usize> FieldElement<RuntimePrimeField<M, NUM_LIMBS>>
+where
+    M: IsRuntimeModulus<NUM_LIMBS>,
+{
+    /// Converts an element of a static field with the same modulus.
+    pub fn from_static<F>(x: &FieldElement<F>) -> Result<Self, RuntimeModulusError>
+    where
+        F: IsPrimeField<RepresentativeType = UnsignedInteger<NUM_LIMBS>>,
+    {
+        if static_modulus::<F, NUM_LIMBS>() != M::params().modulus {
+            return Err(RuntimeModulusError::ModulusMismatch);
+        }
+        Ok(Self::new(x.representative()))
+    }
+
+    /// Converts the element to a static field with the same modulus.
+    pub fn to_static<F>(&self) -> Result<FieldElement<F>, RuntimeModulusError>
+    where
+        F: IsPrimeField<
+            BaseType = UnsignedInteger<NUM_LIMBS>,
+            RepresentativeType = UnsignedInteger<NUM_LIMBS>,
+        >,
+    {
+        if static_modulus::<F, NUM_LIMBS>() != M::params().modulus {
+            return Err(RuntimeModulusError::ModulusMismatch);
+        }
+        Ok(FieldElement::new(self.representative()))
+    }
+}
+
+impl<M, const NUM_LIMBS: usize> fmt::Display for FieldElement<RuntimePrimeField<M, NUM_LIMBS>>
+where
+    M: IsRuntimeModulus<NUM_LIMBS>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.representative())
+    }
+}
+
+impl<M, const NUM_LIMBS: usize> ByteConversion for FieldElement<RuntimePrimeField<M, NUM_LIMBS>>
+where
+    M: IsRuntimeModulus<NUM_LIMBS>,
+{
+    fn to_bytes_be(&self) -> Vec<u8> {
+        self.representative().to_bytes_be()
+    }
+
+    fn to_bytes_le(&self) -> Vec<u8> {
+        self.representative().to_bytes_le()
+    }
+
+    fn from_bytes_be(bytes: &[u8]) -> Result<Self, crate::errors::ByteConversionError> {
+        let value = UnsignedInteger::from_bytes_be(bytes)?;
+        Ok(Self::new(value))
+    }
+
+    fn from_bytes_le(bytes: &[u8]) -> Result<Self, crate::errors::ByteConversionError> {
+        let value = UnsignedInteger::from_bytes_le(bytes)?;
+        Ok(Self::new(value))
+    }
+}
+
+impl<M, const NUM_LIMBS: usize> Serializable for FieldElement<RuntimePrimeField<M, NUM_LIMBS>>
+where
+    M: IsRuntimeModulus<NUM_LIMBS>,
+{
+    fn serialize(&self) -> Vec<u8> {
+        self.value().to_bytes_be()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::fields::fft_friendly::babybear::Babybear31PrimeField;
+    use crate::field::fields::fft_friendly::stark_252_prime_field::Stark252PrimeField;
+    use crate::field::fields::fft_friendly::u64_goldilocks::U64GoldilocksPrimeField;
+    use crate::polynomial::Polynomial;
+    use crate::unsigned_integer::element::{U256, U64};
+    use proptest::prelude::*;
+
+    // Every test sets the same modulus in a given slot, since they run concurrently.
+    declare_runtime_modulus!(BabybearModulus, 1);
+    declare_runtime_modulus!(Stark252Modulus, 4);
+    declare_runtime_modulus!(Secp256k1Modulus, 4);
+    declare_runtime_modulus!(ReloadedModulus, 1);
+
+    type RuntimeBabybear = FieldElement<U64RuntimePrimeField<BabybearModulus>>;
+    type RuntimeStark252 = FieldElement<U256RuntimePrimeField<Stark252Modulus>>;
+    type RuntimeSecp256k1 = FieldElement<U256RuntimePrimeField<Secp256k1Modulus>>;
+
+    fn set_babybear() {
+        BabybearModulus::set(RuntimeModulus::from_static_field::<Babybear31PrimeField>().unwrap());
+    }
+
+    fn set_stark252() {
+        Stark252Modulus::set(RuntimeModulus::from_static_field::<Stark252PrimeField>().unwrap());
+    }
+
+    fn set_secp256k1() {
+        Secp256k1Modulus::set(
+            RuntimeModulus::new(U256::from_hex_unchecked(
+                "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f",
+            ))
+            .unwrap(),
+        );
+    }
+
+    #[test]
+    fn even_and_composite_moduli_are_rejected() {
+        for modulus in [1, 2, 15, 561, 2013265923] {
+            assert_eq!(
+                RuntimeModulus::new(U64::from_u64(modulus)),
+                Err(RuntimeModulusError::NotAnOddPrime)
+            );
+        }
+    }
+
+    #[test]
+    fn strong_pseudoprime_to_the_fixed_bases_is_rejected() {
+        // 399165290221 * 798330580441 passes the Miller-Rabin test with the first 12 primes
+        assert_eq!(
+            RuntimeModulus::new(U256::from_hex_unchecked("437ae92817f9fc85b7e5")),
+            Err(RuntimeModulusError::NotAnOddPrime)
+        );
+    }
+
+    #[test]
+    fn parameters_match_the_static_field() {
+        let params = RuntimeModulus::from_static_field::<Stark252PrimeField>().unwrap();
+        assert_eq!(params.r2, Stark252PrimeField::R2);
+        assert_eq!(params.mu, Stark252PrimeField::MU);
+        assert_eq!(params.two_adicity(), 192);
+        assert_eq!(params.bits(), 252);
+    }
+
+    #[test]
+    fn two_adicity_of_small_primes() {
+        assert_eq!(
+            RuntimeModulus::new(U64::from_u64(3)).unwrap().two_adicity(),
+            1
+        );
+        assert_eq!(
+            RuntimeModulus::new(U64::from_u64(97))
+                .unwrap()
+                .two_adicity(),
+            5
+        );
+        let goldilocks = RuntimeModulus::from_static_field::<U64GoldilocksPrimeField>().unwrap();
+        assert_eq!(goldilocks.two_adicity(), 32);
+    }
+
+    #[test]
+    fn two_adic_root_of_unity_is_primitive() {
+        set_babybear();
+        assert_eq!(U64RuntimePrimeField::<BabybearModulus>::two_adicity(), 27);
+        let root =
+            U64RuntimePrimeField::<BabybearModulus>::get_primitive_root_of_unity(27).unwrap();
+        let half_order = (0..26).fold(root, |acc, _| acc.square());
+        assert_eq!(half_order, -RuntimeBabybear::one());
+        assert!(U64RuntimePrimeField::<BabybearModulus>::get_primitive_root_of_unity(28).is_err());
+    }
+
+    #[test]
+    fn loading_a_modulus_again_reuses_its_parameters() {
+        let babybear = || RuntimeModulus::from_static_field::<Babybear31PrimeField>().unwrap();
+        let goldilocks = || RuntimeModulus::from_static_field::<U64GoldilocksPrimeField>().unwrap();
+
+        let first = ReloadedModulus::set(babybear());
+        let other = ReloadedModulus::set(goldilocks());
+        assert!(!ptr::eq(first, other));
+        for _ in 0..3 {
+            assert!(ptr::eq(ReloadedModulus::set(babybear()), first));
+            assert!(ptr::eq(ReloadedModulus::set(goldilocks()), other));
+        }
+        assert!(ptr::eq(ReloadedModulus::params(), other));
+    }
+
+    #[test]
+    fn conversion_from_a_field_with_another_modulus_fails() {
+        set_babybear();
+        let x = FieldElement::<U64GoldilocksPrimeField>::from(5);
+        assert_eq!(
+            RuntimeBabybear::from_static(&x),
+            Err(RuntimeModulusError::ModulusMismatch)
+        );
+        assert!(RuntimeBabybear::from(5)
+            .to_static::<U64GoldilocksPrimeField>()
+            .is_err());
+    }
+
+    #[test]
+    fn inverse_of_full_width_modulus() {
+        set_secp256k1();
+        let x = RuntimeSecp256k1::new(U256::from_hex_unchecked(
+            "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2e",
+        ));
+        assert_eq!(x.inv().unwrap() * x, RuntimeSecp256k1::one());
+    }
+
+    #[test]
+    fn fft_evaluation_matches_naive_evaluation() {
+        set_babybear();
+        let poly = Polynomial::new(&(0..16).map(RuntimeBabybear::from).collect::<Vec<_>>());
+        let evaluations = Polynomial::evaluate_runtime_fft(&poly, 1, None).unwrap();
+        let root = U64RuntimePrimeField::<BabybearModulus>::get_primitive_root_of_unity(4).unwrap();
+        for (i, evaluation) in evaluations.iter().enumerate() {
+            assert_eq!(*evaluation, poly.evaluate(&root.pow(i)));
+        }
+        let interpolated = Polynomial::interpolate_runtime_fft(&evaluations).unwrap();
+        assert_eq!(interpolated, poly);
+    }
+
+    proptest! {
+        #[test]
+        fn operations_match_the_static_field(a in any::<[u64; 4]>(), b in any::<[u64; 4]>()) {
+            set_stark252();
+            let (x, y) = (
+                FieldElement::<Stark252PrimeField>::new(UnsignedInteger::from_limbs(a)),
+                FieldElement::<Stark252PrimeField>::new(UnsignedInteger::from_limbs(b)),
+            );
+            let (z, w) = (
+                RuntimeStark252::from_static(&x).unwrap(),
+                RuntimeStark252::from_static(&y).unwrap(),
+            );
+            prop_assert_eq!((z + w).to_static().unwrap(), x + y);
+            prop_assert_eq!((z - w).to_static().unwrap(), x - y);
+            prop_assert_eq!((z * w).to_static().unwrap(), x * y);
+            prop_assert_eq!(z.square().to_static().unwrap(), x.square());
+            prop_assert_eq!((-z).to_static().unwrap(), -x);
+            if y != FieldElement::zero() {
+                prop_assert_eq!((z / w).to_static().unwrap(), x / y);
+            }
+        }
+    }
+}
@@ -0,0 +1,367 @@
+use crate::field::element::FieldElement;
+use crate::field::errors::FieldError;
+use crate::field::fields::montgomery_backed_prime_fields::{IsFFTModulus, IsModulus};
+use crate::field::traits::{IsFFTField, IsField, IsPrimeField};
+use crate::traits::{ByteConversion, Serializable};
+use crate::unsigned_integer::{
+    element::UnsignedInteger, modular::ModularAlgorithms, solinas::SolinasAlgorithms,
+};
+
+use core::fmt::{self, Debug};
+use core::marker::PhantomData;
+
+pub type U384SolinasPrimeField<M> = SolinasBackendPrimeField<M, 6>;
+pub type U256SolinasPrimeField<M> = SolinasBackendPrimeField<M, 4>;
+pub type U64SolinasPrimeField<M> = SolinasBackendPrimeField<M, 1>;
+
+/// Prime field for moduli of the form `2^n - c`, such as pseudo-Mersenne primes like
+/// secp256k1's base field (`c = 2^32 + 977`) and Solinas primes like p448 (`c = 2^224 + 1`).
+/// Elements are kept in canonical form, and products are reduced by replacing `2^n` with `c`,
+/// which is cheaper than a Montgomery or Barrett reduction when `c` has few nonzero limbs and
+/// at most `n / 2` bits. Any odd prime works, but the reduction gets slower as `c` grows.
+/// Its API mirrors `MontgomeryBackendPrimeField`, and moduli are declared the same way.
+#[cfg_attr(
+    any(
+        feature = "lambdaworks-serde-binary",
+        feature = "lambdaworks-serde-string"
+    ),
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(Clone, Debug, Hash, Copy)]
+pub struct SolinasBackendPrimeField<M, const NUM_LIMBS: usize> {
+    phantom: PhantomData<M>,
+}
+
+impl<M, const NUM_LIMBS: usize> SolinasBackendPrimeField<M, NUM_LIMBS>
+where
+    M: IsModulus<UnsignedInteger<NUM_LIMBS>>,
+{
+    /// `2^n - modulus`, where `n` is the bit size of the modulus.
+    pub const C: UnsignedInteger<NUM_LIMBS> = SolinasAlgorithms::compute_c(&M::MODULUS);
+    /// Bit size of the modulus.
+    pub const BITS: usize = M::MODULUS.bits_le();
+    pub const ZERO: UnsignedInteger<NUM_LIMBS> = UnsignedInteger::from_u64(0);
+    pub const ONE: UnsignedInteger<NUM_LIMBS> = UnsignedInteger::from_u64(1);
+
+    #[inline(always)]
+    fn reduce(
+        hi: &UnsignedInteger<NUM_LIMBS>,
+        lo: &UnsignedInteger<NUM_LIMBS>,
+    ) -> UnsignedInteger<NUM_LIMBS> {
+        SolinasAlgorithms::reduce(hi, lo, &M::MODULUS, &Self::C, Self::BITS)
+    }
+
+    /// Reduces an integer that fits in `NUM_LIMBS` limbs.
+    #[inline(always)]
+    fn reduce_single(x: &UnsignedInteger<NUM_LIMBS>) -> UnsignedInteger<NUM_LIMBS> {
+        if *x < M::MODULUS {
+            *x
+        } else {
+            Self::reduce(&Self::ZERO, x)
+        }
+    }
+}
+
+impl<M, const NUM_LIMBS: usize> IsField for SolinasBackendPrimeField<M, NUM_LIMBS>
+where
+    M: IsModulus<UnsignedInteger<NUM_LIMBS>> + Clone + Debug,
+{
+    type BaseType = UnsignedInteger<NUM_LIMBS>;
+
+    #[inline(always)]
+    fn add(a: &Self::BaseType, b: &Self::BaseType) -> Self::BaseType {
+        ModularAlgorithms::add(a, b, &M::MODULUS)
+    }
+
+    #[inline(always)]
+    fn mul(a: &Self::BaseType, b: &Self::BaseType) -> Self::BaseType {
+        let (hi, lo) = UnsignedInteger::mul(a, b);
+        Self::reduce(&hi, &lo)
+    }
+
+    #[inline(always)]
+    fn square(a: &Self::BaseType) -> Self::BaseType {
+        let (hi, lo) = UnsignedInteger::square(a);
+        Self::reduce(&hi, &lo)
+    }
+
+    #[inline(always)]
+    fn sub(a: &Self::BaseType, b: &Self::BaseType) -> Self::BaseType {
+        ModularAlgorithms::sub(a, b, &M::MODULUS)
+    }
+
+    #[inline(always)]
+    fn neg(a: &Self::BaseType) -> Self::BaseType {
+        ModularAlgorithms::neg(a, &M::MODULUS)
+    }
+
+    #[inline(always)]
+    fn inv(a: &Self::BaseType) -> Result<Self::BaseType, FieldError> {
+        if a == &Self::ZERO {
+            Err(FieldError::InvZeroError)
+        } else {
+            Ok(ModularAlgorithms::inv(a, &M::MODULUS))
+        }
+    }
+
+    #[inline(always)]
+    fn div(a: &Self::BaseType, b: &Self::BaseType) -> Self::BaseType {
+        Self::mul(a, &Self::inv(b).unwrap())
+    }
+
+    #[inline(always)]
+    fn eq(a: &Self::BaseType, b: &Self::BaseType) -> bool {
+        a == b
+    }
+
+    #[inline(always)]
+    fn zero() -> Self::BaseType {
+        Self::ZERO
+    }
+
+    #[inline(always)]
+    fn one() -> Self::BaseType {
+        Self::ONE
+    }
+
+    #[inline(always)]
+    fn from_u64(x: u64) -> Self::BaseType {
+        Self::reduce_single(&UnsignedInteger::from_u64(x))
+    }
+
+    #[inline(always)]
+    fn from_base_type(x: Self::BaseType) -> Self::BaseType {
+        Self::reduce_single(&x)
+    }
+}
+
+impl<M, const NUM_LIMBS: usize> IsPrimeField for SolinasBackendPrimeField<M, NUM_LIMBS>
+where
+    M: IsModulus<UnsignedInteger<NUM_LIMBS>> + Clone + Debug,
+{
+    type RepresentativeType = Self::BaseType;
+
+    fn representative(x: &Self::BaseType) -> Self::RepresentativeType {
+        *x
+    }
+
+    fn field_bit_size() -> usize {
+        Self::BITS
+    }
+
+    fn from_hex(hex_string: &str) -> Result<Self::BaseType, crate::errors::CreationError> {
+        let integer = Self::BaseType::from_hex(hex_string)?;
+        Ok(Self::reduce_single(&integer))
+    }
+}
+
+impl<M, const NUM_LIMBS: usize> IsFFTField for SolinasBackendPrimeField<M, NUM_LIMBS>
+where
+    M: IsFFTModulus<UnsignedInteger<NUM_LIMBS>> + Clone + Debug,
+{
+    const TWO_ADICITY: u64 = M::TWO_ADICITY;
+    const TWO_ADIC_PRIMITVE_ROOT_OF_UNITY: Self::BaseType = M::TWO_ADIC_PRIMITVE_ROOT_OF_UNITY;
+
+    fn field_name() -> &'static str {
+        M::field_name()
+    }
+}
+
+impl<M, const NUM_LIMBS: usize> fmt::Display
+    for FieldElement<SolinasBackendPrimeField<M, NUM_LIMBS>>
+where
+    M: IsModulus<UnsignedInteger<NUM_LIMBS>> + Clone + Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value())
+    }
+}
+
+impl<M, const NUM_LIMBS: usize> ByteConversion
+    for FieldElement<SolinasBackendPrimeField<M, NUM_LIMBS>>
+where
+    M: IsModulus<UnsignedInteger<NUM_LIMBS>> + Clone + Debug,
+{
+    #[cfg(feature = "std")]
+    fn to_bytes_be(&self) -> Vec<u8> {
+        self.value().to_bytes_be()
+    }
+
+    #[cfg(feature = "std")]
+    fn to_bytes_le(&self) -> Vec<u8> {
+        self.value().to_bytes_le()
+    }
+
+    fn from_bytes_be(bytes: &[u8]) -> Result<Self, crate::errors::ByteConversionError> {
+        let value = UnsignedInteger::from_bytes_be(bytes)?;
+        Ok(Self::new(value))
+    }
+
+    fn from_bytes_le(bytes: &[u8]) -> Result<Self, crate::errors::ByteConversionError> {
+        let value = UnsignedInteger::from_bytes_le(bytes)?;
+        Ok(Self::new(value))
+    }
+}
+
+impl<M, const NUM_LIMBS: usize> Serializable
+    for FieldElement<SolinasBackendPrimeField<M, NUM_LIMBS>>
+where
+    M: IsModulus<UnsignedInteger<NUM_LIMBS>> + Clone + Debug,
+{
+    #[cfg(feature = "std")]
+    fn serialize(&self) -> Vec<u8> {
+        self.value().to_bytes_be()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::fields::montgomery_backed_prime_fields::{
+        MontgomeryBackendPrimeField, U256PrimeField,
+    };
+    use crate::unsigned_integer::element::{U256, U64};
+    use proptest::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Copy)]
+    struct U64Modulus23;
+    impl IsModulus<U64> for U64Modulus23 {
+        const MODULUS: U64 = UnsignedInteger::from_u64(23);
+    }
+
+    type F23 = U64SolinasPrimeField<U64Modulus23>;
+    type F23Element = FieldElement<F23>;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Copy)]
+    struct Secp256k1Modulus;
+    impl IsModulus<U256> for Secp256k1Modulus {
+        const MODULUS: U256 = U256::from_hex_unchecked(
+            "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f",
+        );
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq, Copy)]
+    struct P256Modulus;
+    impl IsModulus<U256> for P256Modulus {
+        const MODULUS: U256 = U256::from_hex_unchecked(
+            "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff",
+        );
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq, Copy)]
+    struct P448Modulus;
+    impl IsModulus<UnsignedInteger<7>> for P448Modulus {
+        const MODULUS: UnsignedInteger<7> = UnsignedInteger::from_hex_unchecked(
+            "fffffffffffffffffffffffffffffffffffffffffffffffffffffffeffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+        );
+    }
+
+    type Secp256k1Solinas = FieldElement<U256SolinasPrimeField<Secp256k1Modulus>>;
+    type Secp256k1Montgomery = FieldElement<U256PrimeField<Secp256k1Modulus>>;
+
+    #[test]
+    fn c_of_secp256k1_modulus_is_2_to_the_32_plus_977() {
+        assert_eq!(
+            U256SolinasPrimeField::<Secp256k1Modulus>::C,
+            U256::from_u64(0x1000003d1)
+        );
+    }
+
+    #[test]
+    fn c_of_p448_modulus_is_2_to_the_224_plus_1() {
+        let expected = (UnsignedInteger::<7>::from_u64(1) << 224) + UnsignedInteger::from_u64(1);
+        assert_eq!(SolinasBackendPrimeField::<P448Modulus, 7>::C, expected);
+    }
+
+    #[test]
+    fn from_u64_reduces_modulo_23() {
+        assert_eq!(F23Element::from(25).representative(), U64::from_u64(2));
+    }
+
+    #[test]
+    fn mul_order_minus_1() {
+        let a = F23Element::from(22);
+        assert_eq!(a * a, F23Element::one());
+    }
+
+    #[test]
+    fn inv_0_error() {
+        assert!(matches!(
+            F23Element::zero().inv(),
+            Err(FieldError::InvZeroError)
+        ));
+    }
+
+    #[test]
+    fn three_inverse() {
+        let a = F23Element::from(3);
+        assert_eq!(a.inv().unwrap(), F23Element::from(8));
+    }
+
+    #[test]
+    fn to_bytes_from_bytes_be_is_the_identity() {
+        let x = Secp256k1Solinas::new(U256::from_hex_unchecked("0123456789abcdef0123456789abcdef"));
+        assert_eq!(
+            Secp256k1Solinas::from_bytes_be(&x.to_bytes_be()).unwrap(),
+            x
+        );
+    }
+
+    /// Checks the operations of the Solinas backend against the Montgomery one. The inverse is
+    /// checked on its own, since Montgomery's doesn't support moduli using all the limbs.
+    fn assert_operations_match_montgomery<M, const NUM_LIMBS: usize>(
+        a: UnsignedInteger<NUM_LIMBS>,
+        b: UnsignedInteger<NUM_LIMBS>,
+    ) where
+        M: IsModulus<UnsignedInteger<NUM_LIMBS>> + Clone + Debug,
+    {
+        let (x, y) = (
+            FieldElement::<SolinasBackendPrimeField<M, NUM_LIMBS>>::new(a),
+            FieldElement::<SolinasBackendPrimeField<M, NUM_LIMBS>>::new(b),
+        );
+        let (z, w) = (
+            FieldElement::<MontgomeryBackendPrimeField<M, NUM_LIMBS>>::new(a),
+            FieldElement::<MontgomeryBackendPrimeField<M, NUM_LIMBS>>::new(b),
+        );
+        assert_eq!((&x + &y).representative(), (&z + &w).representative());
+        assert_eq!((&x - &y).representative(), (&z - &w).representative());
+        assert_eq!((&x * &y).representative(), (&z * &w).representative());
+        assert_eq!(x.square().representative(), z.square().representative());
+        if y != FieldElement::zero() {
+            assert_eq!(&y.inv().unwrap() * &y, FieldElement::one());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn secp256k1_operations_match_montgomery_backend(a in any::<[u64; 4]>(), b in any::<[u64; 4]>()) {
+            assert_operations_match_montgomery::<Secp256k1Modulus, 4>(
+                UnsignedInteger::from_limbs(a),
+                UnsignedInteger::from_limbs(b),
+            );
+        }
+
+        #[test]
+        fn p256_operations_match_montgomery_backend(a in any::<[u64; 4]>(), b in any::<[u64; 4]>()) {
+            assert_operations_match_montgomery::<P256Modulus, 4>(
+                UnsignedInteger::from_limbs(a),
+                UnsignedInteger::from_limbs(b),
+            );
+        }
+
+        #[test]
+        fn p448_operations_match_montgomery_backend(a in any::<[u64; 7]>(), b in any::<[u64; 7]>()) {
+            assert_operations_match_montgomery::<P448Modulus, 7>(
+                UnsignedInteger::from_limbs(a),
+                UnsignedInteger::from_limbs(b),
+            );
+        }
+    }
+
+    #[test]
+    fn secp256k1_mul_matches_montgomery_backend_for_largest_values() {
+        let a = Secp256k1Solinas::from(0) - Secp256k1Solinas::one();
+        let b = Secp256k1Montgomery::from(0) - Secp256k1Montgomery::one();
+        assert_eq!((a * a).representative(), (b * b).representative());
+    }
+}
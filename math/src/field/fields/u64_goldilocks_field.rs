@@ -8,6 +8,7 @@ use crate::{
         element::FieldElement,
         errors::FieldError,
         extensions::quadratic::{HasQuadraticNonResidue, QuadraticExtensionField},
+        packed::{goldilocks::PackedGoldilocks64, impl_packed_batch_operations},
         traits::{IsField, IsPrimeField},
     },
 };
@@ -148,6 +149,8 @@ impl IsField for Goldilocks64Field {
     fn from_base_type(x: u64) -> u64 {
         Self::representative(&x)
     }
+
+    impl_packed_batch_operations!(PackedGoldilocks64);
 }
 
 impl IsPrimeField for Goldilocks64Field {
@@ -35,7 +35,11 @@ impl<const MODULUS: u64> IsField for U64PrimeField<MODULUS> {
     }
 
     fn neg(a: &u64) -> u64 {
-        MODULUS - a
+        if *a == 0 {
+            0
+        } else {
+            MODULUS - a
+        }
     }
 
     fn mul(a: &u64, b: &u64) -> u64 {
@@ -312,6 +316,7 @@ mod tests {
         let zero = FE::new(0);
 
         assert_eq!(-zero, zero);
+        assert_eq!(*(-zero).value(), 0);
     }
 
     #[test]
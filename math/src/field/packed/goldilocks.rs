@@ -0,0 +1,478 @@
+//! Packed arithmetic for `Goldilocks64Field`. The results are canonical, as with the
+//! scalar field.
+//!
+//! NOTE: The SIMD backends follow the ones written by the Plonky3 team
+//! https://github.com/Plonky3/Plonky3/tree/main/goldilocks/src
+
+#[cfg(all(target_arch = "x86_64", target_feature = "avx512f"))]
+pub use avx512::PackedGoldilocks64;
+
+#[cfg(all(
+    target_arch = "x86_64",
+    target_feature = "avx2",
+    not(target_feature = "avx512f")
+))]
+pub use avx2::PackedGoldilocks64;
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+pub use neon::PackedGoldilocks64;
+
+#[cfg(not(any(
+    all(target_arch = "x86_64", target_feature = "avx2"),
+    all(target_arch = "aarch64", target_feature = "neon")
+)))]
+pub use portable::PackedGoldilocks64;
+
+#[cfg(all(target_arch = "x86_64", target_feature = "avx512f"))]
+mod avx512 {
+    use crate::field::{fields::u64_goldilocks_field::Goldilocks64Field, packed::IsPackedField};
+    use core::arch::x86_64::*;
+
+    const WIDTH: usize = 8;
+
+    /// Eight elements of `Goldilocks64Field` in an AVX-512 register.
+    #[derive(Clone, Copy, Debug)]
+    pub struct PackedGoldilocks64(__m512i);
+
+    #[inline(always)]
+    fn order() -> __m512i {
+        // SAFETY: the module is only compiled with AVX-512 enabled.
+        unsafe { _mm512_set1_epi64(Goldilocks64Field::ORDER as i64) }
+    }
+
+    #[inline(always)]
+    fn neg_order() -> __m512i {
+        // SAFETY: the module is only compiled with AVX-512 enabled.
+        unsafe { _mm512_set1_epi64(Goldilocks64Field::NEG_ORDER as i64) }
+    }
+
+    /// Subtracts the order from the lanes that are not below it.
+    #[inline(always)]
+    fn canonicalize(x: __m512i) -> __m512i {
+        // SAFETY: the module is only compiled with AVX-512 enabled.
+        unsafe {
+            let mask = _mm512_cmpge_epu64_mask(x, order());
+            _mm512_mask_add_epi64(x, mask, x, neg_order())
+        }
+    }
+
+    /// Adds `NEG_ORDER` to `x + y` for every lane where the addition overflows.
+    #[inline(always)]
+    fn add_with_wraparound(x: __m512i, y: __m512i) -> __m512i {
+        // SAFETY: the module is only compiled with AVX-512 enabled.
+        unsafe {
+            let sum = _mm512_add_epi64(x, y);
+            let carry = _mm512_cmplt_epu64_mask(sum, y);
+            _mm512_mask_add_epi64(sum, carry, sum, neg_order())
+        }
+    }
+
+    /// Reduces the 128-bit values with high halves `hi` and low halves `lo`.
+    #[inline(always)]
+    fn reduce_128(hi: __m512i, lo: __m512i) -> __m512i {
+        // SAFETY: the module is only compiled with AVX-512 enabled.
+        unsafe {
+            let hi_hi = _mm512_srli_epi64::<32>(hi);
+            let borrow = _mm512_cmplt_epu64_mask(lo, hi_hi);
+            let t0 = _mm512_sub_epi64(lo, hi_hi);
+            let t0 = _mm512_mask_sub_epi64(t0, borrow, t0, neg_order());
+            // The low half of `hi` times 2^32 - 1.
+            let t1 = _mm512_mul_epu32(hi, neg_order());
+            add_with_wraparound(t0, t1)
+        }
+    }
+
+    impl IsPackedField for PackedGoldilocks64 {
+        type Scalar = u64;
+        const WIDTH: usize = WIDTH;
+
+        #[inline(always)]
+        fn broadcast(value: u64) -> Self {
+            // SAFETY: the module is only compiled with AVX-512 enabled.
+            unsafe { Self(_mm512_set1_epi64(value as i64)) }
+        }
+
+        #[inline(always)]
+        fn load(values: &[u64]) -> Self {
+            let mut lanes = [0u64; WIDTH];
+            let len = values.len().min(WIDTH);
+            lanes[..len].copy_from_slice(&values[..len]);
+            // SAFETY: `lanes` has exactly 64 bytes and unaligned loads are allowed.
+            Self(unsafe { _mm512_loadu_si512(lanes.as_ptr().cast()) })
+        }
+
+        #[inline(always)]
+        fn store(self, out: &mut [u64]) {
+            let mut lanes = [0u64; WIDTH];
+            // SAFETY: `lanes` has exactly 64 bytes and unaligned stores are allowed.
+            unsafe { _mm512_storeu_si512(lanes.as_mut_ptr().cast(), self.0) };
+            out.copy_from_slice(&lanes[..out.len()]);
+        }
+
+        #[inline(always)]
+        fn add(self, other: Self) -> Self {
+            let sum = add_with_wraparound(canonicalize(self.0), canonicalize(other.0));
+            Self(canonicalize(sum))
+        }
+
+        #[inline(always)]
+        fn sub(self, other: Self) -> Self {
+            // SAFETY: the module is only compiled with AVX-512 enabled.
+            unsafe {
+                let (a, b) = (canonicalize(self.0), canonicalize(other.0));
+                let borrow = _mm512_cmplt_epu64_mask(a, b);
+                let diff = _mm512_sub_epi64(a, b);
+                Self(_mm512_mask_sub_epi64(diff, borrow, diff, neg_order()))
+            }
+        }
+
+        #[inline(always)]
+        fn mul(self, other: Self) -> Self {
+            // SAFETY: the module is only compiled with AVX-512 enabled.
+            unsafe {
+                let lo_32 = _mm512_set1_epi64(u32::MAX as i64);
+                let a_hi = _mm512_srli_epi64::<32>(self.0);
+                let b_hi = _mm512_srli_epi64::<32>(other.0);
+                let ll = _mm512_mul_epu32(self.0, other.0);
+                let lh = _mm512_mul_epu32(self.0, b_hi);
+                let hl = _mm512_mul_epu32(a_hi, other.0);
+                let hh = _mm512_mul_epu32(a_hi, b_hi);
+                // None of these sums overflow, since each partial product is below (2^32 - 1)^2.
+                let t = _mm512_add_epi64(hl, _mm512_srli_epi64::<32>(ll));
+                let u = _mm512_add_epi64(lh, _mm512_and_si512(t, lo_32));
+                let lo = _mm512_or_si512(_mm512_slli_epi64::<32>(u), _mm512_and_si512(ll, lo_32));
+                let hi = _mm512_add_epi64(
+                    _mm512_add_epi64(hh, _mm512_srli_epi64::<32>(t)),
+                    _mm512_srli_epi64::<32>(u),
+                );
+                Self(canonicalize(reduce_128(hi, lo)))
+            }
+        }
+    }
+}
+
+#[cfg(all(
+    target_arch = "x86_64",
+    target_feature = "avx2",
+    not(target_feature = "avx512f")
+))]
+mod avx2 {
+    use crate::field::{fields::u64_goldilocks_field::Goldilocks64Field, packed::IsPackedField};
+    use core::arch::x86_64::*;
+
+    const WIDTH: usize = 4;
+
+    /// Four elements of `Goldilocks64Field` in an AVX2 register.
+    #[derive(Clone, Copy, Debug)]
+    pub struct PackedGoldilocks64(__m256i);
+
+    #[inline(always)]
+    fn neg_order() -> __m256i {
+        // SAFETY: the module is only compiled with AVX2 enabled.
+        unsafe { _mm256_set1_epi64x(Goldilocks64Field::NEG_ORDER as i64) }
+    }
+
+    /// Returns all ones in the lanes where `x < y` as unsigned integers. AVX2 only has
+    /// signed comparisons, so the sign bits are flipped first.
+    #[inline(always)]
+    fn unsigned_lt(x: __m256i, y: __m256i) -> __m256i {
+        // SAFETY: the module is only compiled with AVX2 enabled.
+        unsafe {
+            let sign = _mm256_set1_epi64x(i64::MIN);
+            _mm256_cmpgt_epi64(_mm256_xor_si256(y, sign), _mm256_xor_si256(x, sign))
+        }
+    }
+
+    /// Subtracts the order from the lanes that are not below it.
+    #[inline(always)]
+    fn canonicalize(x: __m256i) -> __m256i {
+        // SAFETY: the module is only compiled with AVX2 enabled.
+        unsafe {
+            let below = unsigned_lt(x, _mm256_set1_epi64x(Goldilocks64Field::ORDER as i64));
+            _mm256_add_epi64(x, _mm256_andnot_si256(below, neg_order()))
+        }
+    }
+
+    /// Adds `NEG_ORDER` to `x + y` for every lane where the addition overflows.
+    #[inline(always)]
+    fn add_with_wraparound(x: __m256i, y: __m256i) -> __m256i {
+        // SAFETY: the module is only compiled with AVX2 enabled.
+        unsafe {
+            let sum = _mm256_add_epi64(x, y);
+            let carry = unsigned_lt(sum, y);
+            _mm256_add_epi64(sum, _mm256_and_si256(carry, neg_order()))
+        }
+    }
+
+    /// Subtracts `NEG_ORDER` from `x - y` for every lane where the subtraction underflows.
+    #[inline(always)]
+    fn sub_with_wraparound(x: __m256i, y: __m256i) -> __m256i {
+        // SAFETY: the module is only compiled with AVX2 enabled.
+        unsafe {
+            let borrow = unsigned_lt(x, y);
+            let diff = _mm256_sub_epi64(x, y);
+            _mm256_sub_epi64(diff, _mm256_and_si256(borrow, neg_order()))
+        }
+    }
+
+    /// Reduces the 128-bit values with high halves `hi` and low halves `lo`.
+    #[inline(always)]
+    fn reduce_128(hi: __m256i, lo: __m256i) -> __m256i {
+        // SAFETY: the module is only compiled with AVX2 enabled.
+        unsafe {
+            let t0 = sub_with_wraparound(lo, _mm256_srli_epi64::<32>(hi));
+            // The low half of `hi` times 2^32 - 1.
+            let t1 = _mm256_mul_epu32(hi, neg_order());
+            add_with_wraparound(t0, t1)
+        }
+    }
+
+    impl IsPackedField for PackedGoldilocks64 {
+        type Scalar = u64;
+        const WIDTH: usize = WIDTH;
+
+        #[inline(always)]
+        fn broadcast(value: u64) -> Self {
+            // SAFETY: the module is only compiled with AVX2 enabled.
+            unsafe { Self(_mm256_set1_epi64x(value as i64)) }
+        }
+
+        #[inline(always)]
+        fn load(values: &[u64]) -> Self {
+            let mut lanes = [0u64; WIDTH];
+            let len = values.len().min(WIDTH);
+            lanes[..len].copy_from_slice(&values[..len]);
+            // SAFETY: `lanes` has exactly 32 bytes and unaligned loads are allowed.
+            Self(unsafe { _mm256_loadu_si256(lanes.as_ptr().cast()) })
+        }
+
+        #[inline(always)]
+        fn store(self, out: &mut [u64]) {
+            let mut lanes = [0u64; WIDTH];
+            // SAFETY: `lanes` has exactly 32 bytes and unaligned stores are allowed.
+            unsafe { _mm256_storeu_si256(lanes.as_mut_ptr().cast(), self.0) };
+            out.copy_from_slice(&lanes[..out.len()]);
+        }
+
+        #[inline(always)]
+        fn add(self, other: Self) -> Self {
+            let sum = add_with_wraparound(canonicalize(self.0), canonicalize(other.0));
+            Self(canonicalize(sum))
+        }
+
+        #[inline(always)]
+        fn sub(self, other: Self) -> Self {
+            Self(sub_with_wraparound(
+                canonicalize(self.0),
+                canonicalize(other.0),
+            ))
+        }
+
+        #[inline(always)]
+        fn mul(self, other: Self) -> Self {
+            // SAFETY: the module is only compiled with AVX2 enabled.
+            unsafe {
+                let lo_32 = _mm256_set1_epi64x(u32::MAX as i64);
+                let a_hi = _mm256_srli_epi64::<32>(self.0);
+                let b_hi = _mm256_srli_epi64::<32>(other.0);
+                let ll = _mm256_mul_epu32(self.0, other.0);
+                let lh = _mm256_mul_epu32(self.0, b_hi);
+                let hl = _mm256_mul_epu32(a_hi, other.0);
+                let hh = _mm256_mul_epu32(a_hi, b_hi);
+                // None of these sums overflow, since each partial product is below (2^32 - 1)^2.
+                let t = _mm256_add_epi64(hl, _mm256_srli_epi64::<32>(ll));
+                let u = _mm256_add_epi64(lh, _mm256_and_si256(t, lo_32));
+                let lo = _mm256_or_si256(_mm256_slli_epi64::<32>(u), _mm256_and_si256(ll, lo_32));
+                let hi = _mm256_add_epi64(
+                    _mm256_add_epi64(hh, _mm256_srli_epi64::<32>(t)),
+                    _mm256_srli_epi64::<32>(u),
+                );
+                Self(canonicalize(reduce_128(hi, lo)))
+            }
+        }
+    }
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+mod neon {
+    use crate::field::{
+        fields::u64_goldilocks_field::Goldilocks64Field, packed::IsPackedField, traits::IsField,
+    };
+    use core::arch::aarch64::*;
+
+    const WIDTH: usize = 2;
+
+    /// Two elements of `Goldilocks64Field` in a NEON register. NEON has no 64-bit lane
+    /// multiplication, so products are computed one lane at a time.
+    #[derive(Clone, Copy, Debug)]
+    pub struct PackedGoldilocks64(uint64x2_t);
+
+    #[inline(always)]
+    fn neg_order() -> uint64x2_t {
+        // SAFETY: the module is only compiled with NEON enabled.
+        unsafe { vdupq_n_u64(Goldilocks64Field::NEG_ORDER) }
+    }
+
+    /// Subtracts the order from the lanes that are not below it.
+    #[inline(always)]
+    fn canonicalize(x: uint64x2_t) -> uint64x2_t {
+        // SAFETY: the module is only compiled with NEON enabled.
+        unsafe {
+            let mask = vcgeq_u64(x, vdupq_n_u64(Goldilocks64Field::ORDER));
+            vaddq_u64(x, vandq_u64(mask, neg_order()))
+        }
+    }
+
+    impl IsPackedField for PackedGoldilocks64 {
+        type Scalar = u64;
+        const WIDTH: usize = WIDTH;
+
+        #[inline(always)]
+        fn broadcast(value: u64) -> Self {
+            // SAFETY: the module is only compiled with NEON enabled.
+            unsafe { Self(vdupq_n_u64(value)) }
+        }
+
+        #[inline(always)]
+        fn load(values: &[u64]) -> Self {
+            let mut lanes = [0u64; WIDTH];
+            let len = values.len().min(WIDTH);
+            lanes[..len].copy_from_slice(&values[..len]);
+            // SAFETY: `lanes` has exactly two values.
+            Self(unsafe { vld1q_u64(lanes.as_ptr()) })
+        }
+
+        #[inline(always)]
+        fn store(self, out: &mut [u64]) {
+            let mut lanes = [0u64; WIDTH];
+            // SAFETY: `lanes` has room for exactly two values.
+            unsafe { vst1q_u64(lanes.as_mut_ptr(), self.0) };
+            out.copy_from_slice(&lanes[..out.len()]);
+        }
+
+        #[inline(always)]
+        fn add(self, other: Self) -> Self {
+            // SAFETY: the module is only compiled with NEON enabled.
+            unsafe {
+                let (a, b) = (canonicalize(self.0), canonicalize(other.0));
+                let sum = vaddq_u64(a, b);
+                let carry = vcltq_u64(sum, b);
+                Self(canonicalize(vaddq_u64(sum, vandq_u64(carry, neg_order()))))
+            }
+        }
+
+        #[inline(always)]
+        fn sub(self, other: Self) -> Self {
+            // SAFETY: the module is only compiled with NEON enabled.
+            unsafe {
+                let (a, b) = (canonicalize(self.0), canonicalize(other.0));
+                let borrow = vcltq_u64(a, b);
+                Self(vsubq_u64(vsubq_u64(a, b), vandq_u64(borrow, neg_order())))
+            }
+        }
+
+        #[inline(always)]
+        fn mul(self, other: Self) -> Self {
+            let (mut a, mut b) = ([0u64; WIDTH], [0u64; WIDTH]);
+            self.store(&mut a);
+            other.store(&mut b);
+            Self::load(&[
+                Goldilocks64Field::mul(&a[0], &b[0]),
+                Goldilocks64Field::mul(&a[1], &b[1]),
+            ])
+        }
+    }
+}
+
+#[cfg(not(any(
+    all(target_arch = "x86_64", target_feature = "avx2"),
+    all(target_arch = "aarch64", target_feature = "neon")
+)))]
+mod portable {
+    use crate::field::{
+        fields::u64_goldilocks_field::Goldilocks64Field, packed::IsPackedField, traits::IsField,
+    };
+
+    const WIDTH: usize = 4;
+
+    /// Four elements of `Goldilocks64Field`, operated on one at a time.
+    #[derive(Clone, Copy, Debug)]
+    pub struct PackedGoldilocks64([u64; WIDTH]);
+
+    impl PackedGoldilocks64 {
+        #[inline(always)]
+        fn zip_with(self, other: Self, f: impl Fn(&u64, &u64) -> u64) -> Self {
+            Self(core::array::from_fn(|i| f(&self.0[i], &other.0[i])))
+        }
+    }
+
+    impl IsPackedField for PackedGoldilocks64 {
+        type Scalar = u64;
+        const WIDTH: usize = WIDTH;
+
+        #[inline(always)]
+        fn broadcast(value: u64) -> Self {
+            Self([value; WIDTH])
+        }
+
+        #[inline(always)]
+        fn load(values: &[u64]) -> Self {
+            let mut lanes = [0u64; WIDTH];
+            let len = values.len().min(WIDTH);
+            lanes[..len].copy_from_slice(&values[..len]);
+            Self(lanes)
+        }
+
+        #[inline(always)]
+        fn store(self, out: &mut [u64]) {
+            out.copy_from_slice(&self.0[..out.len()]);
+        }
+
+        #[inline(always)]
+        fn add(self, other: Self) -> Self {
+            self.zip_with(other, Goldilocks64Field::add)
+        }
+
+        #[inline(always)]
+        fn sub(self, other: Self) -> Self {
+            self.zip_with(other, Goldilocks64Field::sub)
+        }
+
+        #[inline(always)]
+        fn mul(self, other: Self) -> Self {
+            self.zip_with(other, Goldilocks64Field::mul)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::{
+        element::FieldElement, fields::u64_goldilocks_field::Goldilocks64Field,
+        packed::test_helpers::assert_batch_operations_match_scalar, packed::IsPackedField,
+    };
+    use proptest::{collection, prelude::*};
+
+    type FE = FieldElement<Goldilocks64Field>;
+
+    #[test]
+    fn packed_operations_on_edge_values_match_scalar() {
+        let p = Goldilocks64Field::ORDER;
+        let values: Vec<u64> = [0, 1, 2, p - 2, p - 1, 1 << 32, (1 << 32) - 1, 1 << 63]
+            .into_iter()
+            .cycle()
+            .take(3 * PackedGoldilocks64::WIDTH + 3)
+            .collect();
+        let elements: Vec<_> = values.iter().map(|x| FE::from_raw(*x)).collect();
+        assert_batch_operations_match_scalar(&elements);
+    }
+
+    proptest! {
+        #[test]
+        fn packed_operations_match_scalar(values in collection::vec(0..Goldilocks64Field::ORDER, 2..100)) {
+            let elements: Vec<_> = values.iter().map(|x| FE::from(*x)).collect();
+            assert_batch_operations_match_scalar(&elements);
+        }
+    }
+}
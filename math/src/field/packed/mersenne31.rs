@@ -0,0 +1,396 @@
+//! Packed arithmetic for `Mersenne31Field`. Lanes hold values in `[0, p]`, with `p`
+//! representing zero, as in the scalar field.
+//!
+//! NOTE: The SIMD backends follow the ones written by the Plonky3 team
+//! https://github.com/Plonky3/Plonky3/tree/main/mersenne-31/src
+
+use super::IsPackedField;
+#[cfg(not(any(
+    all(target_arch = "x86_64", target_feature = "avx2"),
+    all(target_arch = "aarch64", target_feature = "neon")
+)))]
+use crate::field::{fields::mersenne31::field::Mersenne31Field, traits::IsField};
+
+#[cfg(all(target_arch = "x86_64", target_feature = "avx512f"))]
+pub use avx512::PackedMersenne31;
+
+#[cfg(all(
+    target_arch = "x86_64",
+    target_feature = "avx2",
+    not(target_feature = "avx512f")
+))]
+pub use avx2::PackedMersenne31;
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+pub use neon::PackedMersenne31;
+
+#[cfg(not(any(
+    all(target_arch = "x86_64", target_feature = "avx2"),
+    all(target_arch = "aarch64", target_feature = "neon")
+)))]
+pub use portable::PackedMersenne31;
+
+#[cfg(any(
+    all(target_arch = "x86_64", target_feature = "avx2"),
+    all(target_arch = "aarch64", target_feature = "neon")
+))]
+use crate::field::fields::mersenne31::field::MERSENNE_31_PRIME_FIELD_ORDER as P;
+
+#[cfg(all(target_arch = "x86_64", target_feature = "avx512f"))]
+mod avx512 {
+    use super::{IsPackedField, P};
+    use core::arch::x86_64::*;
+
+    const WIDTH: usize = 16;
+
+    /// Sixteen elements of `Mersenne31Field` in an AVX-512 register.
+    #[derive(Clone, Copy, Debug)]
+    pub struct PackedMersenne31(__m512i);
+
+    impl IsPackedField for PackedMersenne31 {
+        type Scalar = u32;
+        const WIDTH: usize = WIDTH;
+
+        #[inline(always)]
+        fn broadcast(value: u32) -> Self {
+            // SAFETY: the module is only compiled with AVX-512 enabled.
+            unsafe { Self(_mm512_set1_epi32(value as i32)) }
+        }
+
+        #[inline(always)]
+        fn load(values: &[u32]) -> Self {
+            let mut lanes = [0u32; WIDTH];
+            let len = values.len().min(WIDTH);
+            lanes[..len].copy_from_slice(&values[..len]);
+            // SAFETY: `lanes` has exactly 64 bytes and unaligned loads are allowed.
+            Self(unsafe { _mm512_loadu_si512(lanes.as_ptr().cast()) })
+        }
+
+        #[inline(always)]
+        fn store(self, out: &mut [u32]) {
+            let mut lanes = [0u32; WIDTH];
+            // SAFETY: `lanes` has exactly 64 bytes and unaligned stores are allowed.
+            unsafe { _mm512_storeu_si512(lanes.as_mut_ptr().cast(), self.0) };
+            out.copy_from_slice(&lanes[..out.len()]);
+        }
+
+        #[inline(always)]
+        fn add(self, other: Self) -> Self {
+            // SAFETY: the module is only compiled with AVX-512 enabled.
+            unsafe {
+                // The sum is below 2p, so it's reduced by taking the minimum of t and t - p,
+                // as t - p wraps around when t < p.
+                let t = _mm512_add_epi32(self.0, other.0);
+                Self(_mm512_min_epu32(
+                    t,
+                    _mm512_sub_epi32(t, _mm512_set1_epi32(P as i32)),
+                ))
+            }
+        }
+
+        #[inline(always)]
+        fn sub(self, other: Self) -> Self {
+            // SAFETY: the module is only compiled with AVX-512 enabled.
+            unsafe {
+                // If the difference wraps around, adding p wraps it back below p.
+                let t = _mm512_sub_epi32(self.0, other.0);
+                Self(_mm512_min_epu32(
+                    t,
+                    _mm512_add_epi32(t, _mm512_set1_epi32(P as i32)),
+                ))
+            }
+        }
+
+        #[inline(always)]
+        fn mul(self, other: Self) -> Self {
+            // SAFETY: the module is only compiled with AVX-512 enabled.
+            unsafe {
+                // 64-bit products of the even and odd lanes.
+                let prod_evn = _mm512_mul_epu32(self.0, other.0);
+                let prod_odd = _mm512_mul_epu32(
+                    _mm512_srli_epi64::<32>(self.0),
+                    _mm512_srli_epi64::<32>(other.0),
+                );
+                // Since 2^31 = 1 mod p, the product is its low 31 bits plus the rest shifted by 31.
+                let lo = _mm512_and_si512(
+                    _mm512_mask_blend_epi32(0xAAAA, prod_evn, _mm512_slli_epi64::<32>(prod_odd)),
+                    _mm512_set1_epi32(P as i32),
+                );
+                let hi = _mm512_mask_blend_epi32(
+                    0xAAAA,
+                    _mm512_srli_epi64::<31>(prod_evn),
+                    _mm512_slli_epi64::<1>(prod_odd),
+                );
+                Self(lo).add(Self(hi))
+            }
+        }
+    }
+}
+
+#[cfg(all(
+    target_arch = "x86_64",
+    target_feature = "avx2",
+    not(target_feature = "avx512f")
+))]
+mod avx2 {
+    use super::{IsPackedField, P};
+    use core::arch::x86_64::*;
+
+    const WIDTH: usize = 8;
+
+    /// Eight elements of `Mersenne31Field` in an AVX2 register.
+    #[derive(Clone, Copy, Debug)]
+    pub struct PackedMersenne31(__m256i);
+
+    impl IsPackedField for PackedMersenne31 {
+        type Scalar = u32;
+        const WIDTH: usize = WIDTH;
+
+        #[inline(always)]
+        fn broadcast(value: u32) -> Self {
+            // SAFETY: the module is only compiled with AVX2 enabled.
+            unsafe { Self(_mm256_set1_epi32(value as i32)) }
+        }
+
+        #[inline(always)]
+        fn load(values: &[u32]) -> Self {
+            let mut lanes = [0u32; WIDTH];
+            let len = values.len().min(WIDTH);
+            lanes[..len].copy_from_slice(&values[..len]);
+            // SAFETY: `lanes` has exactly 32 bytes and unaligned loads are allowed.
+            Self(unsafe { _mm256_loadu_si256(lanes.as_ptr().cast()) })
+        }
+
+        #[inline(always)]
+        fn store(self, out: &mut [u32]) {
+            let mut lanes = [0u32; WIDTH];
+            // SAFETY: `lanes` has exactly 32 bytes and unaligned stores are allowed.
+            unsafe { _mm256_storeu_si256(lanes.as_mut_ptr().cast(), self.0) };
+            out.copy_from_slice(&lanes[..out.len()]);
+        }
+
+        #[inline(always)]
+        fn add(self, other: Self) -> Self {
+            // SAFETY: the module is only compiled with AVX2 enabled.
+            unsafe {
+                // The sum is below 2p, so it's reduced by taking the minimum of t and t - p,
+                // as t - p wraps around when t < p.
+                let t = _mm256_add_epi32(self.0, other.0);
+                Self(_mm256_min_epu32(
+                    t,
+                    _mm256_sub_epi32(t, _mm256_set1_epi32(P as i32)),
+                ))
+            }
+        }
+
+        #[inline(always)]
+        fn sub(self, other: Self) -> Self {
+            // SAFETY: the module is only compiled with AVX2 enabled.
+            unsafe {
+                // If the difference wraps around, adding p wraps it back below p.
+                let t = _mm256_sub_epi32(self.0, other.0);
+                Self(_mm256_min_epu32(
+                    t,
+                    _mm256_add_epi32(t, _mm256_set1_epi32(P as i32)),
+                ))
+            }
+        }
+
+        #[inline(always)]
+        fn mul(self, other: Self) -> Self {
+            // SAFETY: the module is only compiled with AVX2 enabled.
+            unsafe {
+                // 64-bit products of the even and odd lanes.
+                let prod_evn = _mm256_mul_epu32(self.0, other.0);
+                let prod_odd = _mm256_mul_epu32(
+                    _mm256_srli_epi64::<32>(self.0),
+                    _mm256_srli_epi64::<32>(other.0),
+                );
+                // Since 2^31 = 1 mod p, the product is its low 31 bits plus the rest shifted by 31.
+                let lo = _mm256_and_si256(
+                    _mm256_blend_epi32::<0b1010_1010>(prod_evn, _mm256_slli_epi64::<32>(prod_odd)),
+                    _mm256_set1_epi32(P as i32),
+                );
+                let hi = _mm256_blend_epi32::<0b1010_1010>(
+                    _mm256_srli_epi64::<31>(prod_evn),
+                    _mm256_slli_epi64::<1>(prod_odd),
+                );
+                Self(lo).add(Self(hi))
+            }
+        }
+    }
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+mod neon {
+    use super::{IsPackedField, P};
+    use core::arch::aarch64::*;
+
+    const WIDTH: usize = 4;
+
+    /// Four elements of `Mersenne31Field` in a NEON register.
+    #[derive(Clone, Copy, Debug)]
+    pub struct PackedMersenne31(uint32x4_t);
+
+    impl IsPackedField for PackedMersenne31 {
+        type Scalar = u32;
+        const WIDTH: usize = WIDTH;
+
+        #[inline(always)]
+        fn broadcast(value: u32) -> Self {
+            // SAFETY: the module is only compiled with NEON enabled.
+            unsafe { Self(vdupq_n_u32(value)) }
+        }
+
+        #[inline(always)]
+        fn load(values: &[u32]) -> Self {
+            let mut lanes = [0u32; WIDTH];
+            let len = values.len().min(WIDTH);
+            lanes[..len].copy_from_slice(&values[..len]);
+            // SAFETY: `lanes` has exactly four values.
+            Self(unsafe { vld1q_u32(lanes.as_ptr()) })
+        }
+
+        #[inline(always)]
+        fn store(self, out: &mut [u32]) {
+            let mut lanes = [0u32; WIDTH];
+            // SAFETY: `lanes` has room for exactly four values.
+            unsafe { vst1q_u32(lanes.as_mut_ptr(), self.0) };
+            out.copy_from_slice(&lanes[..out.len()]);
+        }
+
+        #[inline(always)]
+        fn add(self, other: Self) -> Self {
+            // SAFETY: the module is only compiled with NEON enabled.
+            unsafe {
+                let t = vaddq_u32(self.0, other.0);
+                Self(vminq_u32(t, vsubq_u32(t, vdupq_n_u32(P))))
+            }
+        }
+
+        #[inline(always)]
+        fn sub(self, other: Self) -> Self {
+            // SAFETY: the module is only compiled with NEON enabled.
+            unsafe {
+                let t = vsubq_u32(self.0, other.0);
+                Self(vminq_u32(t, vaddq_u32(t, vdupq_n_u32(P))))
+            }
+        }
+
+        #[inline(always)]
+        fn mul(self, other: Self) -> Self {
+            // SAFETY: the module is only compiled with NEON enabled.
+            unsafe {
+                let prod_lo = vmull_u32(vget_low_u32(self.0), vget_low_u32(other.0));
+                let prod_hi = vmull_high_u32(self.0, other.0);
+                // Since 2^31 = 1 mod p, the product is its low 31 bits plus the rest shifted by 31.
+                let lo = vandq_u32(
+                    vcombine_u32(vmovn_u64(prod_lo), vmovn_u64(prod_hi)),
+                    vdupq_n_u32(P),
+                );
+                let hi = vcombine_u32(vshrn_n_u64::<31>(prod_lo), vshrn_n_u64::<31>(prod_hi));
+                Self(lo).add(Self(hi))
+            }
+        }
+    }
+}
+
+#[cfg(not(any(
+    all(target_arch = "x86_64", target_feature = "avx2"),
+    all(target_arch = "aarch64", target_feature = "neon")
+)))]
+mod portable {
+    use super::{IsField, IsPackedField, Mersenne31Field};
+
+    const WIDTH: usize = 8;
+
+    /// Eight elements of `Mersenne31Field`, operated on one at a time.
+    #[derive(Clone, Copy, Debug)]
+    pub struct PackedMersenne31([u32; WIDTH]);
+
+    impl PackedMersenne31 {
+        #[inline(always)]
+        fn zip_with(self, other: Self, f: impl Fn(&u32, &u32) -> u32) -> Self {
+            Self(core::array::from_fn(|i| f(&self.0[i], &other.0[i])))
+        }
+    }
+
+    impl IsPackedField for PackedMersenne31 {
+        type Scalar = u32;
+        const WIDTH: usize = WIDTH;
+
+        #[inline(always)]
+        fn broadcast(value: u32) -> Self {
+            Self([value; WIDTH])
+        }
+
+        #[inline(always)]
+        fn load(values: &[u32]) -> Self {
+            let mut lanes = [0u32; WIDTH];
+            let len = values.len().min(WIDTH);
+            lanes[..len].copy_from_slice(&values[..len]);
+            Self(lanes)
+        }
+
+        #[inline(always)]
+        fn store(self, out: &mut [u32]) {
+            out.copy_from_slice(&self.0[..out.len()]);
+        }
+
+        #[inline(always)]
+        fn add(self, other: Self) -> Self {
+            self.zip_with(other, Mersenne31Field::add)
+        }
+
+        #[inline(always)]
+        fn sub(self, other: Self) -> Self {
+            self.zip_with(other, Mersenne31Field::sub)
+        }
+
+        #[inline(always)]
+        fn mul(self, other: Self) -> Self {
+            self.zip_with(other, Mersenne31Field::mul)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::{
+        element::FieldElement,
+        fields::mersenne31::field::{Mersenne31Field, MERSENNE_31_PRIME_FIELD_ORDER},
+        packed::test_helpers::assert_batch_operations_match_scalar,
+    };
+    use proptest::{collection, prelude::*};
+
+    type FE = FieldElement<Mersenne31Field>;
+
+    #[test]
+    fn packed_operations_on_edge_values_match_scalar() {
+        let p = MERSENNE_31_PRIME_FIELD_ORDER;
+        let values: Vec<u32> = [0, 1, 2, p - 2, p - 1, p, 1 << 30, (1 << 30) + 1]
+            .into_iter()
+            .cycle()
+            .take(3 * PackedMersenne31::WIDTH + 3)
+            .collect();
+        let elements: Vec<_> = values.iter().map(|x| FE::from_raw(*x)).collect();
+        assert_batch_operations_match_scalar(&elements);
+    }
+
+    #[test]
+    fn partial_vectors_leave_values_past_the_end_untouched() {
+        let mut a = vec![FE::from(3); 3];
+        let b = [FE::from(5); 3];
+        FE::mul_assign_batch(&mut a[..2], &b[..2]);
+        assert_eq!(a, vec![FE::from(15), FE::from(15), FE::from(3)]);
+    }
+
+    proptest! {
+        #[test]
+        fn packed_operations_match_scalar(values in collection::vec(0..MERSENNE_31_PRIME_FIELD_ORDER, 2..100)) {
+            let elements: Vec<_> = values.iter().map(|x| FE::from(*x as u64)).collect();
+            assert_batch_operations_match_scalar(&elements);
+        }
+    }
+}
@@ -0,0 +1,229 @@
+//! Packed field arithmetic: vectors of field elements operated on lane by lane with SIMD
+//! instructions.
+//!
+//! The backend of every packed type is chosen at compile time from the enabled target
+//! features: AVX-512 or AVX2 on `x86_64`, NEON on `aarch64`, and a portable implementation
+//! otherwise. Build with `RUSTFLAGS="-C target-cpu=native"` to use the best one available.
+//!
+//! Packed types work on the internal representation of the elements (`IsField::BaseType`, or
+//! its single limb for Montgomery fields). They are used through the batch operations of
+//! `IsField`, such as `IsField::mul_assign_batch`, which fields with packed arithmetic override.
+
+/// Packed arithmetic for the Goldilocks field (p = 2^64 - 2^32 + 1)
+pub mod goldilocks;
+/// Packed arithmetic for the Mersenne31 field (p = 2^31 - 1)
+pub mod mersenne31;
+/// Packed arithmetic for Montgomery fields with moduli below 2^31, such as BabyBear
+pub mod montgomery31;
+
+/// A vector of `WIDTH` field elements, given by their internal representation, on which
+/// arithmetic is done lane by lane.
+pub trait IsPackedField: Copy {
+    /// Internal representation of the element in a single lane.
+    type Scalar: Copy + Default;
+
+    /// Number of lanes.
+    const WIDTH: usize;
+
+    /// Returns a vector with `value` in every lane.
+    fn broadcast(value: Self::Scalar) -> Self;
+
+    /// Loads the first `WIDTH` values of `values`. If there are fewer values, the
+    /// remaining lanes are filled with zeros.
+    fn load(values: &[Self::Scalar]) -> Self;
+
+    /// Stores the first `out.len()` lanes, which must be at most `WIDTH`, into `out`.
+    fn store(self, out: &mut [Self::Scalar]);
+
+    /// Returns the lane-wise sum of `self` and `other`.
+    fn add(self, other: Self) -> Self;
+
+    /// Returns the lane-wise subtraction of `self` and `other`.
+    fn sub(self, other: Self) -> Self;
+
+    /// Returns the lane-wise multiplication of `self` and `other`.
+    fn mul(self, other: Self) -> Self;
+}
+
+/// Sets `a[i] = a[i] + b[i]` for every `i`.
+pub fn add_assign_batch<P: IsPackedField>(a: &mut [P::Scalar], b: &[P::Scalar]) {
+    debug_assert_eq!(a.len(), b.len());
+    for (x, y) in a.chunks_mut(P::WIDTH).zip(b.chunks(P::WIDTH)) {
+        P::load(x).add(P::load(y)).store(x);
+    }
+}
+
+/// Sets `a[i] = a[i] - b[i]` for every `i`.
+pub fn sub_assign_batch<P: IsPackedField>(a: &mut [P::Scalar], b: &[P::Scalar]) {
+    debug_assert_eq!(a.len(), b.len());
+    for (x, y) in a.chunks_mut(P::WIDTH).zip(b.chunks(P::WIDTH)) {
+        P::load(x).sub(P::load(y)).store(x);
+    }
+}
+
+/// Sets `a[i] = a[i] * b[i]` for every `i`.
+pub fn mul_assign_batch<P: IsPackedField>(a: &mut [P::Scalar], b: &[P::Scalar]) {
+    debug_assert_eq!(a.len(), b.len());
+    for (x, y) in a.chunks_mut(P::WIDTH).zip(b.chunks(P::WIDTH)) {
+        P::load(x).mul(P::load(y)).store(x);
+    }
+}
+
+/// Sets `a[i] = a[i] * b[i] + c` for every `i`.
+pub fn mul_add_scalar_batch<P: IsPackedField>(a: &mut [P::Scalar], b: &[P::Scalar], c: P::Scalar) {
+    debug_assert_eq!(a.len(), b.len());
+    let c = P::broadcast(c);
+    for (x, y) in a.chunks_mut(P::WIDTH).zip(b.chunks(P::WIDTH)) {
+        P::load(x).mul(P::load(y)).add(c).store(x);
+    }
+}
+
+/// Sets `(lo[i], hi[i]) = (lo[i] + w * hi[i], lo[i] - w * hi[i])` for every `i`.
+pub fn butterfly_batch<P: IsPackedField>(lo: &mut [P::Scalar], hi: &mut [P::Scalar], w: P::Scalar) {
+    debug_assert_eq!(lo.len(), hi.len());
+    let w = P::broadcast(w);
+    for (x, y) in lo.chunks_mut(P::WIDTH).zip(hi.chunks_mut(P::WIDTH)) {
+        let a = P::load(x);
+        let wb = P::load(y).mul(w);
+        a.add(wb).store(x);
+        a.sub(wb).store(y);
+    }
+}
+
+/// Batch operations done one element at a time, used by fields without packed arithmetic.
+pub(crate) mod scalar_batch {
+    use crate::field::{element::FieldElement, traits::IsField};
+
+    pub fn add_assign<F: IsField>(a: &mut [FieldElement<F>], b: &[FieldElement<F>]) {
+        for (x, y) in a.iter_mut().zip(b) {
+            *x = FieldElement::from_raw(F::add(x.value(), y.value()));
+        }
+    }
+
+    pub fn sub_assign<F: IsField>(a: &mut [FieldElement<F>], b: &[FieldElement<F>]) {
+        for (x, y) in a.iter_mut().zip(b) {
+            *x = FieldElement::from_raw(F::sub(x.value(), y.value()));
+        }
+    }
+
+    pub fn mul_assign<F: IsField>(a: &mut [FieldElement<F>], b: &[FieldElement<F>]) {
+        for (x, y) in a.iter_mut().zip(b) {
+            *x = FieldElement::from_raw(F::mul(x.value(), y.value()));
+        }
+    }
+
+    pub fn mul_add_scalar<F: IsField>(
+        a: &mut [FieldElement<F>],
+        b: &[FieldElement<F>],
+        c: &FieldElement<F>,
+    ) {
+        for (x, y) in a.iter_mut().zip(b) {
+            let product = F::mul(x.value(), y.value());
+            *x = FieldElement::from_raw(F::add(&product, c.value()));
+        }
+    }
+
+    pub fn butterfly<F: IsField>(
+        lo: &mut [FieldElement<F>],
+        hi: &mut [FieldElement<F>],
+        w: &FieldElement<F>,
+    ) {
+        for (x, y) in lo.iter_mut().zip(hi) {
+            let wy = F::mul(w.value(), y.value());
+            *y = FieldElement::from_raw(F::sub(x.value(), &wy));
+            *x = FieldElement::from_raw(F::add(x.value(), &wy));
+        }
+    }
+}
+
+/// Implements the batch operations of `IsField` with the packed type `$packed`, whose
+/// scalars are the `BaseType` of the field.
+macro_rules! impl_packed_batch_operations {
+    ($packed:ty) => {
+        fn add_assign_batch(a: &mut [FieldElement<Self>], b: &[FieldElement<Self>]) {
+            $crate::field::packed::add_assign_batch::<$packed>(
+                FieldElement::slice_as_raw_mut(a),
+                FieldElement::slice_as_raw(b),
+            )
+        }
+
+        fn sub_assign_batch(a: &mut [FieldElement<Self>], b: &[FieldElement<Self>]) {
+            $crate::field::packed::sub_assign_batch::<$packed>(
+                FieldElement::slice_as_raw_mut(a),
+                FieldElement::slice_as_raw(b),
+            )
+        }
+
+        fn mul_assign_batch(a: &mut [FieldElement<Self>], b: &[FieldElement<Self>]) {
+            $crate::field::packed::mul_assign_batch::<$packed>(
+                FieldElement::slice_as_raw_mut(a),
+                FieldElement::slice_as_raw(b),
+            )
+        }
+
+        fn mul_add_scalar_batch(
+            a: &mut [FieldElement<Self>],
+            b: &[FieldElement<Self>],
+            c: &FieldElement<Self>,
+        ) {
+            $crate::field::packed::mul_add_scalar_batch::<$packed>(
+                FieldElement::slice_as_raw_mut(a),
+                FieldElement::slice_as_raw(b),
+                *c.value(),
+            )
+        }
+
+        fn butterfly_batch(
+            lo: &mut [FieldElement<Self>],
+            hi: &mut [FieldElement<Self>],
+            w: &FieldElement<Self>,
+        ) {
+            $crate::field::packed::butterfly_batch::<$packed>(
+                FieldElement::slice_as_raw_mut(lo),
+                FieldElement::slice_as_raw_mut(hi),
+                *w.value(),
+            )
+        }
+    };
+}
+pub(crate) use impl_packed_batch_operations;
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use crate::field::{element::FieldElement, traits::IsField};
+
+    /// Checks every batch operation of `F` against the scalar operations on vectors whose
+    /// lengths are not multiples of the packing width.
+    pub fn assert_batch_operations_match_scalar<F: IsField>(values: &[FieldElement<F>]) {
+        let (a, b) = values.split_at(values.len() / 2);
+        let b = &b[..a.len()];
+        let c = &values[values.len() - 1];
+
+        let mut result = a.to_vec();
+        FieldElement::add_assign_batch(&mut result, b);
+        let expected: Vec<_> = a.iter().zip(b).map(|(x, y)| x + y).collect();
+        assert_eq!(result, expected);
+
+        let mut result = a.to_vec();
+        FieldElement::sub_assign_batch(&mut result, b);
+        let expected: Vec<_> = a.iter().zip(b).map(|(x, y)| x - y).collect();
+        assert_eq!(result, expected);
+
+        let mut result = a.to_vec();
+        FieldElement::mul_assign_batch(&mut result, b);
+        let expected: Vec<_> = a.iter().zip(b).map(|(x, y)| x * y).collect();
+        assert_eq!(result, expected);
+
+        let mut result = a.to_vec();
+        FieldElement::mul_add_scalar_batch(&mut result, b, c);
+        let expected: Vec<_> = a.iter().zip(b).map(|(x, y)| x * y + c).collect();
+        assert_eq!(result, expected);
+
+        let (mut lo, mut hi) = (a.to_vec(), b.to_vec());
+        FieldElement::butterfly_batch(&mut lo, &mut hi, c);
+        let expected_lo: Vec<_> = a.iter().zip(b).map(|(x, y)| x + c * y).collect();
+        let expected_hi: Vec<_> = a.iter().zip(b).map(|(x, y)| x - c * y).collect();
+        assert_eq!(lo, expected_lo);
+        assert_eq!(hi, expected_hi);
+    }
+}
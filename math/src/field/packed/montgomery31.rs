@@ -0,0 +1,494 @@
+//! Packed arithmetic for single-limb `MontgomeryBackendPrimeField`s with moduli below 2^31,
+//! such as BabyBear.
+//!
+//! Elements are kept in the same Montgomery form as the scalar field, `a * 2^64 mod p`, with
+//! canonical values. Since the modulus fits in 32 bits, the Montgomery reduction by 2^64 is
+//! done as two signed reductions by 2^32, each one a couple of 32-bit multiplications:
+//! `(t - (t * p^{-1} mod 2^32) * p) / 2^32`, which lies in `(-p, p)` for `t < p * 2^32`.
+//!
+//! NOTE: The SIMD backends follow the ones written by the Plonky3 team
+//! https://github.com/Plonky3/Plonky3/tree/main/monty-31/src
+
+use crate::field::fields::montgomery_backed_prime_fields::IsModulus;
+use crate::unsigned_integer::element::UnsignedInteger;
+use core::fmt::{self, Debug};
+use core::marker::PhantomData;
+
+#[cfg(all(target_arch = "x86_64", target_feature = "avx512f"))]
+type Vector = core::arch::x86_64::__m512i;
+
+#[cfg(all(
+    target_arch = "x86_64",
+    target_feature = "avx2",
+    not(target_feature = "avx512f")
+))]
+type Vector = core::arch::x86_64::__m256i;
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+type Vector = core::arch::aarch64::uint32x4_t;
+
+#[cfg(not(any(
+    all(target_arch = "x86_64", target_feature = "avx2"),
+    all(target_arch = "aarch64", target_feature = "neon")
+)))]
+type Vector = [u64; 8];
+
+/// Elements of `MontgomeryBackendPrimeField<M, NUM_LIMBS>`, whose modulus must be below
+/// 2^31 and fit in a single limb, given by their limbs.
+pub struct PackedMontgomery31<M, const NUM_LIMBS: usize> {
+    value: Vector,
+    phantom: PhantomData<M>,
+}
+
+impl<M, const NUM_LIMBS: usize> Clone for PackedMontgomery31<M, NUM_LIMBS> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<M, const NUM_LIMBS: usize> Copy for PackedMontgomery31<M, NUM_LIMBS> {}
+
+impl<M, const NUM_LIMBS: usize> Debug for PackedMontgomery31<M, NUM_LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("PackedMontgomery31")
+            .field(&self.value)
+            .finish()
+    }
+}
+
+impl<M, const NUM_LIMBS: usize> PackedMontgomery31<M, NUM_LIMBS>
+where
+    M: IsModulus<UnsignedInteger<NUM_LIMBS>>,
+{
+    /// The modulus.
+    const P: u32 = M::MODULUS.limbs[NUM_LIMBS - 1] as u32;
+
+    /// `p^{-1} mod 2^32`, computed with Newton's iteration. Each step doubles the number of
+    /// correct bits, and `p` is its own inverse modulo 2^3.
+    const P_INV: u32 = {
+        let p = Self::P;
+        let mut inv = p;
+        let mut i = 0;
+        while i < 4 {
+            inv = inv.wrapping_mul(2u32.wrapping_sub(p.wrapping_mul(inv)));
+            i += 1;
+        }
+        inv
+    };
+
+    #[inline(always)]
+    fn new(value: Vector) -> Self {
+        Self {
+            value,
+            phantom: PhantomData,
+        }
+    }
+}
+
+#[cfg(all(target_arch = "x86_64", target_feature = "avx512f"))]
+mod avx512 {
+    use super::{IsModulus, PackedMontgomery31, UnsignedInteger};
+    use crate::field::packed::IsPackedField;
+    use core::arch::x86_64::*;
+
+    const WIDTH: usize = 8;
+
+    impl<M, const NUM_LIMBS: usize> PackedMontgomery31<M, NUM_LIMBS>
+    where
+        M: IsModulus<UnsignedInteger<NUM_LIMBS>>,
+    {
+        /// Returns `t / 2^32 mod p` for 64-bit lanes `t < p * 2^32`.
+        #[inline(always)]
+        fn reduce(t: __m512i) -> __m512i {
+            // SAFETY: the module is only compiled with AVX-512 enabled.
+            unsafe {
+                let p = _mm512_set1_epi64(Self::P as i64);
+                let q = _mm512_mul_epu32(t, _mm512_set1_epi64(Self::P_INV as i64));
+                let qp = _mm512_mul_epu32(q, p);
+                let d = _mm512_sub_epi32(_mm512_srli_epi64::<32>(t), _mm512_srli_epi64::<32>(qp));
+                _mm512_min_epu32(d, _mm512_add_epi32(d, p))
+            }
+        }
+    }
+
+    impl<M, const NUM_LIMBS: usize> IsPackedField for PackedMontgomery31<M, NUM_LIMBS>
+    where
+        M: IsModulus<UnsignedInteger<NUM_LIMBS>>,
+    {
+        type Scalar = u64;
+        const WIDTH: usize = WIDTH;
+
+        #[inline(always)]
+        fn broadcast(value: u64) -> Self {
+            // SAFETY: the module is only compiled with AVX-512 enabled.
+            unsafe { Self::new(_mm512_set1_epi64(value as i64)) }
+        }
+
+        #[inline(always)]
+        fn load(values: &[u64]) -> Self {
+            let mut lanes = [0u64; WIDTH];
+            let len = values.len().min(WIDTH);
+            lanes[..len].copy_from_slice(&values[..len]);
+            // SAFETY: `lanes` has exactly 64 bytes and unaligned loads are allowed.
+            Self::new(unsafe { _mm512_loadu_si512(lanes.as_ptr().cast()) })
+        }
+
+        #[inline(always)]
+        fn store(self, out: &mut [u64]) {
+            let mut lanes = [0u64; WIDTH];
+            // SAFETY: `lanes` has exactly 64 bytes and unaligned stores are allowed.
+            unsafe { _mm512_storeu_si512(lanes.as_mut_ptr().cast(), self.value) };
+            out.copy_from_slice(&lanes[..out.len()]);
+        }
+
+        #[inline(always)]
+        fn add(self, other: Self) -> Self {
+            // SAFETY: the module is only compiled with AVX-512 enabled.
+            unsafe {
+                // The high halves of the lanes are zero, so 32-bit operations leave them as is.
+                let t = _mm512_add_epi32(self.value, other.value);
+                let p = _mm512_set1_epi64(Self::P as i64);
+                Self::new(_mm512_min_epu32(t, _mm512_sub_epi32(t, p)))
+            }
+        }
+
+        #[inline(always)]
+        fn sub(self, other: Self) -> Self {
+            // SAFETY: the module is only compiled with AVX-512 enabled.
+            unsafe {
+                let t = _mm512_sub_epi32(self.value, other.value);
+                let p = _mm512_set1_epi64(Self::P as i64);
+                Self::new(_mm512_min_epu32(t, _mm512_add_epi32(t, p)))
+            }
+        }
+
+        #[inline(always)]
+        fn mul(self, other: Self) -> Self {
+            // SAFETY: the module is only compiled with AVX-512 enabled.
+            unsafe {
+                let t = _mm512_mul_epu32(self.value, other.value);
+                Self::new(Self::reduce(Self::reduce(t)))
+            }
+        }
+    }
+}
+
+#[cfg(all(
+    target_arch = "x86_64",
+    target_feature = "avx2",
+    not(target_feature = "avx512f")
+))]
+mod avx2 {
+    use super::{IsModulus, PackedMontgomery31, UnsignedInteger};
+    use crate::field::packed::IsPackedField;
+    use core::arch::x86_64::*;
+
+    const WIDTH: usize = 4;
+
+    impl<M, const NUM_LIMBS: usize> PackedMontgomery31<M, NUM_LIMBS>
+    where
+        M: IsModulus<UnsignedInteger<NUM_LIMBS>>,
+    {
+        /// Returns `t / 2^32 mod p` for 64-bit lanes `t < p * 2^32`.
+        #[inline(always)]
+        fn reduce(t: __m256i) -> __m256i {
+            // SAFETY: the module is only compiled with AVX2 enabled.
+            unsafe {
+                let p = _mm256_set1_epi64x(Self::P as i64);
+                let q = _mm256_mul_epu32(t, _mm256_set1_epi64x(Self::P_INV as i64));
+                let qp = _mm256_mul_epu32(q, p);
+                let d = _mm256_sub_epi32(_mm256_srli_epi64::<32>(t), _mm256_srli_epi64::<32>(qp));
+                _mm256_min_epu32(d, _mm256_add_epi32(d, p))
+            }
+        }
+    }
+
+    impl<M, const NUM_LIMBS: usize> IsPackedField for PackedMontgomery31<M, NUM_LIMBS>
+    where
+        M: IsModulus<UnsignedInteger<NUM_LIMBS>>,
+    {
+        type Scalar = u64;
+        const WIDTH: usize = WIDTH;
+
+        #[inline(always)]
+        fn broadcast(value: u64) -> Self {
+            // SAFETY: the module is only compiled with AVX2 enabled.
+            unsafe { Self::new(_mm256_set1_epi64x(value as i64)) }
+        }
+
+        #[inline(always)]
+        fn load(values: &[u64]) -> Self {
+            let mut lanes = [0u64; WIDTH];
+            let len = values.len().min(WIDTH);
+            lanes[..len].copy_from_slice(&values[..len]);
+            // SAFETY: `lanes` has exactly 32 bytes and unaligned loads are allowed.
+            Self::new(unsafe { _mm256_loadu_si256(lanes.as_ptr().cast()) })
+        }
+
+        #[inline(always)]
+        fn store(self, out: &mut [u64]) {
+            let mut lanes = [0u64; WIDTH];
+            // SAFETY: `lanes` has exactly 32 bytes and unaligned stores are allowed.
+            unsafe { _mm256_storeu_si256(lanes.as_mut_ptr().cast(), self.value) };
+            out.copy_from_slice(&lanes[..out.len()]);
+        }
+
+        #[inline(always)]
+        fn add(self, other: Self) -> Self {
+            // SAFETY: the module is only compiled with AVX2 enabled.
+            unsafe {
+                // The high halves of the lanes are zero, so 32-bit operations leave them as is.
+                let t = _mm256_add_epi32(self.value, other.value);
+                let p = _mm256_set1_epi64x(Self::P as i64);
+                Self::new(_mm256_min_epu32(t, _mm256_sub_epi32(t, p)))
+            }
+        }
+
+        #[inline(always)]
+        fn sub(self, other: Self) -> Self {
+            // SAFETY: the module is only compiled with AVX2 enabled.
+            unsafe {
+                let t = _mm256_sub_epi32(self.value, other.value);
+                let p = _mm256_set1_epi64x(Self::P as i64);
+                Self::new(_mm256_min_epu32(t, _mm256_add_epi32(t, p)))
+            }
+        }
+
+        #[inline(always)]
+        fn mul(self, other: Self) -> Self {
+            // SAFETY: the module is only compiled with AVX2 enabled.
+            unsafe {
+                let t = _mm256_mul_epu32(self.value, other.value);
+                Self::new(Self::reduce(Self::reduce(t)))
+            }
+        }
+    }
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+mod neon {
+    use super::{IsModulus, PackedMontgomery31, UnsignedInteger};
+    use crate::field::packed::IsPackedField;
+    use core::arch::aarch64::*;
+
+    const WIDTH: usize = 4;
+
+    impl<M, const NUM_LIMBS: usize> PackedMontgomery31<M, NUM_LIMBS>
+    where
+        M: IsModulus<UnsignedInteger<NUM_LIMBS>>,
+    {
+        /// Returns `t / 2^32 mod p` for 64-bit lanes `t < p * 2^32`.
+        #[inline(always)]
+        fn reduce(t: uint64x2_t) -> uint32x2_t {
+            // SAFETY: the module is only compiled with NEON enabled.
+            unsafe {
+                let p = vdup_n_u32(Self::P);
+                let q = vmul_u32(vmovn_u64(t), vdup_n_u32(Self::P_INV));
+                let qp = vmull_u32(q, p);
+                let d = vsub_u32(vshrn_n_u64::<32>(t), vshrn_n_u64::<32>(qp));
+                vmin_u32(d, vadd_u32(d, p))
+            }
+        }
+
+        /// Returns `a / 2^32 mod p` for lanes `a < p`.
+        #[inline(always)]
+        fn reduce_u32(a: uint32x2_t) -> uint32x2_t {
+            // SAFETY: the module is only compiled with NEON enabled.
+            unsafe { Self::reduce(vmovl_u32(a)) }
+        }
+    }
+
+    impl<M, const NUM_LIMBS: usize> IsPackedField for PackedMontgomery31<M, NUM_LIMBS>
+    where
+        M: IsModulus<UnsignedInteger<NUM_LIMBS>>,
+    {
+        type Scalar = u64;
+        const WIDTH: usize = WIDTH;
+
+        #[inline(always)]
+        fn broadcast(value: u64) -> Self {
+            // SAFETY: the module is only compiled with NEON enabled.
+            unsafe { Self::new(vdupq_n_u32(value as u32)) }
+        }
+
+        #[inline(always)]
+        fn load(values: &[u64]) -> Self {
+            let mut lanes = [0u32; WIDTH];
+            for (lane, value) in lanes.iter_mut().zip(values) {
+                *lane = *value as u32;
+            }
+            // SAFETY: `lanes` has exactly four values.
+            Self::new(unsafe { vld1q_u32(lanes.as_ptr()) })
+        }
+
+        #[inline(always)]
+        fn store(self, out: &mut [u64]) {
+            let mut lanes = [0u32; WIDTH];
+            // SAFETY: `lanes` has room for exactly four values.
+            unsafe { vst1q_u32(lanes.as_mut_ptr(), self.value) };
+            for (value, lane) in out.iter_mut().zip(lanes) {
+                *value = lane as u64;
+            }
+        }
+
+        #[inline(always)]
+        fn add(self, other: Self) -> Self {
+            // SAFETY: the module is only compiled with NEON enabled.
+            unsafe {
+                let t = vaddq_u32(self.value, other.value);
+                Self::new(vminq_u32(t, vsubq_u32(t, vdupq_n_u32(Self::P))))
+            }
+        }
+
+        #[inline(always)]
+        fn sub(self, other: Self) -> Self {
+            // SAFETY: the module is only compiled with NEON enabled.
+            unsafe {
+                let t = vsubq_u32(self.value, other.value);
+                Self::new(vminq_u32(t, vaddq_u32(t, vdupq_n_u32(Self::P))))
+            }
+        }
+
+        #[inline(always)]
+        fn mul(self, other: Self) -> Self {
+            // SAFETY: the module is only compiled with NEON enabled.
+            unsafe {
+                let lo = vmull_u32(vget_low_u32(self.value), vget_low_u32(other.value));
+                let hi = vmull_high_u32(self.value, other.value);
+                let lo = Self::reduce_u32(Self::reduce(lo));
+                let hi = Self::reduce_u32(Self::reduce(hi));
+                Self::new(vcombine_u32(lo, hi))
+            }
+        }
+    }
+}
+
+#[cfg(not(any(
+    all(target_arch = "x86_64", target_feature = "avx2"),
+    all(target_arch = "aarch64", target_feature = "neon")
+)))]
+mod portable {
+    use super::{IsModulus, PackedMontgomery31, UnsignedInteger};
+    use crate::field::packed::IsPackedField;
+
+    const WIDTH: usize = 8;
+
+    impl<M, const NUM_LIMBS: usize> PackedMontgomery31<M, NUM_LIMBS>
+    where
+        M: IsModulus<UnsignedInteger<NUM_LIMBS>>,
+    {
+        /// Returns `t / 2^32 mod p` for `t < p * 2^32`.
+        #[inline(always)]
+        fn reduce(t: u64) -> u64 {
+            let q = (t as u32).wrapping_mul(Self::P_INV);
+            let qp = q as u64 * Self::P as u64;
+            let (d, borrow) = ((t >> 32) as u32).overflowing_sub((qp >> 32) as u32);
+            if borrow {
+                d.wrapping_add(Self::P) as u64
+            } else {
+                d as u64
+            }
+        }
+
+        #[inline(always)]
+        fn zip_with(self, other: Self, f: impl Fn(u64, u64) -> u64) -> Self {
+            Self::new(core::array::from_fn(|i| f(self.value[i], other.value[i])))
+        }
+    }
+
+    impl<M, const NUM_LIMBS: usize> IsPackedField for PackedMontgomery31<M, NUM_LIMBS>
+    where
+        M: IsModulus<UnsignedInteger<NUM_LIMBS>>,
+    {
+        type Scalar = u64;
+        const WIDTH: usize = WIDTH;
+
+        #[inline(always)]
+        fn broadcast(value: u64) -> Self {
+            Self::new([value; WIDTH])
+        }
+
+        #[inline(always)]
+        fn load(values: &[u64]) -> Self {
+            let mut lanes = [0u64; WIDTH];
+            let len = values.len().min(WIDTH);
+            lanes[..len].copy_from_slice(&values[..len]);
+            Self::new(lanes)
+        }
+
+        #[inline(always)]
+        fn store(self, out: &mut [u64]) {
+            out.copy_from_slice(&self.value[..out.len()]);
+        }
+
+        #[inline(always)]
+        fn add(self, other: Self) -> Self {
+            self.zip_with(other, |a, b| {
+                let sum = a + b;
+                if sum >= Self::P as u64 {
+                    sum - Self::P as u64
+                } else {
+                    sum
+                }
+            })
+        }
+
+        #[inline(always)]
+        fn sub(self, other: Self) -> Self {
+            self.zip_with(other, |a, b| {
+                if a >= b {
+                    a - b
+                } else {
+                    a + Self::P as u64 - b
+                }
+            })
+        }
+
+        #[inline(always)]
+        fn mul(self, other: Self) -> Self {
+            self.zip_with(other, |a, b| Self::reduce(Self::reduce(a * b)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::{
+        element::FieldElement,
+        fields::fft_friendly::babybear::{
+            Babybear31PrimeField, MontgomeryConfigBabybear31PrimeField,
+        },
+        packed::{test_helpers::assert_batch_operations_match_scalar, IsPackedField},
+    };
+    use proptest::{collection, prelude::*};
+
+    type FE = FieldElement<Babybear31PrimeField>;
+    type Packed = PackedMontgomery31<MontgomeryConfigBabybear31PrimeField, 1>;
+
+    const P: u64 = 2013265921;
+
+    #[test]
+    fn p_inv_is_the_inverse_of_the_modulus() {
+        assert_eq!(Packed::P.wrapping_mul(Packed::P_INV), 1);
+    }
+
+    #[test]
+    fn packed_operations_on_edge_values_match_scalar() {
+        let values: Vec<FE> = [0, 1, 2, P - 2, P - 1, 1 << 30, (1 << 30) + 1]
+            .into_iter()
+            .cycle()
+            .take(3 * Packed::WIDTH + 3)
+            .map(FE::from)
+            .collect();
+        assert_batch_operations_match_scalar(&values);
+    }
+
+    proptest! {
+        #[test]
+        fn packed_operations_match_scalar(values in collection::vec(0..P, 2..100)) {
+            let elements: Vec<_> = values.iter().map(|x| FE::from(*x)).collect();
+            assert_batch_operations_match_scalar(&elements);
+        }
+    }
+}
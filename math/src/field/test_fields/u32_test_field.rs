@@ -1,7 +1,7 @@
 use crate::{
     errors::CreationError,
     field::errors::FieldError,
-    field::traits::{IsFFTField, IsField, IsPrimeField},
+    field::traits::{IsFFTField, IsField, IsPrimeField, IsSmoothFFTField},
 };
 
 #[cfg(feature = "lambdaworks-serde-binary")]
@@ -127,6 +127,12 @@ impl IsFFTField for U32TestField {
     const TWO_ADIC_PRIMITVE_ROOT_OF_UNITY: u32 = 440532289;
 }
 
+// The whole multiplicative group, of order 2^27 * 3 * 5, is smooth and generated by 31.
+impl IsSmoothFFTField for U32TestField {
+    const SMOOTH_SUBGROUP_EXPONENTS: [u64; 3] = [27, 1, 1];
+    const SMOOTH_SUBGROUP_GENERATOR: u32 = 31;
+}
+
 #[cfg(test)]
 mod tests_u32_test_field {
     use crate::field::{test_fields::u32_test_field::U32TestField, traits::IsPrimeField};
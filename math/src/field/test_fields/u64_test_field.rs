@@ -3,7 +3,7 @@ use crate::{
     field::{
         element::FieldElement,
         extensions::quadratic::QuadraticExtensionField,
-        traits::{IsFFTField, IsField, IsPrimeField},
+        traits::{IsFFTField, IsField, IsPrimeField, IsSmoothFFTField},
     },
     field::{errors::FieldError, extensions::quadratic::HasQuadraticNonResidue},
 };
@@ -98,6 +98,12 @@ impl IsFFTField for U64TestField {
     const TWO_ADIC_PRIMITVE_ROOT_OF_UNITY: u64 = 1753635133440165772;
 }
 
+// The multiplicative group has order 2^32 * 3 * 5 * 17 * 257 * 65537. The generator is 7^(17 * 257 * 65537).
+impl IsSmoothFFTField for U64TestField {
+    const SMOOTH_SUBGROUP_EXPONENTS: [u64; 3] = [32, 1, 1];
+    const SMOOTH_SUBGROUP_GENERATOR: u64 = 1226330035855801067;
+}
+
 #[derive(Clone, Debug)]
 pub struct TestNonResidue;
 impl HasQuadraticNonResidue<U64TestField> for TestNonResidue {
@@ -1,4 +1,4 @@
-use super::{element::FieldElement, errors::FieldError};
+use super::{element::FieldElement, errors::FieldError, packed::scalar_batch};
 #[cfg(feature = "lambdaworks-serde-binary")]
 use crate::traits::ByteConversion;
 use crate::{errors::CreationError, unsigned_integer::traits::IsUnsignedInteger};
@@ -22,6 +22,20 @@ pub trait IsSubFieldOf<F: IsField>: IsField {
     fn embed(a: Self::BaseType) -> F::BaseType;
     #[cfg(feature = "std")]
     fn to_subfield_vec(b: F::BaseType) -> Vec<Self::BaseType>;
+
+    /// Sets `(lo[i], hi[i]) = (lo[i] + w * hi[i], lo[i] - w * hi[i])` for every `i`, with `w`
+    /// in the subfield. `lo` and `hi` must have the same length.
+    fn subfield_butterfly_batch(
+        lo: &mut [FieldElement<F>],
+        hi: &mut [FieldElement<F>],
+        w: &FieldElement<Self>,
+    ) {
+        for (x, y) in lo.iter_mut().zip(hi) {
+            let wy = <Self as IsSubFieldOf<F>>::mul(w.value(), y.value());
+            *y = FieldElement::from_raw(F::sub(x.value(), &wy));
+            *x = FieldElement::from_raw(F::add(x.value(), &wy));
+        }
+    }
 }
 
 impl<F> IsSubFieldOf<F> for F
@@ -57,6 +71,15 @@ where
     fn to_subfield_vec(b: F::BaseType) -> Vec<Self::BaseType> {
         vec![b]
     }
+
+    #[inline(always)]
+    fn subfield_butterfly_batch(
+        lo: &mut [FieldElement<F>],
+        hi: &mut [FieldElement<F>],
+        w: &FieldElement<F>,
+    ) {
+        F::butterfly_batch(lo, hi, w)
+    }
 }
 
 /// Trait to define necessary parameters for FFT-friendly Fields.
@@ -93,14 +116,80 @@ pub trait IsFFTField: IsField {
     }
 }
 
+/// Trait for two-adic fields whose two-adicity and roots of unity are only known at runtime, like
+/// the fields with a modulus chosen at runtime. They can't provide the constants of [IsFFTField],
+/// so the FFT is used with them through `Polynomial::evaluate_runtime_fft` and
+/// `Polynomial::interpolate_runtime_fft`.
+pub trait IsRuntimeFFTField: IsField {
+    /// Returns the largest `k` such that $2^k$ divides the order of the multiplicative group.
+    fn two_adicity() -> u64;
+
+    /// Returns a primitive root of unity of order $2^{order}$.
+    fn get_primitive_root_of_unity(order: u64) -> Result<FieldElement<Self>, FieldError>;
+}
+
+/// Trait for FFT-friendly fields whose multiplicative group has a large subgroup of order
+/// $2^a 3^b 5^c$. This subgroup has roots of unity of every order dividing its own, so FFTs can
+/// run over domains whose size isn't a power of two, mixing radices 2, 3 and 5.
+pub trait IsSmoothFFTField: IsFFTField {
+    /// The exponents $[a, b, c]$ of the order $2^a 3^b 5^c$ of the smooth subgroup.
+    const SMOOTH_SUBGROUP_EXPONENTS: [u64; 3];
+    /// A generator of the smooth subgroup.
+    const SMOOTH_SUBGROUP_GENERATOR: Self::BaseType;
+
+    /// Returns a primitive root of unity of order `n`, which needs to divide the order of the
+    /// smooth subgroup.
+    fn get_primitive_root_of_unity_of_order(n: u64) -> Result<FieldElement<Self>, FieldError> {
+        let mut root = FieldElement::new(Self::SMOOTH_SUBGROUP_GENERATOR);
+        let mut remaining = n;
+        for (prime, exponent) in [2u64, 3, 5]
+            .into_iter()
+            .zip(Self::SMOOTH_SUBGROUP_EXPONENTS)
+        {
+            let mut multiplicity = 0;
+            while remaining != 0 && remaining.is_multiple_of(prime) {
+                remaining /= prime;
+                multiplicity += 1;
+            }
+            if multiplicity > exponent {
+                return Err(FieldError::RootOfUnityError(n));
+            }
+            // The order of the generator drops from prime^exponent to prime^multiplicity.
+            for _ in multiplicity..exponent {
+                root = root.pow(prime);
+            }
+        }
+        if remaining != 1 {
+            return Err(FieldError::RootOfUnityError(n));
+        }
+        Ok(root)
+    }
+}
+
+/// Marker trait for fields of characteristic two, where addition and subtraction coincide.
+/// The additive FFT works over these fields instead of relying on multiplicative subgroups.
+pub trait IsBinaryField: IsField {}
+
+/// `Send + Sync` when the `rayon` feature is enabled, so that the FFT and other routines can
+/// split work on field elements across threads. Without it, every type implements this trait.
+#[cfg(feature = "rayon")]
+pub trait IsThreadSafe: Send + Sync {}
+#[cfg(feature = "rayon")]
+impl<T: Send + Sync> IsThreadSafe for T {}
+
+#[cfg(not(feature = "rayon"))]
+pub trait IsThreadSafe {}
+#[cfg(not(feature = "rayon"))]
+impl<T> IsThreadSafe for T {}
+
 /// Trait to add field behaviour to a struct.
 pub trait IsField: Debug + Clone {
     /// The underlying base type for representing elements from the field.
     // TODO: Relax Unpin for non cuda usage
     #[cfg(feature = "lambdaworks-serde-binary")]
-    type BaseType: Clone + Debug + Unpin + ByteConversion;
+    type BaseType: Clone + Debug + Unpin + IsThreadSafe + ByteConversion;
     #[cfg(not(feature = "lambdaworks-serde-binary"))]
-    type BaseType: Clone + Debug + Unpin;
+    type BaseType: Clone + Debug + Unpin + IsThreadSafe;
 
     /// Returns the sum of `a` and `b`.
     fn add(a: &Self::BaseType, b: &Self::BaseType) -> Self::BaseType;
@@ -178,6 +267,42 @@ pub trait IsField: Debug + Clone {
     /// Takes as input an element of BaseType and returns the internal representation
     /// of that element in the field.
     fn from_base_type(x: Self::BaseType) -> Self::BaseType;
+
+    /// Sets `a[i] = a[i] + b[i]` for every `i`. `a` and `b` must have the same length.
+    /// Fields with packed arithmetic override the batch operations to process several
+    /// elements at once.
+    fn add_assign_batch(a: &mut [FieldElement<Self>], b: &[FieldElement<Self>]) {
+        scalar_batch::add_assign(a, b)
+    }
+
+    /// Sets `a[i] = a[i] - b[i]` for every `i`. `a` and `b` must have the same length.
+    fn sub_assign_batch(a: &mut [FieldElement<Self>], b: &[FieldElement<Self>]) {
+        scalar_batch::sub_assign(a, b)
+    }
+
+    /// Sets `a[i] = a[i] * b[i]` for every `i`. `a` and `b` must have the same length.
+    fn mul_assign_batch(a: &mut [FieldElement<Self>], b: &[FieldElement<Self>]) {
+        scalar_batch::mul_assign(a, b)
+    }
+
+    /// Sets `a[i] = a[i] * b[i] + c` for every `i`. `a` and `b` must have the same length.
+    fn mul_add_scalar_batch(
+        a: &mut [FieldElement<Self>],
+        b: &[FieldElement<Self>],
+        c: &FieldElement<Self>,
+    ) {
+        scalar_batch::mul_add_scalar(a, b, c)
+    }
+
+    /// Sets `(lo[i], hi[i]) = (lo[i] + w * hi[i], lo[i] - w * hi[i])` for every `i`.
+    /// `lo` and `hi` must have the same length.
+    fn butterfly_batch(
+        lo: &mut [FieldElement<Self>],
+        hi: &mut [FieldElement<Self>],
+        w: &FieldElement<Self>,
+    ) {
+        scalar_batch::butterfly(lo, hi, w)
+    }
 }
 
 #[derive(PartialEq)]
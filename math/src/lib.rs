@@ -1,5 +1,9 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+// Lets the code generated by `lambdaworks-math-macros` refer to this crate by name,
+// also when the macros are used inside of it.
+extern crate self as lambdaworks_math;
+
 pub mod cyclic_group;
 pub mod elliptic_curve;
 pub mod errors;
@@ -12,8 +16,12 @@ pub mod gpu;
 
 // These modules don't work in no-std mode
 #[cfg(feature = "std")]
+pub mod circle;
+#[cfg(feature = "std")]
 pub mod fft;
 #[cfg(feature = "std")]
 pub mod msm;
 #[cfg(feature = "std")]
 pub mod polynomial;
+#[cfg(feature = "std")]
+pub mod reed_solomon;
@@ -0,0 +1,333 @@
+use crate::field::{element::FieldElement, traits::IsField};
+use std::ops;
+
+use super::MultivariateError;
+
+/// Multilinear polynomial in `n` variables, given by its `2^n` evaluations over the boolean
+/// hypercube `{0, 1}^n`.
+///
+/// The evaluation at `(b_1, ..., b_n)` is at the index whose binary digits are `b_1 ... b_n`,
+/// so the first variable is the most significant bit of the index. This is the multilinear
+/// extension of the evaluations: the only polynomial of degree at most one in each variable
+/// that takes those values over the hypercube.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DenseMultilinearPolynomial<F: IsField> {
+    evals: Vec<FieldElement<F>>,
+    n_vars: usize,
+}
+
+impl<F: IsField> DenseMultilinearPolynomial<F> {
+    /// Returns the multilinear extension of `evals`, padded with zeros up to the next power of
+    /// two. An empty vector gives the zero polynomial in no variables.
+    pub fn new(mut evals: Vec<FieldElement<F>>) -> Self {
+        let len = evals.len().next_power_of_two();
+        evals.resize(len, FieldElement::zero());
+        Self {
+            n_vars: len.trailing_zeros() as usize,
+            evals,
+        }
+    }
+
+    /// Returns the polynomial `eq(x, r)`, which is one when `x = r` over the hypercube and zero
+    /// at its other points, so `sum_x eq(x, r) p(x) = p(r)` for every multilinear `p`.
+    ///
+    /// Each variable doubles the evaluations, by `1 - r_i` where its bit is zero and by `r_i`
+    /// where it's one.
+    pub fn eq(r: &[FieldElement<F>]) -> Self {
+        let mut evals = Vec::with_capacity(1 << r.len());
+        evals.push(FieldElement::one());
+        for r_i in r {
+            evals = evals
+                .iter()
+                .flat_map(|eval| {
+                    let high = eval * r_i;
+                    [eval - &high, high]
+                })
+                .collect();
+        }
+        Self {
+            evals,
+            n_vars: r.len(),
+        }
+    }
+
+    /// Evaluates `eq(x, r) = prod_i (x_i r_i + (1 - x_i) (1 - r_i))`.
+    pub fn eq_evaluation(
+        x: &[FieldElement<F>],
+        r: &[FieldElement<F>],
+    ) -> Result<FieldElement<F>, MultivariateError> {
+        if x.len() != r.len() {
+            return Err(MultivariateError::WrongNumberOfVariables(x.len(), r.len()));
+        }
+        Ok(x.iter()
+            .zip(r)
+            .fold(FieldElement::one(), |product, (x_i, r_i)| {
+                let x_r = x_i * r_i;
+                product * (&x_r + &x_r - x_i - r_i + FieldElement::one())
+            }))
+    }
+
+    pub fn num_vars(&self) -> usize {
+        self.n_vars
+    }
+
+    pub fn len(&self) -> usize {
+        self.evals.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.evals.is_empty()
+    }
+
+    /// Returns the evaluations over the hypercube.
+    pub fn evals(&self) -> &[FieldElement<F>] {
+        &self.evals
+    }
+
+    /// Evaluates the polynomial at `point`, fixing one variable at a time.
+    pub fn evaluate(
+        &self,
+        point: &[FieldElement<F>],
+    ) -> Result<FieldElement<F>, MultivariateError> {
+        if point.len() != self.n_vars {
+            return Err(MultivariateError::WrongNumberOfVariables(
+                self.n_vars,
+                point.len(),
+            ));
+        }
+        Ok(self.fix_variables(point)?.evals.swap_remove(0))
+    }
+
+    /// Returns the polynomial in the remaining variables after setting the first one to `r`.
+    /// Panics if the polynomial has no variables.
+    pub fn fix_first_variable(&self, r: &FieldElement<F>) -> Self {
+        assert!(self.n_vars > 0, "the polynomial has no variables to fix");
+        let (low, high) = self.evals.split_at(self.len() / 2);
+        let evals = low
+            .iter()
+            .zip(high)
+            .map(|(low, high)| low + r * (high - low))
+            .collect();
+        Self {
+            evals,
+            n_vars: self.n_vars - 1,
+        }
+    }
+
+    /// Returns the polynomial in the remaining variables after setting the last one to `r`.
+    /// Panics if the polynomial has no variables.
+    pub fn fix_last_variable(&self, r: &FieldElement<F>) -> Self {
+        assert!(self.n_vars > 0, "the polynomial has no variables to fix");
+        let evals = self
+            .evals
+            .chunks(2)
+            .map(|pair| &pair[0] + r * (&pair[1] - &pair[0]))
+            .collect();
+        Self {
+            evals,
+            n_vars: self.n_vars - 1,
+        }
+    }
+
+    /// Returns the polynomial in the remaining variables after setting the first
+    /// `partial_point.len()` ones to its values.
+    pub fn fix_variables(
+        &self,
+        partial_point: &[FieldElement<F>],
+    ) -> Result<Self, MultivariateError> {
+        if partial_point.len() > self.n_vars {
+            return Err(MultivariateError::WrongNumberOfVariables(
+                self.n_vars,
+                partial_point.len(),
+            ));
+        }
+        Ok(partial_point
+            .iter()
+            .fold(self.clone(), |poly, r| poly.fix_first_variable(r)))
+    }
+
+    /// Returns the sum of the evaluations over the hypercube.
+    pub fn sum_over_hypercube(&self) -> FieldElement<F> {
+        self.evals.iter().cloned().sum()
+    }
+
+    /// Returns the polynomial multiplied by `scalar`.
+    pub fn scale(&self, scalar: &FieldElement<F>) -> Self {
+        Self {
+            evals: self.evals.iter().map(|eval| eval * scalar).collect(),
+            n_vars: self.n_vars,
+        }
+    }
+}
+
+impl<F: IsField> ops::Add<&DenseMultilinearPolynomial<F>> for &DenseMultilinearPolynomial<F> {
+    type Output = DenseMultilinearPolynomial<F>;
+
+    /// Panics if the polynomials have a different number of variables.
+    fn add(self, other: &DenseMultilinearPolynomial<F>) -> DenseMultilinearPolynomial<F> {
+        assert_eq!(self.n_vars, other.n_vars, "different number of variables");
+        DenseMultilinearPolynomial {
+            evals: self
+                .evals
+                .iter()
+                .zip(&other.evals)
+                .map(|(a, b)| a + b)
+                .collect(),
+            n_vars: self.n_vars,
+        }
+    }
+}
+
+impl<F: IsField> ops::Sub<&DenseMultilinearPolynomial<F>> for &DenseMultilinearPolynomial<F> {
+    type Output = DenseMultilinearPolynomial<F>;
+
+    /// Panics if the polynomials have a different number of variables.
+    fn sub(self, other: &DenseMultilinearPolynomial<F>) -> DenseMultilinearPolynomial<F> {
+        assert_eq!(self.n_vars, other.n_vars, "different number of variables");
+        DenseMultilinearPolynomial {
+            evals: self
+                .evals
+                .iter()
+                .zip(&other.evals)
+                .map(|(a, b)| a - b)
+                .collect(),
+            n_vars: self.n_vars,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::fields::u64_prime_field::U64PrimeField;
+    use proptest::{collection, prelude::*};
+
+    type F = U64PrimeField<101>;
+    type FE = FieldElement<F>;
+
+    fn fes(values: &[u64]) -> Vec<FE> {
+        values.iter().map(|v| FE::from(*v)).collect()
+    }
+
+    /// Returns the point of the hypercube for the index `i`, first variable first.
+    fn hypercube_point(i: usize, n_vars: usize) -> Vec<FE> {
+        (0..n_vars)
+            .map(|k| FE::from(((i >> (n_vars - 1 - k)) & 1) as u64))
+            .collect()
+    }
+
+    #[test]
+    fn new_pads_to_power_of_two() {
+        let poly = DenseMultilinearPolynomial::new(fes(&[1, 2, 3]));
+        assert_eq!(poly.num_vars(), 2);
+        assert_eq!(poly.evals(), fes(&[1, 2, 3, 0]));
+
+        let empty = DenseMultilinearPolynomial::<F>::new(Vec::new());
+        assert_eq!(empty.num_vars(), 0);
+        assert_eq!(empty.evaluate(&[]).unwrap(), FE::zero());
+    }
+
+    #[test]
+    fn evaluation_over_hypercube_gives_evaluations() {
+        let poly = DenseMultilinearPolynomial::new(fes(&[3, 1, 4, 1, 5, 9, 2, 6]));
+        for i in 0..8 {
+            assert_eq!(
+                poly.evaluate(&hypercube_point(i, 3)).unwrap(),
+                poly.evals()[i]
+            );
+        }
+    }
+
+    #[test]
+    fn evaluation_of_two_variables_by_hand() {
+        // p(x1, x2) = 2 (1 - x1)(1 - x2) + 3 (1 - x1) x2 + 5 x1 (1 - x2) + 7 x1 x2
+        let poly = DenseMultilinearPolynomial::new(fes(&[2, 3, 5, 7]));
+        let (x1, x2) = (FE::from(10), FE::from(20));
+        let one = FE::one();
+        let expected = FE::from(2) * (one - x1) * (one - x2)
+            + FE::from(3) * (one - x1) * x2
+            + FE::from(5) * x1 * (one - x2)
+            + FE::from(7) * x1 * x2;
+        assert_eq!(poly.evaluate(&[x1, x2]).unwrap(), expected);
+    }
+
+    #[test]
+    fn fixing_first_and_last_variables() {
+        let poly = DenseMultilinearPolynomial::new(fes(&[3, 1, 4, 1, 5, 9, 2, 6]));
+        let (a, b, c) = (FE::from(17), FE::from(33), FE::from(58));
+        let expected = poly.evaluate(&[a, b, c]).unwrap();
+
+        let fixed = poly.fix_first_variable(&a).fix_last_variable(&c);
+        assert_eq!(fixed.num_vars(), 1);
+        assert_eq!(fixed.evaluate(std::slice::from_ref(&b)).unwrap(), expected);
+
+        let partial = poly.fix_variables(&[a, b]).unwrap();
+        assert_eq!(partial.evaluate(&[c]).unwrap(), expected);
+    }
+
+    #[test]
+    fn wrong_number_of_variables_fails() {
+        let poly = DenseMultilinearPolynomial::new(fes(&[1, 2, 3, 4]));
+        assert_eq!(
+            poly.evaluate(&fes(&[1])),
+            Err(MultivariateError::WrongNumberOfVariables(2, 1))
+        );
+        assert_eq!(
+            poly.fix_variables(&fes(&[1, 2, 3])),
+            Err(MultivariateError::WrongNumberOfVariables(2, 3))
+        );
+    }
+
+    #[test]
+    fn eq_is_indicator_over_hypercube() {
+        let r = hypercube_point(5, 3);
+        let eq = DenseMultilinearPolynomial::eq(&r);
+        for i in 0..8 {
+            let expected = if i == 5 { FE::one() } else { FE::zero() };
+            assert_eq!(eq.evals()[i], expected);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn sum_against_eq_evaluates_polynomial(
+            evals in collection::vec(0..101_u64, 8),
+            r in collection::vec(0..101_u64, 3),
+        ) {
+            let poly = DenseMultilinearPolynomial::new(fes(&evals));
+            let r = fes(&r);
+            let eq = DenseMultilinearPolynomial::eq(&r);
+
+            let sum: FE = eq.evals().iter().zip(poly.evals()).map(|(e, p)| e * p).sum();
+            prop_assert_eq!(sum, poly.evaluate(&r).unwrap());
+            for i in 0..8 {
+                let x = hypercube_point(i, 3);
+                prop_assert_eq!(
+                    &DenseMultilinearPolynomial::eq_evaluation(&x, &r).unwrap(),
+                    &eq.evals()[i]
+                );
+            }
+        }
+
+        #[test]
+        fn operations_are_pointwise(
+            a in collection::vec(0..101_u64, 4),
+            b in collection::vec(0..101_u64, 4),
+            point in collection::vec(0..101_u64, 2),
+        ) {
+            let a = DenseMultilinearPolynomial::new(fes(&a));
+            let b = DenseMultilinearPolynomial::new(fes(&b));
+            let point = fes(&point);
+            let a_at = a.evaluate(&point).unwrap();
+            let b_at = b.evaluate(&point).unwrap();
+
+            prop_assert_eq!((&a + &b).evaluate(&point).unwrap(), a_at + b_at);
+            prop_assert_eq!((&a - &b).evaluate(&point).unwrap(), a_at - b_at);
+            let scaled = a.scale(&FE::from(7)).evaluate(&point).unwrap();
+            prop_assert_eq!(scaled, FE::from(7) * a_at);
+
+            let sum: FE = (0..4).map(|i| a.evaluate(&hypercube_point(i, 2)).unwrap()).sum();
+            prop_assert_eq!(a.sum_over_hypercube(), sum);
+        }
+    }
+}
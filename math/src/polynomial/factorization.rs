@@ -0,0 +1,504 @@
+use crate::field::{
+    element::FieldElement,
+    traits::{IsField, IsPrimeField, IsSubFieldOf},
+};
+use crate::unsigned_integer::traits::IsUnsignedInteger;
+
+use super::Polynomial;
+
+/// Generator of the pseudo-random polynomials used to split polynomials in Cantor–Zassenhaus.
+/// A fixed seed makes the factorizations deterministic, while any sequence that doesn't follow
+/// the structure of the polynomials splits them with probability about one half at each try.
+struct SplittingPolynomials {
+    state: u64,
+}
+
+impl SplittingPolynomials {
+    fn new() -> Self {
+        Self {
+            state: 0x9E37_79B9_7F4A_7C15,
+        }
+    }
+
+    /// SplitMix64.
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    fn next_polynomial<F: IsField>(&mut self, length: usize) -> Polynomial<FieldElement<F>> {
+        let coefficients: Vec<_> = (0..length)
+            .map(|_| FieldElement::from(self.next_u64()))
+            .collect();
+        Polynomial::new(&coefficients)
+    }
+}
+
+impl<F: IsPrimeField> Polynomial<FieldElement<F>> {
+    /// Returns the remainder of the division by `modulus`.
+    fn reduce(&self, modulus: &Self) -> Self {
+        let (_quotient, remainder) = self.clone().long_division_with_remainder(modulus);
+        remainder
+    }
+
+    /// Returns the order of the field, which is its characteristic.
+    fn characteristic() -> F::RepresentativeType {
+        F::modulus_minus_one() + F::RepresentativeType::from(1)
+    }
+
+    /// Returns `self^exponent` modulo `modulus`, by square and multiply.
+    pub fn pow_mod<T: IsUnsignedInteger>(&self, exponent: T, modulus: &Self) -> Self {
+        let zero = T::from(0);
+        let one = T::from(1);
+        let mut result = Polynomial::new(&[FieldElement::one()]).reduce(modulus);
+        let mut base = self.reduce(modulus);
+        let mut exponent = exponent;
+        while exponent != zero {
+            if exponent & one == one {
+                result = result.mul_with_ref(&base).reduce(modulus);
+            }
+            base = base.mul_with_ref(&base).reduce(modulus);
+            exponent = exponent >> 1;
+        }
+        result
+    }
+
+    /// Returns the polynomial `g` such that `self = g^p`, for a nonconstant polynomial whose
+    /// derivative is zero, along with `p`. Its coefficients are nonzero only at multiples of
+    /// `p`, and the Frobenius map is the identity over the prime field, so `g` has the same
+    /// coefficients at the indices divided by `p`.
+    fn characteristic_root(&self) -> (Self, usize) {
+        // Every index with a nonzero coefficient is a multiple of p, so p isn't larger than
+        // the degree and it's the smallest index that is zero as an element of the field.
+        let p = (1..=self.degree())
+            .find(|i| FieldElement::<F>::from(*i as u64) == FieldElement::zero())
+            .expect("a nonconstant polynomial with zero derivative has degree at least p");
+        let coefficients: Vec<_> = self.coefficients.iter().step_by(p).cloned().collect();
+        (Polynomial::new(&coefficients), p)
+    }
+
+    /// Square-free factorization. Returns pairs `(g_i, i)` with distinct multiplicities `i` and
+    /// square-free, pairwise coprime monic polynomials `g_i` such that `self` is its leading
+    /// coefficient times the product of the `g_i^i`. Returns no pairs for constants.
+    pub fn square_free_factorization(&self) -> Vec<(Self, usize)> {
+        let f = self.to_monic();
+        if f.degree() == 0 {
+            return Vec::new();
+        }
+
+        let derivative = f.differentiate();
+        if derivative == Polynomial::zero() {
+            return Self::square_free_factorization_of_power(f.characteristic_root());
+        }
+
+        // c holds the factors of multiplicity larger than i, with multiplicity reduced by one,
+        // and w the product of the factors of multiplicity at least i.
+        let one = Polynomial::new(&[FieldElement::one()]);
+        let mut factors = Vec::new();
+        let mut c = Polynomial::gcd(&f, &derivative);
+        let mut w = f.div_with_ref(&c);
+        let mut i = 1;
+        while w != one {
+            let y = Polynomial::gcd(&w, &c);
+            let factor = w.div_with_ref(&y);
+            if factor != one {
+                factors.push((factor, i));
+            }
+            w = y;
+            c = c.div_with_ref(&w);
+            i += 1;
+        }
+
+        // The factors left in c have multiplicity divisible by p.
+        if c != one {
+            factors.extend(Self::square_free_factorization_of_power(
+                c.characteristic_root(),
+            ));
+        }
+        factors
+    }
+
+    /// Returns the square-free factorization of `root^p`.
+    fn square_free_factorization_of_power((root, p): (Self, usize)) -> Vec<(Self, usize)> {
+        root.square_free_factorization()
+            .into_iter()
+            .map(|(factor, multiplicity)| (factor, multiplicity * p))
+            .collect()
+    }
+
+    /// Distinct-degree factorization of a square-free polynomial. Returns pairs `(g_d, d)` where
+    /// `g_d` is the monic product of all the irreducible factors of degree `d`, for the degrees
+    /// `d` that have any.
+    ///
+    /// `x^(p^d) - x` is the product of the irreducible monic polynomials of degree dividing `d`,
+    /// so its gcd with what is left of the polynomial after removing the factors of smaller
+    /// degree has the factors of degree `d`.
+    pub fn distinct_degree_factorization(&self) -> Vec<(Self, usize)> {
+        let one = Polynomial::new(&[FieldElement::one()]);
+        let x = Polynomial::new_monomial(FieldElement::one(), 1);
+        let p = Self::characteristic();
+
+        let mut factors = Vec::new();
+        let mut f = self.to_monic();
+        let mut h = x.clone();
+        let mut d = 1;
+        while f.degree() >= 2 * d {
+            h = h.pow_mod(p, &f);
+            let g = Polynomial::gcd(&f, &(&h - &x));
+            if g != one {
+                f = f.div_with_ref(&g);
+                h = h.reduce(&f);
+                factors.push((g, d));
+            }
+            d += 1;
+        }
+        if f.degree() > 0 {
+            let degree = f.degree();
+            factors.push((f, degree));
+        }
+        factors
+    }
+
+    /// Equal-degree factorization with Cantor–Zassenhaus. Splits a square-free polynomial
+    /// whose irreducible factors all have degree `degree` into them, as monic polynomials.
+    ///
+    /// For odd `p`, half of the units of each `F_p[x] / (g_i)` are squares, so a polynomial `a`
+    /// raised to `(p^d - 1) / 2` is `1` modulo about half of the factors, which the gcd with
+    /// that power minus one separates. For `p = 2`, the trace `a + a^2 + ... + a^(2^(d-1))` is
+    /// `0` modulo about half of them instead.
+    pub fn equal_degree_factorization(&self, degree: usize) -> Vec<Self> {
+        let f = self.to_monic();
+        if f.degree() == 0 {
+            return Vec::new();
+        }
+
+        let mut splitting_polynomials = SplittingPolynomials::new();
+        let mut pending = vec![f];
+        let mut factors = Vec::new();
+        while let Some(g) = pending.pop() {
+            if g.degree() <= degree {
+                factors.push(g);
+                continue;
+            }
+            loop {
+                let a = splitting_polynomials.next_polynomial::<F>(g.degree());
+                let h = Polynomial::gcd(&g, &Self::splitting_map(&a, degree, &g));
+                if h.degree() > 0 && h.degree() < g.degree() {
+                    pending.push(g.div_with_ref(&h));
+                    pending.push(h);
+                    break;
+                }
+            }
+        }
+        factors
+    }
+
+    /// Returns `a^((p^d - 1) / 2) - 1` modulo `g` for odd `p`, and the trace of `a` for `p = 2`.
+    fn splitting_map(a: &Self, degree: usize, g: &Self) -> Self {
+        let p = Self::characteristic();
+        let mut frobenius = a.reduce(g);
+        if FieldElement::<F>::from(2_u64) == FieldElement::zero() {
+            let mut trace = frobenius.clone();
+            for _ in 1..degree {
+                frobenius = frobenius.pow_mod(p, g);
+                trace = trace + &frobenius;
+            }
+            return trace;
+        }
+
+        // (p^d - 1) / 2 = (1 + p + ... + p^(d-1)) (p - 1) / 2
+        let mut norm = frobenius.clone();
+        for _ in 1..degree {
+            frobenius = frobenius.pow_mod(p, g);
+            norm = norm.mul_with_ref(&frobenius).reduce(g);
+        }
+        norm.pow_mod(F::modulus_minus_one() >> 1, g) - FieldElement::<F>::one()
+    }
+
+    /// Returns the monic irreducible factors of the polynomial with their multiplicities.
+    /// Returns no factors for constants.
+    pub fn factor(&self) -> Vec<(Self, usize)> {
+        let mut factors = Vec::new();
+        for (square_free, multiplicity) in self.square_free_factorization() {
+            for (product, degree) in square_free.distinct_degree_factorization() {
+                factors.extend(
+                    product
+                        .equal_degree_factorization(degree)
+                        .into_iter()
+                        .map(|factor| (factor, multiplicity)),
+                );
+            }
+        }
+        factors
+    }
+
+    /// Returns the distinct roots of the polynomial in the field, with Cantor–Zassenhaus.
+    /// The zero polynomial is reported as having no roots.
+    pub fn roots(&self) -> Vec<FieldElement<F>> {
+        if self.degree() == 0 {
+            return Vec::new();
+        }
+
+        // The gcd with x^p - x is the product of the x - r for the roots r.
+        let x = Polynomial::new_monomial(FieldElement::one(), 1);
+        let f = self.to_monic();
+        let frobenius = x.pow_mod(Self::characteristic(), &f);
+        let linear_factors = Polynomial::gcd(&f, &(frobenius - &x));
+
+        linear_factors
+            .equal_degree_factorization(1)
+            .into_iter()
+            .map(|factor| -&factor.coefficients[0])
+            .collect()
+    }
+
+    /// Rabin's irreducibility test. A polynomial of degree `n` is irreducible when
+    /// `x^(p^n) = x` modulo it, and `x^(p^(n/q)) - x` is coprime with it for every prime `q`
+    /// dividing `n`. Constants aren't irreducible.
+    pub fn is_irreducible(&self) -> bool {
+        let n = self.degree();
+        if n == 0 {
+            return false;
+        }
+
+        let one = Polynomial::new(&[FieldElement::one()]);
+        let x = Polynomial::new_monomial(FieldElement::one(), 1);
+        let f = self.to_monic();
+        let p = Self::characteristic();
+
+        // frobenius_powers[i] = x^(p^i) mod f
+        let mut frobenius_powers = vec![x.reduce(&f)];
+        for i in 1..=n {
+            let next = frobenius_powers[i - 1].pow_mod(p, &f);
+            frobenius_powers.push(next);
+        }
+
+        frobenius_powers[n] == x.reduce(&f)
+            && prime_divisors(n)
+                .into_iter()
+                .all(|q| Polynomial::gcd(&f, &(&frobenius_powers[n / q] - &x)) == one)
+    }
+}
+
+/// Returns the distinct prime divisors of `n`.
+pub(crate) fn prime_divisors(mut n: usize) -> Vec<usize> {
+    let mut divisors = Vec::new();
+    let mut q = 2;
+    while q * q <= n {
+        if n.is_multiple_of(q) {
+            divisors.push(q);
+            while n.is_multiple_of(q) {
+                n /= q;
+            }
+        }
+        q += 1;
+    }
+    if n > 1 {
+        divisors.push(n);
+    }
+    divisors
+}
+
+/// Returns the minimal polynomial over the prime field `F` of an element of an extension `E`
+/// of it: the monic polynomial of smallest degree over `F` that vanishes at `element`.
+///
+/// It's the product of `x - c` over the distinct conjugates `c = element^(p^i)`, whose
+/// coefficients are fixed by the Frobenius map, so they are in `F`.
+pub fn minimal_polynomial<F, E>(element: &FieldElement<E>) -> Polynomial<FieldElement<F>>
+where
+    F: IsPrimeField + IsSubFieldOf<E>,
+    E: IsField,
+{
+    let p = Polynomial::<FieldElement<F>>::characteristic();
+    let mut product = Polynomial::new(&[FieldElement::<E>::one()]);
+    let mut conjugate = element.clone();
+    loop {
+        product = product.mul_with_ref(&Polynomial::new(&[-&conjugate, FieldElement::one()]));
+        conjugate = conjugate.pow(p);
+        if conjugate == *element {
+            break;
+        }
+    }
+
+    let coefficients: Vec<_> = product
+        .coefficients
+        .into_iter()
+        .map(|coefficient| {
+            // The coordinates of an element of F are its embedding and zeros.
+            let coordinates = <F as IsSubFieldOf<E>>::to_subfield_vec(coefficient.value().clone());
+            FieldElement::<F>::from_raw(coordinates[0].clone())
+        })
+        .collect();
+    Polynomial::new(&coefficients)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::{
+        fields::u64_prime_field::U64PrimeField,
+        test_fields::u64_test_field::{U64TestField, U64TestFieldExtension},
+    };
+
+    type F23 = U64PrimeField<23>;
+    type FE = FieldElement<F23>;
+
+    fn poly(coefficients: &[u64]) -> Polynomial<FE> {
+        let coefficients: Vec<_> = coefficients.iter().map(|c| FE::from(*c)).collect();
+        Polynomial::new(&coefficients)
+    }
+
+    fn product(factors: &[(Polynomial<FE>, usize)]) -> Polynomial<FE> {
+        factors
+            .iter()
+            .fold(poly(&[1]), |acc, (factor, multiplicity)| {
+                (0..*multiplicity).fold(acc, |acc, _| acc.mul_with_ref(factor))
+            })
+    }
+
+    fn sorted(mut factors: Vec<(Polynomial<FE>, usize)>) -> Vec<(Polynomial<FE>, usize)> {
+        factors.sort_by_key(|(factor, multiplicity)| {
+            let coefficients: Vec<_> = factor.coefficients().iter().map(|c| *c.value()).collect();
+            (factor.degree(), coefficients, *multiplicity)
+        });
+        factors
+    }
+
+    #[test]
+    fn pow_mod_reduces_powers() {
+        // x^2 = -1 modulo x^2 + 1, so x^23 = -x.
+        let x = poly(&[0, 1]);
+        assert_eq!(x.pow_mod(23_u64, &poly(&[1, 0, 1])), poly(&[0, 22]));
+    }
+
+    #[test]
+    fn square_free_factorization_separates_multiplicities() {
+        // 3 (x + 5) (x^2 + 1)^2 (x - 1)^3
+        let factors = [
+            (poly(&[5, 1]), 1),
+            (poly(&[1, 0, 1]), 2),
+            (poly(&[22, 1]), 3),
+        ];
+        let f = product(&factors).scale_coeffs(&FE::from(3));
+
+        assert_eq!(f.square_free_factorization(), factors.to_vec());
+    }
+
+    #[test]
+    fn square_free_factorization_of_multiplicity_divisible_by_characteristic() {
+        // (x + 2) (x - 1)^23 = (x + 2) (x^23 - 1)
+        let factors = [(poly(&[2, 1]), 1), (poly(&[22, 1]), 23)];
+        let f = product(&factors);
+
+        assert_eq!(f.square_free_factorization(), factors.to_vec());
+    }
+
+    #[test]
+    fn distinct_degree_factorization_groups_factors_by_degree() {
+        let linear = poly(&[3, 1]).mul_with_ref(&poly(&[20, 1]));
+        let quadratic = poly(&[1, 0, 1]);
+        let cubic = poly(&[1, 3, 0, 1]);
+        let f = linear.mul_with_ref(&quadratic).mul_with_ref(&cubic);
+
+        assert_eq!(
+            f.distinct_degree_factorization(),
+            vec![(linear, 1), (quadratic, 2), (cubic, 3)]
+        );
+    }
+
+    #[test]
+    fn factor_returns_irreducible_factors_with_multiplicities() {
+        let factors = vec![
+            (poly(&[20, 1]), 1),
+            (poly(&[16, 1]), 2),
+            (poly(&[1, 0, 1]), 1),
+            (poly(&[1, 3, 0, 1]), 2),
+        ];
+        let f = product(&factors).scale_coeffs(&FE::from(5));
+
+        assert_eq!(sorted(f.factor()), sorted(factors));
+    }
+
+    #[test]
+    fn roots_over_large_field() {
+        type FE = FieldElement<U64TestField>;
+        let roots = [FE::from(5), FE::from(17), FE::from(123456789)];
+        let f = roots
+            .iter()
+            .fold(Polynomial::new(&[FE::from(7)]), |acc, root| {
+                acc.mul_with_ref(&Polynomial::new(&[-root, FE::one()]))
+            })
+            .mul_with_ref(&Polynomial::new(&[-&roots[1], FE::one()]))
+            // 7 isn't a square
+            .mul_with_ref(&Polynomial::new(&[-FE::from(7), FE::zero(), FE::one()]));
+
+        let mut found = f.roots();
+        found.sort_by_key(|root| *root.value());
+        assert_eq!(found, roots.to_vec());
+    }
+
+    #[test]
+    fn polynomials_without_roots() {
+        assert!(poly(&[1, 3, 0, 1]).roots().is_empty());
+        assert!(poly(&[4]).roots().is_empty());
+        assert!(Polynomial::<FE>::zero().roots().is_empty());
+    }
+
+    #[test]
+    fn irreducibility_test() {
+        assert!(poly(&[1, 0, 1]).is_irreducible());
+        assert!(poly(&[1, 3, 0, 1]).is_irreducible());
+        assert!(poly(&[7, 2]).is_irreducible());
+        assert!(!poly(&[22, 0, 1]).is_irreducible());
+        assert!(!poly(&[1, 0, 1])
+            .mul_with_ref(&poly(&[1, 0, 1]))
+            .is_irreducible());
+        // 23 = 7 mod 8, so x^4 + 1 splits in quadratic factors.
+        assert!(!poly(&[1, 0, 0, 0, 1]).is_irreducible());
+        assert!(!poly(&[5]).is_irreducible());
+    }
+
+    #[test]
+    fn factoring_in_characteristic_two() {
+        type FE = FieldElement<U64PrimeField<2>>;
+        let x_plus_one = Polynomial::new(&[FE::one(), FE::one()]);
+        let x_squared_plus_x_plus_one = Polynomial::new(&[FE::one(), FE::one(), FE::one()]);
+        // x^3 + 1
+        let f = Polynomial::new(&[FE::one(), FE::zero(), FE::zero(), FE::one()]);
+
+        let mut factors = f.factor();
+        factors.sort_by_key(|(factor, _)| factor.degree());
+        assert_eq!(
+            factors,
+            vec![(x_plus_one, 1), (x_squared_plus_x_plus_one, 1)]
+        );
+
+        let mut roots = Polynomial::new(&[FE::zero(), FE::one(), FE::one()]).roots();
+        roots.sort_by_key(|root| *root.value());
+        assert_eq!(roots, vec![FE::zero(), FE::one()]);
+    }
+
+    #[test]
+    fn minimal_polynomial_of_extension_element() {
+        type FE = FieldElement<U64TestField>;
+        type ExtFE = FieldElement<U64TestFieldExtension>;
+        // u^2 = 7, so a + b u is a root of x^2 - 2 a x + a^2 - 7 b^2.
+        let (a, b) = (FE::from(3), FE::from(5));
+        let element = ExtFE::new([a.clone(), b.clone()]);
+
+        let minimal = minimal_polynomial::<U64TestField, U64TestFieldExtension>(&element);
+        let expected =
+            Polynomial::new(&[a.square() - FE::from(7) * b.square(), -(&a + &a), FE::one()]);
+        assert_eq!(minimal, expected);
+        assert_eq!(minimal.evaluate(&element), ExtFE::zero());
+
+        let base_element = ExtFE::new([a.clone(), FE::zero()]);
+        assert_eq!(
+            minimal_polynomial::<U64TestField, U64TestFieldExtension>(&base_element),
+            Polynomial::new(&[-&a, FE::one()])
+        );
+    }
+}
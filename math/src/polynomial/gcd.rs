@@ -0,0 +1,161 @@
+use crate::field::{element::FieldElement, traits::IsField};
+
+use super::Polynomial;
+
+impl<F: IsField> Polynomial<FieldElement<F>> {
+    /// Returns the polynomial divided by its leading coefficient. The zero polynomial stays zero.
+    pub fn to_monic(&self) -> Self {
+        match self.leading_coefficient().inv() {
+            Ok(inverse) => self.scale_coeffs(&inverse),
+            Err(_) => Polynomial::zero(),
+        }
+    }
+
+    /// Extended Euclidean algorithm. Returns `(g, s, t)` such that `g` is the monic greatest
+    /// common divisor of `a` and `b` and `s * a + t * b = g`.
+    /// The gcd of two zero polynomials is zero.
+    pub fn xgcd(a: &Self, b: &Self) -> (Self, Self, Self) {
+        let (mut old_r, mut r) = (a.clone(), b.clone());
+        let (mut old_s, mut s) = (Polynomial::new(&[FieldElement::one()]), Polynomial::zero());
+        let (mut old_t, mut t) = (Polynomial::zero(), Polynomial::new(&[FieldElement::one()]));
+
+        while r != Polynomial::zero() {
+            let (quotient, remainder) = old_r.long_division_with_remainder(&r);
+            old_r = core::mem::replace(&mut r, remainder);
+            let new_s = &old_s - quotient.mul_with_ref(&s);
+            old_s = core::mem::replace(&mut s, new_s);
+            let new_t = &old_t - quotient.mul_with_ref(&t);
+            old_t = core::mem::replace(&mut t, new_t);
+        }
+
+        match old_r.leading_coefficient().inv() {
+            Ok(inverse) => (
+                old_r.scale_coeffs(&inverse),
+                old_s.scale_coeffs(&inverse),
+                old_t.scale_coeffs(&inverse),
+            ),
+            Err(_) => (Polynomial::zero(), Polynomial::zero(), Polynomial::zero()),
+        }
+    }
+
+    /// Returns the monic greatest common divisor of `a` and `b`.
+    pub fn gcd(a: &Self, b: &Self) -> Self {
+        let (mut a, mut b) = (a.clone(), b.clone());
+        while b != Polynomial::zero() {
+            let (_quotient, remainder) = a.long_division_with_remainder(&b);
+            a = core::mem::replace(&mut b, remainder);
+        }
+        a.to_monic()
+    }
+
+    /// Returns the resultant of `a` and `b`, the product of `b(x)` over the roots `x` of `a`
+    /// times `lc(a)^deg(b)`. It's zero exactly when they have a common root in the algebraic
+    /// closure, or when one of them is zero.
+    ///
+    /// Uses `res(a, b) = (-1)^(deg a deg b) lc(b)^(deg a - deg r) res(b, r)`, where `r` is the
+    /// remainder of `a` divided by `b`.
+    pub fn resultant(a: &Self, b: &Self) -> FieldElement<F> {
+        let (mut a, mut b) = (a.clone(), b.clone());
+        let mut result = FieldElement::one();
+        loop {
+            if a == Polynomial::zero() || b == Polynomial::zero() {
+                return FieldElement::zero();
+            }
+            let (n, m) = (a.degree(), b.degree());
+            if m == 0 {
+                return result * b.leading_coefficient().pow(n as u64);
+            }
+
+            let (_quotient, remainder) = a.long_division_with_remainder(&b);
+            if remainder == Polynomial::zero() {
+                return FieldElement::zero();
+            }
+            if n % 2 == 1 && m % 2 == 1 {
+                result = -result;
+            }
+            result = result * b.leading_coefficient().pow((n - remainder.degree()) as u64);
+            a = core::mem::replace(&mut b, remainder);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::fields::u64_prime_field::U64PrimeField;
+    use proptest::{collection, prelude::*};
+
+    type FE = FieldElement<U64PrimeField<23>>;
+
+    fn poly(coefficients: &[u64]) -> Polynomial<FE> {
+        let coefficients: Vec<_> = coefficients.iter().map(|c| FE::from(*c)).collect();
+        Polynomial::new(&coefficients)
+    }
+
+    #[test]
+    fn gcd_of_polynomials_with_common_factor() {
+        // a = (x - 1)(x - 2)(x + 3), b = 5 (x - 1)(x - 2)(x - 4)
+        let common = poly(&[22, 1]).mul_with_ref(&poly(&[21, 1]));
+        let a = common.mul_with_ref(&poly(&[3, 1]));
+        let b = common
+            .mul_with_ref(&poly(&[19, 1]))
+            .scale_coeffs(&FE::from(5));
+
+        assert_eq!(Polynomial::gcd(&a, &b), common);
+    }
+
+    #[test]
+    fn gcd_with_zero_is_monic_polynomial() {
+        let a = poly(&[4, 6, 2]);
+        assert_eq!(Polynomial::gcd(&a, &Polynomial::zero()), poly(&[2, 3, 1]));
+        assert_eq!(
+            Polynomial::<FE>::gcd(&Polynomial::zero(), &Polynomial::zero()),
+            Polynomial::zero()
+        );
+    }
+
+    #[test]
+    fn resultant_of_linear_polynomials() {
+        // res(x - 3, x - 7) = 3 - 7
+        assert_eq!(
+            Polynomial::resultant(&poly(&[20, 1]), &poly(&[16, 1])),
+            FE::from(3) - FE::from(7)
+        );
+    }
+
+    #[test]
+    fn resultant_is_product_of_evaluations_at_roots() {
+        // a = 2 (x - 1)(x - 5), so res(a, b) = 2^deg(b) b(1) b(5)
+        let a = poly(&[5, 17, 1]).scale_coeffs(&FE::from(2));
+        let b = poly(&[7, 3, 0, 11]);
+        let expected = FE::from(2).pow(3_u64) * b.evaluate(&FE::from(1)) * b.evaluate(&FE::from(5));
+
+        assert_eq!(Polynomial::resultant(&a, &b), expected);
+    }
+
+    #[test]
+    fn resultant_of_polynomials_with_common_root_is_zero() {
+        let a = poly(&[22, 1]).mul_with_ref(&poly(&[3, 4, 1]));
+        let b = poly(&[22, 1]).mul_with_ref(&poly(&[9, 1]));
+        assert_eq!(Polynomial::resultant(&a, &b), FE::zero());
+    }
+
+    proptest! {
+        #[test]
+        fn xgcd_satisfies_bezout_identity(
+            a in collection::vec(0..23_u64, 0..10),
+            b in collection::vec(0..23_u64, 0..10),
+        ) {
+            let (a, b) = (poly(&a), poly(&b));
+            let (g, s, t) = Polynomial::xgcd(&a, &b);
+
+            prop_assert_eq!(&g, &Polynomial::gcd(&a, &b));
+            prop_assert_eq!(s.mul_with_ref(&a) + t.mul_with_ref(&b), g.clone());
+            if g != Polynomial::zero() {
+                prop_assert_eq!(g.leading_coefficient(), FE::one());
+                let (_q, r) = a.long_division_with_remainder(&g);
+                prop_assert_eq!(r, Polynomial::zero());
+            }
+        }
+    }
+}
@@ -0,0 +1,344 @@
+use crate::fft::errors::FFTError;
+use crate::field::{
+    element::FieldElement,
+    traits::{IsFFTField, IsField, IsSubFieldOf},
+};
+use std::ops;
+
+use super::Polynomial;
+
+/// Polynomial in the Lagrange basis of a domain, that is, given by its evaluations over the
+/// domain instead of by its coefficients.
+///
+/// The domain is the coset `offset * <w>` of the subgroup of order `2^order`, and the `i`-th
+/// evaluation is the one at `offset * w^i`. Adding or multiplying polynomials over the same
+/// domain is done pointwise, which gives the right result as long as the degree of the result
+/// stays below the size of the domain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LagrangePolynomial<F: IsFFTField> {
+    order: u64,
+    offset: FieldElement<F>,
+    /// A primitive root of unity of order `2^order`, which generates the subgroup.
+    root: FieldElement<F>,
+    evaluations: Vec<FieldElement<F>>,
+}
+
+impl<F: IsFFTField> LagrangePolynomial<F> {
+    /// Creates the polynomial with `evaluations` over the subgroup of their size, which must be
+    /// a power of two.
+    pub fn new(evaluations: Vec<FieldElement<F>>) -> Result<Self, FFTError> {
+        Self::new_with_offset(evaluations, FieldElement::one())
+    }
+
+    /// Creates the polynomial with `evaluations` over the coset `offset * <w>` of the subgroup
+    /// of their size, which must be a power of two. Returns an error if `offset` is zero.
+    pub fn new_with_offset(
+        evaluations: Vec<FieldElement<F>>,
+        offset: FieldElement<F>,
+    ) -> Result<Self, FFTError> {
+        if !evaluations.len().is_power_of_two() {
+            return Err(FFTError::InputError(evaluations.len()));
+        }
+        if offset == FieldElement::zero() {
+            return Err(FFTError::CosetOffsetError);
+        }
+        let order = evaluations.len().trailing_zeros() as u64;
+        let root = F::get_primitive_root_of_unity(order)?;
+        Ok(Self {
+            order,
+            offset,
+            root,
+            evaluations,
+        })
+    }
+
+    /// Evaluates `poly` over the coset `offset * <w>` of the subgroup of order `2^order`, with
+    /// FFT. Returns an error if it has more coefficients than points in the domain.
+    pub fn from_polynomial(
+        poly: &Polynomial<FieldElement<F>>,
+        order: u64,
+        offset: FieldElement<F>,
+    ) -> Result<Self, FFTError> {
+        let size = 1_usize << order;
+        if poly.coeff_len() > size {
+            return Err(FFTError::DomainSizeError(poly.coeff_len(), size));
+        }
+        let evaluations = Polynomial::evaluate_offset_fft::<F>(poly, 1, Some(size), &offset)?;
+        Self::new_with_offset(evaluations, offset)
+    }
+
+    /// Returns the polynomial in the monomial basis, with an inverse FFT.
+    pub fn to_polynomial(&self) -> Result<Polynomial<FieldElement<F>>, FFTError> {
+        Polynomial::interpolate_offset_fft::<F>(&self.evaluations, &self.offset)
+    }
+
+    pub fn evaluations(&self) -> &[FieldElement<F>] {
+        &self.evaluations
+    }
+
+    pub fn order(&self) -> u64 {
+        self.order
+    }
+
+    pub fn offset(&self) -> &FieldElement<F> {
+        &self.offset
+    }
+
+    pub fn size(&self) -> usize {
+        self.evaluations.len()
+    }
+
+    /// Returns the points of the domain, `offset * w^i`, in order.
+    pub fn domain_points(&self) -> Vec<FieldElement<F>> {
+        let mut points = Vec::with_capacity(self.size());
+        points.extend((0..self.size()).scan(self.offset.clone(), |point, _| {
+            let current = point.clone();
+            *point = &*point * &self.root;
+            Some(current)
+        }));
+        points
+    }
+
+    /// Evaluates the polynomial at `z`, in the field or in an extension of it, with the
+    /// barycentric formula. When `z` is in the domain, returns its evaluation.
+    ///
+    /// With `n` the size of the domain and `h` its offset, the polynomial that vanishes on it is
+    /// `z^n - h^n`, so
+    /// `p(z) = (z^n - h^n) / (n h^n) * sum_i p(x_i) x_i / (z - x_i)`.
+    /// The `n` denominators are inverted all at once.
+    pub fn evaluate<E: IsField>(&self, z: &FieldElement<E>) -> FieldElement<E>
+    where
+        F: IsSubFieldOf<E>,
+    {
+        let points = self.domain_points();
+        // (x_i - z), which is zero only for a point of the domain.
+        let mut differences: Vec<FieldElement<E>> = points.iter().map(|x| x - z).collect();
+        if let Some(i) = differences
+            .iter()
+            .position(|difference| *difference == FieldElement::zero())
+        {
+            return self.evaluations[i].clone().to_extension();
+        }
+        FieldElement::inplace_batch_inverse(&mut differences).unwrap();
+
+        let sum: FieldElement<E> = points
+            .iter()
+            .zip(&self.evaluations)
+            .zip(&differences)
+            .map(|((x, evaluation), inverse)| (x * evaluation) * inverse)
+            .sum();
+
+        // p(z) = (h^n - z^n) / (n h^n) * sum_i p(x_i) x_i / (x_i - z)
+        let size = self.size() as u64;
+        let offset_power = self.offset.pow(size);
+        let vanishing = &offset_power - z.pow(size);
+        // The domain only has powers of two points, so its size is invertible in an FFT field.
+        let scale = (FieldElement::<F>::from(size) * offset_power)
+            .inv()
+            .unwrap();
+        scale * vanishing * sum
+    }
+
+    /// Returns the evaluations of the same polynomial over the domain `blowup_factor` times
+    /// larger with the same offset, which is its low degree extension. `blowup_factor` must be
+    /// a power of two.
+    pub fn extend(&self, blowup_factor: usize) -> Result<Self, FFTError> {
+        if !blowup_factor.is_power_of_two() {
+            return Err(FFTError::InputError(blowup_factor));
+        }
+        let poly = self.to_polynomial()?;
+        let evaluations = Polynomial::evaluate_offset_fft::<F>(
+            &poly,
+            blowup_factor,
+            Some(self.size()),
+            &self.offset,
+        )?;
+        Self::new_with_offset(evaluations, self.offset.clone())
+    }
+
+    /// Applies `operation` to the evaluations of both polynomials at each point.
+    /// Panics if they aren't over the same domain.
+    fn pointwise(
+        &self,
+        other: &Self,
+        operation: impl Fn(&FieldElement<F>, &FieldElement<F>) -> FieldElement<F>,
+    ) -> Self {
+        assert!(
+            self.order == other.order && self.offset == other.offset,
+            "Lagrange polynomials must be over the same domain"
+        );
+        let evaluations = self
+            .evaluations
+            .iter()
+            .zip(&other.evaluations)
+            .map(|(a, b)| operation(a, b))
+            .collect();
+        self.with_evaluations(evaluations)
+    }
+
+    /// Returns the polynomial with `evaluations` over the same domain.
+    fn with_evaluations(&self, evaluations: Vec<FieldElement<F>>) -> Self {
+        Self {
+            order: self.order,
+            offset: self.offset.clone(),
+            root: self.root.clone(),
+            evaluations,
+        }
+    }
+}
+
+impl<F: IsFFTField> ops::Add<&LagrangePolynomial<F>> for &LagrangePolynomial<F> {
+    type Output = LagrangePolynomial<F>;
+
+    fn add(self, other: &LagrangePolynomial<F>) -> LagrangePolynomial<F> {
+        self.pointwise(other, |a, b| a + b)
+    }
+}
+
+impl<F: IsFFTField> ops::Sub<&LagrangePolynomial<F>> for &LagrangePolynomial<F> {
+    type Output = LagrangePolynomial<F>;
+
+    fn sub(self, other: &LagrangePolynomial<F>) -> LagrangePolynomial<F> {
+        self.pointwise(other, |a, b| a - b)
+    }
+}
+
+impl<F: IsFFTField> ops::Mul<&LagrangePolynomial<F>> for &LagrangePolynomial<F> {
+    type Output = LagrangePolynomial<F>;
+
+    fn mul(self, other: &LagrangePolynomial<F>) -> LagrangePolynomial<F> {
+        self.pointwise(other, |a, b| a * b)
+    }
+}
+
+impl<F: IsFFTField> ops::Mul<&FieldElement<F>> for &LagrangePolynomial<F> {
+    type Output = LagrangePolynomial<F>;
+
+    fn mul(self, scalar: &FieldElement<F>) -> LagrangePolynomial<F> {
+        self.with_evaluations(self.evaluations.iter().map(|a| a * scalar).collect())
+    }
+}
+
+impl<F: IsFFTField> ops::Neg for &LagrangePolynomial<F> {
+    type Output = LagrangePolynomial<F>;
+
+    fn neg(self) -> LagrangePolynomial<F> {
+        self.with_evaluations(self.evaluations.iter().map(|a| -a).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::test_fields::u64_test_field::{U64TestField, U64TestFieldExtension};
+    use proptest::{collection, prelude::*};
+
+    type FE = FieldElement<U64TestField>;
+    type ExtFE = FieldElement<U64TestFieldExtension>;
+
+    fn polynomial(coefficients: &[u64]) -> Polynomial<FE> {
+        let coefficients: Vec<_> = coefficients.iter().map(|c| FE::from(*c)).collect();
+        Polynomial::new(&coefficients)
+    }
+
+    #[test]
+    fn evaluations_are_at_domain_points() {
+        let poly = polynomial(&[3, 1, 4, 1, 5]);
+        let lagrange = LagrangePolynomial::from_polynomial(&poly, 3, FE::from(7)).unwrap();
+
+        assert_eq!(lagrange.size(), 8);
+        assert_eq!(
+            lagrange.evaluations(),
+            poly.evaluate_slice(&lagrange.domain_points())
+        );
+        assert_eq!(lagrange.to_polynomial().unwrap(), poly);
+    }
+
+    #[test]
+    fn barycentric_evaluation_matches_polynomial() {
+        let poly = polynomial(&[9, 2, 6, 5, 3, 5, 8]);
+        let lagrange = LagrangePolynomial::from_polynomial(&poly, 3, FE::from(3)).unwrap();
+
+        let z = FE::from(123456789);
+        assert_eq!(lagrange.evaluate(&z), poly.evaluate(&z));
+
+        let z = ExtFE::new([FE::from(11), FE::from(13)]);
+        assert_eq!(lagrange.evaluate(&z), poly.evaluate(&z));
+    }
+
+    #[test]
+    fn barycentric_evaluation_at_domain_point_is_its_evaluation() {
+        let poly = polynomial(&[1, 2, 3]);
+        let lagrange = LagrangePolynomial::from_polynomial(&poly, 2, FE::one()).unwrap();
+
+        let point = &lagrange.domain_points()[3];
+        assert_eq!(lagrange.evaluate(point), lagrange.evaluations()[3]);
+    }
+
+    #[test]
+    fn pointwise_operations_match_polynomial_operations() {
+        let p = polynomial(&[1, 2, 3]);
+        let q = polynomial(&[4, 0, 5, 6]);
+        let offset = FE::from(5);
+        let lp = LagrangePolynomial::from_polynomial(&p, 3, offset.clone()).unwrap();
+        let lq = LagrangePolynomial::from_polynomial(&q, 3, offset).unwrap();
+
+        assert_eq!((&lp + &lq).to_polynomial().unwrap(), &p + &q);
+        assert_eq!((&lp - &lq).to_polynomial().unwrap(), &p - &q);
+        assert_eq!((&lp * &lq).to_polynomial().unwrap(), &p * &q);
+        assert_eq!((-&lp).to_polynomial().unwrap(), -&p);
+        assert_eq!(
+            (&lp * &FE::from(3)).to_polynomial().unwrap(),
+            p.scale_coeffs(&FE::from(3))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "same domain")]
+    fn operations_over_different_domains_panic() {
+        let p = polynomial(&[1, 2]);
+        let lp = LagrangePolynomial::from_polynomial(&p, 2, FE::one()).unwrap();
+        let lq = LagrangePolynomial::from_polynomial(&p, 2, FE::from(3)).unwrap();
+        let _ = &lp + &lq;
+    }
+
+    #[test]
+    fn invalid_sizes_are_rejected() {
+        assert!(matches!(
+            LagrangePolynomial::new(vec![FE::one(); 6]),
+            Err(FFTError::InputError(6))
+        ));
+        assert!(matches!(
+            LagrangePolynomial::from_polynomial(&polynomial(&[1; 5]), 2, FE::one()),
+            Err(FFTError::DomainSizeError(5, 4))
+        ));
+    }
+
+    #[test]
+    fn zero_offset_is_rejected() {
+        assert!(matches!(
+            LagrangePolynomial::new_with_offset(vec![FE::one(); 4], FE::zero()),
+            Err(FFTError::CosetOffsetError)
+        ));
+        assert!(matches!(
+            LagrangePolynomial::from_polynomial(&polynomial(&[1, 2]), 2, FE::zero()),
+            Err(FFTError::CosetOffsetError)
+        ));
+    }
+
+    proptest! {
+        #[test]
+        fn extension_matches_evaluation_over_larger_domain(
+            coefficients in collection::vec(any::<u64>(), 1..16),
+            offset in 1..u64::from(u32::MAX),
+        ) {
+            let poly = polynomial(&coefficients);
+            let offset = FE::from(offset);
+            let lagrange = LagrangePolynomial::from_polynomial(&poly, 4, offset.clone()).unwrap();
+
+            let extended = lagrange.extend(4).unwrap();
+            let expected = LagrangePolynomial::from_polynomial(&poly, 6, offset).unwrap();
+            prop_assert_eq!(extended, expected);
+        }
+    }
+}
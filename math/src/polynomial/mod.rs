@@ -1,7 +1,23 @@
 use super::field::element::FieldElement;
-use crate::field::traits::{IsField, IsSubFieldOf};
+use crate::fft::errors::FFTError;
+use crate::field::traits::{IsFFTField, IsField, IsSubFieldOf};
 use std::ops;
 
+pub mod dense_multilinear_poly;
+pub mod factorization;
+pub mod gcd;
+pub mod lagrange;
+pub mod sparse_multivariate_poly;
+pub mod subproduct_tree;
+
+/// Minimum length of the operands for which [Polynomial::fast_multiplication] uses FFT instead of
+/// the schoolbook multiplication.
+pub const FFT_MULTIPLICATION_THRESHOLD: usize = 64;
+
+/// Minimum degree of the divisor and of the quotient for which [Polynomial::fast_division] uses
+/// Newton iteration instead of long division.
+pub const FAST_DIVISION_THRESHOLD: usize = 64;
+
 /// Represents the polynomial c_0 + c_1 * X + c_2 * X^2 + ... + c_n * X^n
 /// as a vector of coefficients `[c_0, c_1, ... , c_n]`
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -38,6 +54,9 @@ impl<F: IsField> Polynomial<FieldElement<F>> {
     /// Returns a polynomial that interpolates the points with x coordinates and y coordinates given by
     /// `xs` and `ys`.
     /// `xs` and `ys` must be the same length, and `xs` values should be unique. If not, panics.
+    ///
+    /// This takes a quadratic number of operations. Over FFT-friendly fields, use
+    /// [Self::fast_interpolate()], which switches to a subproduct tree for many points.
     pub fn interpolate(
         xs: &[FieldElement<F>],
         ys: &[FieldElement<F>],
@@ -103,8 +122,14 @@ impl<F: IsField> Polynomial<FieldElement<F>> {
             })
     }
 
+    /// Evaluates the polynomial at every element of `input`, running Horner's rule on all of
+    /// them at once with the batch operations of the field.
     pub fn evaluate_slice(&self, input: &[FieldElement<F>]) -> Vec<FieldElement<F>> {
-        input.iter().map(|x| self.evaluate(x)).collect()
+        let mut result = vec![FieldElement::zero(); input.len()];
+        for coeff in self.coefficients.iter().rev() {
+            FieldElement::mul_add_scalar_batch(&mut result, input, coeff);
+        }
+        result
     }
 
     pub fn degree(&self) -> usize {
@@ -166,6 +191,9 @@ impl<F: IsField> Polynomial<FieldElement<F>> {
 
     /// Computes quotient and remainder of polynomial division.
     ///
+    /// This takes a quadratic number of operations. Over FFT-friendly fields, use
+    /// [Self::fast_division()], which switches to Newton iteration for large polynomials.
+    ///
     /// Output: (quotient, remainder)
     pub fn long_division_with_remainder(self, dividend: &Self) -> (Self, Self) {
         if dividend.degree() > self.degree() {
@@ -233,6 +261,33 @@ impl<F: IsField> Polynomial<FieldElement<F>> {
         }
     }
 
+    /// Returns the formal derivative of the polynomial.
+    pub fn differentiate(&self) -> Self {
+        let coefficients: Vec<_> = self
+            .coefficients
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, coeff)| FieldElement::<F>::from(i as u64) * coeff)
+            .collect();
+        Polynomial::new(&coefficients)
+    }
+
+    /// Returns the polynomial with the first `length` coefficients of this one, that is, this
+    /// polynomial modulo `X^length`.
+    fn truncate(&self, length: usize) -> Self {
+        Polynomial::new(&self.coefficients[..length.min(self.coeff_len())])
+    }
+
+    /// Returns `X^degree * p(1 / X)`, the polynomial with the coefficients in reverse order when
+    /// this one is seen as a polynomial of degree `degree`.
+    fn reverse(&self, degree: usize) -> Self {
+        let mut coefficients = self.coefficients.clone();
+        coefficients.resize(degree + 1, FieldElement::zero());
+        coefficients.reverse();
+        Polynomial::new(&coefficients)
+    }
+
     /// Returns a vector of polynomials [p₀, p₁, ..., p_{d-1}], where d is `number_of_parts`, such that `self` equals
     /// p₀(Xᵈ) + Xp₁(Xᵈ) + ... + X^(d-1)p_{d-1}(Xᵈ).
     ///
@@ -267,6 +322,100 @@ impl<F: IsField> Polynomial<FieldElement<F>> {
     }
 }
 
+impl<E: IsField> Polynomial<FieldElement<E>> {
+    /// Multiplies two polynomials by evaluating them with FFT over a domain of the subfield `F`
+    /// large enough for their product, and interpolating the product of the evaluations.
+    pub fn fast_fft_multiplication<F: IsFFTField + IsSubFieldOf<E>>(
+        &self,
+        other: &Self,
+    ) -> Result<Self, FFTError> {
+        if self.coefficients.is_empty() || other.coefficients.is_empty() {
+            return Ok(Polynomial::zero());
+        }
+
+        let domain_size = self.coeff_len() + other.coeff_len() - 1;
+        let p = Polynomial::evaluate_fft::<F>(self, 1, Some(domain_size))?;
+        let q = Polynomial::evaluate_fft::<F>(other, 1, Some(domain_size))?;
+        let r: Vec<_> = p.into_iter().zip(q).map(|(a, b)| a * b).collect();
+
+        Polynomial::interpolate_fft::<F>(&r)
+    }
+
+    /// Multiplies two polynomials, with FFT if both have at least
+    /// [FFT_MULTIPLICATION_THRESHOLD] coefficients and with the schoolbook multiplication
+    /// otherwise.
+    pub fn fast_multiplication<F: IsFFTField + IsSubFieldOf<E>>(
+        &self,
+        other: &Self,
+    ) -> Result<Self, FFTError> {
+        if self.coeff_len().min(other.coeff_len()) < FFT_MULTIPLICATION_THRESHOLD {
+            Ok(self.mul_with_ref(other))
+        } else {
+            self.fast_fft_multiplication::<F>(other)
+        }
+    }
+
+    /// Computes the inverse of the polynomial modulo `X^k` with Newton iteration, which doubles
+    /// the number of correct coefficients of `g` at each step with `g <- g (2 - p g)`.
+    /// Returns an error if the constant coefficient of the polynomial is zero, since then it has
+    /// no inverse.
+    pub fn invert_polynomial_mod<F: IsFFTField + IsSubFieldOf<E>>(
+        &self,
+        k: usize,
+    ) -> Result<Self, FFTError> {
+        let constant_inverse = self
+            .coefficients
+            .first()
+            .and_then(|constant| constant.inv().ok())
+            .ok_or(FFTError::PolynomialInverseError)?;
+        let mut inverse = Polynomial::new(&[constant_inverse]);
+        let mut precision = 1;
+        while precision < k {
+            precision = (2 * precision).min(k);
+            let product = self
+                .truncate(precision)
+                .fast_multiplication::<F>(&inverse)?
+                .truncate(precision);
+            let correction = FieldElement::<E>::from(2_u64) - product;
+            inverse = inverse
+                .fast_multiplication::<F>(&correction)?
+                .truncate(precision);
+        }
+        Ok(inverse)
+    }
+
+    /// Computes quotient and remainder of polynomial division, in `O(n log n)` with Newton
+    /// iteration when both the divisor and the quotient have degree at least
+    /// [FAST_DIVISION_THRESHOLD], and with long division otherwise.
+    /// Panics if the divisor is zero.
+    ///
+    /// Output: (quotient, remainder)
+    pub fn fast_division<F: IsFFTField + IsSubFieldOf<E>>(
+        &self,
+        divisor: &Self,
+    ) -> Result<(Self, Self), FFTError> {
+        let n = self.degree();
+        let m = divisor.degree();
+        if self.coefficients.is_empty() || n < m {
+            return Ok((Polynomial::zero(), self.clone()));
+        }
+        if m < FAST_DIVISION_THRESHOLD || n - m < FAST_DIVISION_THRESHOLD {
+            return Ok(self.clone().long_division_with_remainder(divisor));
+        }
+
+        // The reversal of the quotient is the reversal of the dividend divided by the reversal
+        // of the divisor, modulo X^(n - m + 1).
+        let divisor_reversed_inverse = divisor.reverse(m).invert_polynomial_mod::<F>(n - m + 1)?;
+        let quotient = self
+            .reverse(n)
+            .fast_multiplication::<F>(&divisor_reversed_inverse)?
+            .truncate(n - m + 1)
+            .reverse(n - m);
+        let remainder = self - quotient.fast_multiplication::<F>(divisor)?;
+        Ok((quotient, remainder))
+    }
+}
+
 pub fn pad_with_zero_coefficients_to_length<F: IsField>(
     pa: &mut Polynomial<FieldElement<F>>,
     n: usize,
@@ -787,6 +936,16 @@ pub enum InterpolateError {
     UnequalLengths(usize, usize),
     #[error("xs values should be unique.")]
     NonUniqueXs,
+    #[error(transparent)]
+    FFTError(#[from] FFTError),
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum MultivariateError {
+    #[error("Expected values for {0} variables, but got {1}")]
+    WrongNumberOfVariables(usize, usize),
+    #[error("Variable {0} is out of range for a polynomial in {1} variables")]
+    VariableOutOfRange(usize, usize),
 }
 
 #[cfg(test)]
@@ -1106,4 +1265,99 @@ mod tests {
             prop_assert_eq!(q, p);
         }
     }
+
+    #[test]
+    fn differentiate_polynomial() {
+        // p = 3 X^3 + X^2 + 2X + 1
+        let p = Polynomial::new(&[FE::new(1), FE::new(2), FE::new(1), FE::new(3)]);
+        let expected = Polynomial::new(&[FE::new(2), FE::new(2), FE::new(9)]);
+        assert_eq!(p.differentiate(), expected);
+        assert_eq!(
+            Polynomial::new(&[FE::new(5)]).differentiate(),
+            Polynomial::zero()
+        );
+    }
+
+    mod fft_field {
+        use super::super::*;
+        use crate::field::test_fields::u64_test_field::U64TestField;
+        use proptest::{collection, prelude::*};
+
+        type FE = FieldElement<U64TestField>;
+
+        fn polynomial(length: usize, seed: u64) -> Polynomial<FE> {
+            let coefficients: Vec<_> = (0..length as u64)
+                .map(|i| FE::from(i * i * seed + 1))
+                .collect();
+            Polynomial::new(&coefficients)
+        }
+
+        #[test]
+        fn fast_multiplication_matches_schoolbook_above_threshold() {
+            let p = polynomial(100, 3);
+            let q = polynomial(150, 7);
+            let product = p.fast_multiplication::<U64TestField>(&q).unwrap();
+            assert_eq!(product, p.mul_with_ref(&q));
+        }
+
+        #[test]
+        fn fast_multiplication_by_zero_is_zero() {
+            let p = polynomial(100, 3);
+            let product = p.fast_fft_multiplication::<U64TestField>(&Polynomial::zero());
+            assert_eq!(product.unwrap(), Polynomial::zero());
+        }
+
+        #[test]
+        fn inverse_mod_x_k_times_polynomial_is_one() {
+            let p = polynomial(90, 5);
+            let inverse = p.invert_polynomial_mod::<U64TestField>(100).unwrap();
+            let product = p.mul_with_ref(&inverse);
+            assert_eq!(product.truncate(100), Polynomial::new(&[FE::one()]));
+        }
+
+        #[test]
+        fn inverse_mod_x_k_of_polynomial_divisible_by_x_fails() {
+            let p = Polynomial::new(&[FE::zero(), FE::one()]);
+            assert!(matches!(
+                p.invert_polynomial_mod::<U64TestField>(10),
+                Err(FFTError::PolynomialInverseError)
+            ));
+            assert!(matches!(
+                Polynomial::<FE>::zero().invert_polynomial_mod::<U64TestField>(10),
+                Err(FFTError::PolynomialInverseError)
+            ));
+        }
+
+        #[test]
+        fn fast_division_matches_long_division_above_threshold() {
+            let dividend = polynomial(300, 11);
+            let divisor = polynomial(120, 13);
+            let (quotient, remainder) = dividend.fast_division::<U64TestField>(&divisor).unwrap();
+            assert_eq!(
+                (quotient, remainder),
+                dividend.long_division_with_remainder(&divisor)
+            );
+        }
+
+        proptest! {
+            #[test]
+            fn fast_division_satisfies_division_identity(
+                dividend in collection::vec(any::<u64>().prop_map(FE::from), 0..300),
+                divisor in collection::vec(any::<u64>().prop_map(FE::from), 1..150),
+            ) {
+                let dividend = Polynomial::new(&dividend);
+                let mut divisor = Polynomial::new(&divisor);
+                if divisor == Polynomial::zero() {
+                    divisor = Polynomial::new(&[FE::one()]);
+                }
+
+                let (quotient, remainder) =
+                    dividend.fast_division::<U64TestField>(&divisor).unwrap();
+                prop_assert!(
+                    remainder == Polynomial::zero() || remainder.degree() < divisor.degree()
+                );
+                prop_assert_eq!(quotient.mul_with_ref(&divisor) + remainder, dividend);
+            }
+        }
+    }
 }
@@ -0,0 +1,190 @@
+use crate::field::{element::FieldElement, traits::IsField};
+use std::collections::BTreeMap;
+
+use super::MultivariateError;
+
+/// Monomial `x_{i_1}^{e_1} ... x_{i_k}^{e_k}`, as the pairs `(i_j, e_j)` sorted by variable,
+/// with distinct variables and positive exponents.
+pub type Monomial = Vec<(usize, usize)>;
+
+/// Multivariate polynomial in `n` variables, given by its nonzero terms.
+///
+/// Only the terms with nonzero coefficients are stored, so it fits polynomials with few terms
+/// and high degree or many variables, like the constraints of a circuit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseMultivariatePolynomial<F: IsField> {
+    n_vars: usize,
+    /// The terms, as coefficients and monomials, sorted by monomial.
+    terms: Vec<(FieldElement<F>, Monomial)>,
+}
+
+impl<F: IsField> SparseMultivariatePolynomial<F> {
+    /// Creates the polynomial in `n_vars` variables with the sum of `terms`. The terms with the
+    /// same monomial are added together and zero exponents and coefficients are dropped.
+    /// Returns an error if a term has a variable out of range.
+    pub fn new(
+        n_vars: usize,
+        terms: &[(FieldElement<F>, Monomial)],
+    ) -> Result<Self, MultivariateError> {
+        let mut combined: BTreeMap<Monomial, FieldElement<F>> = BTreeMap::new();
+        for (coefficient, monomial) in terms {
+            let mut powers: BTreeMap<usize, usize> = BTreeMap::new();
+            for (variable, exponent) in monomial {
+                if *variable >= n_vars {
+                    return Err(MultivariateError::VariableOutOfRange(*variable, n_vars));
+                }
+                *powers.entry(*variable).or_insert(0) += exponent;
+            }
+            let monomial: Monomial = powers
+                .into_iter()
+                .filter(|(_, exponent)| *exponent > 0)
+                .collect();
+            let entry = combined.entry(monomial).or_insert_with(FieldElement::zero);
+            *entry = &*entry + coefficient;
+        }
+
+        let terms = combined
+            .into_iter()
+            .filter(|(_, coefficient)| *coefficient != FieldElement::zero())
+            .map(|(monomial, coefficient)| (coefficient, monomial))
+            .collect();
+        Ok(Self { n_vars, terms })
+    }
+
+    pub fn num_vars(&self) -> usize {
+        self.n_vars
+    }
+
+    pub fn terms(&self) -> &[(FieldElement<F>, Monomial)] {
+        &self.terms
+    }
+
+    /// Evaluates the polynomial at `point`, which has one value per variable.
+    pub fn evaluate(
+        &self,
+        point: &[FieldElement<F>],
+    ) -> Result<FieldElement<F>, MultivariateError> {
+        if point.len() != self.n_vars {
+            return Err(MultivariateError::WrongNumberOfVariables(
+                self.n_vars,
+                point.len(),
+            ));
+        }
+        Ok(self
+            .terms
+            .iter()
+            .map(|(coefficient, monomial)| {
+                monomial
+                    .iter()
+                    .fold(coefficient.clone(), |product, (variable, exponent)| {
+                        product * point[*variable].pow(*exponent as u64)
+                    })
+            })
+            .sum())
+    }
+
+    /// Returns the largest sum of the exponents of a term, which is zero for constants and for
+    /// the zero polynomial.
+    pub fn total_degree(&self) -> usize {
+        self.terms
+            .iter()
+            .map(|(_, monomial)| monomial.iter().map(|(_, exponent)| exponent).sum::<usize>())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns the largest exponent of `variable` in a term.
+    pub fn degree_in(&self, variable: usize) -> Result<usize, MultivariateError> {
+        if variable >= self.n_vars {
+            return Err(MultivariateError::VariableOutOfRange(variable, self.n_vars));
+        }
+        Ok(self
+            .terms
+            .iter()
+            .flat_map(|(_, monomial)| monomial.iter())
+            .filter(|(v, _)| *v == variable)
+            .map(|(_, exponent)| *exponent)
+            .max()
+            .unwrap_or(0))
+    }
+
+    /// Returns whether every term has degree at most one in each variable.
+    pub fn is_multilinear(&self) -> bool {
+        self.terms
+            .iter()
+            .all(|(_, monomial)| monomial.iter().all(|(_, exponent)| *exponent <= 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::fields::u64_prime_field::U64PrimeField;
+
+    type FE = FieldElement<U64PrimeField<101>>;
+
+    /// 3 x0^2 x2 + 5 x1 + 7
+    fn polynomial() -> SparseMultivariatePolynomial<U64PrimeField<101>> {
+        SparseMultivariatePolynomial::new(
+            3,
+            &[
+                (FE::from(3), vec![(0, 2), (2, 1)]),
+                (FE::from(5), vec![(1, 1)]),
+                (FE::from(7), vec![]),
+            ],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn evaluation_matches_hand_computation() {
+        let point = [FE::from(2), FE::from(3), FE::from(4)];
+        // 3 * 4 * 4 + 5 * 3 + 7 = 70
+        assert_eq!(polynomial().evaluate(&point).unwrap(), FE::from(70));
+        assert_eq!(
+            polynomial().evaluate(&point[..2]),
+            Err(MultivariateError::WrongNumberOfVariables(3, 2))
+        );
+    }
+
+    #[test]
+    fn degree_queries() {
+        let poly = polynomial();
+        assert_eq!(poly.total_degree(), 3);
+        assert_eq!(poly.degree_in(0), Ok(2));
+        assert_eq!(poly.degree_in(1), Ok(1));
+        assert_eq!(poly.degree_in(2), Ok(1));
+        assert_eq!(
+            poly.degree_in(3),
+            Err(MultivariateError::VariableOutOfRange(3, 3))
+        );
+        assert!(!poly.is_multilinear());
+    }
+
+    #[test]
+    fn like_terms_are_combined() {
+        // x0 x1 + x1 x0 + 99 x0 x1 + x0^0 x1^0 = 1 over F_101
+        let poly = SparseMultivariatePolynomial::new(
+            2,
+            &[
+                (FE::from(1), vec![(0, 1), (1, 1)]),
+                (FE::from(1), vec![(1, 1), (0, 1)]),
+                (FE::from(99), vec![(0, 1), (1, 1)]),
+                (FE::from(1), vec![(0, 0), (1, 0)]),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(poly.terms(), &[(FE::one(), vec![])]);
+        assert_eq!(poly.total_degree(), 0);
+        assert!(poly.is_multilinear());
+    }
+
+    #[test]
+    fn variables_out_of_range_are_rejected() {
+        assert_eq!(
+            SparseMultivariatePolynomial::new(2, &[(FE::one(), vec![(2, 1)])]),
+            Err(MultivariateError::VariableOutOfRange(2, 2))
+        );
+    }
+}
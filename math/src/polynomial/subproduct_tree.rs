@@ -0,0 +1,251 @@
+use crate::fft::errors::FFTError;
+use crate::field::{element::FieldElement, traits::IsFFTField};
+
+use super::{InterpolateError, Polynomial};
+
+/// Minimum number of points for which [Polynomial::fast_evaluate_slice] and
+/// [Polynomial::fast_interpolate] use a [SubproductTree] instead of the quadratic algorithms.
+pub const SUBPRODUCT_TREE_THRESHOLD: usize = 128;
+
+/// Binary tree of the products of the polynomials `X - x_i` for a set of points `x_i`.
+///
+/// The leaves are the polynomials `X - x_i`, in the order of the points, and each node is the
+/// product of its children, so the root is the polynomial that vanishes on all the points.
+/// When a layer has an odd number of nodes, the last one moves up to the next layer unchanged.
+/// With FFT multiplication and Newton division, the tree evaluates and interpolates polynomials
+/// of degree `n` at `n` arbitrary points in `O(n log^2 n)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubproductTree<F: IsFFTField> {
+    points: Vec<FieldElement<F>>,
+    /// Layers of the tree, from the leaves to the root.
+    layers: Vec<Vec<Polynomial<FieldElement<F>>>>,
+}
+
+impl<F: IsFFTField> SubproductTree<F> {
+    /// Builds the subproduct tree of `points`.
+    pub fn new(points: &[FieldElement<F>]) -> Result<Self, FFTError> {
+        let leaves: Vec<_> = points
+            .iter()
+            .map(|x| Polynomial::new(&[-x, FieldElement::one()]))
+            .collect();
+
+        let mut layers = vec![leaves];
+        while layers[layers.len() - 1].len() > 1 {
+            let next_layer = layers[layers.len() - 1]
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => left.fast_multiplication::<F>(right),
+                    _ => Ok(pair[0].clone()),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            layers.push(next_layer);
+        }
+
+        Ok(Self {
+            points: points.to_vec(),
+            layers,
+        })
+    }
+
+    pub fn points(&self) -> &[FieldElement<F>] {
+        &self.points
+    }
+
+    /// Returns the polynomial `(X - x_0) ... (X - x_{n-1})`, which vanishes on all the points.
+    pub fn vanishing_polynomial(&self) -> Polynomial<FieldElement<F>> {
+        match self.layers[self.layers.len() - 1].first() {
+            Some(root) => root.clone(),
+            None => Polynomial::new(&[FieldElement::one()]),
+        }
+    }
+
+    /// Evaluates `poly` at every point of the tree, in their order.
+    ///
+    /// The remainder of `poly` modulo the root is divided down the tree, so each node gets the
+    /// remainder of `poly` modulo itself, and each leaf `X - x_i` gets `poly(x_i)`.
+    pub fn evaluate(
+        &self,
+        poly: &Polynomial<FieldElement<F>>,
+    ) -> Result<Vec<FieldElement<F>>, FFTError> {
+        if self.points.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut remainders = vec![poly.clone()];
+        for layer in self.layers.iter().rev() {
+            remainders = layer
+                .iter()
+                .enumerate()
+                .map(|(i, node)| {
+                    let (_quotient, remainder) = remainders[i / 2].fast_division::<F>(node)?;
+                    Ok(remainder)
+                })
+                .collect::<Result<Vec<_>, FFTError>>()?;
+        }
+
+        Ok(remainders
+            .into_iter()
+            .map(|remainder| remainder.leading_coefficient())
+            .collect())
+    }
+
+    /// Returns the polynomial of degree less than the number of points that takes the values
+    /// `ys` at the points of the tree.
+    ///
+    /// With `m` the vanishing polynomial, this is `sum_i ys_i / m'(x_i) * m(X) / (X - x_i)`,
+    /// which is computed from the leaves up, joining the sums of two children `a` and `b` as
+    /// `a * right + b * left`, where `left` and `right` are their nodes in the tree.
+    pub fn interpolate(
+        &self,
+        ys: &[FieldElement<F>],
+    ) -> Result<Polynomial<FieldElement<F>>, InterpolateError> {
+        if self.points.len() != ys.len() {
+            return Err(InterpolateError::UnequalLengths(
+                self.points.len(),
+                ys.len(),
+            ));
+        }
+        if ys.is_empty() {
+            return Ok(Polynomial::zero());
+        }
+
+        // m'(x_i) is zero exactly when x_i is repeated.
+        let mut weights = self.evaluate(&self.vanishing_polynomial().differentiate())?;
+        if weights.iter().any(|weight| *weight == FieldElement::zero()) {
+            return Err(InterpolateError::NonUniqueXs);
+        }
+        FieldElement::inplace_batch_inverse(&mut weights).unwrap();
+
+        let mut sums: Vec<_> = weights
+            .iter()
+            .zip(ys)
+            .map(|(weight, y)| Polynomial::new(&[weight * y]))
+            .collect();
+        for layer in self.layers.iter().take(self.layers.len() - 1) {
+            sums =
+                sums.chunks(2)
+                    .zip(layer.chunks(2))
+                    .map(|(sums, nodes)| match (sums, nodes) {
+                        ([a, b], [left, right]) => Ok(a.fast_multiplication::<F>(right)?
+                            + b.fast_multiplication::<F>(left)?),
+                        _ => Ok(sums[0].clone()),
+                    })
+                    .collect::<Result<Vec<_>, FFTError>>()?;
+        }
+
+        Ok(sums.swap_remove(0))
+    }
+}
+
+impl<F: IsFFTField> Polynomial<FieldElement<F>> {
+    /// Evaluates the polynomial at every element of `input`, with a [SubproductTree] if there
+    /// are at least [SUBPRODUCT_TREE_THRESHOLD] of them, and with [Self::evaluate_slice()]
+    /// otherwise.
+    pub fn fast_evaluate_slice(
+        &self,
+        input: &[FieldElement<F>],
+    ) -> Result<Vec<FieldElement<F>>, FFTError> {
+        if input.len() < SUBPRODUCT_TREE_THRESHOLD {
+            Ok(self.evaluate_slice(input))
+        } else {
+            SubproductTree::new(input)?.evaluate(self)
+        }
+    }
+
+    /// Returns a polynomial that interpolates the points with x coordinates and y coordinates
+    /// given by `xs` and `ys`, like [Self::interpolate()]. Uses a [SubproductTree] if there are
+    /// at least [SUBPRODUCT_TREE_THRESHOLD] points.
+    pub fn fast_interpolate(
+        xs: &[FieldElement<F>],
+        ys: &[FieldElement<F>],
+    ) -> Result<Self, InterpolateError> {
+        if xs.len() < SUBPRODUCT_TREE_THRESHOLD {
+            Polynomial::interpolate(xs, ys)
+        } else {
+            SubproductTree::new(xs)?.interpolate(ys)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::test_fields::u64_test_field::U64TestField;
+    use proptest::{collection, prelude::*};
+
+    type FE = FieldElement<U64TestField>;
+
+    fn points(n: u64) -> Vec<FE> {
+        (0..n).map(|i| FE::from(i * i + 3 * i + 7)).collect()
+    }
+
+    #[test]
+    fn vanishing_polynomial_vanishes_on_points() {
+        let xs = points(13);
+        let tree = SubproductTree::new(&xs).unwrap();
+        let vanishing = tree.vanishing_polynomial();
+
+        assert_eq!(vanishing.degree(), 13);
+        assert!(xs.iter().all(|x| vanishing.evaluate(x) == FE::zero()));
+    }
+
+    #[test]
+    fn tree_evaluation_matches_naive_evaluation() {
+        let poly = Polynomial::new(&(0..300_u64).map(FE::from).collect::<Vec<_>>());
+        let xs = points(200);
+
+        let evaluations = SubproductTree::new(&xs).unwrap().evaluate(&poly).unwrap();
+        assert_eq!(evaluations, poly.evaluate_slice(&xs));
+        assert_eq!(poly.fast_evaluate_slice(&xs).unwrap(), evaluations);
+    }
+
+    #[test]
+    fn tree_interpolation_matches_naive_interpolation() {
+        let xs = points(150);
+        let ys: Vec<_> = (0..150_u64).map(|i| FE::from(i * 31 + 5)).collect();
+
+        let poly = Polynomial::fast_interpolate(&xs, &ys).unwrap();
+        assert!(poly.degree() < 150);
+        assert_eq!(poly.evaluate_slice(&xs), ys);
+
+        let small_poly = SubproductTree::new(&xs[..20])
+            .unwrap()
+            .interpolate(&ys[..20])
+            .unwrap();
+        assert_eq!(
+            small_poly,
+            Polynomial::interpolate(&xs[..20], &ys[..20]).unwrap()
+        );
+    }
+
+    #[test]
+    fn interpolation_with_repeated_points_fails() {
+        let mut xs = points(10);
+        xs[7] = xs[2].clone();
+        let tree = SubproductTree::new(&xs).unwrap();
+
+        assert!(matches!(
+            tree.interpolate(&vec![FE::one(); 10]),
+            Err(InterpolateError::NonUniqueXs)
+        ));
+        assert!(matches!(
+            tree.interpolate(&vec![FE::one(); 3]),
+            Err(InterpolateError::UnequalLengths(10, 3))
+        ));
+    }
+
+    proptest! {
+        #[test]
+        fn interpolate_is_inverse_of_evaluate(
+            coefficients in collection::vec(any::<u64>().prop_map(FE::from), 1..40)
+        ) {
+            let poly = Polynomial::new(&coefficients);
+            let xs = points(coefficients.len() as u64);
+            let tree = SubproductTree::new(&xs).unwrap();
+
+            let evaluations = tree.evaluate(&poly).unwrap();
+            prop_assert_eq!(evaluations, poly.evaluate_slice(&xs));
+            prop_assert_eq!(tree.interpolate(&poly.evaluate_slice(&xs)).unwrap(), poly);
+        }
+    }
+}
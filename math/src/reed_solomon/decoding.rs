@@ -0,0 +1,116 @@
+use crate::field::{element::FieldElement, traits::IsFFTField};
+use crate::polynomial::Polynomial;
+
+use super::{ReedSolomonCode, ReedSolomonError};
+
+impl<F: IsFFTField> ReedSolomonCode<F> {
+    /// Returns the number of wrong symbols that [Self::correct_errors] can correct,
+    /// `(n - k) / 2`.
+    pub fn max_correctable_errors(&self) -> usize {
+        (self.codeword_length() - self.message_length) / 2
+    }
+
+    /// Corrects up to [Self::max_correctable_errors] wrong symbols of `received` with Gao's
+    /// algorithm, and returns the closest codeword.
+    ///
+    /// With `g_0 = X^n - offset^n`, the polynomial that vanishes on the domain, and `g_1` the
+    /// interpolant of `received`, the extended Euclidean algorithm on `g_0` and `g_1` is run
+    /// until the remainder `g = u g_0 + v g_1` has degree less than `(n + k) / 2`. When there
+    /// are few enough errors, `v` vanishes on their points and the message polynomial is
+    /// `g / v`. The interpolation is an FFT, the final division is fast for large codes, and
+    /// the rest takes `O(n^2)`.
+    pub fn correct_errors(
+        &self,
+        received: &[FieldElement<F>],
+    ) -> Result<Vec<FieldElement<F>>, ReedSolomonError> {
+        let n = self.codeword_length();
+        let k = self.message_length;
+        if received.len() != n {
+            return Err(ReedSolomonError::WrongLength(n, received.len()));
+        }
+
+        let mut vanishing_coefficients = vec![FieldElement::zero(); n + 1];
+        vanishing_coefficients[0] = -self.offset.pow(n as u64);
+        vanishing_coefficients[n] = FieldElement::one();
+
+        let (mut old_r, mut r) = (
+            Polynomial::new(&vanishing_coefficients),
+            Polynomial::interpolate_offset_fft::<F>(received, &self.offset)?,
+        );
+        let (mut old_v, mut v) = (Polynomial::zero(), Polynomial::new(&[FieldElement::one()]));
+        while r != Polynomial::zero() && 2 * r.degree() >= n + k {
+            let (quotient, remainder) = old_r.fast_division::<F>(&r)?;
+            old_r = core::mem::replace(&mut r, remainder);
+            let new_v = &old_v - quotient.mul_with_ref(&v);
+            old_v = core::mem::replace(&mut v, new_v);
+        }
+
+        let (poly, remainder) = r.fast_division::<F>(&v)?;
+        if remainder != Polynomial::zero() || poly.coeff_len() > k {
+            return Err(ReedSolomonError::TooManyErrors);
+        }
+        let codeword = self.encode_polynomial(&poly)?;
+        let errors = codeword
+            .iter()
+            .zip(received)
+            .filter(|(symbol, received)| symbol != received)
+            .count();
+        if errors > self.max_correctable_errors() {
+            return Err(ReedSolomonError::TooManyErrors);
+        }
+        Ok(codeword)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::test_fields::u64_test_field::U64TestField;
+    use proptest::{collection, prelude::*};
+
+    type FE = FieldElement<U64TestField>;
+
+    fn code() -> ReedSolomonCode<U64TestField> {
+        ReedSolomonCode::new_with_offset(4, 4, FE::from(5)).unwrap()
+    }
+
+    fn codeword(values: &[u64]) -> Vec<FE> {
+        let message: Vec<_> = values.iter().map(|v| FE::from(*v)).collect();
+        code().encode(&message).unwrap()
+    }
+
+    #[test]
+    fn codewords_are_left_unchanged() {
+        let codeword = codeword(&[2, 7, 1, 8]);
+        assert_eq!(code().correct_errors(&codeword).unwrap(), codeword);
+    }
+
+    #[test]
+    fn too_many_errors_are_detected() {
+        let codeword = codeword(&[2, 7, 1, 8]);
+        let mut received = codeword.clone();
+        for symbol in received.iter_mut().take(9) {
+            *symbol = &*symbol + FE::one();
+        }
+        assert!(matches!(
+            code().correct_errors(&received),
+            Err(ReedSolomonError::TooManyErrors)
+        ));
+    }
+
+    proptest! {
+        #[test]
+        fn up_to_half_the_distance_is_corrected(
+            values in collection::vec(any::<u64>(), 4),
+            errors in collection::vec((0..16_usize, 1..1_u64 << 32), 0..=6),
+        ) {
+            let codeword = codeword(&values);
+            let mut received = codeword.clone();
+            for (position, error) in errors {
+                received[position] = &received[position] + FE::from(error);
+            }
+
+            prop_assert_eq!(code().correct_errors(&received).unwrap(), codeword);
+        }
+    }
+}
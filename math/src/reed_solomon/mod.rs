@@ -0,0 +1,408 @@
+//! Reed-Solomon codes over the multiplicative subgroups of FFT fields and their cosets.
+//!
+//! A message of `k` symbols is the list of evaluations of a polynomial of degree less than `k`
+//! over a domain of size `k`, and its codeword is the list of evaluations of the same
+//! polynomial over a domain `blowup_factor` times larger that contains the first one. Any `k`
+//! symbols of the codeword determine the rest, and up to `(n - k) / 2` wrong symbols can be
+//! corrected, where `n` is the length of the codeword.
+
+pub mod decoding;
+pub mod tensor;
+
+use crate::fft::errors::FFTError;
+use crate::field::{element::FieldElement, errors::FieldError, traits::IsFFTField};
+use crate::polynomial::Polynomial;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ReedSolomonError {
+    #[error("The message length and the blowup factor must be powers of two. Got: {0} and {1}")]
+    InvalidParameters(usize, usize),
+    #[error("Expected {0} symbols, got {1}")]
+    WrongLength(usize, usize),
+    #[error("Share index {0} is out of range for codewords of length {1}")]
+    ShareOutOfRange(usize, usize),
+    #[error("Share index {0} appears more than once")]
+    DuplicateShare(usize),
+    #[error("At least {0} shares are needed, got {1}")]
+    NotEnoughShares(usize, usize),
+    #[error("The shares are not from a codeword")]
+    InconsistentShares,
+    #[error("{0} entries of the matrix can't be recovered")]
+    UnrecoverableEntries(usize),
+    #[error("The word has too many errors to be corrected")]
+    TooManyErrors,
+    #[error(transparent)]
+    FFTError(#[from] FFTError),
+}
+
+impl From<FieldError> for ReedSolomonError {
+    fn from(error: FieldError) -> Self {
+        ReedSolomonError::FFTError(error.into())
+    }
+}
+
+/// Reed-Solomon code with messages of length `k` and codewords of length `n = k *
+/// blowup_factor`, both powers of two.
+///
+/// The codeword of a message holds the evaluations of its polynomial at the points
+/// `offset * w^i` for `i` in `0..n`, where `w` is a primitive `n`th root of unity. The code is
+/// systematic: the points `offset * w^(i * blowup_factor)` form the domain of the message, so
+/// symbol `i` of the message is symbol `i * blowup_factor` of the codeword.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReedSolomonCode<F: IsFFTField> {
+    message_length: usize,
+    blowup_factor: usize,
+    offset: FieldElement<F>,
+    /// Primitive root of unity of order `n`.
+    root: FieldElement<F>,
+}
+
+impl<F: IsFFTField> ReedSolomonCode<F> {
+    /// Creates the code over the subgroup of order `message_length * blowup_factor`.
+    pub fn new(message_length: usize, blowup_factor: usize) -> Result<Self, ReedSolomonError> {
+        Self::new_with_offset(message_length, blowup_factor, FieldElement::one())
+    }
+
+    /// Creates the code over the coset of the subgroup of order
+    /// `message_length * blowup_factor` given by `offset`, which must be nonzero.
+    pub fn new_with_offset(
+        message_length: usize,
+        blowup_factor: usize,
+        offset: FieldElement<F>,
+    ) -> Result<Self, ReedSolomonError> {
+        if !message_length.is_power_of_two() || !blowup_factor.is_power_of_two() {
+            return Err(ReedSolomonError::InvalidParameters(
+                message_length,
+                blowup_factor,
+            ));
+        }
+        let order = (message_length * blowup_factor).trailing_zeros() as u64;
+        let root = F::get_primitive_root_of_unity(order)?;
+        Ok(Self {
+            message_length,
+            blowup_factor,
+            offset,
+            root,
+        })
+    }
+
+    /// Returns `k`, the number of symbols of a message.
+    pub fn message_length(&self) -> usize {
+        self.message_length
+    }
+
+    /// Returns `n`, the number of symbols of a codeword.
+    pub fn codeword_length(&self) -> usize {
+        self.message_length * self.blowup_factor
+    }
+
+    pub fn blowup_factor(&self) -> usize {
+        self.blowup_factor
+    }
+
+    pub fn offset(&self) -> &FieldElement<F> {
+        &self.offset
+    }
+
+    /// Returns the points of the domain of the codewords, `offset * w^i`, in order.
+    pub fn domain_points(&self) -> Vec<FieldElement<F>> {
+        let mut points = Vec::with_capacity(self.codeword_length());
+        points.extend(
+            (0..self.codeword_length()).scan(self.offset.clone(), |point, _| {
+                let current = point.clone();
+                *point = &*point * &self.root;
+                Some(current)
+            }),
+        );
+        points
+    }
+
+    /// Returns the polynomial of degree less than `k` whose evaluations over the domain of the
+    /// messages are `message`.
+    pub fn message_polynomial(
+        &self,
+        message: &[FieldElement<F>],
+    ) -> Result<Polynomial<FieldElement<F>>, ReedSolomonError> {
+        if message.len() != self.message_length {
+            return Err(ReedSolomonError::WrongLength(
+                self.message_length,
+                message.len(),
+            ));
+        }
+        Ok(Polynomial::interpolate_offset_fft::<F>(
+            message,
+            &self.offset,
+        )?)
+    }
+
+    /// Encodes `message`, extending its `k` symbols to `n` with two FFTs.
+    pub fn encode(
+        &self,
+        message: &[FieldElement<F>],
+    ) -> Result<Vec<FieldElement<F>>, ReedSolomonError> {
+        let poly = self.message_polynomial(message)?;
+        self.encode_polynomial(&poly)
+    }
+
+    /// Returns the codeword of `poly`, its evaluations over the domain of the codewords.
+    /// The degree of `poly` must be less than `k`.
+    pub fn encode_polynomial(
+        &self,
+        poly: &Polynomial<FieldElement<F>>,
+    ) -> Result<Vec<FieldElement<F>>, ReedSolomonError> {
+        if poly.coeff_len() > self.message_length {
+            return Err(ReedSolomonError::WrongLength(
+                self.message_length,
+                poly.coeff_len(),
+            ));
+        }
+        Ok(Polynomial::evaluate_offset_fft::<F>(
+            poly,
+            self.blowup_factor,
+            Some(self.message_length),
+            &self.offset,
+        )?)
+    }
+
+    /// Returns the message of a codeword, which is in its symbols at the multiples of the
+    /// blowup factor.
+    pub fn message(
+        &self,
+        codeword: &[FieldElement<F>],
+    ) -> Result<Vec<FieldElement<F>>, ReedSolomonError> {
+        if codeword.len() != self.codeword_length() {
+            return Err(ReedSolomonError::WrongLength(
+                self.codeword_length(),
+                codeword.len(),
+            ));
+        }
+        Ok(codeword
+            .iter()
+            .step_by(self.blowup_factor)
+            .cloned()
+            .collect())
+    }
+
+    /// Recovers the codeword from some of its symbols, given as `(index, symbol)` pairs. At
+    /// least `k` shares are needed, and all of them must be correct.
+    ///
+    /// With `Z` the polynomial that vanishes on the points of the missing symbols, the
+    /// evaluations of `P Z` are known over the whole domain: `c_i Z(x_i)` for the shares and
+    /// zero for the missing symbols. So `P Z` is interpolated with an FFT and divided by `Z`.
+    /// Every step, including building `Z`, takes `O(n log n)`.
+    pub fn recover(
+        &self,
+        shares: &[(usize, FieldElement<F>)],
+    ) -> Result<Vec<FieldElement<F>>, ReedSolomonError> {
+        let n = self.codeword_length();
+        let mut symbols: Vec<Option<FieldElement<F>>> = vec![None; n];
+        for (index, symbol) in shares {
+            match symbols.get_mut(*index) {
+                None => return Err(ReedSolomonError::ShareOutOfRange(*index, n)),
+                Some(Some(_)) => return Err(ReedSolomonError::DuplicateShare(*index)),
+                Some(slot) => *slot = Some(symbol.clone()),
+            }
+        }
+        if shares.len() < self.message_length {
+            return Err(ReedSolomonError::NotEnoughShares(
+                self.message_length,
+                shares.len(),
+            ));
+        }
+
+        let missing: Vec<usize> = (0..n).filter(|i| symbols[*i].is_none()).collect();
+        let vanishing = self.vanishing_polynomial(&missing)?;
+        let vanishing_evaluations =
+            Polynomial::evaluate_offset_fft::<F>(&vanishing, 1, Some(n), &self.offset)?;
+
+        let product_evaluations: Vec<_> = symbols
+            .iter()
+            .zip(&vanishing_evaluations)
+            .map(|(symbol, z)| match symbol {
+                Some(symbol) => symbol * z,
+                None => FieldElement::zero(),
+            })
+            .collect();
+        let product = Polynomial::interpolate_offset_fft::<F>(&product_evaluations, &self.offset)?;
+        // `P Z` vanishes on the roots of `Z`, so the remainder is zero.
+        let (poly, _remainder) = product.fast_division::<F>(&vanishing)?;
+        if poly.coeff_len() > self.message_length {
+            return Err(ReedSolomonError::InconsistentShares);
+        }
+        self.encode_polynomial(&poly)
+    }
+
+    /// Returns the monic polynomial that vanishes on the points of the domain at `indices`,
+    /// which must be distinct and less than `n`.
+    ///
+    /// With `a_j` those points, the reversal of the polynomial is `prod_j (1 - a_j X)`, which
+    /// is `exp(-sum_i p_i X^i / i)` as a power series, where `p_i = sum_j a_j^i`. The power sum
+    /// `p_i` is `offset^i` times the evaluation at `w^i` of the polynomial with coefficient 1
+    /// at `indices` and 0 elsewhere, so all of them come from a single FFT.
+    fn vanishing_polynomial(
+        &self,
+        indices: &[usize],
+    ) -> Result<Polynomial<FieldElement<F>>, ReedSolomonError> {
+        let n = self.codeword_length();
+        let m = indices.len();
+        if m == 0 {
+            return Ok(Polynomial::new(&[FieldElement::one()]));
+        }
+        let mut indicator = vec![FieldElement::<F>::zero(); n];
+        for index in indices {
+            indicator[*index] = FieldElement::one();
+        }
+        let sums = Polynomial::evaluate_fft::<F>(&Polynomial::new(&indicator), 1, Some(n))?;
+
+        let inverses = inverses_up_to::<F>(m)?;
+        let mut log = vec![FieldElement::zero(); m + 1];
+        let mut offset_power = FieldElement::<F>::one();
+        for i in 1..=m {
+            offset_power = &offset_power * &self.offset;
+            log[i] = -(&offset_power * &sums[i % n] * &inverses[i]);
+        }
+        let reversed = series_exp::<F>(&log, &inverses)?;
+        let mut coefficients: Vec<_> = (0..=m).map(|i| coefficient(&reversed, i)).collect();
+        coefficients.reverse();
+        Ok(Polynomial::new(&coefficients))
+    }
+}
+
+fn coefficient<F: IsFFTField>(poly: &Polynomial<FieldElement<F>>, i: usize) -> FieldElement<F> {
+    poly.coefficients
+        .get(i)
+        .cloned()
+        .unwrap_or_else(FieldElement::zero)
+}
+
+/// Returns `[0, 1, 1/2, ..., 1/m]`.
+fn inverses_up_to<F: IsFFTField>(m: usize) -> Result<Vec<FieldElement<F>>, FieldError> {
+    let mut inverses: Vec<_> = (0..=m as u64).map(FieldElement::<F>::from).collect();
+    inverses[0] = FieldElement::one();
+    FieldElement::inplace_batch_inverse(&mut inverses)?;
+    inverses[0] = FieldElement::zero();
+    Ok(inverses)
+}
+
+/// Returns `exp(h)` modulo `X^len(h)` for a power series `h` with zero constant term, with the
+/// Newton iteration `g <- g (1 + h - log(g))`, which doubles the number of correct coefficients
+/// of `g` at each step. `inverses` holds `1/i` for `i` up to `len(h)`.
+fn series_exp<F: IsFFTField>(
+    h: &[FieldElement<F>],
+    inverses: &[FieldElement<F>],
+) -> Result<Polynomial<FieldElement<F>>, ReedSolomonError> {
+    let mut exp = Polynomial::new(&[FieldElement::one()]);
+    let mut precision = 1;
+    while precision < h.len() {
+        precision = (2 * precision).min(h.len());
+        // log(g) is the integral of g' / g.
+        let quotient = exp
+            .differentiate()
+            .fast_multiplication::<F>(&exp.invert_polynomial_mod::<F>(precision - 1)?)?;
+        let correction: Vec<_> = (0..precision)
+            .map(|i| match i {
+                0 => &h[0] + FieldElement::one(),
+                _ => &h[i] - coefficient(&quotient, i - 1) * &inverses[i],
+            })
+            .collect();
+        let product = exp.fast_multiplication::<F>(&Polynomial::new(&correction))?;
+        exp = Polynomial::new(&product.coefficients[..precision.min(product.coeff_len())]);
+    }
+    Ok(exp)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::test_fields::u64_test_field::U64TestField;
+    use proptest::{collection, prelude::*};
+
+    type FE = FieldElement<U64TestField>;
+
+    fn message(values: &[u64]) -> Vec<FE> {
+        values.iter().map(|v| FE::from(*v)).collect()
+    }
+
+    #[test]
+    fn codewords_are_evaluations_of_the_message_polynomial() {
+        let code = ReedSolomonCode::new_with_offset(4, 4, FE::from(7)).unwrap();
+        let message = message(&[3, 1, 4, 1]);
+        let codeword = code.encode(&message).unwrap();
+        let poly = code.message_polynomial(&message).unwrap();
+
+        assert_eq!(codeword.len(), 16);
+        assert_eq!(codeword, poly.evaluate_slice(&code.domain_points()));
+        assert_eq!(code.message(&codeword).unwrap(), message);
+    }
+
+    #[test]
+    fn invalid_parameters_are_rejected() {
+        assert!(matches!(
+            ReedSolomonCode::<U64TestField>::new(3, 2),
+            Err(ReedSolomonError::InvalidParameters(3, 2))
+        ));
+        let code = ReedSolomonCode::<U64TestField>::new(4, 2).unwrap();
+        assert!(matches!(
+            code.encode(&message(&[1, 2, 3])),
+            Err(ReedSolomonError::WrongLength(4, 3))
+        ));
+    }
+
+    #[test]
+    fn recovery_rejects_bad_shares() {
+        let code = ReedSolomonCode::new(2, 2).unwrap();
+        let codeword = code.encode(&message(&[5, 8])).unwrap();
+        let share = |i: usize| (i, codeword[i].clone());
+
+        assert!(matches!(
+            code.recover(&[share(0)]),
+            Err(ReedSolomonError::NotEnoughShares(2, 1))
+        ));
+        assert!(matches!(
+            code.recover(&[share(0), share(0)]),
+            Err(ReedSolomonError::DuplicateShare(0))
+        ));
+        assert!(matches!(
+            code.recover(&[share(0), (4, FE::one())]),
+            Err(ReedSolomonError::ShareOutOfRange(4, 4))
+        ));
+        assert!(matches!(
+            code.recover(&[share(0), share(1), (2, &codeword[2] + FE::one())]),
+            Err(ReedSolomonError::InconsistentShares)
+        ));
+    }
+
+    #[test]
+    fn vanishing_polynomial_has_the_missing_points_as_roots() {
+        let code = ReedSolomonCode::new_with_offset(8, 4, FE::from(3)).unwrap();
+        let domain = code.domain_points();
+        let indices = [0, 2, 3, 7, 11, 12, 19, 20, 21, 22, 30];
+        let vanishing = code.vanishing_polynomial(&indices).unwrap();
+        let expected = indices
+            .iter()
+            .fold(Polynomial::new(&[FE::one()]), |acc, i| {
+                acc * Polynomial::new(&[-&domain[*i], FE::one()])
+            });
+
+        assert_eq!(vanishing, expected);
+        assert_eq!(
+            code.vanishing_polynomial(&[]).unwrap(),
+            Polynomial::new(&[FE::one()])
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn any_k_shares_recover_the_codeword(
+            values in collection::vec(any::<u64>(), 8),
+            kept in Just((0..32).collect::<Vec<usize>>()).prop_shuffle(),
+        ) {
+            let code = ReedSolomonCode::new_with_offset(8, 4, FE::from(3)).unwrap();
+            let codeword = code.encode(&message(&values)).unwrap();
+            let shares: Vec<_> = kept[..8].iter().map(|i| (*i, codeword[*i].clone())).collect();
+
+            prop_assert_eq!(code.recover(&shares).unwrap(), codeword);
+        }
+    }
+}
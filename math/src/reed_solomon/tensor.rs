@@ -0,0 +1,223 @@
+use crate::field::{element::FieldElement, traits::IsFFTField};
+
+use super::{ReedSolomonCode, ReedSolomonError};
+
+/// Tensor product of two Reed-Solomon codes, which extends a matrix of data in two dimensions,
+/// like in two-dimensional data availability sampling.
+///
+/// The data is a matrix with as many rows as the message length of `column_code` and as many
+/// columns as the message length of `row_code`. Its extension encodes each row with
+/// `row_code` and then each column of the result with `column_code`, so every row of the
+/// extension is a codeword of `row_code` and every column is a codeword of `column_code`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TensorCode<F: IsFFTField> {
+    row_code: ReedSolomonCode<F>,
+    column_code: ReedSolomonCode<F>,
+}
+
+impl<F: IsFFTField> TensorCode<F> {
+    pub fn new(row_code: ReedSolomonCode<F>, column_code: ReedSolomonCode<F>) -> Self {
+        Self {
+            row_code,
+            column_code,
+        }
+    }
+
+    pub fn row_code(&self) -> &ReedSolomonCode<F> {
+        &self.row_code
+    }
+
+    pub fn column_code(&self) -> &ReedSolomonCode<F> {
+        &self.column_code
+    }
+
+    /// Extends the matrix `data`, given as its rows, to the matrix of the tensor code.
+    pub fn extend(
+        &self,
+        data: &[Vec<FieldElement<F>>],
+    ) -> Result<Vec<Vec<FieldElement<F>>>, ReedSolomonError> {
+        if data.len() != self.column_code.message_length() {
+            return Err(ReedSolomonError::WrongLength(
+                self.column_code.message_length(),
+                data.len(),
+            ));
+        }
+        let rows = data
+            .iter()
+            .map(|row| self.row_code.encode(row))
+            .collect::<Result<Vec<_>, _>>()?;
+        let columns = (0..self.row_code.codeword_length())
+            .map(|j| {
+                let column: Vec<_> = rows.iter().map(|row| row[j].clone()).collect();
+                self.column_code.encode(&column)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(transpose(&columns))
+    }
+
+    /// Returns the data of an extended matrix, which is in the entries whose row and column
+    /// are multiples of the blowup factors of the codes.
+    pub fn data(
+        &self,
+        extended: &[Vec<FieldElement<F>>],
+    ) -> Result<Vec<Vec<FieldElement<F>>>, ReedSolomonError> {
+        if extended.len() != self.column_code.codeword_length() {
+            return Err(ReedSolomonError::WrongLength(
+                self.column_code.codeword_length(),
+                extended.len(),
+            ));
+        }
+        extended
+            .iter()
+            .step_by(self.column_code.blowup_factor())
+            .map(|row| self.row_code.message(row))
+            .collect()
+    }
+
+    /// Recovers an extended matrix from some of its entries, with `None` in the missing ones.
+    ///
+    /// Rows and columns with enough entries are recovered in turns, each one giving new entries
+    /// to the others, until the matrix is complete. Returns an error if it gets stuck before.
+    pub fn recover(
+        &self,
+        entries: &[Vec<Option<FieldElement<F>>>],
+    ) -> Result<Vec<Vec<FieldElement<F>>>, ReedSolomonError> {
+        let (height, width) = (
+            self.column_code.codeword_length(),
+            self.row_code.codeword_length(),
+        );
+        if entries.len() != height {
+            return Err(ReedSolomonError::WrongLength(height, entries.len()));
+        }
+        if let Some(row) = entries.iter().find(|row| row.len() != width) {
+            return Err(ReedSolomonError::WrongLength(width, row.len()));
+        }
+
+        let mut rows = entries.to_vec();
+        loop {
+            let mut progress = recover_lines(&self.row_code, &mut rows)?;
+            let mut columns = transpose(&rows);
+            progress |= recover_lines(&self.column_code, &mut columns)?;
+            rows = transpose(&columns);
+
+            let missing = rows.iter().flatten().filter(|e| e.is_none()).count();
+            if missing == 0 {
+                return Ok(rows
+                    .into_iter()
+                    .map(|row| row.into_iter().flatten().collect())
+                    .collect());
+            }
+            if !progress {
+                return Err(ReedSolomonError::UnrecoverableEntries(missing));
+            }
+        }
+    }
+}
+
+/// Recovers every line that is incomplete but has enough entries to be recovered with `code`.
+/// Returns whether any line was recovered.
+fn recover_lines<F: IsFFTField>(
+    code: &ReedSolomonCode<F>,
+    lines: &mut [Vec<Option<FieldElement<F>>>],
+) -> Result<bool, ReedSolomonError> {
+    let mut progress = false;
+    for line in lines.iter_mut() {
+        let shares: Vec<_> = line
+            .iter()
+            .enumerate()
+            .filter_map(|(i, entry)| entry.clone().map(|entry| (i, entry)))
+            .collect();
+        if shares.len() < line.len() && shares.len() >= code.message_length() {
+            *line = code.recover(&shares)?.into_iter().map(Some).collect();
+            progress = true;
+        }
+    }
+    Ok(progress)
+}
+
+fn transpose<T: Clone>(matrix: &[Vec<T>]) -> Vec<Vec<T>> {
+    match matrix.first() {
+        Some(first) => (0..first.len())
+            .map(|j| matrix.iter().map(|row| row[j].clone()).collect())
+            .collect(),
+        None => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::test_fields::u64_test_field::U64TestField;
+
+    type FE = FieldElement<U64TestField>;
+
+    fn tensor_code() -> TensorCode<U64TestField> {
+        TensorCode::new(
+            ReedSolomonCode::new(4, 2).unwrap(),
+            ReedSolomonCode::new_with_offset(2, 2, FE::from(3)).unwrap(),
+        )
+    }
+
+    fn data() -> Vec<Vec<FE>> {
+        vec![
+            [1, 2, 3, 4].iter().map(|v| FE::from(*v)).collect(),
+            [5, 6, 7, 8].iter().map(|v| FE::from(*v)).collect(),
+        ]
+    }
+
+    #[test]
+    fn rows_and_columns_of_the_extension_are_codewords() {
+        let code = tensor_code();
+        let extended = code.extend(&data()).unwrap();
+
+        assert_eq!(extended.len(), 4);
+        for row in &extended {
+            let message = code.row_code().message(row).unwrap();
+            assert_eq!(&code.row_code().encode(&message).unwrap(), row);
+        }
+        for column in transpose(&extended) {
+            let message = code.column_code().message(&column).unwrap();
+            assert_eq!(code.column_code().encode(&message).unwrap(), column);
+        }
+        assert_eq!(code.data(&extended).unwrap(), data());
+    }
+
+    #[test]
+    fn a_quarter_of_the_extension_recovers_it() {
+        let code = tensor_code();
+        let extended = code.extend(&data()).unwrap();
+        // Keeps the last two rows and the last four columns.
+        let entries: Vec<Vec<_>> = extended
+            .iter()
+            .enumerate()
+            .map(|(i, row)| {
+                row.iter()
+                    .enumerate()
+                    .map(|(j, entry)| (i >= 2 && j >= 4).then(|| entry.clone()))
+                    .collect()
+            })
+            .collect();
+
+        assert_eq!(code.recover(&entries).unwrap(), extended);
+    }
+
+    #[test]
+    fn recovery_fails_without_enough_entries() {
+        let code = tensor_code();
+        let extended = code.extend(&data()).unwrap();
+        let entries: Vec<Vec<_>> = extended
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .enumerate()
+                    .map(|(j, entry)| (j < 3).then(|| entry.clone()))
+                    .collect()
+            })
+            .collect();
+
+        assert!(matches!(
+            code.recover(&entries),
+            Err(ReedSolomonError::UnrecoverableEntries(20))
+        ));
+    }
+}
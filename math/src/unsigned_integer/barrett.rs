@@ -0,0 +1,151 @@
+use super::element::UnsignedInteger;
+
+pub struct BarrettAlgorithms;
+impl BarrettAlgorithms {
+    /// Computes `floor(2^{2n} / q) - 2^n`, where `n` is the bit size of the modulus `q`.
+    /// The quotient lies between `2^n` and `2^{n+1}` when `q` is not a power of two, so its
+    /// leading bit is dropped to always fit in `NUM_LIMBS` limbs, even if `q` uses all of them.
+    pub const fn compute_mu<const NUM_LIMBS: usize>(
+        q: &UnsignedInteger<NUM_LIMBS>,
+    ) -> UnsignedInteger<NUM_LIMBS> {
+        let n = q.bits_le();
+        let mut remainder = UnsignedInteger::from_u64(0);
+        let mut mu = [0u64; NUM_LIMBS];
+
+        // Long division of 2^{2n} by q, one bit of the dividend at a time.
+        let mut i = 2 * n + 1;
+        while i > 0 {
+            i -= 1;
+            let (mut doubled, overflow) = UnsignedInteger::add(&remainder, &remainder);
+            if i == 2 * n {
+                doubled.limbs[NUM_LIMBS - 1] |= 1;
+            }
+            if overflow || UnsignedInteger::const_le(q, &doubled) {
+                doubled = UnsignedInteger::sub(&doubled, q).0;
+                if i < n {
+                    mu[NUM_LIMBS - 1 - i / 64] |= 1 << (i % 64);
+                }
+            }
+            remainder = doubled;
+        }
+        UnsignedInteger::from_limbs(mu)
+    }
+
+    /// Returns `floor((hi * 2^{64 * NUM_LIMBS} + lo) / 2^n)`, which must fit in `NUM_LIMBS` limbs.
+    /// `n` must be positive and at most `64 * NUM_LIMBS`.
+    #[inline(always)]
+    pub fn shr_wide<const NUM_LIMBS: usize>(
+        hi: &UnsignedInteger<NUM_LIMBS>,
+        lo: &UnsignedInteger<NUM_LIMBS>,
+        n: usize,
+    ) -> UnsignedInteger<NUM_LIMBS> {
+        if n == 64 * NUM_LIMBS {
+            *hi
+        } else {
+            (hi << (64 * NUM_LIMBS - n)) | (lo >> n)
+        }
+    }
+
+    /// Computes `(hi * 2^{64 * NUM_LIMBS} + lo) mod q` for values below `2^{2n}`, such as
+    /// products of two residues. `n` is the bit size of `q` and `mu` the output of `compute_mu`.
+    ///
+    /// The quotient is estimated as `floor(floor(x / 2^n) * (2^n + mu) / 2^n)`, which falls short
+    /// of `floor(x / q)` by at most 3, so the remainder is fixed with a few subtractions.
+    /// See section 14.3.3 of "Handbook of Applied Cryptography" (https://cacr.uwaterloo.ca/hac/)
+    #[inline(always)]
+    pub fn reduce<const NUM_LIMBS: usize>(
+        hi: &UnsignedInteger<NUM_LIMBS>,
+        lo: &UnsignedInteger<NUM_LIMBS>,
+        q: &UnsignedInteger<NUM_LIMBS>,
+        mu: &UnsignedInteger<NUM_LIMBS>,
+        n: usize,
+    ) -> UnsignedInteger<NUM_LIMBS> {
+        let x1 = Self::shr_wide(hi, lo, n);
+        let (prod_hi, prod_lo) = UnsignedInteger::mul(&x1, mu);
+        // It doesn't overflow, since it's at most `floor(x / q)`, which is below q.
+        let estimate = x1 + Self::shr_wide(&prod_hi, &prod_lo, n);
+
+        // The remainder is below 4q, so only the lowest limb of its high half can be nonzero
+        // and it's enough to compute that limb modulo 2^64.
+        let (estimate_q_hi, estimate_q_lo) = UnsignedInteger::mul(&estimate, q);
+        let (mut remainder, borrow) = UnsignedInteger::sub(lo, &estimate_q_lo);
+        let mut remainder_hi = hi.limbs[NUM_LIMBS - 1]
+            .wrapping_sub(estimate_q_hi.limbs[NUM_LIMBS - 1])
+            .wrapping_sub(borrow as u64);
+
+        while remainder_hi != 0 || remainder >= *q {
+            let (diff, borrow) = UnsignedInteger::sub(&remainder, q);
+            remainder = diff;
+            remainder_hi -= borrow as u64;
+        }
+        remainder
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unsigned_integer::element::{U256, U64};
+    use proptest::prelude::*;
+
+    // secp256k1 base field modulus, which uses the 256 bits
+    const Q: U256 = U256::from_hex_unchecked(
+        "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f",
+    );
+
+    #[test]
+    fn mu_of_small_modulus_is_computed_correctly() {
+        // floor(2^10 / 23) - 2^5 = 44 - 32
+        assert_eq!(
+            BarrettAlgorithms::compute_mu(&U64::from_u64(23)),
+            U64::from_u64(12)
+        );
+    }
+
+    #[test]
+    fn mu_of_full_width_modulus_is_computed_correctly() {
+        // 2^512 / q = 2^256 + c + c^2 / 2^256 + ..., where c = 2^256 - q = 2^32 + 977,
+        // and c^2 is way below 2^256.
+        let c = U256::from_u64(0x1000003d1);
+        assert_eq!(BarrettAlgorithms::compute_mu(&Q), c);
+    }
+
+    /// Computes `(hi * 2^256 + lo) mod q` with a 512-bit division.
+    fn reference_reduce(hi: &U256, lo: &U256, q: &U256) -> U256 {
+        let mut x = [0u64; 8];
+        x[..4].copy_from_slice(&hi.limbs);
+        x[4..].copy_from_slice(&lo.limbs);
+        let mut wide_q = [0u64; 8];
+        wide_q[4..].copy_from_slice(&q.limbs);
+        let remainder = UnsignedInteger::from_limbs(x)
+            .div_rem(&UnsignedInteger::from_limbs(wide_q))
+            .1;
+        U256::from_limbs(remainder.limbs[4..].try_into().unwrap())
+    }
+
+    proptest! {
+        #[test]
+        fn reduce_of_full_width_modulus_matches_division(a in any::<[u64; 4]>(), b in any::<[u64; 4]>()) {
+            let a = U256::from_limbs(a).div_rem(&Q).1;
+            let b = U256::from_limbs(b).div_rem(&Q).1;
+            let (hi, lo) = UnsignedInteger::mul(&a, &b);
+            let mu = BarrettAlgorithms::compute_mu(&Q);
+            let reduced = BarrettAlgorithms::reduce(&hi, &lo, &Q, &mu, 256);
+            prop_assert_eq!(reduced, reference_reduce(&hi, &lo, &Q));
+        }
+
+        #[test]
+        fn reduce_of_smaller_modulus_matches_division(a in any::<[u64; 4]>(), b in any::<[u64; 4]>()) {
+            // 2^255 - 19
+            let q = U256::from_hex_unchecked(
+                "7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffed",
+            );
+            let a = U256::from_limbs(a).div_rem(&q).1;
+            let b = U256::from_limbs(b).div_rem(&q).1;
+            let (hi, lo) = UnsignedInteger::mul(&a, &b);
+            let mu = BarrettAlgorithms::compute_mu(&q);
+            let reduced = BarrettAlgorithms::reduce(&hi, &lo, &q, &mu, 255);
+            prop_assert_eq!(reduced, reference_reduce(&hi, &lo, &q));
+        }
+    }
+}
@@ -30,6 +30,7 @@ pub type U64 = UnsignedInteger<1>;
 /// That is, the array `[a_n, ..., a_0]` represents the
 /// integer 2^{64 * n} * a_n + ... + 2^{64} * a_1 + a_0.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(transparent)]
 pub struct UnsignedInteger<const NUM_LIMBS: usize> {
     pub limbs: [u64; NUM_LIMBS],
 }
@@ -392,6 +393,21 @@ impl<const NUM_LIMBS: usize> UnsignedInteger<NUM_LIMBS> {
         Self { limbs }
     }
 
+    /// Returns the limbs of all the integers in `values`, one integer after the other.
+    pub fn slice_as_limbs(values: &[Self]) -> &[u64] {
+        // SAFETY: `UnsignedInteger` is `repr(transparent)` over `[u64; NUM_LIMBS]`.
+        unsafe { core::slice::from_raw_parts(values.as_ptr().cast(), values.len() * NUM_LIMBS) }
+    }
+
+    /// Returns the limbs of all the integers in `values` as a mutable slice, one integer
+    /// after the other.
+    pub fn slice_as_limbs_mut(values: &mut [Self]) -> &mut [u64] {
+        // SAFETY: `UnsignedInteger` is `repr(transparent)` over `[u64; NUM_LIMBS]`.
+        unsafe {
+            core::slice::from_raw_parts_mut(values.as_mut_ptr().cast(), values.len() * NUM_LIMBS)
+        }
+    }
+
     #[inline(always)]
     pub const fn from_u64(value: u64) -> Self {
         let mut limbs = [0u64; NUM_LIMBS];
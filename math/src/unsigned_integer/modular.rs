@@ -0,0 +1,151 @@
+use super::element::UnsignedInteger;
+
+/// Arithmetic on residues modulo `q` kept in canonical form, that is, as integers in `[0, q)`.
+/// It is shared by the prime field backends that don't change the representation of the
+/// elements, such as the Barrett and Solinas ones.
+pub struct ModularAlgorithms;
+impl ModularAlgorithms {
+    /// Computes `a + b mod q`.
+    #[inline(always)]
+    pub fn add<const NUM_LIMBS: usize>(
+        a: &UnsignedInteger<NUM_LIMBS>,
+        b: &UnsignedInteger<NUM_LIMBS>,
+        q: &UnsignedInteger<NUM_LIMBS>,
+    ) -> UnsignedInteger<NUM_LIMBS> {
+        let (sum, overflow) = UnsignedInteger::add(a, b);
+        if overflow || sum >= *q {
+            UnsignedInteger::sub(&sum, q).0
+        } else {
+            sum
+        }
+    }
+
+    /// Computes `a - b mod q`.
+    #[inline(always)]
+    pub fn sub<const NUM_LIMBS: usize>(
+        a: &UnsignedInteger<NUM_LIMBS>,
+        b: &UnsignedInteger<NUM_LIMBS>,
+        q: &UnsignedInteger<NUM_LIMBS>,
+    ) -> UnsignedInteger<NUM_LIMBS> {
+        let (diff, borrow) = UnsignedInteger::sub(a, b);
+        if borrow {
+            UnsignedInteger::add(&diff, q).0
+        } else {
+            diff
+        }
+    }
+
+    /// Computes `-a mod q`.
+    #[inline(always)]
+    pub fn neg<const NUM_LIMBS: usize>(
+        a: &UnsignedInteger<NUM_LIMBS>,
+        q: &UnsignedInteger<NUM_LIMBS>,
+    ) -> UnsignedInteger<NUM_LIMBS> {
+        if *a == UnsignedInteger::from_u64(0) {
+            *a
+        } else {
+            q - a
+        }
+    }
+
+    /// Computes `a / 2 mod q` for an odd modulus `q`.
+    #[inline(always)]
+    fn half<const NUM_LIMBS: usize>(
+        a: &UnsignedInteger<NUM_LIMBS>,
+        q: &UnsignedInteger<NUM_LIMBS>,
+    ) -> UnsignedInteger<NUM_LIMBS> {
+        if a.limbs[NUM_LIMBS - 1] & 1 == 0 {
+            a >> 1
+        } else {
+            let (sum, carry) = UnsignedInteger::add(a, q);
+            let mut half = sum >> 1;
+            if carry {
+                half.limbs[0] |= 1 << 63;
+            }
+            half
+        }
+    }
+
+    /// Computes the inverse of a nonzero `a` modulo an odd prime `q`.
+    /// Guajardo Kumar Paar Pelzl
+    /// Efficient Software-Implementation of Finite Fields with Applications to
+    /// Cryptography
+    /// Algorithm 16 (BEA for Inversion in Fp)
+    pub fn inv<const NUM_LIMBS: usize>(
+        a: &UnsignedInteger<NUM_LIMBS>,
+        q: &UnsignedInteger<NUM_LIMBS>,
+    ) -> UnsignedInteger<NUM_LIMBS> {
+        let one = UnsignedInteger::from_u64(1);
+
+        let mut u = *a;
+        let mut v = *q;
+        let mut b = one;
+        let mut c = UnsignedInteger::from_u64(0);
+
+        while u != one && v != one {
+            while u.limbs[NUM_LIMBS - 1] & 1 == 0 {
+                u >>= 1;
+                b = Self::half(&b, q);
+            }
+
+            while v.limbs[NUM_LIMBS - 1] & 1 == 0 {
+                v >>= 1;
+                c = Self::half(&c, q);
+            }
+
+            if v <= u {
+                u = u - v;
+                b = Self::sub(&b, &c, q);
+            } else {
+                v = v - u;
+                c = Self::sub(&c, &b, q);
+            }
+        }
+
+        if u == one {
+            b
+        } else {
+            c
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unsigned_integer::element::U256;
+
+    // secp256k1 base field modulus
+    const Q: U256 = U256::from_hex_unchecked(
+        "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f",
+    );
+
+    #[test]
+    fn add_wraps_around_the_modulus() {
+        let a = Q - U256::from_u64(1);
+        assert_eq!(
+            ModularAlgorithms::add(&a, &U256::from_u64(3), &Q),
+            U256::from_u64(2)
+        );
+    }
+
+    #[test]
+    fn sub_wraps_around_the_modulus() {
+        assert_eq!(
+            ModularAlgorithms::sub(&U256::from_u64(1), &U256::from_u64(3), &Q),
+            Q - U256::from_u64(2)
+        );
+    }
+
+    #[test]
+    fn neg_zero_is_zero() {
+        let zero = U256::from_u64(0);
+        assert_eq!(ModularAlgorithms::neg(&zero, &Q), zero);
+    }
+
+    #[test]
+    fn inverse_of_two_is_half_of_modulus_plus_one() {
+        let expected = (Q + U256::from_u64(1)) >> 1;
+        assert_eq!(ModularAlgorithms::inv(&U256::from_u64(2), &Q), expected);
+    }
+}
@@ -2,6 +2,60 @@ use super::element::UnsignedInteger;
 
 pub struct MontgomeryAlgorithms;
 impl MontgomeryAlgorithms {
+    /// Computes `- modulus^{-1} mod 2^{64}`
+    /// This algorithm is given  by Dussé and Kaliski Jr. in
+    /// "S. R. Dussé and B. S. Kaliski Jr. A cryptographic library for the Motorola
+    /// DSP56000. In I. Damgård, editor, Advances in Cryptology – EUROCRYPT’90,
+    /// volume 473 of Lecture Notes in Computer Science, pages 230–244. Springer,
+    /// Heidelberg, May 1991."
+    pub const fn compute_mu_parameter<const NUM_LIMBS: usize>(
+        modulus: &UnsignedInteger<NUM_LIMBS>,
+    ) -> u64 {
+        let mut y = 1;
+        let word_size = 64;
+        let mut i: usize = 2;
+        while i <= word_size {
+            let (_, lo) = UnsignedInteger::mul(modulus, &UnsignedInteger::from_u64(y));
+            let least_significant_limb = lo.limbs[NUM_LIMBS - 1];
+            if (least_significant_limb << (word_size - i)) >> (word_size - i) != 1 {
+                y += 1 << (i - 1);
+            }
+            i += 1;
+        }
+        y.wrapping_neg()
+    }
+
+    /// Computes 2^{384 * 2} modulo `modulus`
+    pub const fn compute_r2_parameter<const NUM_LIMBS: usize>(
+        modulus: &UnsignedInteger<NUM_LIMBS>,
+    ) -> UnsignedInteger<NUM_LIMBS> {
+        let word_size = 64;
+        let mut l: usize = 0;
+        let zero = UnsignedInteger::from_u64(0);
+        // Define `c` as the largest power of 2 smaller than `modulus`
+        while l < NUM_LIMBS * word_size {
+            if UnsignedInteger::const_ne(&modulus.const_shr(l), &zero) {
+                break;
+            }
+            l += 1;
+        }
+        let mut c = UnsignedInteger::from_u64(1).const_shl(l);
+
+        // Double `c` and reduce modulo `modulus` until getting
+        // `2^{2 * number_limbs * word_size}` mod `modulus`
+        let mut i: usize = 1;
+        while i <= 2 * NUM_LIMBS * word_size - l {
+            let (double_c, overflow) = UnsignedInteger::add(&c, &c);
+            c = if UnsignedInteger::const_le(modulus, &double_c) || overflow {
+                UnsignedInteger::sub(&double_c, modulus).0
+            } else {
+                double_c
+            };
+            i += 1;
+        }
+        c
+    }
+
     /// Compute CIOS multiplication of `a` * `b`
     /// `q` is the modulus
     /// `mu` is the inverse of -q modulo 2^{64}
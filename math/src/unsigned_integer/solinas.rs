@@ -0,0 +1,199 @@
+use super::{barrett::BarrettAlgorithms, element::UnsignedInteger};
+
+pub struct SolinasAlgorithms;
+impl SolinasAlgorithms {
+    /// Computes `2^n - q`, where `n` is the bit size of the modulus `q`.
+    pub const fn compute_c<const NUM_LIMBS: usize>(
+        q: &UnsignedInteger<NUM_LIMBS>,
+    ) -> UnsignedInteger<NUM_LIMBS> {
+        let n = q.bits_le();
+        let zero = UnsignedInteger::from_u64(0);
+        if n == 64 * NUM_LIMBS {
+            UnsignedInteger::sub(&zero, q).0
+        } else {
+            let power = UnsignedInteger::from_u64(1).const_shl(n);
+            UnsignedInteger::sub(&power, q).0
+        }
+    }
+
+    /// Multi-precision multiplication which skips the zero limbs of `c`. Moduli of special
+    /// form have very few nonzero limbs in `c`, such as one for secp256k1 and two for p448.
+    /// The loops are the ones of `UnsignedInteger::mul`.
+    #[inline(always)]
+    fn mul_by_sparse<const NUM_LIMBS: usize>(
+        a: &UnsignedInteger<NUM_LIMBS>,
+        c: &UnsignedInteger<NUM_LIMBS>,
+    ) -> (UnsignedInteger<NUM_LIMBS>, UnsignedInteger<NUM_LIMBS>) {
+        let mut hi = [0u64; NUM_LIMBS];
+        let mut lo = [0u64; NUM_LIMBS];
+        let mut i = NUM_LIMBS;
+        while i > 0 {
+            i -= 1;
+            if c.limbs[i] == 0 {
+                continue;
+            }
+            let mut carry = 0u128;
+            let mut j = NUM_LIMBS;
+            while j > 0 {
+                j -= 1;
+                let k = i + j;
+                if k >= NUM_LIMBS - 1 {
+                    let index = k + 1 - NUM_LIMBS;
+                    let uv =
+                        (lo[index] as u128) + (a.limbs[j] as u128) * (c.limbs[i] as u128) + carry;
+                    carry = uv >> 64;
+                    lo[index] = uv as u64;
+                } else {
+                    let uv =
+                        (hi[k + 1] as u128) + (a.limbs[j] as u128) * (c.limbs[i] as u128) + carry;
+                    carry = uv >> 64;
+                    hi[k + 1] = uv as u64;
+                }
+            }
+            hi[i] = carry as u64;
+        }
+        (
+            UnsignedInteger::from_limbs(hi),
+            UnsignedInteger::from_limbs(lo),
+        )
+    }
+
+    /// Returns `x mod 2^n` for `n` at most `64 * NUM_LIMBS`.
+    #[inline(always)]
+    fn low_bits<const NUM_LIMBS: usize>(
+        x: &UnsignedInteger<NUM_LIMBS>,
+        n: usize,
+    ) -> UnsignedInteger<NUM_LIMBS> {
+        if n == 64 * NUM_LIMBS {
+            *x
+        } else {
+            let mut low = *x;
+            let mut i = 0;
+            while (NUM_LIMBS - i) * 64 > n {
+                let bits_to_keep = n.saturating_sub((NUM_LIMBS - 1 - i) * 64);
+                low.limbs[i] &= if bits_to_keep == 0 {
+                    0
+                } else {
+                    u64::MAX >> (64 - bits_to_keep)
+                };
+                i += 1;
+            }
+            low
+        }
+    }
+
+    /// Computes `(hi * 2^{64 * NUM_LIMBS} + lo) mod q` for values below `2^{2n}`, such as
+    /// products of two residues, for a modulus of the form `q = 2^n - c`, where `n` is the bit
+    /// size of `q` and `c` the output of `compute_c`.
+    ///
+    /// Since `2^n = c mod q`, writing `x = h * 2^n + l` gives `x = h * c + l mod q`, which is
+    /// smaller than `x` by about `n - bits(c)` bits. This is repeated until `x` is below `2^n`,
+    /// which takes two or three steps when `c` has at most `n / 2` bits.
+    #[inline(always)]
+    pub fn reduce<const NUM_LIMBS: usize>(
+        hi: &UnsignedInteger<NUM_LIMBS>,
+        lo: &UnsignedInteger<NUM_LIMBS>,
+        q: &UnsignedInteger<NUM_LIMBS>,
+        c: &UnsignedInteger<NUM_LIMBS>,
+        n: usize,
+    ) -> UnsignedInteger<NUM_LIMBS> {
+        let zero = UnsignedInteger::from_u64(0);
+        let mut h = BarrettAlgorithms::shr_wide(hi, lo, n);
+        let mut l = Self::low_bits(lo, n);
+
+        while h != zero {
+            let (hc_hi, hc_lo) = Self::mul_by_sparse(&h, c);
+            let (sum_lo, carry) = UnsignedInteger::add(&hc_lo, &l);
+            let sum_hi = if carry {
+                hc_hi + UnsignedInteger::from_u64(1)
+            } else {
+                hc_hi
+            };
+            h = BarrettAlgorithms::shr_wide(&sum_hi, &sum_lo, n);
+            l = Self::low_bits(&sum_lo, n);
+        }
+
+        // `l` is below 2^n, which is less than 2q.
+        if l >= *q {
+            l - q
+        } else {
+            l
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unsigned_integer::element::{U256, U64};
+    use proptest::prelude::*;
+
+    /// Computes `(hi * 2^256 + lo) mod q` with a 512-bit division.
+    fn reference_reduce(hi: &U256, lo: &U256, q: &U256) -> U256 {
+        let mut x = [0u64; 8];
+        x[..4].copy_from_slice(&hi.limbs);
+        x[4..].copy_from_slice(&lo.limbs);
+        let mut wide_q = [0u64; 8];
+        wide_q[4..].copy_from_slice(&q.limbs);
+        let remainder = UnsignedInteger::from_limbs(x)
+            .div_rem(&UnsignedInteger::from_limbs(wide_q))
+            .1;
+        U256::from_limbs(remainder.limbs[4..].try_into().unwrap())
+    }
+
+    #[test]
+    fn c_of_secp256k1_modulus_is_computed_correctly() {
+        let q = U256::from_hex_unchecked(
+            "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f",
+        );
+        assert_eq!(
+            SolinasAlgorithms::compute_c(&q),
+            U256::from_u64(0x1000003d1)
+        );
+    }
+
+    #[test]
+    fn c_of_mersenne_modulus_is_one() {
+        let q = U64::from_u64((1 << 61) - 1);
+        assert_eq!(SolinasAlgorithms::compute_c(&q), U64::from_u64(1));
+    }
+
+    #[test]
+    fn low_bits_clears_the_bits_above_n() {
+        let x = U256::from_hex_unchecked(
+            "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+        );
+        assert_eq!(
+            SolinasAlgorithms::low_bits(&x, 100),
+            (U256::from_u64(1) << 100) - U256::from_u64(1)
+        );
+        assert_eq!(
+            SolinasAlgorithms::low_bits(&x, 128),
+            U256::from_u128(u128::MAX)
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn reduce_matches_division(
+            a in any::<[u64; 4]>(),
+            b in any::<[u64; 4]>(),
+            modulus in prop_oneof![
+                // secp256k1
+                Just("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f"),
+                // 2^255 - 19
+                Just("7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffed"),
+                // P-256, whose c has many nonzero limbs
+                Just("ffffffff00000001000000000000000000000000ffffffffffffffffffffffff"),
+            ],
+        ) {
+            let q = U256::from_hex_unchecked(modulus);
+            let a = U256::from_limbs(a).div_rem(&q).1;
+            let b = U256::from_limbs(b).div_rem(&q).1;
+            let (hi, lo) = UnsignedInteger::mul(&a, &b);
+            let c = SolinasAlgorithms::compute_c(&q);
+            let reduced = SolinasAlgorithms::reduce(&hi, &lo, &q, &c, q.bits_le());
+            prop_assert_eq!(reduced, reference_reduce(&hi, &lo, &q));
+        }
+    }
+}
@@ -18,8 +18,7 @@ use super::boundary::BoundaryConstraints;
 #[cfg(all(debug_assertions, not(feature = "parallel")))]
 use crate::debug::check_boundary_polys_divisibility;
 use crate::traits::AIR;
-use crate::{domain::Domain, table::EvaluationTable};
-use crate::{frame::Frame, prover::evaluate_polynomial_on_lde_domain};
+use crate::{domain::Domain, frame::Frame, table::EvaluationTable};
 
 pub struct ConstraintEvaluator<A: AIR> {
     boundary_constraints: BoundaryConstraints<A::FieldExtension>,
@@ -74,14 +73,7 @@ impl<A: AIR> ConstraintEvaluator<A> {
         let lde_periodic_columns = air
             .get_periodic_column_polynomials()
             .iter()
-            .map(|poly| {
-                evaluate_polynomial_on_lde_domain(
-                    poly,
-                    domain.blowup_factor,
-                    domain.interpolation_domain_size,
-                    &domain.coset_offset,
-                )
-            })
+            .map(|poly| domain.lde_fft_domain.evaluate::<A::Field>(poly))
             .collect::<Result<Vec<Vec<FieldElement<A::Field>>>, FFTError>>()
             .unwrap();
 
@@ -273,14 +265,6 @@ where
     let exemptions_iter = transition_exemptions.iter();
 
     exemptions_iter
-        .map(|exemption| {
-            evaluate_polynomial_on_lde_domain(
-                exemption,
-                domain.blowup_factor,
-                domain.interpolation_domain_size,
-                &domain.coset_offset,
-            )
-            .unwrap()
-        })
+        .map(|exemption| domain.lde_fft_domain.evaluate(exemption).unwrap())
         .collect()
 }
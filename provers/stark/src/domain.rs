@@ -1,5 +1,5 @@
 use lambdaworks_math::{
-    fft::cpu::roots_of_unity::get_powers_of_primitive_root_coset,
+    fft::{cpu::roots_of_unity::get_powers_of_primitive_root_coset, domain::FFTDomain},
     field::{element::FieldElement, traits::IsFFTField},
 };
 
@@ -8,10 +8,9 @@ use super::traits::AIR;
 pub struct Domain<F: IsFFTField> {
     pub(crate) root_order: u32,
     pub(crate) lde_roots_of_unity_coset: Vec<FieldElement<F>>,
+    pub(crate) lde_fft_domain: FFTDomain<F>,
     pub(crate) trace_primitive_root: FieldElement<F>,
     pub(crate) trace_roots_of_unity: Vec<FieldElement<F>>,
-    pub(crate) coset_offset: FieldElement<F>,
-    pub(crate) blowup_factor: usize,
     pub(crate) interpolation_domain_size: usize,
 }
 
@@ -41,14 +40,15 @@ impl<F: IsFFTField> Domain<F> {
             &coset_offset,
         )
         .unwrap();
+        let lde_fft_domain =
+            FFTDomain::new_with_offset(lde_root_order as u64, coset_offset).unwrap();
 
         Self {
             root_order,
             lde_roots_of_unity_coset,
+            lde_fft_domain,
             trace_primitive_root,
             trace_roots_of_unity,
-            blowup_factor,
-            coset_offset,
             interpolation_domain_size,
         }
     }
@@ -202,14 +202,7 @@ pub trait IsStarkProver<A: AIR> {
         let trace_polys_iter = trace_polys.par_iter();
 
         trace_polys_iter
-            .map(|poly| {
-                evaluate_polynomial_on_lde_domain(
-                    poly,
-                    domain.blowup_factor,
-                    domain.interpolation_domain_size,
-                    &domain.coset_offset,
-                )
-            })
+            .map(|poly| domain.lde_fft_domain.evaluate(poly))
             .collect::<Result<Vec<Vec<FieldElement<E>>>, FFTError>>()
             .unwrap()
     }
@@ -313,25 +306,18 @@ pub trait IsStarkProver<A: AIR> {
         );
 
         // Get coefficients of the composition poly H
-        let composition_poly =
-            Polynomial::interpolate_offset_fft(&constraint_evaluations, &domain.coset_offset)
-                .unwrap();
+        let composition_poly = domain
+            .lde_fft_domain
+            .interpolate(&constraint_evaluations)
+            .unwrap();
 
         let number_of_parts = air.composition_poly_degree_bound() / air.trace_length();
         let composition_poly_parts = composition_poly.break_in_parts(number_of_parts);
 
-        let lde_composition_poly_parts_evaluations: Vec<_> = composition_poly_parts
-            .iter()
-            .map(|part| {
-                evaluate_polynomial_on_lde_domain(
-                    part,
-                    domain.blowup_factor,
-                    domain.interpolation_domain_size,
-                    &domain.coset_offset,
-                )
-                .unwrap()
-            })
-            .collect();
+        let lde_composition_poly_parts_evaluations = domain
+            .lde_fft_domain
+            .evaluate_batch(&composition_poly_parts)
+            .unwrap();
 
         let (composition_poly_merkle_tree, composition_poly_root) =
             Self::commit_composition_polynomial(&lde_composition_poly_parts_evaluations);
@@ -992,10 +978,13 @@ mod tests {
             &pub_inputs,
             &proof_options,
         ));
-        assert_eq!(domain.blowup_factor, 2);
+        assert_eq!(domain.lde_fft_domain.size(), trace_length * blowup_factor);
         assert_eq!(domain.interpolation_domain_size, trace_length);
         assert_eq!(domain.root_order, trace_length.trailing_zeros());
-        assert_eq!(domain.coset_offset, FieldElement::from(coset_offset));
+        assert_eq!(
+            *domain.lde_fft_domain.offset(),
+            FieldElement::from(coset_offset)
+        );
 
         let primitive_root = Stark252PrimeField::get_primitive_root_of_unity(
             (trace_length * blowup_factor).trailing_zeros() as u64,